@@ -49,6 +49,7 @@ pub fn provision_import_test(
     let shared_notify = Arc::new(Notify::new());
     let params = Config {
         header_batch_size: header_batch_size as usize,
+        fetch_full_blocks: false,
         block_stream_buffer_size,
     };
     let p2p = Arc::new(PressurePeerToPeer::new(
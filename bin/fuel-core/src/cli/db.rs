@@ -0,0 +1,159 @@
+use crate::cli::{
+    run::rocksdb::RocksDbArgs,
+    DEFAULT_DB_PATH,
+};
+use clap::{
+    Parser,
+    Subcommand,
+};
+use std::path::PathBuf;
+
+/// Maintenance operations over the database of the node.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The sub-command of the database operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Creates a backup of the database of the stopped node.
+    /// Use the `backupDatabase` GraphQL mutation to back up the running node.
+    #[command(arg_required_else_help = true)]
+    Backup {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+        )]
+        database_path: PathBuf,
+        /// The path to the empty directory where the backup will be created.
+        #[clap(long = "backup-path", value_parser)]
+        backup_path: PathBuf,
+        #[clap(flatten)]
+        rocksdb_args: RocksDbArgs,
+    },
+    /// Restores the database from the backup after verifying its consistency.
+    #[command(arg_required_else_help = true)]
+    Restore {
+        /// The path to the empty directory where the database will be restored.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+        )]
+        database_path: PathBuf,
+        /// The path to the backup.
+        #[clap(long = "backup-path", value_parser)]
+        backup_path: PathBuf,
+        /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+        /// The backup is restored only if it was created for the same chain config.
+        #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
+        chain_config: String,
+    },
+    /// Verifies the integrity of the database of the stopped node and
+    /// reports every found discrepancy.
+    Check {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+        )]
+        database_path: PathBuf,
+        /// Repairs the discrepancies in the data derived from other tables,
+        /// like indexes and merkle trees of contracts. It also backfills
+        /// the indexed balances of databases created by older versions.
+        #[clap(long = "repair")]
+        repair: bool,
+        #[clap(flatten)]
+        rocksdb_args: RocksDbArgs,
+    },
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(_: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database commands"
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::{
+        chain_config::ChainConfig,
+        combined_database::CombinedDatabase,
+    };
+
+    /// The cache size is not important for the maintenance operations.
+    const CACHE_SIZE: usize = 10 * 1024 * 1024;
+
+    match command.subcommand {
+        SubCommands::Backup {
+            database_path,
+            backup_path,
+            rocksdb_args,
+        } => {
+            let config = rocksdb_args.into_config()?;
+            let database = CombinedDatabase::open(&database_path, CACHE_SIZE, &config)
+                .map_err(Into::<anyhow::Error>::into)
+                .context(format!(
+                    "failed to open database at path {}",
+                    database_path.display()
+                ))?;
+            let metadata = database.backup(&backup_path)?;
+            tracing::info!(
+                "Created the backup at {} for the block height {}",
+                backup_path.display(),
+                metadata.block_height
+            );
+        }
+        SubCommands::Restore {
+            database_path,
+            backup_path,
+            chain_config,
+        } => {
+            let chain_config: ChainConfig = chain_config.parse()?;
+            let metadata =
+                CombinedDatabase::restore(&backup_path, &database_path, &chain_config)?;
+            tracing::info!(
+                "Restored the database at {} for the block height {}",
+                database_path.display(),
+                metadata.block_height
+            );
+        }
+        SubCommands::Check {
+            database_path,
+            repair,
+            rocksdb_args,
+        } => {
+            let config = rocksdb_args.into_config()?;
+            let mut database =
+                CombinedDatabase::open(&database_path, CACHE_SIZE, &config)
+                    .map_err(Into::<anyhow::Error>::into)
+                    .context(format!(
+                        "failed to open database at path {}",
+                        database_path.display()
+                    ))?;
+            let report = database.check(repair)?;
+            for discrepancy in &report.discrepancies {
+                println!("{discrepancy}");
+            }
+            println!(
+                "Found {} discrepancies, repaired {}",
+                report.discrepancies.len(),
+                report.repaired
+            );
+            if report.discrepancies.len() > report.repaired {
+                return Err(anyhow::anyhow!("The database is inconsistent"))
+            }
+        }
+    }
+    Ok(())
+}
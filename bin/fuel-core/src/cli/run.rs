@@ -18,7 +18,12 @@ use fuel_core::{
     },
     producer::Config as ProducerConfig,
     service::{
-        config::Trigger,
+        config::{
+            QueryLimits,
+            RateLimit,
+            ReadinessConfig,
+            Trigger,
+        },
         Config,
         DbType,
         RelayerConsensusConfig,
@@ -60,10 +65,12 @@ const DEFAULT_DATABASE_CACHE_SIZE: usize = 1024 * 1024 * 1024;
 #[cfg(feature = "p2p")]
 mod p2p;
 
+mod auth;
 mod consensus;
 mod profiling;
 #[cfg(feature = "relayer")]
 mod relayer;
+pub(crate) mod rocksdb;
 
 /// Run the Fuel client node locally.
 #[derive(Debug, Clone, Parser)]
@@ -104,6 +111,9 @@ pub struct Command {
     )]
     pub database_type: DbType,
 
+    #[clap(flatten)]
+    pub rocksdb_args: rocksdb::RocksDbArgs,
+
     /// Specify either an alias to a built-in configuration or filepath to a JSON file.
     #[arg(
         name = "CHAIN_CONFIG",
@@ -201,6 +211,63 @@ pub struct Command {
     #[clap(long = "api-request-timeout", default_value = "30m", env)]
     pub api_request_timeout: humantime::Duration,
 
+    /// The maximum number of blocks the node can be behind the best peer
+    /// to be reported as ready by the `/health/ready` endpoint.
+    #[clap(long = "ready-max-block-lag", default_value = "10", env)]
+    pub ready_max_block_lag: u32,
+
+    /// The maximum number of DA blocks the relayer can be behind the DA layer
+    /// to be reported as ready by the `/health/ready` endpoint.
+    #[clap(long = "ready-max-da-lag", default_value = "10", env)]
+    pub ready_max_da_lag: u64,
+
+    /// The maximum depth of the GraphQL query.
+    #[clap(long = "graphql-max-depth", default_value = "16", env)]
+    pub graphql_max_depth: usize,
+
+    /// The maximum complexity of the GraphQL query.
+    #[clap(long = "graphql-max-complexity", default_value = "100000", env)]
+    pub graphql_max_complexity: usize,
+
+    /// The maximum number of entries requested from one page of the GraphQL connection.
+    #[clap(long = "graphql-max-page-size", default_value = "1024", env)]
+    pub graphql_max_page_size: usize,
+
+    /// The maximum number of queries in one GraphQL batch request.
+    #[clap(long = "graphql-max-batch-size", default_value = "32", env)]
+    pub graphql_max_batch_size: usize,
+
+    /// The number of parsed GraphQL persisted queries cached by the node.
+    /// The persisted queries are disabled if it is `0`.
+    #[clap(
+        long = "graphql-persisted-queries-cache-size",
+        default_value = "1000",
+        env
+    )]
+    pub graphql_persisted_queries_cache_size: usize,
+
+    /// The number of GraphQL requests per second allowed for each client.
+    /// Clients are identified by their valid credentials or by the IP address.
+    /// Requests are not limited if not specified.
+    #[clap(long = "api-rate-limit", env)]
+    pub api_rate_limit: Option<u32>,
+
+    /// The number of requests the client can make at once before being rate limited.
+    #[clap(long = "api-rate-limit-burst", default_value = "100", env)]
+    pub api_rate_limit_burst: u32,
+
+    #[clap(flatten)]
+    pub auth_args: auth::AuthArgs,
+
+    /// Enables the JSON-RPC 2.0 API at the `/jsonrpc` endpoint over HTTP and WebSocket.
+    #[clap(long = "json-rpc", env)]
+    pub json_rpc: bool,
+
+    /// The directory where the `backupDatabase` GraphQL mutation creates backups.
+    /// The mutation is disabled if not specified.
+    #[clap(long = "backup-root", env)]
+    pub backup_root: Option<PathBuf>,
+
     #[clap(flatten)]
     pub profiling: profiling::ProfilingArgs,
 }
@@ -214,6 +281,7 @@ impl Command {
             max_database_cache_size,
             database_path,
             database_type,
+            rocksdb_args,
             chain_config,
             vm_backtrace,
             debug,
@@ -239,6 +307,18 @@ impl Command {
             time_until_synced,
             query_log_threshold_time,
             api_request_timeout,
+            ready_max_block_lag,
+            ready_max_da_lag,
+            graphql_max_depth,
+            graphql_max_complexity,
+            graphql_max_page_size,
+            graphql_max_batch_size,
+            graphql_persisted_queries_cache_size,
+            api_rate_limit,
+            api_rate_limit_burst,
+            auth_args,
+            json_rpc,
+            backup_root,
             profiling: _,
         } = self;
 
@@ -246,6 +326,8 @@ impl Command {
 
         let chain_conf: ChainConfig = chain_config.as_str().parse()?;
 
+        let rocksdb = rocksdb_args.into_config()?;
+
         #[cfg(feature = "relayer")]
         let relayer_cfg = relayer_args.into_config();
 
@@ -303,6 +385,7 @@ impl Command {
             max_database_cache_size,
             database_path,
             database_type,
+            rocksdb,
             chain_conf: chain_conf.clone(),
             debug,
             utxo_validation,
@@ -331,6 +414,14 @@ impl Command {
             #[cfg(feature = "p2p")]
             p2p: p2p_cfg,
             #[cfg(feature = "p2p")]
+            snap_sync: sync_args.snap_sync_config(),
+            #[cfg(feature = "p2p")]
+            light_client: sync_args.light_client_config(),
+            #[cfg(feature = "p2p")]
+            snapshot_interval: sync_args.snapshot_interval,
+            #[cfg(not(feature = "p2p"))]
+            snapshot_interval: None,
+            #[cfg(feature = "p2p")]
             sync: sync_args.into(),
             consensus_key,
             name,
@@ -338,6 +429,26 @@ impl Command {
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
             query_log_threshold_time: query_log_threshold_time.into(),
+            readiness: ReadinessConfig {
+                max_block_lag: ready_max_block_lag,
+                max_da_lag: ready_max_da_lag,
+            },
+            query_limits: QueryLimits {
+                max_depth: graphql_max_depth,
+                max_complexity: graphql_max_complexity,
+                max_page_size: graphql_max_page_size,
+                max_batch_size: graphql_max_batch_size,
+            },
+            api_rate_limit: api_rate_limit.map(|requests_per_sec| RateLimit {
+                requests_per_sec,
+                burst: api_rate_limit_burst,
+            }),
+            admin_addr: auth_args.admin_addr,
+            api_cors_origin: auth_args.cors_origin.clone(),
+            api_auth: auth_args.into_config(),
+            json_rpc,
+            persisted_queries_cache_size: graphql_persisted_queries_cache_size,
+            backup_root,
         };
         Ok(config)
     }
@@ -0,0 +1,87 @@
+use clap::Args;
+use fuel_core::{
+    service::config::{
+        AuthConfig,
+        Role,
+    },
+    types::secrecy::SecretString,
+};
+use std::{
+    net::SocketAddr,
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct AuthArgs {
+    /// The API keys of the GraphQL clients in the `<role>:<key>` format,
+    /// where the role is `public`, `submitter` or `admin`.
+    /// The key is passed by the client in the `X-Api-Key` header.
+    #[arg(long = "api-key", value_delimiter = ',', env = "API_KEYS")]
+    pub api_keys: Vec<ApiKey>,
+
+    /// The secret used to verify the HS256 JWTs passed by the GraphQL clients
+    /// in the `Authorization: Bearer <token>` header. The `role` claim is the role of the client,
+    /// and the `sub` claim identifies the client for the rate limiting.
+    #[arg(long = "api-jwt-secret", env)]
+    pub jwt_secret: Option<String>,
+
+    /// The address of the GraphQL listener that grants the admin role to all clients.
+    /// It must be reachable only from the private network. When it is set, or when
+    /// the credentials are configured, clients of the public listener
+    /// without credentials have the `public` role.
+    #[arg(long = "admin-addr", env)]
+    pub admin_addr: Option<SocketAddr>,
+
+    /// The origin allowed to make cross-origin requests to the public GraphQL listener,
+    /// like `https://app.fuel.network`. The admin listener doesn't allow cross-origin requests.
+    #[arg(long = "api-cors-origin", default_value = "*", env)]
+    pub cors_origin: String,
+}
+
+#[derive(Clone)]
+pub struct ApiKey {
+    role: Role,
+    key: String,
+}
+
+impl std::fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiKey")
+            .field("role", &self.role)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FromStr for ApiKey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (role, key) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("Expected `<role>:<key>`"))?;
+        if key.is_empty() {
+            return Err(anyhow::anyhow!("The API key is empty"))
+        }
+        Ok(Self {
+            role: role.parse()?,
+            key: key.to_string(),
+        })
+    }
+}
+
+impl AuthArgs {
+    pub fn into_config(self) -> Option<AuthConfig> {
+        if self.api_keys.is_empty() && self.jwt_secret.is_none() {
+            return None
+        }
+
+        Some(AuthConfig {
+            api_keys: self
+                .api_keys
+                .into_iter()
+                .map(|api_key| (api_key.key, api_key.role))
+                .collect(),
+            jwt_secret: self.jwt_secret.map(SecretString::new),
+        })
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::anyhow;
 use clap::{
     builder::ArgPredicate::IsPresent,
     Args,
+    ValueEnum,
 };
 use fuel_core::{
     p2p::{
@@ -9,15 +10,18 @@ use fuel_core::{
             convert_to_libp2p_keypair,
             Config,
             NotInitialized,
+            TxGossipMode,
             MAX_RESPONSE_SIZE,
         },
         gossipsub_config::default_gossipsub_builder,
         heartbeat,
+        request_response::rate_limit::RequestRateLimits,
         Multiaddr,
     },
     types::{
         fuel_crypto,
         fuel_crypto::SecretKey,
+        fuel_types::Bytes32,
     },
 };
 use std::{
@@ -58,6 +62,16 @@ pub struct P2PArgs {
     #[clap(long = "peering-port", default_value = "30333", env)]
     pub peering_port: u16,
 
+    /// p2p network's UDP Port for QUIC connections.
+    /// If set, the node listens on both TCP and QUIC and prefers QUIC for peers that support it.
+    #[clap(long = "quic-port", env)]
+    pub quic_port: Option<u16>,
+
+    /// How the node gossips new transactions: `full` sends whole transactions to peers,
+    /// `announcement` sends only their ids, and peers request the transactions they miss.
+    #[clap(long = "tx-gossip-mode", value_enum, default_value = "full", env)]
+    pub tx_gossip_mode: TxGossipModeArg,
+
     /// Max Block size
     #[clap(long = "max-block-size", default_value = MAX_RESPONSE_SIZE_STR, env)]
     pub max_block_size: usize,
@@ -66,6 +80,22 @@ pub struct P2PArgs {
     #[clap(long = "max-headers-per-request", default_value = "100", env)]
     pub max_headers_per_request: u32,
 
+    /// The average number of requests per second served to a single peer [default: 50]
+    #[clap(long = "peer-requests-per-sec", env)]
+    pub peer_requests_per_sec: Option<u32>,
+
+    /// The number of requests that a single peer can make at once [default: 100]
+    #[clap(long = "peer-request-burst", env)]
+    pub peer_request_burst: Option<u32>,
+
+    /// The average number of response bytes per second sent to a single peer [default: 10 MiB]
+    #[clap(long = "peer-response-bytes-per-sec", env)]
+    pub peer_response_bytes_per_sec: Option<u64>,
+
+    /// The number of response bytes that can be sent to a single peer at once [default: 2 * max-block-size]
+    #[clap(long = "peer-response-bytes-burst", env)]
+    pub peer_response_bytes_burst: Option<u64>,
+
     /// Addresses of the bootstrap nodes
     /// They should contain PeerId within their `Multiaddr`
     #[clap(long = "bootstrap-nodes", value_delimiter = ',', env)]
@@ -80,6 +110,27 @@ pub struct P2PArgs {
     #[clap(long = "reserved-nodes-only-mode", env)]
     pub reserved_nodes_only_mode: bool,
 
+    /// Addresses of the private nodes, which turns this node into their sentry node.
+    /// Private nodes should also be reserved nodes and should contain PeerId within their `Multiaddr`.
+    /// They are never advertised to other peers, while the sentry relays gossip and serves sync requests for them.
+    #[clap(long = "private-nodes", value_delimiter = ',', env)]
+    pub private_nodes: Vec<Multiaddr>,
+
+    /// Map the ports of the node on the gateway with UPnP, so other peers can dial the node behind the NAT
+    #[clap(long = "enable-upnp", env)]
+    pub enable_upnp: bool,
+
+    /// Relay connections for the peers that are behind the NAT.
+    /// The relay should be reachable at the `public-address` or detect its external address on its own.
+    #[clap(long = "enable-relay-server", env)]
+    pub enable_relay_server: bool,
+
+    /// Addresses of the relay nodes that the node listens through when it is behind the NAT.
+    /// Relayed connections are upgraded to direct ones with hole punching.
+    /// They should contain PeerId within their `Multiaddr`
+    #[clap(long = "relay-nodes", value_delimiter = ',', env)]
+    pub relay_nodes: Vec<Multiaddr>,
+
     /// Allow nodes to be discoverable on the local network
     #[clap(long = "enable-mdns", env)]
     pub enable_mdns: bool,
@@ -191,6 +242,59 @@ pub struct SyncArgs {
     /// The maximum number of headers to request in a single batch.
     #[clap(long = "sync-header-batch-size", default_value = "10", env)]
     pub header_batch_size: u32,
+    /// Requests full blocks from peers with a single request per batch,
+    /// instead of requesting headers and transactions separately.
+    #[clap(long = "sync-full-blocks", env)]
+    pub fetch_full_blocks: bool,
+    /// Downloads the state at a recent height from peers instead of executing
+    /// all blocks since the genesis. Used only if the node has no blocks yet.
+    /// Only the snapshot with the trusted height and root is imported.
+    #[clap(
+        long = "snap-sync",
+        requires_all = ["snap_sync_height", "snap_sync_root"],
+        env
+    )]
+    pub snap_sync: bool,
+    /// The height of the trusted snapshot for the snap-sync.
+    #[clap(long = "snap-sync-height", env)]
+    pub snap_sync_height: Option<u32>,
+    /// The root of the trusted snapshot for the snap-sync. The node that serves
+    /// snapshots logs the root of each snapshot.
+    #[clap(long = "snap-sync-root", env)]
+    pub snap_sync_root: Option<Bytes32>,
+    /// Runs the node as a light client that syncs and verifies only block headers.
+    /// Transactions of blocks are requested from full peers on demand,
+    /// and the block production is disabled.
+    #[clap(long = "light-client", conflicts_with = "snap_sync", env)]
+    pub light_client: bool,
+    /// The number of blocks between snapshots served to peers performing the snap-sync.
+    /// Snapshots are not served if not set.
+    #[clap(long = "snapshot-interval", env)]
+    pub snapshot_interval: Option<u32>,
+}
+
+impl SyncArgs {
+    pub fn snap_sync_config(&self) -> Option<fuel_core::sync::snap_sync::Config> {
+        let (true, Some(height), Some(root)) =
+            (self.snap_sync, self.snap_sync_height, self.snap_sync_root)
+        else {
+            return None
+        };
+        Some(fuel_core::sync::snap_sync::Config::new(
+            fuel_core::sync::snap_sync::TrustedSnapshot {
+                height: height.into(),
+                root,
+            },
+        ))
+    }
+
+    pub fn light_client_config(&self) -> Option<fuel_core::sync::light_sync::Config> {
+        self.light_client
+            .then(|| fuel_core::sync::light_sync::Config {
+                header_batch_size: self.header_batch_size,
+                ..Default::default()
+            })
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -218,11 +322,27 @@ impl KeypairArg {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TxGossipModeArg {
+    Full,
+    Announcement,
+}
+
+impl From<TxGossipModeArg> for TxGossipMode {
+    fn from(value: TxGossipModeArg) -> Self {
+        match value {
+            TxGossipModeArg::Full => TxGossipMode::Full,
+            TxGossipModeArg::Announcement => TxGossipMode::Announcement,
+        }
+    }
+}
+
 impl From<SyncArgs> for fuel_core::sync::Config {
     fn from(value: SyncArgs) -> Self {
         Self {
             block_stream_buffer_size: value.block_stream_buffer_size,
             header_batch_size: value.header_batch_size as usize,
+            fetch_full_blocks: value.fetch_full_blocks,
         }
     }
 }
@@ -283,6 +403,20 @@ impl P2PArgs {
             )
         };
 
+        let mut request_rate_limits = RequestRateLimits::new(self.max_block_size);
+        if let Some(requests_per_sec) = self.peer_requests_per_sec {
+            request_rate_limits.requests_per_sec = requests_per_sec;
+        }
+        if let Some(request_burst) = self.peer_request_burst {
+            request_rate_limits.request_burst = request_burst;
+        }
+        if let Some(response_bytes_per_sec) = self.peer_response_bytes_per_sec {
+            request_rate_limits.response_bytes_per_sec = response_bytes_per_sec;
+        }
+        if let Some(response_bytes_burst) = self.peer_response_bytes_burst {
+            request_rate_limits.response_bytes_burst = response_bytes_burst;
+        }
+
         let config = Config {
             keypair: local_keypair,
             network_name,
@@ -292,11 +426,17 @@ impl P2PArgs {
                 .unwrap_or_else(|| IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0]))),
             public_address: self.public_address,
             tcp_port: self.peering_port,
+            quic_port: self.quic_port,
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
+            request_rate_limits,
             bootstrap_nodes: self.bootstrap_nodes,
             reserved_nodes: self.reserved_nodes,
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
+            private_nodes: self.private_nodes,
+            enable_upnp: self.enable_upnp,
+            enable_relay_server: self.enable_relay_server,
+            relay_nodes: self.relay_nodes,
             enable_mdns: self.enable_mdns,
             max_peers_connected: self.max_peers_connected,
             max_connections_per_peer: self.max_connections_per_peer,
@@ -318,6 +458,8 @@ impl P2PArgs {
             ),
             info_interval: Some(Duration::from_secs(self.info_interval)),
             identify_interval: Some(Duration::from_secs(self.identify_interval)),
+            tx_gossip_mode: self.tx_gossip_mode.into(),
+            subscribe_to_transactions: true,
             metrics,
             state: NotInitialized,
         };
@@ -0,0 +1,85 @@
+use clap::Args;
+use fuel_core::state::rocks_db_config::{
+    CompactionStyle,
+    Compression,
+    RocksDbConfig,
+};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub struct RocksDbArgs {
+    /// Path to the TOML file with the RocksDB configuration.
+    /// The options provided via CLI override the values from the file.
+    #[arg(long = "rocksdb-config", env)]
+    pub rocksdb_config: Option<PathBuf>,
+
+    /// The default compression of all columns.
+    #[arg(long = "rocksdb-compression", value_enum, env)]
+    pub compression: Option<Compression>,
+
+    /// The default size of the block of all columns in bytes.
+    #[arg(long = "rocksdb-block-size", env)]
+    pub block_size: Option<usize>,
+
+    /// The default number of bits per key used by the bloom filter.
+    /// The `0` disables the bloom filter.
+    #[arg(long = "rocksdb-bloom-filter-bits", env)]
+    pub bloom_filter_bits: Option<f64>,
+
+    /// The percentage of the database cache size used by the block cache.
+    #[arg(long = "rocksdb-block-cache-percent", env)]
+    pub block_cache_percent: Option<u8>,
+
+    /// The percentage of the database cache size used by the row cache.
+    #[arg(long = "rocksdb-row-cache-percent", env)]
+    pub row_cache_percent: Option<u8>,
+
+    /// The maximum total size of the write-ahead log files in bytes.
+    #[arg(long = "rocksdb-max-total-wal-size", env)]
+    pub max_total_wal_size: Option<u64>,
+
+    /// The limit of the background I/O(flushes and compactions) in bytes per second.
+    #[arg(long = "rocksdb-rate-limit", env)]
+    pub rate_limit_bytes_per_sec: Option<u64>,
+
+    /// The default compaction style of all columns.
+    #[arg(long = "rocksdb-compaction-style", value_enum, env)]
+    pub compaction_style: Option<CompactionStyle>,
+}
+
+impl RocksDbArgs {
+    pub fn into_config(self) -> anyhow::Result<RocksDbConfig> {
+        let mut config = match self.rocksdb_config {
+            Some(path) => RocksDbConfig::load_from_file(path)?,
+            None => RocksDbConfig::default(),
+        };
+
+        if let Some(compression) = self.compression {
+            config.compression = compression;
+        }
+        if let Some(block_size) = self.block_size {
+            config.block_size = block_size;
+        }
+        if let Some(bloom_filter_bits) = self.bloom_filter_bits {
+            config.bloom_filter_bits = Some(bloom_filter_bits);
+        }
+        if let Some(block_cache_percent) = self.block_cache_percent {
+            config.block_cache_percent = block_cache_percent;
+        }
+        if let Some(row_cache_percent) = self.row_cache_percent {
+            config.row_cache_percent = row_cache_percent;
+        }
+        if let Some(max_total_wal_size) = self.max_total_wal_size {
+            config.max_total_wal_size = max_total_wal_size;
+        }
+        if let Some(rate_limit) = self.rate_limit_bytes_per_sec {
+            config.rate_limit_bytes_per_sec = Some(rate_limit);
+        }
+        if let Some(compaction_style) = self.compaction_style {
+            config.compaction_style = compaction_style;
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+}
@@ -5,7 +5,10 @@ use crate::client::{
             ExcludeInput,
             SpendQueryElementInput,
         },
-        contract::ContractBalanceQueryArgs,
+        contract::{
+            ContractBalanceQueryArgs,
+            ContractStorageSlotArgs,
+        },
         gas_price::EstimateGasPrice,
         message::MessageStatusArgs,
         tx::DryRunArg,
@@ -19,6 +22,7 @@ use crate::client::{
             Address,
             AssetId,
             BlockId,
+            Bytes32,
             ContractId,
             UtxoId,
         },
@@ -116,7 +120,9 @@ use self::schema::{
     block::ProduceBlockArgs,
     message::{
         MessageProofArgs,
+        MessageReceiptProofArgs,
         NonceArgs,
+        TransactionProofArgs,
     },
 };
 
@@ -126,12 +132,17 @@ pub mod types;
 
 type RegisterId = u32;
 
+/// The header with the API key of the client.
+const API_KEY_HEADER: &str = "X-Api-Key";
+
 #[derive(Debug, Clone)]
 pub struct FuelClient {
     client: reqwest::Client,
     #[cfg(feature = "subscriptions")]
     cookie: std::sync::Arc<reqwest::cookie::Jar>,
     url: reqwest::Url,
+    /// The API key sent in the `X-Api-Key` header.
+    api_key: Option<String>,
 }
 
 impl FromStr for FuelClient {
@@ -159,13 +170,18 @@ impl FromStr for FuelClient {
                 client,
                 cookie,
                 url,
+                api_key: None,
             })
         }
 
         #[cfg(not(feature = "subscriptions"))]
         {
             let client = reqwest::Client::new();
-            Ok(Self { client, url })
+            Ok(Self {
+                client,
+                url,
+                api_key: None,
+            })
         }
     }
 }
@@ -198,6 +214,12 @@ impl FuelClient {
         Self::from_str(url.as_ref())
     }
 
+    /// Authenticates the requests of the client with the `api_key`.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
     /// Send the GraphQL query to the client.
     pub async fn query<ResponseData, Vars>(
         &self,
@@ -207,9 +229,11 @@ impl FuelClient {
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
-        let response = self
-            .client
-            .post(self.url.clone())
+        let mut request = self.client.post(self.url.clone());
+        if let Some(api_key) = &self.api_key {
+            request = request.header(API_KEY_HEADER, api_key);
+        }
+        let response = request
             .run_graphql(q)
             .await
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
@@ -217,6 +241,56 @@ impl FuelClient {
         Self::decode_response(response)
     }
 
+    /// Send the GraphQL queries to the client in one batch request.
+    /// The results are returned in the order of the queries.
+    pub async fn query_batch<ResponseData, Vars>(
+        &self,
+        queries: Vec<Operation<ResponseData, Vars>>,
+    ) -> io::Result<Vec<io::Result<ResponseData>>>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum BatchResponse<R> {
+            Batch(Vec<GraphQlResponse<R>>),
+            // The whole batch is rejected by the node.
+            Single(GraphQlResponse<R>),
+        }
+
+        let mut request = self
+            .client
+            .post(self.url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&queries)?);
+        if let Some(api_key) = &self.api_key {
+            request = request.header(API_KEY_HEADER, api_key);
+        }
+        let body = request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+            .bytes()
+            .await
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        let response: BatchResponse<ResponseData> = serde_json::from_slice(&body)?;
+
+        match response {
+            BatchResponse::Batch(responses) => {
+                Ok(responses.into_iter().map(Self::decode_response).collect())
+            }
+            BatchResponse::Single(response) => match Self::decode_response(response) {
+                Ok(_) => Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    "Expected a batch response",
+                )),
+                Err(err) => Err(err),
+            },
+        }
+    }
+
     fn decode_response<R>(response: GraphQlResponse<R>) -> io::Result<R>
     where
         R: serde::de::DeserializeOwned + 'static,
@@ -264,6 +338,18 @@ impl FuelClient {
                 )
             })?;
 
+        if let Some(api_key) = &self.api_key {
+            client_builder =
+                client_builder
+                    .header(API_KEY_HEADER, api_key)
+                    .map_err(|e| {
+                        io::Error::new(
+                            io::ErrorKind::Other,
+                            format!("Failed to add API key header to client {e:?}"),
+                        )
+                    })?;
+        }
+
         if let Some(value) = self.cookie.deref().cookies(&self.url) {
             let value = value.to_str().map_err(|e| {
                 io::Error::new(
@@ -365,9 +451,14 @@ impl FuelClient {
 
     pub async fn connected_peers_info(&self) -> io::Result<Vec<PeerInfo>> {
         let query = schema::node_info::QueryPeersInfo::build(());
-        self.query(query)
-            .await
-            .map(|r| r.node_info.peers.into_iter().map(Into::into).collect())
+        self.query(query).await.map(|r| {
+            r.node_info
+                .peers
+                .into_iter()
+                .filter(|peer| peer.ban.is_none())
+                .map(Into::into)
+                .collect()
+        })
     }
 
     pub async fn chain_info(&self) -> io::Result<types::ChainInfo> {
@@ -675,6 +766,23 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions associated with a txo owner address
+    /// that match the `filter`, along with the changes of the owner's balances.
+    pub async fn transactions_by_owner_filtered(
+        &self,
+        owner: &Address,
+        filter: schema::tx::TransactionsByOwnerFilter,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::OwnedTransactionResponse, String>> {
+        let owner: schema::Address = (*owner).into();
+        let query = schema::tx::FilteredTransactionsByOwnerQuery::build(
+            (owner, filter, request).into(),
+        );
+
+        let transactions = self.query(query).await?.transactions_by_owner.try_into()?;
+        Ok(transactions)
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
@@ -865,6 +973,24 @@ impl FuelClient {
         Ok(balance.amount)
     }
 
+    /// Returns the balance of the contract along with its inclusion proof
+    /// against the assets root of the contract.
+    pub async fn contract_balance_with_proof(
+        &self,
+        id: &ContractId,
+        asset: &AssetId,
+    ) -> io::Result<types::ContractBalanceWithProof> {
+        let query = schema::contract::ContractBalanceWithProofQuery::build(
+            ContractBalanceQueryArgs {
+                id: (*id).into(),
+                asset: (*asset).into(),
+            },
+        );
+
+        let balance = self.query(query).await?.contract_balance.into();
+        Ok(balance)
+    }
+
     pub async fn balance(
         &self,
         owner: &Address,
@@ -907,6 +1033,46 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Returns the value of the contract's storage slot along with its inclusion proof
+    /// against the state root of the contract.
+    pub async fn contract_storage_slot(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> io::Result<Option<types::ContractStorageSlotWithProof>> {
+        let query =
+            schema::contract::ContractStorageSlotQuery::build(ContractStorageSlotArgs {
+                contract: (*contract).into(),
+                key: (*key).into(),
+            });
+
+        let slot = self
+            .query(query)
+            .await?
+            .contract_storage_slot
+            .map(Into::into);
+
+        Ok(slot)
+    }
+
+    /// Returns the storage slots of the contract with keys starting with the `prefix`.
+    pub async fn contract_storage_slots(
+        &self,
+        contract: &ContractId,
+        prefix: Option<&[u8]>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractStorageSlot, String>> {
+        let contract_id: schema::ContractId = (*contract).into();
+        let prefix = prefix.map(|prefix| HexString(Bytes(prefix.to_vec())));
+        let query = schema::contract::ContractStorageSlotsQuery::build(
+            (contract_id, prefix, request).into(),
+        );
+
+        let slots = self.query(query).await?.contract_storage_slots.into();
+
+        Ok(slots)
+    }
+
     // Retrieve a message by its nonce
     pub async fn message(&self, nonce: &Nonce) -> io::Result<Option<types::Message>> {
         let query = schema::message::MessageQuery::build(NonceArgs {
@@ -962,6 +1128,43 @@ impl FuelClient {
 
         Ok(proof)
     }
+
+    /// Returns the proof that the transaction is included in the block
+    /// against the `transactions_root` of the block header.
+    pub async fn transaction_proof(
+        &self,
+        transaction_id: &TxId,
+    ) -> io::Result<Option<types::TransactionProof>> {
+        let query = schema::message::TransactionProofQuery::build(TransactionProofArgs {
+            transaction_id: (*transaction_id).into(),
+        });
+
+        let proof = self.query(query).await?.transaction_proof.map(Into::into);
+
+        Ok(proof)
+    }
+
+    /// Returns the proof that the `MessageOut` receipt with the `nonce` was produced
+    /// by the transaction against the `message_receipt_root` of the block header.
+    pub async fn message_receipt_proof(
+        &self,
+        transaction_id: &TxId,
+        nonce: &Nonce,
+    ) -> io::Result<Option<types::MessageReceiptProof>> {
+        let query =
+            schema::message::MessageReceiptProofQuery::build(MessageReceiptProofArgs {
+                transaction_id: (*transaction_id).into(),
+                nonce: (*nonce).into(),
+            });
+
+        let proof = self
+            .query(query)
+            .await?
+            .message_receipt_proof
+            .map(Into::into);
+
+        Ok(proof)
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
@@ -2,10 +2,12 @@ use crate::client::{
     schema::{
         schema,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
         Salt,
+        U32,
         U64,
     },
     PageDirection,
@@ -53,6 +55,26 @@ pub struct ContractBalanceQuery {
     pub contract_balance: ContractBalance,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractBalanceQueryArgs"
+)]
+pub struct ContractBalanceWithProofQuery {
+    #[arguments(contract: $id, asset: $asset)]
+    pub contract_balance: ContractBalanceWithProof,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "ContractBalance")]
+pub struct ContractBalanceWithProof {
+    pub contract: ContractId,
+    pub amount: U64,
+    pub asset_id: AssetId,
+    pub proof: Option<SparseMerkleProof>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Contract {
@@ -135,6 +157,124 @@ impl From<(ContractId, PaginationRequest<String>)> for ContractBalancesConnectio
     }
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageSlotArgs {
+    pub contract: ContractId,
+    pub key: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageSlotArgs"
+)]
+pub struct ContractStorageSlotQuery {
+    #[arguments(contract: $contract, key: $key)]
+    pub contract_storage_slot: Option<ContractStorageSlotWithProof>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "ContractStorageSlot"
+)]
+pub struct ContractStorageSlotWithProof {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub value: Bytes32,
+    pub proof: SparseMerkleProof,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlot {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub value: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SparseMerkleProof {
+    pub root: Bytes32,
+    pub path: Vec<SparseMerkleProofNode>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SparseMerkleProofNode {
+    pub height: U32,
+    pub sibling: Bytes32,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageSlotsConnectionArgs {
+    /// The contract that owns the slots
+    pub contract: ContractId,
+    /// Returns only the slots with keys starting with the prefix
+    pub prefix: Option<HexString>,
+    /// Skip until slot key (forward pagination)
+    pub after: Option<String>,
+    /// Skip until slot key (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n slots in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n slots in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotEdge {
+    pub cursor: String,
+    pub node: ContractStorageSlot,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotConnection {
+    pub edges: Vec<ContractStorageSlotEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageSlotsConnectionArgs"
+)]
+pub struct ContractStorageSlotsQuery {
+    #[arguments(contract: $contract, prefix: $prefix, after: $after, before: $before, first: $first, last: $last)]
+    pub contract_storage_slots: ContractStorageSlotConnection,
+}
+
+impl From<(ContractId, Option<HexString>, PaginationRequest<String>)>
+    for ContractStorageSlotsConnectionArgs
+{
+    fn from(r: (ContractId, Option<HexString>, PaginationRequest<String>)) -> Self {
+        match r.2.direction {
+            PageDirection::Forward => ContractStorageSlotsConnectionArgs {
+                contract: r.0,
+                prefix: r.1,
+                after: r.2.cursor,
+                before: None,
+                first: Some(r.2.results),
+                last: None,
+            },
+            PageDirection::Backward => ContractStorageSlotsConnectionArgs {
+                contract: r.0,
+                prefix: r.1,
+                after: None,
+                before: r.2.cursor,
+                first: None,
+                last: Some(r.2.results),
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +287,42 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn contract_balance_with_proof_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractBalanceWithProofQuery::build(ContractBalanceQueryArgs {
+            id: ContractId::default(),
+            asset: AssetId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_storage_slot_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStorageSlotQuery::build(ContractStorageSlotArgs {
+            contract: ContractId::default(),
+            key: Bytes32::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_storage_slots_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStorageSlotsQuery::build(
+            (
+                ContractId::default(),
+                None,
+                PaginationRequest {
+                    cursor: None,
+                    results: 10,
+                    direction: PageDirection::Forward,
+                },
+            )
+                .into(),
+        );
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -3,6 +3,7 @@ use super::{
     BlockId,
     Bytes32,
     HexString,
+    MessageId,
     PageInfo,
     TransactionId,
 };
@@ -169,6 +170,62 @@ pub struct MessageProofArgs {
     pub commit_block_height: Option<U32>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionProofArgs {
+    /// The transaction that requires a proof.
+    pub transaction_id: TransactionId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionProofArgs"
+)]
+pub struct TransactionProofQuery {
+    #[arguments(transactionId: $transaction_id)]
+    pub transaction_proof: Option<TransactionProof>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionProof {
+    /// Proof that the transaction is contained within the provided block header.
+    pub transaction_proof: MerkleProof,
+    /// The header of the block that contains the transaction.
+    pub block_header: Header,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MessageReceiptProofArgs {
+    /// Transaction id that contains the output message.
+    pub transaction_id: TransactionId,
+    /// The `Nonce` identifier of the output message that requires a proof.
+    pub nonce: Nonce,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "MessageReceiptProofArgs"
+)]
+pub struct MessageReceiptProofQuery {
+    #[arguments(transactionId: $transaction_id, nonce: $nonce)]
+    pub message_receipt_proof: Option<MessageReceiptProof>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageReceiptProof {
+    /// The id of the message produced by the receipt.
+    pub message_id: MessageId,
+    /// Proof that the message id is contained within the provided block header.
+    pub message_proof: MerkleProof,
+    /// The header of the block that contains the receipt.
+    pub block_header: Header,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -225,4 +282,27 @@ mod tests {
 
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn transaction_proof_query_gql_output() {
+        use cynic::QueryBuilder;
+
+        let operation = TransactionProofQuery::build(TransactionProofArgs {
+            transaction_id: TransactionId::default(),
+        });
+
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn message_receipt_proof_query_gql_output() {
+        use cynic::QueryBuilder;
+
+        let operation = MessageReceiptProofQuery::build(MessageReceiptProofArgs {
+            transaction_id: TransactionId::default(),
+            nonce: Nonce::default(),
+        });
+
+        insta::assert_snapshot!(operation.query)
+    }
 }
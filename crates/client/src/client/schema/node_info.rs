@@ -1,5 +1,6 @@
 use crate::client::schema::{
     schema,
+    Tai64Timestamp,
     U32,
     U64,
 };
@@ -56,6 +57,14 @@ pub struct PeerInfo {
     pub block_height: Option<U32>,
     pub last_heartbeat_ms: U64,
     pub app_score: f64,
+    pub ban: Option<PeerBanInfo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PeerBanInfo {
+    pub reason: String,
+    pub expires_at: Option<Tai64Timestamp>,
 }
 
 impl From<PeerInfo> for fuel_core_types::services::p2p::PeerInfo {
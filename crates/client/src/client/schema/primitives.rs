@@ -114,6 +114,7 @@ fuel_type_scalar!(Salt, Salt);
 fuel_type_scalar!(TransactionId, Bytes32);
 fuel_type_scalar!(Signature, Bytes64);
 fuel_type_scalar!(Nonce, Nonce);
+fuel_type_scalar!(MessageId, MessageId);
 
 impl LowerHex for Nonce {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -273,6 +274,7 @@ macro_rules! number_scalar {
 number_scalar!(U64, u64);
 number_scalar!(U32, u32);
 number_scalar!(U8, u8);
+number_scalar!(I64, i64);
 
 impl TryFrom<U64> for PanicInstruction {
     type Error = ConversionError;
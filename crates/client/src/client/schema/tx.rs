@@ -4,14 +4,19 @@ use crate::client::{
         schema,
         tx::transparent_receipt::Receipt,
         Address,
+        AssetId,
         ConnectionArgs,
         ConversionError,
         HexString,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        I64,
+    },
+    types::{
+        OwnedTransactionResponse,
+        TransactionResponse,
     },
-    types::TransactionResponse,
     PageDirection,
     PaginatedResult,
     PaginationRequest,
@@ -324,6 +329,159 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TransactionDirection {
+    Sent,
+    Received,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TransactionOutcome {
+    Success,
+    Failure,
+}
+
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionsByOwnerFilter {
+    /// Returns only transactions sent or received by the owner.
+    pub direction: Option<TransactionDirection>,
+    /// Returns only transactions that spent or received the asset of the owner.
+    pub asset_id: Option<AssetId>,
+    /// Returns only transactions included in blocks produced at or after the time.
+    pub from_time: Option<Tai64Timestamp>,
+    /// Returns only transactions included in blocks produced at or before the time.
+    pub to_time: Option<Tai64Timestamp>,
+    /// Returns only transactions with the execution outcome.
+    pub status: Option<TransactionOutcome>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct FilteredTransactionsByOwnerConnectionArgs {
+    /// Select transactions based on related `owner`s
+    pub owner: Address,
+    /// Select transactions matching the filter
+    pub filter: Option<TransactionsByOwnerFilter>,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl
+    From<(
+        Address,
+        TransactionsByOwnerFilter,
+        PaginationRequest<String>,
+    )> for FilteredTransactionsByOwnerConnectionArgs
+{
+    fn from(
+        r: (
+            Address,
+            TransactionsByOwnerFilter,
+            PaginationRequest<String>,
+        ),
+    ) -> Self {
+        match r.2.direction {
+            PageDirection::Forward => FilteredTransactionsByOwnerConnectionArgs {
+                owner: r.0,
+                filter: Some(r.1),
+                after: r.2.cursor,
+                before: None,
+                first: Some(r.2.results),
+                last: None,
+            },
+            PageDirection::Backward => FilteredTransactionsByOwnerConnectionArgs {
+                owner: r.0,
+                filter: Some(r.1),
+                after: None,
+                before: r.2.cursor,
+                first: None,
+                last: Some(r.2.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "FilteredTransactionsByOwnerConnectionArgs"
+)]
+pub struct FilteredTransactionsByOwnerQuery {
+    #[arguments(owner: $owner, filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_owner: OwnedTransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "TransactionConnection",
+    variables = "FilteredTransactionsByOwnerConnectionArgs"
+)]
+pub struct OwnedTransactionConnection {
+    pub edges: Vec<OwnedTransactionEdge>,
+    pub page_info: PageInfo,
+}
+
+impl TryFrom<OwnedTransactionConnection>
+    for PaginatedResult<OwnedTransactionResponse, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(conn: OwnedTransactionConnection) -> Result<Self, Self::Error> {
+        let results: Result<Vec<OwnedTransactionResponse>, Self::Error> =
+            conn.edges.into_iter().map(|e| e.node.try_into()).collect();
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "TransactionEdge",
+    variables = "FilteredTransactionsByOwnerConnectionArgs"
+)]
+pub struct OwnedTransactionEdge {
+    pub cursor: String,
+    pub node: OwnedTransaction,
+}
+
+/// The transaction in opaque form along with the changes of the owner's balances.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Transaction",
+    variables = "FilteredTransactionsByOwnerConnectionArgs"
+)]
+pub struct OwnedTransaction {
+    pub raw_payload: HexString,
+    pub status: Option<TransactionStatus>,
+    #[arguments(owner: $owner)]
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BalanceChange {
+    pub asset_id: AssetId,
+    pub amount: I64,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -448,6 +606,22 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn filtered_transactions_by_owner_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = FilteredTransactionsByOwnerQuery::build(
+            FilteredTransactionsByOwnerConnectionArgs {
+                owner: Default::default(),
+                filter: None,
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            },
+        );
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -10,7 +10,10 @@ pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
 
-pub use balance::Balance;
+pub use balance::{
+    Balance,
+    BalanceChange,
+};
 pub use block::{
     Block,
     Consensus,
@@ -24,6 +27,9 @@ pub use coins::{
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractBalanceWithProof,
+    ContractStorageSlot,
+    ContractStorageSlotWithProof,
 };
 pub use gas_costs::{
     DependentCost,
@@ -33,12 +39,15 @@ pub use merkle_proof::MerkleProof;
 pub use message::{
     Message,
     MessageProof,
+    MessageReceiptProof,
+    TransactionProof,
 };
 pub use node_info::NodeInfo;
 
 use crate::client::schema::{
     tx::{
         OpaqueTransaction,
+        OwnedTransaction,
         TransactionStatus as SchemaTxStatus,
     },
     ConversionError,
@@ -86,6 +95,13 @@ pub struct TransactionResponse {
     pub status: TransactionStatus,
 }
 
+/// The transaction of the owner along with the changes of the owner's balances per asset.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct OwnedTransactionResponse {
+    pub transaction: TransactionResponse,
+    pub balance_changes: Vec<BalanceChange>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum TransactionStatus {
     Submitted {
@@ -166,3 +182,21 @@ impl TryFrom<OpaqueTransaction> for TransactionResponse {
         })
     }
 }
+
+impl TryFrom<OwnedTransaction> for OwnedTransactionResponse {
+    type Error = ConversionError;
+
+    fn try_from(value: OwnedTransaction) -> Result<Self, Self::Error> {
+        let transaction = OpaqueTransaction {
+            raw_payload: value.raw_payload,
+            status: value.status,
+        }
+        .try_into()?;
+        let balance_changes = value.balance_changes.into_iter().map(Into::into).collect();
+
+        Ok(Self {
+            transaction,
+            balance_changes,
+        })
+    }
+}
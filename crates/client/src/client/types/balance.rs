@@ -13,6 +13,13 @@ pub struct Balance {
     pub asset_id: AssetId,
 }
 
+/// The net change of the owner's balance of the asset caused by a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BalanceChange {
+    pub asset_id: AssetId,
+    pub amount: i64,
+}
+
 // GraphQL Translation
 
 impl From<schema::balance::Balance> for Balance {
@@ -25,6 +32,15 @@ impl From<schema::balance::Balance> for Balance {
     }
 }
 
+impl From<schema::tx::BalanceChange> for BalanceChange {
+    fn from(value: schema::tx::BalanceChange) -> Self {
+        BalanceChange {
+            asset_id: value.asset_id.into(),
+            amount: value.amount.into(),
+        }
+    }
+}
+
 impl From<schema::balance::BalanceConnection> for PaginatedResult<Balance, String> {
     fn from(conn: schema::balance::BalanceConnection) -> Self {
         PaginatedResult {
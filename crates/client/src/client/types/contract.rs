@@ -3,11 +3,16 @@ use crate::client::{
     types::primitives::{
         AssetId,
         Bytes,
+        Bytes32,
         ContractId,
         Salt,
     },
     PaginatedResult,
 };
+use fuel_core_types::entities::contract::{
+    SparseMerkleProof,
+    SparseMerkleProofNode,
+};
 
 pub struct Contract {
     pub id: ContractId,
@@ -22,6 +27,55 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+#[derive(Debug)]
+pub struct ContractStorageSlot {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub value: Bytes32,
+}
+
+/// The balance of the contract along with its inclusion proof against the assets root
+/// of the contract. The proof is `None` if the contract doesn't hold the asset.
+#[derive(Debug)]
+pub struct ContractBalanceWithProof {
+    pub balance: ContractBalance,
+    pub proof: Option<SparseMerkleProof>,
+}
+
+impl ContractBalanceWithProof {
+    /// Verifies that the balance is included in the assets tree with the root from the proof.
+    /// The caller is responsible for checking that `proof.root` is the trusted assets root
+    /// of the contract.
+    pub fn verify(&self) -> bool {
+        let Some(proof) = &self.proof else {
+            return false
+        };
+        let key = [
+            self.balance.contract.as_ref(),
+            self.balance.asset_id.as_ref(),
+        ]
+        .concat();
+        proof.verify(&key, &self.balance.amount.to_be_bytes())
+    }
+}
+
+/// The storage slot along with its inclusion proof against the state root of the contract.
+#[derive(Debug)]
+pub struct ContractStorageSlotWithProof {
+    pub slot: ContractStorageSlot,
+    pub proof: SparseMerkleProof,
+}
+
+impl ContractStorageSlotWithProof {
+    /// Verifies that the slot is included in the state tree with the root from the proof.
+    /// The caller is responsible for checking that `proof.root` is the trusted state root
+    /// of the contract.
+    pub fn verify(&self) -> bool {
+        let key = [self.slot.contract.as_ref(), self.slot.key.as_ref()].concat();
+        self.proof.verify(&key, self.slot.value.as_ref())
+    }
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -56,3 +110,70 @@ impl From<schema::contract::ContractBalanceConnection>
         }
     }
 }
+
+impl From<schema::contract::ContractBalanceWithProof> for ContractBalanceWithProof {
+    fn from(value: schema::contract::ContractBalanceWithProof) -> Self {
+        Self {
+            balance: ContractBalance {
+                contract: value.contract.into(),
+                amount: value.amount.into(),
+                asset_id: value.asset_id.into(),
+            },
+            proof: value.proof.map(Into::into),
+        }
+    }
+}
+
+impl From<schema::contract::ContractStorageSlot> for ContractStorageSlot {
+    fn from(value: schema::contract::ContractStorageSlot) -> Self {
+        Self {
+            contract: value.contract.into(),
+            key: value.key.into(),
+            value: value.value.into(),
+        }
+    }
+}
+
+impl From<schema::contract::ContractStorageSlotWithProof>
+    for ContractStorageSlotWithProof
+{
+    fn from(value: schema::contract::ContractStorageSlotWithProof) -> Self {
+        Self {
+            slot: ContractStorageSlot {
+                contract: value.contract.into(),
+                key: value.key.into(),
+                value: value.value.into(),
+            },
+            proof: value.proof.into(),
+        }
+    }
+}
+
+impl From<schema::contract::SparseMerkleProof> for SparseMerkleProof {
+    fn from(value: schema::contract::SparseMerkleProof) -> Self {
+        Self {
+            root: value.root.into(),
+            path: value
+                .path
+                .into_iter()
+                .map(|node| SparseMerkleProofNode {
+                    height: node.height.into(),
+                    sibling: node.sibling.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<schema::contract::ContractStorageSlotConnection>
+    for PaginatedResult<ContractStorageSlot, String>
+{
+    fn from(conn: schema::contract::ContractStorageSlotConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
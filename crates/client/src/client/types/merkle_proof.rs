@@ -2,6 +2,7 @@ use crate::client::{
     schema,
     types::primitives::MerkleRoot,
 };
+use fuel_core_types::fuel_merkle::binary;
 
 #[derive(Debug)]
 pub struct MerkleProof {
@@ -11,6 +12,24 @@ pub struct MerkleProof {
     pub proof_index: u64,
 }
 
+impl MerkleProof {
+    /// Verifies that the `data` is the leaf of the binary merkle tree
+    /// with the `root` and `num_leaves` leaves.
+    pub fn verify<T: AsRef<[u8]>>(
+        &self,
+        root: &MerkleRoot,
+        data: &T,
+        num_leaves: u64,
+    ) -> bool {
+        let proof_set = self
+            .proof_set
+            .iter()
+            .map(|bytes| **bytes)
+            .collect::<Vec<_>>();
+        binary::verify(root, data, &proof_set, self.proof_index, num_leaves)
+    }
+}
+
 // GraphQL Translation
 
 impl From<schema::message::MerkleProof> for MerkleProof {
@@ -5,12 +5,17 @@ use crate::client::{
         primitives::{
             Address,
             Bytes,
+            MessageId,
             Nonce,
         },
         MerkleProof,
     },
     PaginatedResult,
 };
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    fuel_types::canonical::Serialize,
+};
 
 #[derive(Debug)]
 pub struct Message {
@@ -46,6 +51,54 @@ pub struct MessageProof {
     pub data: Bytes,
 }
 
+/// Proves that the transaction was included in the block.
+#[derive(Debug)]
+pub struct TransactionProof {
+    /// Proof that the transaction is contained within the provided block header.
+    pub transaction_proof: MerkleProof,
+    /// The header of the block that contains the transaction.
+    pub block_header: Header,
+}
+
+impl TransactionProof {
+    /// Verifies that the `transaction` is included in the block against its
+    /// `transactions_root`. The `transaction` should be in the form it was included
+    /// into the block, because the execution updates some of its fields.
+    /// The caller is responsible for checking that the block header is part
+    /// of the trusted chain.
+    pub fn verify(&self, transaction: &Transaction) -> bool {
+        self.transaction_proof.verify(
+            &self.block_header.transactions_root,
+            &transaction.to_bytes(),
+            self.block_header.transactions_count,
+        )
+    }
+}
+
+/// Proves that the `MessageOut` receipt was produced in the block.
+#[derive(Debug)]
+pub struct MessageReceiptProof {
+    /// The id of the message produced by the receipt.
+    pub message_id: MessageId,
+    /// Proof that the message id is contained within the provided block header.
+    pub message_proof: MerkleProof,
+    /// The header of the block that contains the receipt.
+    pub block_header: Header,
+}
+
+impl MessageReceiptProof {
+    /// Verifies that the `message_id` is included in the block against its
+    /// `message_receipt_root`. The caller is responsible for checking that
+    /// the block header is part of the trusted chain.
+    pub fn verify(&self) -> bool {
+        self.message_proof.verify(
+            &self.block_header.message_receipt_root,
+            &self.message_id,
+            self.block_header.message_receipt_count,
+        )
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum MessageStatus {
     Unspent,
@@ -104,3 +157,22 @@ impl From<schema::message::MessageProof> for MessageProof {
         }
     }
 }
+
+impl From<schema::message::TransactionProof> for TransactionProof {
+    fn from(value: schema::message::TransactionProof) -> Self {
+        Self {
+            transaction_proof: value.transaction_proof.into(),
+            block_header: value.block_header.into(),
+        }
+    }
+}
+
+impl From<schema::message::MessageReceiptProof> for MessageReceiptProof {
+    fn from(value: schema::message::MessageReceiptProof) -> Self {
+        Self {
+            message_id: value.message_id.into(),
+            message_proof: value.message_proof.into(),
+            block_header: value.block_header.into(),
+        }
+    }
+}
@@ -37,6 +37,9 @@ pub enum Error {
         /// the database version expected by this build of fuel-core
         expected: u32,
     },
+    /// The database of the light client stores only the headers of blocks.
+    #[display(fmt = "The database of the light client can't be used by the full node")]
+    LightClientDatabase,
 
     /// Not related to database error.
     #[from]
@@ -1,13 +1,25 @@
+#[cfg(feature = "rocksdb")]
+pub mod backup;
+pub mod check;
+
 use crate::database::{
     database_description::{
         off_chain::OffChain,
         on_chain::OnChain,
         relayer::Relayer,
+        DatabaseMetadata,
     },
+    metadata::MetadataTable,
     Database,
+    Error as DatabaseError,
     Result as DatabaseResult,
 };
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    not_found,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     fuel_types::BlockHeight,
@@ -35,11 +47,27 @@ impl CombinedDatabase {
     }
 
     #[cfg(feature = "rocksdb")]
-    pub fn open(path: &std::path::Path, capacity: usize) -> DatabaseResult<Self> {
+    pub fn open(
+        path: &std::path::Path,
+        capacity: usize,
+        config: &crate::state::rocks_db_config::RocksDbConfig,
+    ) -> DatabaseResult<Self> {
         // TODO: Use different cache sizes for different databases
-        let on_chain = Database::open(path, capacity)?;
-        let off_chain = Database::open(path, capacity)?;
-        let relayer = Database::open(path, capacity)?;
+        let on_chain = Database::open_with_config(path, capacity, config)?;
+        let off_chain = Database::open_with_config(path, capacity, config)?;
+        let relayer = Database::open_with_config(path, capacity, config)?;
+        Ok(Self {
+            on_chain,
+            off_chain,
+            relayer,
+        })
+    }
+
+    #[cfg(feature = "redb")]
+    pub fn open_redb(path: &std::path::Path) -> DatabaseResult<Self> {
+        let on_chain = Database::open_redb(path)?;
+        let off_chain = Database::open_redb(path)?;
+        let relayer = Database::open_redb(path)?;
         Ok(Self {
             on_chain,
             off_chain,
@@ -66,6 +94,36 @@ impl CombinedDatabase {
         Ok(())
     }
 
+    /// Marks the on-chain database of the light client, which stores only the headers
+    /// of blocks, and refuses to use such a database by the full node.
+    /// The database of the full node can be reused by the light client.
+    pub fn init_light_client(&mut self, light_client: bool) -> StorageResult<()> {
+        let metadata = self
+            .on_chain
+            .storage::<MetadataTable<OnChain>>()
+            .get(&())?
+            .ok_or(not_found!(MetadataTable<OnChain>))?
+            .into_owned();
+
+        match (metadata.light_client(), light_client) {
+            (true, false) => Err(DatabaseError::LightClientDatabase.into()),
+            (false, true) => {
+                self.on_chain
+                    .storage_as_mut::<MetadataTable<OnChain>>()
+                    .insert(
+                        &(),
+                        &DatabaseMetadata::new(
+                            metadata.version(),
+                            *metadata.height(),
+                            light_client,
+                        ),
+                    )?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
     pub fn on_chain(&self) -> &Database<OnChain> {
         &self.on_chain
     }
@@ -95,3 +153,34 @@ impl CombinedDatabase {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core_storage::tables::FuelBlocks;
+    use fuel_core_types::blockchain::block::CompressedBlock;
+
+    #[test]
+    fn init_light_client__refuses_light_client_database_in_full_mode() {
+        let mut database = CombinedDatabase::in_memory();
+        database.init(&0u32.into(), &0u64.into()).unwrap();
+        database.init_light_client(false).unwrap();
+
+        database.init_light_client(true).unwrap();
+        // The mark survives the import of blocks.
+        let mut block = CompressedBlock::default();
+        block.header_mut().set_block_height(1u32.into());
+        block.header_mut().recalculate_metadata();
+        database
+            .on_chain_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&1u32.into(), &block)
+            .unwrap();
+        assert_eq!(database.on_chain().latest_height().unwrap(), 1u32.into());
+
+        database.init_light_client(true).unwrap();
+        assert!(database.init_light_client(false).is_err());
+    }
+}
@@ -0,0 +1,428 @@
+//! Online backups of the [`CombinedDatabase`].
+//!
+//! The backup is a set of RocksDB checkpoints of all databases taken at the same
+//! block height and the `backup.json` file with metadata about them. The node
+//! continues to process blocks while the backup is created.
+
+use super::CombinedDatabase;
+use crate::{
+    database::database_description::{
+        off_chain::OffChain,
+        on_chain::OnChain,
+        relayer::Relayer,
+        DatabaseDescription,
+    },
+    state::rocks_db_config::RocksDbConfig,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_chain_config::{
+    ChainConfig,
+    GenesisCommitment,
+};
+use fuel_core_storage::Error as StorageError;
+use fuel_core_types::{
+    blockchain::{
+        consensus::Genesis,
+        primitives::DaBlockHeight,
+    },
+    fuel_types::BlockHeight,
+};
+use std::{
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
+
+/// The name of the file with the metadata of the backup.
+pub const BACKUP_METADATA_FILE: &str = "backup.json";
+
+/// The number of attempts to create a backup when the node is producing/importing blocks.
+const MAX_BACKUP_ATTEMPTS: usize = 10;
+/// The interval between attempts to create a backup.
+const BACKUP_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+/// The cache size used to open the backup for verification.
+const VERIFICATION_CACHE_SIZE: usize = 10 * 1024 * 1024;
+
+/// The metadata of the backup used to verify it during restoring.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BackupMetadata {
+    /// The height of the on-chain and off-chain databases.
+    pub block_height: BlockHeight,
+    /// The height of the relayer database, `None` if it is not initialized.
+    pub da_block_height: Option<DaBlockHeight>,
+    /// The genesis of the chain stored in the backup.
+    pub genesis: Genesis,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Heights {
+    on_chain: BlockHeight,
+    off_chain: BlockHeight,
+    relayer: Option<DaBlockHeight>,
+}
+
+impl CombinedDatabase {
+    /// Creates a backup of all databases inside of the empty `path` directory.
+    ///
+    /// The checkpoints of databases are created one by one, so if a new block was
+    /// committed in the middle of the process, the backup is discarded and retried.
+    ///
+    /// The function blocks the current thread and should be called from
+    /// the blocking context.
+    pub fn backup(&self, path: &Path) -> anyhow::Result<BackupMetadata> {
+        ensure_empty_dir(path)?;
+        std::fs::create_dir_all(path)?;
+        let genesis = self
+            .on_chain
+            .get_genesis()
+            .map_err(anyhow::Error::from)
+            .context("The database doesn't contain the genesis block")?;
+
+        for _ in 0..MAX_BACKUP_ATTEMPTS {
+            let before = self.heights()?;
+
+            if before.on_chain != before.off_chain {
+                // The off-chain database is not yet synced with the on-chain database.
+                std::thread::sleep(BACKUP_RETRY_INTERVAL);
+                continue
+            }
+
+            let result = self
+                .on_chain
+                .checkpoint(path)
+                .and_then(|_| self.off_chain.checkpoint(path))
+                .and_then(|_| self.relayer.checkpoint(path));
+            let after = self.heights();
+
+            match (result, after) {
+                (Ok(()), Ok(after)) if after == before => {
+                    let metadata = BackupMetadata {
+                        block_height: before.on_chain,
+                        da_block_height: before.relayer,
+                        genesis,
+                    };
+                    let file = std::fs::File::create(path.join(BACKUP_METADATA_FILE))?;
+                    serde_json::to_writer_pretty(file, &metadata)?;
+                    return Ok(metadata)
+                }
+                (result, after) => {
+                    remove_checkpoints(path)?;
+                    result?;
+                    after?;
+                    std::thread::sleep(BACKUP_RETRY_INTERVAL);
+                }
+            }
+        }
+
+        Err(anyhow!(
+            "Unable to create a consistent backup after {MAX_BACKUP_ATTEMPTS} attempts"
+        ))
+    }
+
+    /// Restores the backup from the `backup_path` into the empty `database_path`.
+    ///
+    /// The restored databases are verified against the metadata of the backup and
+    /// the `chain_config`. If the restoring fails, the restored files are removed.
+    pub fn restore(
+        backup_path: &Path,
+        database_path: &Path,
+        chain_config: &ChainConfig,
+    ) -> anyhow::Result<BackupMetadata> {
+        let metadata_path = backup_path.join(BACKUP_METADATA_FILE);
+        let file = std::fs::File::open(&metadata_path).with_context(|| {
+            format!(
+                "Unable to open the backup metadata {}",
+                metadata_path.display()
+            )
+        })?;
+        let metadata: BackupMetadata = serde_json::from_reader(file)?;
+
+        let chain_config_hash = chain_config.root()?.into();
+        if metadata.genesis.chain_config_hash != chain_config_hash {
+            return Err(anyhow!(
+                "The backup was created for another chain config: expected {}, found {}",
+                chain_config_hash,
+                metadata.genesis.chain_config_hash,
+            ))
+        }
+
+        ensure_empty_dir(database_path)?;
+        let created = !database_path.exists();
+        let result = Self::restore_into(backup_path, database_path, &metadata);
+
+        if let Err(err) = result {
+            if created {
+                std::fs::remove_dir_all(database_path)?;
+            } else {
+                remove_checkpoints(database_path)?;
+            }
+            return Err(err.context("Unable to restore the backup"))
+        }
+
+        Ok(metadata)
+    }
+
+    fn restore_into(
+        backup_path: &Path,
+        database_path: &Path,
+        metadata: &BackupMetadata,
+    ) -> anyhow::Result<()> {
+        for name in database_names() {
+            let from = backup_path.join(name);
+            if from.exists() {
+                copy_dir_all(&from, &database_path.join(name))?;
+            }
+        }
+
+        Self::open(
+            database_path,
+            VERIFICATION_CACHE_SIZE,
+            &RocksDbConfig::default(),
+        )
+        .map_err(Into::<anyhow::Error>::into)
+        .and_then(|database| database.verify_backup(metadata))
+        .context("The backup is corrupted")
+    }
+
+    fn verify_backup(&self, metadata: &BackupMetadata) -> anyhow::Result<()> {
+        let heights = self.heights()?;
+        let expected = Heights {
+            on_chain: metadata.block_height,
+            off_chain: metadata.block_height,
+            relayer: metadata.da_block_height,
+        };
+        if heights != expected {
+            return Err(anyhow!(
+                "The heights of databases {heights:?} don't match the metadata {expected:?}"
+            ))
+        }
+
+        let genesis = self.on_chain.get_genesis()?;
+        if genesis != metadata.genesis {
+            return Err(anyhow!(
+                "The genesis of the database doesn't match the metadata"
+            ))
+        }
+
+        Ok(())
+    }
+
+    fn heights(&self) -> anyhow::Result<Heights> {
+        let relayer = match self.relayer.latest_height() {
+            Ok(height) => Some(height),
+            Err(StorageError::NotFound(_, _)) => None,
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Heights {
+            on_chain: self.on_chain.latest_height()?,
+            off_chain: self.off_chain.latest_height()?,
+            relayer,
+        })
+    }
+}
+
+/// Resolves the `name` of the backup relative to the `root` directory with backups.
+/// The `name` can't be absolute or point outside of the `root`.
+pub fn resolve_backup_path(root: &Path, name: &Path) -> anyhow::Result<PathBuf> {
+    let is_nested = name.components().next().is_some()
+        && name
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+    if !is_nested {
+        return Err(anyhow!(
+            "The backup path {} should be relative to the backup root",
+            name.display()
+        ))
+    }
+    Ok(root.join(name))
+}
+
+fn database_names() -> [&'static str; 3] {
+    [OnChain::name(), OffChain::name(), Relayer::name()]
+}
+
+fn ensure_empty_dir(path: &Path) -> anyhow::Result<()> {
+    if path.exists() && path.read_dir()?.next().is_some() {
+        return Err(anyhow!("The directory {} is not empty", path.display()))
+    }
+    Ok(())
+}
+
+fn remove_checkpoints(path: &Path) -> anyhow::Result<()> {
+    for name in database_names() {
+        let path = path.join(name);
+        if path.exists() {
+            std::fs::remove_dir_all(path)?;
+        }
+    }
+    Ok(())
+}
+
+fn copy_dir_all(from: &Path, to: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::SealedBlockConsensus,
+        StorageAsMut,
+    };
+    use fuel_core_types::blockchain::consensus::Consensus;
+
+    fn database_with_genesis(
+        path: &Path,
+        chain_config: &ChainConfig,
+    ) -> CombinedDatabase {
+        let mut database =
+            CombinedDatabase::open(path, VERIFICATION_CACHE_SIZE, &Default::default())
+                .unwrap();
+        database
+            .init(&BlockHeight::from(10), &DaBlockHeight(5))
+            .unwrap();
+        let genesis = Genesis {
+            chain_config_hash: chain_config.root().unwrap().into(),
+            ..Default::default()
+        };
+        database
+            .on_chain_mut()
+            .storage_as_mut::<SealedBlockConsensus>()
+            .insert(&BlockHeight::from(0), &Consensus::Genesis(genesis))
+            .unwrap();
+        database
+    }
+
+    #[test]
+    fn backup_can_be_restored() {
+        let tmp = tempfile::tempdir().unwrap();
+        let chain_config = ChainConfig::local_testnet();
+        let database = database_with_genesis(&tmp.path().join("db"), &chain_config);
+
+        let backup_path = tmp.path().join("backup");
+        let metadata = database.backup(&backup_path).unwrap();
+        assert_eq!(metadata.block_height, BlockHeight::from(10));
+        assert_eq!(metadata.da_block_height, Some(DaBlockHeight(5)));
+
+        let restored_path = tmp.path().join("restored");
+        let restored =
+            CombinedDatabase::restore(&backup_path, &restored_path, &chain_config)
+                .unwrap();
+        assert_eq!(restored, metadata);
+
+        let restored = CombinedDatabase::open(
+            &restored_path,
+            VERIFICATION_CACHE_SIZE,
+            &Default::default(),
+        )
+        .unwrap();
+        assert_eq!(restored.heights().unwrap(), database.heights().unwrap());
+    }
+
+    #[test]
+    fn backup_fails_for_not_empty_directory() {
+        let tmp = tempfile::tempdir().unwrap();
+        let chain_config = ChainConfig::local_testnet();
+        let database = database_with_genesis(&tmp.path().join("db"), &chain_config);
+
+        let result = database.backup(&tmp.path().join("db"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn restore_fails_for_another_chain_config() {
+        let tmp = tempfile::tempdir().unwrap();
+        let chain_config = ChainConfig::local_testnet();
+        let database = database_with_genesis(&tmp.path().join("db"), &chain_config);
+        let backup_path = tmp.path().join("backup");
+        database.backup(&backup_path).unwrap();
+
+        let another_chain_config = ChainConfig {
+            chain_name: "another".to_string(),
+            ..chain_config
+        };
+        let restored_path = tmp.path().join("restored");
+        let result = CombinedDatabase::restore(
+            &backup_path,
+            &restored_path,
+            &another_chain_config,
+        );
+        assert!(result.is_err());
+        assert!(!restored_path.join(OnChain::name()).exists());
+    }
+
+    #[test]
+    fn restore_fails_for_mismatched_metadata() {
+        let tmp = tempfile::tempdir().unwrap();
+        let chain_config = ChainConfig::local_testnet();
+        let database = database_with_genesis(&tmp.path().join("db"), &chain_config);
+        let backup_path = tmp.path().join("backup");
+        let metadata = database.backup(&backup_path).unwrap();
+
+        let tampered = BackupMetadata {
+            block_height: BlockHeight::from(11),
+            ..metadata
+        };
+        let file = std::fs::File::create(backup_path.join(BACKUP_METADATA_FILE)).unwrap();
+        serde_json::to_writer(file, &tampered).unwrap();
+
+        let restored_path = tmp.path().join("restored");
+        let result =
+            CombinedDatabase::restore(&backup_path, &restored_path, &chain_config);
+        assert!(result.is_err());
+        assert!(!restored_path.join(OnChain::name()).exists());
+    }
+
+    #[test]
+    fn restore_removes_partially_copied_database() {
+        let tmp = tempfile::tempdir().unwrap();
+        let chain_config = ChainConfig::local_testnet();
+        let database = database_with_genesis(&tmp.path().join("db"), &chain_config);
+        let backup_path = tmp.path().join("backup");
+        database.backup(&backup_path).unwrap();
+
+        // The dangling link can't be copied, so the restoring fails in the middle.
+        std::os::unix::fs::symlink(
+            tmp.path().join("missing"),
+            backup_path.join(OffChain::name()).join("dangling"),
+        )
+        .unwrap();
+
+        let restored_path = tmp.path().join("restored");
+        let result =
+            CombinedDatabase::restore(&backup_path, &restored_path, &chain_config);
+        assert!(result.is_err());
+        assert!(!restored_path.exists());
+    }
+
+    #[test]
+    fn backup_path_is_resolved_inside_of_the_root() {
+        let root = Path::new("/backups");
+
+        assert_eq!(
+            resolve_backup_path(root, Path::new("daily/1")).unwrap(),
+            root.join("daily/1")
+        );
+        assert!(resolve_backup_path(root, Path::new("")).is_err());
+        assert!(resolve_backup_path(root, Path::new("/tmp/backup")).is_err());
+        assert!(resolve_backup_path(root, Path::new("../backup")).is_err());
+        assert!(resolve_backup_path(root, Path::new("daily/../../backup")).is_err());
+        assert!(resolve_backup_path(root, Path::new("./backup")).is_err());
+    }
+}
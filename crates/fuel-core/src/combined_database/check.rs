@@ -0,0 +1,1148 @@
+//! The integrity checker of the [`CombinedDatabase`].
+//!
+//! It verifies invariants that should hold after each committed block and reports
+//! every violation. The data derived from other tables(the secondary index of blocks,
+//! merkle trees of contracts, off-chain indexes) can be repaired in place.
+
+use super::CombinedDatabase;
+use crate::{
+    database::{
+        block::FuelBlockSecondaryKeyBlockHeights,
+        database_description::{
+            off_chain::OffChain,
+            DatabaseDescription,
+            DatabaseMetadata,
+        },
+        metadata::MetadataTable,
+    },
+    fuel_core_graphql_api::{
+        storage::{
+            balances::{
+                CoinBalances,
+                CoinBalancesKey,
+                MessageBalances,
+                TotalBalanceAmount,
+            },
+            coins::{
+                owner_coin_id_key,
+                OwnedCoinKey,
+                OwnedCoins,
+            },
+            messages::{
+                OwnedMessageIds,
+                OwnedMessageKey,
+            },
+            transactions::{
+                OwnedTransactionFlows,
+                OwnedTransactionFlowsKey,
+            },
+        },
+        worker_service::owners_flows,
+    },
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    tables::{
+        merkle::{
+            ContractsAssetsMerkleData,
+            ContractsAssetsMerkleMetadata,
+            ContractsStateMerkleData,
+            ContractsStateMerkleMetadata,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+            SparseMerkleMetadata,
+        },
+        Coins,
+        ContractsAssets,
+        ContractsInfo,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+        Transactions,
+    },
+    MerkleRoot,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    entities::coins::coin::Coin,
+    fuel_merkle::{
+        binary::{
+            root_calculator::MerkleRootCalculator,
+            MerkleTree,
+        },
+        sparse,
+        sparse::{
+            in_memory,
+            MerkleTreeKey,
+        },
+    },
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        Output,
+        Transaction,
+        TxId,
+        TxPointer,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        ContractId,
+        Nonce,
+    },
+};
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+/// The violation of the database invariant found by the [`CombinedDatabase::check`].
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+pub enum Discrepancy {
+    #[display(fmt = "The block stored at the height {key} has the height {height}")]
+    BlockHeightMismatch {
+        key: BlockHeight,
+        height: BlockHeight,
+    },
+    #[display(fmt = "The block {next} doesn't follow the block {previous}")]
+    BlockGap {
+        previous: BlockHeight,
+        next: BlockHeight,
+    },
+    #[display(
+        fmt = "The block {height} has the `prev_root` {actual}, but expected {expected}"
+    )]
+    BlockPrevRootMismatch {
+        height: BlockHeight,
+        expected: Bytes32,
+        actual: Bytes32,
+    },
+    #[display(fmt = "The block id {block_id} is not linked to the height {height}")]
+    MissingBlockId {
+        height: BlockHeight,
+        block_id: BlockId,
+    },
+    #[display(fmt = "The merkle metadata of the block {height} is invalid")]
+    BlockMerkleMetadataMismatch { height: BlockHeight },
+    #[display(fmt = "The merkle data of blocks doesn't match the root at {height}")]
+    BlockMerkleDataMismatch { height: BlockHeight },
+    #[display(
+        fmt = "The state root of the contract {contract_id} is {actual}, but expected {expected}"
+    )]
+    ContractStateRootMismatch {
+        contract_id: ContractId,
+        expected: Bytes32,
+        actual: Bytes32,
+    },
+    #[display(
+        fmt = "The assets root of the contract {contract_id} is {actual}, but expected {expected}"
+    )]
+    ContractAssetsRootMismatch {
+        contract_id: ContractId,
+        expected: Bytes32,
+        actual: Bytes32,
+    },
+    #[display(fmt = "The coin {utxo_id} of {owner} is not indexed off-chain")]
+    MissingOwnedCoin { owner: Address, utxo_id: UtxoId },
+    #[display(fmt = "The indexed coin {utxo_id} of {owner} doesn't exist")]
+    DanglingOwnedCoin { owner: Address, utxo_id: UtxoId },
+    #[display(fmt = "The message {nonce} of {recipient} is not indexed off-chain")]
+    MissingOwnedMessage { recipient: Address, nonce: Nonce },
+    #[display(fmt = "The indexed message {nonce} of {recipient} doesn't exist")]
+    DanglingOwnedMessage { recipient: Address, nonce: Nonce },
+    #[display(
+        fmt = "The indexed balance of the asset {asset_id} of {owner} is {actual}, but expected {expected}"
+    )]
+    CoinBalanceMismatch {
+        owner: Address,
+        asset_id: AssetId,
+        expected: TotalBalanceAmount,
+        actual: TotalBalanceAmount,
+    },
+    #[display(
+        fmt = "The indexed balance of messages of {owner} is {actual}, but expected {expected}"
+    )]
+    MessageBalanceMismatch {
+        owner: Address,
+        expected: TotalBalanceAmount,
+        actual: TotalBalanceAmount,
+    },
+}
+
+impl Discrepancy {
+    /// Returns `true` if the discrepancy is in the derived data and can be repaired.
+    pub fn is_repairable(&self) -> bool {
+        matches!(
+            self,
+            Discrepancy::MissingBlockId { .. }
+                | Discrepancy::ContractStateRootMismatch { .. }
+                | Discrepancy::ContractAssetsRootMismatch { .. }
+                | Discrepancy::MissingOwnedCoin { .. }
+                | Discrepancy::DanglingOwnedCoin { .. }
+                | Discrepancy::MissingOwnedMessage { .. }
+                | Discrepancy::DanglingOwnedMessage { .. }
+                | Discrepancy::CoinBalanceMismatch { .. }
+                | Discrepancy::MessageBalanceMismatch { .. }
+        )
+    }
+}
+
+/// The result of the [`CombinedDatabase::check`].
+#[derive(Debug, Default)]
+pub struct CheckReport {
+    /// All discrepancies found in the databases.
+    pub discrepancies: Vec<Discrepancy>,
+    /// The number of repaired discrepancies.
+    pub repaired: usize,
+}
+
+impl CheckReport {
+    /// Returns `true` if no discrepancies were found.
+    pub fn is_consistent(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+
+    fn report(&mut self, discrepancy: Discrepancy) {
+        self.discrepancies.push(discrepancy);
+    }
+}
+
+/// The encoded key and value of the sparse merkle tree leaf.
+type Leaf = (Vec<u8>, Vec<u8>);
+
+impl CombinedDatabase {
+    /// Verifies the integrity of databases and reports all found discrepancies.
+    /// If `repair` is `true`, repairable discrepancies are fixed in place.
+    ///
+    /// The check iterates over all tables, so it should be run on the stopped node.
+    pub fn check(&mut self, repair: bool) -> StorageResult<CheckReport> {
+        let mut report = CheckReport::default();
+        self.check_blocks(repair, &mut report)?;
+        self.check_contracts(repair, &mut report)?;
+        self.check_owned_coins(repair, &mut report)?;
+        self.check_owned_messages(repair, &mut report)?;
+        self.check_balances(repair, &mut report)?;
+        Ok(report)
+    }
+
+    /// Rebuilds the off-chain indexes of coins, messages and balances from the on-chain state.
+    /// It is used after the import of the snapshot, which contains only the on-chain state,
+    /// and backfills the indexes missing in the databases created by older versions.
+    pub fn rebuild_off_chain_indexes(&mut self) -> StorageResult<CheckReport> {
+        let mut report = CheckReport::default();
+        self.check_owned_coins(true, &mut report)?;
+        self.check_owned_messages(true, &mut report)?;
+        self.check_balances(true, &mut report)?;
+        Ok(report)
+    }
+
+    /// Migrates the off-chain database created by an older version to the current one.
+    /// Each migration step backfills the indexes added by the next version and bumps
+    /// the stored version, so an interrupted migration resumes from the last finished step.
+    ///
+    /// Versions newer than the current one are left untouched and rejected by the `init`.
+    pub fn migrate_off_chain(&mut self, base_asset_id: &AssetId) -> StorageResult<()> {
+        let Some(metadata) = self
+            .off_chain
+            .storage::<MetadataTable<OffChain>>()
+            .get(&())?
+            .map(|metadata| metadata.into_owned())
+        else {
+            return Ok(())
+        };
+
+        let mut version = metadata.version();
+        while version < OffChain::version() {
+            let mut report = CheckReport::default();
+            match version {
+                0 => self.check_balances(true, &mut report)?,
+                1 => self.backfill_transaction_flows(base_asset_id, &mut report)?,
+                _ => break,
+            }
+            version = version.saturating_add(1);
+            tracing::info!(
+                "Migrated the off-chain database to the version {version}, \
+                repaired {} entries",
+                report.repaired
+            );
+            self.off_chain
+                .storage_as_mut::<MetadataTable<OffChain>>()
+                .insert(
+                    &(),
+                    &DatabaseMetadata::V1 {
+                        version,
+                        height: *metadata.height(),
+                    },
+                )?;
+        }
+        Ok(())
+    }
+
+    fn check_blocks(
+        &mut self,
+        repair: bool,
+        report: &mut CheckReport,
+    ) -> StorageResult<()> {
+        let mut calculator = MerkleRootCalculator::new();
+        let mut leaves = 0u64;
+        let mut previous: Option<(BlockHeight, MerkleRoot)> = None;
+        let mut missing_ids = vec![];
+
+        for result in self
+            .on_chain
+            .iter_all::<FuelBlocks>(Some(IterDirection::Forward))
+        {
+            let (key, block) = result?;
+            let height = *block.header().height();
+            if height != key {
+                report.report(Discrepancy::BlockHeightMismatch { key, height });
+            }
+
+            if let Some((previous_height, previous_root)) = previous {
+                if previous_height.succ() != Some(key) {
+                    report.report(Discrepancy::BlockGap {
+                        previous: previous_height,
+                        next: key,
+                    });
+                }
+
+                let prev_root = *block.header().prev_root();
+                if prev_root != Bytes32::from(previous_root) {
+                    report.report(Discrepancy::BlockPrevRootMismatch {
+                        height: key,
+                        expected: previous_root.into(),
+                        actual: prev_root,
+                    });
+                }
+            }
+
+            let block_id = block.id();
+            if self.on_chain.get_block_height(&block_id)? != Some(key) {
+                report.report(Discrepancy::MissingBlockId {
+                    height: key,
+                    block_id,
+                });
+                missing_ids.push((block_id, key));
+            }
+
+            calculator.push(block_id.as_slice());
+            leaves = leaves.saturating_add(1);
+            let root = calculator.clone().root();
+
+            let metadata = self
+                .on_chain
+                .storage::<FuelBlockMerkleMetadata>()
+                .get(&key)?;
+            let is_valid = metadata.map_or(false, |metadata| {
+                metadata.root() == &root && metadata.version() == leaves
+            });
+            if !is_valid {
+                report.report(Discrepancy::BlockMerkleMetadataMismatch { height: key });
+            }
+
+            previous = Some((key, root));
+        }
+
+        // The merkle data is verified only for the latest block, because
+        // the tree of the latest block contains nodes of all previous trees.
+        if let Some((height, root)) = previous {
+            let is_valid =
+                MerkleTree::<FuelBlockMerkleData, _>::load(&self.on_chain, leaves)
+                    .map_or(false, |tree| tree.root() == root);
+            if !is_valid {
+                report.report(Discrepancy::BlockMerkleDataMismatch { height });
+            }
+        }
+
+        if repair {
+            for (block_id, height) in missing_ids {
+                self.on_chain
+                    .storage_as_mut::<FuelBlockSecondaryKeyBlockHeights>()
+                    .insert(&block_id, &height)?;
+                report.repaired = report.repaired.saturating_add(1);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_contracts(
+        &mut self,
+        repair: bool,
+        report: &mut CheckReport,
+    ) -> StorageResult<()> {
+        let mut contracts = BTreeSet::new();
+        for result in self.on_chain.iter_all::<ContractsInfo>(None) {
+            let (contract_id, _) = result?;
+            contracts.insert(contract_id);
+        }
+        for result in self.on_chain.iter_all::<ContractsStateMerkleMetadata>(None) {
+            let (contract_id, _) = result?;
+            contracts.insert(contract_id);
+        }
+        for result in self
+            .on_chain
+            .iter_all::<ContractsAssetsMerkleMetadata>(None)
+        {
+            let (contract_id, _) = result?;
+            contracts.insert(contract_id);
+        }
+
+        for contract_id in contracts {
+            let state = self
+                .on_chain
+                .iter_all_by_prefix::<ContractsState, _>(Some(contract_id.as_ref()))
+                .map(|result| {
+                    result.map(|(key, value)| (key.as_ref().to_vec(), value.to_vec()))
+                })
+                .collect::<StorageResult<Vec<Leaf>>>()?;
+            let expected = sparse_root(&state);
+            let actual = self
+                .on_chain
+                .storage::<ContractsStateMerkleMetadata>()
+                .get(&contract_id)?
+                .map(|metadata| *metadata.root())
+                .unwrap_or_else(|| in_memory::MerkleTree::new().root());
+            if expected != actual {
+                report.report(Discrepancy::ContractStateRootMismatch {
+                    contract_id,
+                    expected: expected.into(),
+                    actual: actual.into(),
+                });
+                if repair {
+                    let (root, nodes) = sparse_nodes(&state);
+                    for (key, node) in nodes {
+                        self.on_chain
+                            .storage_as_mut::<ContractsStateMerkleData>()
+                            .insert(&key, &node)?;
+                    }
+                    self.on_chain
+                        .storage_as_mut::<ContractsStateMerkleMetadata>()
+                        .insert(&contract_id, &SparseMerkleMetadata::new(root))?;
+                    report.repaired = report.repaired.saturating_add(1);
+                }
+            }
+
+            let assets = self
+                .on_chain
+                .iter_all_by_prefix::<ContractsAssets, _>(Some(contract_id.as_ref()))
+                .map(|result| {
+                    result.map(|(key, value)| {
+                        (key.as_ref().to_vec(), value.to_be_bytes().to_vec())
+                    })
+                })
+                .collect::<StorageResult<Vec<Leaf>>>()?;
+            let expected = sparse_root(&assets);
+            let actual = self
+                .on_chain
+                .storage::<ContractsAssetsMerkleMetadata>()
+                .get(&contract_id)?
+                .map(|metadata| *metadata.root())
+                .unwrap_or_else(|| in_memory::MerkleTree::new().root());
+            if expected != actual {
+                report.report(Discrepancy::ContractAssetsRootMismatch {
+                    contract_id,
+                    expected: expected.into(),
+                    actual: actual.into(),
+                });
+                if repair {
+                    let (root, nodes) = sparse_nodes(&assets);
+                    for (key, node) in nodes {
+                        self.on_chain
+                            .storage_as_mut::<ContractsAssetsMerkleData>()
+                            .insert(&key, &node)?;
+                    }
+                    self.on_chain
+                        .storage_as_mut::<ContractsAssetsMerkleMetadata>()
+                        .insert(&contract_id, &SparseMerkleMetadata::new(root))?;
+                    report.repaired = report.repaired.saturating_add(1);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_owned_coins(
+        &mut self,
+        repair: bool,
+        report: &mut CheckReport,
+    ) -> StorageResult<()> {
+        let mut missing = vec![];
+        for result in self.on_chain.iter_all::<Coins>(None) {
+            let (utxo_id, coin) = result?;
+            let key = owner_coin_id_key(coin.owner(), &utxo_id);
+            if !self.off_chain.storage::<OwnedCoins>().contains_key(&key)? {
+                report.report(Discrepancy::MissingOwnedCoin {
+                    owner: *coin.owner(),
+                    utxo_id,
+                });
+                missing.push(key);
+            }
+        }
+
+        let mut dangling = vec![];
+        for result in self.off_chain.iter_all::<OwnedCoins>(None) {
+            let (key, _) = result?;
+            let (owner, utxo_id) = split_owned_coin_key(&key);
+            let coin = self.on_chain.storage::<Coins>().get(&utxo_id)?;
+            if coin.map_or(true, |coin| coin.owner() != &owner) {
+                report.report(Discrepancy::DanglingOwnedCoin { owner, utxo_id });
+                dangling.push(key);
+            }
+        }
+
+        if repair {
+            for key in missing {
+                self.off_chain
+                    .storage_as_mut::<OwnedCoins>()
+                    .insert(&key, &())?;
+                report.repaired = report.repaired.saturating_add(1);
+            }
+            for key in dangling {
+                self.off_chain.storage_as_mut::<OwnedCoins>().remove(&key)?;
+                report.repaired = report.repaired.saturating_add(1);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_owned_messages(
+        &mut self,
+        repair: bool,
+        report: &mut CheckReport,
+    ) -> StorageResult<()> {
+        let mut missing = vec![];
+        for result in self.on_chain.iter_all::<Messages>(None) {
+            let (nonce, message) = result?;
+            let key = OwnedMessageKey::new(message.recipient(), &nonce);
+            if !self
+                .off_chain
+                .storage::<OwnedMessageIds>()
+                .contains_key(&key)?
+            {
+                report.report(Discrepancy::MissingOwnedMessage {
+                    recipient: *message.recipient(),
+                    nonce,
+                });
+                missing.push(key);
+            }
+        }
+
+        let mut dangling = vec![];
+        for result in self.off_chain.iter_all::<OwnedMessageIds>(None) {
+            let (key, _) = result?;
+            let message = self.on_chain.storage::<Messages>().get(key.nonce())?;
+            if message.map_or(true, |message| message.recipient() != key.address()) {
+                report.report(Discrepancy::DanglingOwnedMessage {
+                    recipient: *key.address(),
+                    nonce: *key.nonce(),
+                });
+                dangling.push(key);
+            }
+        }
+
+        if repair {
+            for key in missing {
+                self.off_chain
+                    .storage_as_mut::<OwnedMessageIds>()
+                    .insert(&key, &())?;
+                report.repaired = report.repaired.saturating_add(1);
+            }
+            for key in dangling {
+                self.off_chain
+                    .storage_as_mut::<OwnedMessageIds>()
+                    .remove(&key)?;
+                report.repaired = report.repaired.saturating_add(1);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Indexes the flows of assets of all transactions stored in the on-chain database.
+    /// The transaction creates coins from its coin, change and variable outputs
+    /// with non-zero amounts, the same as the executor inserts them into the UTXO set.
+    fn backfill_transaction_flows(
+        &mut self,
+        base_asset_id: &AssetId,
+        report: &mut CheckReport,
+    ) -> StorageResult<()> {
+        for result in self.on_chain.iter_all::<FuelBlocks>(None) {
+            let (height, block) = result?;
+            for (tx_idx, tx_id) in block.transactions().iter().enumerate() {
+                let Some(tx) = self.on_chain.storage::<Transactions>().get(tx_id)? else {
+                    continue
+                };
+                let (inputs, outputs) = match tx.as_ref() {
+                    Transaction::Script(tx) => {
+                        (tx.inputs().as_slice(), tx.outputs().as_slice())
+                    }
+                    Transaction::Create(tx) => {
+                        (tx.inputs().as_slice(), tx.outputs().as_slice())
+                    }
+                    Transaction::Mint(_) => continue,
+                };
+                let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                    anyhow::anyhow!(
+                        "The block has more than `u16::MAX` transactions, {}",
+                        e
+                    )
+                })?;
+
+                let mut created_coins = vec![];
+                for (output_idx, output) in outputs.iter().enumerate() {
+                    let (Output::Coin {
+                        to,
+                        amount,
+                        asset_id,
+                    }
+                    | Output::Change {
+                        to,
+                        amount,
+                        asset_id,
+                    }
+                    | Output::Variable {
+                        to,
+                        amount,
+                        asset_id,
+                    }) = output
+                    else {
+                        continue
+                    };
+                    if *amount == 0 {
+                        continue
+                    }
+                    let output_idx = u8::try_from(output_idx).map_err(|e| {
+                        anyhow::anyhow!(
+                            "The transaction has more than `u8::MAX` outputs, {}",
+                            e
+                        )
+                    })?;
+                    created_coins.push(Coin {
+                        utxo_id: UtxoId::new(*tx_id, output_idx),
+                        owner: *to,
+                        amount: *amount,
+                        asset_id: *asset_id,
+                        maturity: 0u32.into(),
+                        tx_pointer: TxPointer::new(height, tx_idx),
+                    });
+                }
+                let created_coins = created_coins.iter().collect::<Vec<_>>();
+
+                for (owner, tx_flows) in
+                    owners_flows(inputs, outputs, &created_coins, base_asset_id)
+                {
+                    self.off_chain
+                        .storage_as_mut::<OwnedTransactionFlows>()
+                        .insert(
+                            &OwnedTransactionFlowsKey::new(owner, tx_id),
+                            &tx_flows,
+                        )?;
+                    report.repaired = report.repaired.saturating_add(1);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Compares the indexed balances with the sums of unspent coins and messages.
+    fn check_balances(
+        &mut self,
+        repair: bool,
+        report: &mut CheckReport,
+    ) -> StorageResult<()> {
+        let mut expected_coins = BTreeMap::<CoinBalancesKey, TotalBalanceAmount>::new();
+        for result in self.on_chain.iter_all::<Coins>(None) {
+            let (_, coin) = result?;
+            let balance = expected_coins
+                .entry(CoinBalancesKey::new(coin.owner(), coin.asset_id()))
+                .or_default();
+            *balance = balance.saturating_add((*coin.amount()).into());
+        }
+
+        let mut coins = vec![];
+        for result in self.off_chain.iter_all::<CoinBalances>(None) {
+            let (key, actual) = result?;
+            let expected = expected_coins.remove(&key).unwrap_or_default();
+            if expected != actual {
+                coins.push((key, expected, actual));
+            }
+        }
+        coins.extend(
+            expected_coins
+                .into_iter()
+                .filter(|(_, expected)| *expected != 0)
+                .map(|(key, expected)| (key, expected, 0)),
+        );
+
+        let mut expected_messages = BTreeMap::<Address, TotalBalanceAmount>::new();
+        for result in self.on_chain.iter_all::<Messages>(None) {
+            let (_, message) = result?;
+            // Only messages without data are counted, the same as by the off-chain worker.
+            if message.data().is_empty() {
+                let balance = expected_messages.entry(*message.recipient()).or_default();
+                *balance = balance.saturating_add(message.amount().into());
+            }
+        }
+
+        let mut messages = vec![];
+        for result in self.off_chain.iter_all::<MessageBalances>(None) {
+            let (owner, actual) = result?;
+            let expected = expected_messages.remove(&owner).unwrap_or_default();
+            if expected != actual {
+                messages.push((owner, expected, actual));
+            }
+        }
+        messages.extend(
+            expected_messages
+                .into_iter()
+                .filter(|(_, expected)| *expected != 0)
+                .map(|(owner, expected)| (owner, expected, 0)),
+        );
+
+        for (key, expected, actual) in &coins {
+            report.report(Discrepancy::CoinBalanceMismatch {
+                owner: *key.owner(),
+                asset_id: *key.asset_id(),
+                expected: *expected,
+                actual: *actual,
+            });
+        }
+        for (owner, expected, actual) in &messages {
+            report.report(Discrepancy::MessageBalanceMismatch {
+                owner: *owner,
+                expected: *expected,
+                actual: *actual,
+            });
+        }
+
+        if repair {
+            for (key, expected, _) in coins {
+                if expected == 0 {
+                    self.off_chain
+                        .storage_as_mut::<CoinBalances>()
+                        .remove(&key)?;
+                } else {
+                    self.off_chain
+                        .storage_as_mut::<CoinBalances>()
+                        .insert(&key, &expected)?;
+                }
+                report.repaired = report.repaired.saturating_add(1);
+            }
+            for (owner, expected, _) in messages {
+                if expected == 0 {
+                    self.off_chain
+                        .storage_as_mut::<MessageBalances>()
+                        .remove(&owner)?;
+                } else {
+                    self.off_chain
+                        .storage_as_mut::<MessageBalances>()
+                        .insert(&owner, &expected)?;
+                }
+                report.repaired = report.repaired.saturating_add(1);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn sparse_root(leaves: &[Leaf]) -> MerkleRoot {
+    in_memory::MerkleTree::root_from_set(
+        leaves
+            .iter()
+            .map(|(key, value)| (MerkleTreeKey::new(key), value)),
+    )
+}
+
+fn sparse_nodes(leaves: &[Leaf]) -> (MerkleRoot, Vec<(MerkleRoot, sparse::Primitive)>) {
+    in_memory::MerkleTree::nodes_from_set(
+        leaves
+            .iter()
+            .map(|(key, value)| (MerkleTreeKey::new(key), value)),
+    )
+}
+
+fn split_owned_coin_key(key: &OwnedCoinKey) -> (Address, UtxoId) {
+    // Safety: key is always 65 bytes
+    let owner = Address::try_from(&key[0..32]).expect("The slice has size 32");
+    let tx_id = TxId::try_from(&key[32..64]).expect("The slice has size 32");
+    (owner, UtxoId::new(tx_id, key[64]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuel_core_graphql_api::storage::transactions::{
+        AssetFlow,
+        TransactionFlows,
+    };
+    use fuel_core_storage::ContractsStateKey;
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::{
+            Finalizable,
+            Input,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+    };
+
+    fn database_with_state() -> CombinedDatabase {
+        let mut database = CombinedDatabase::in_memory();
+        let contract_id = ContractId::from([1; 32]);
+        database
+            .on_chain_mut()
+            .storage_as_mut::<ContractsInfo>()
+            .insert(&contract_id, &(Default::default(), Bytes32::zeroed()))
+            .unwrap();
+        database
+            .on_chain_mut()
+            .storage_as_mut::<ContractsState>()
+            .insert(
+                &ContractsStateKey::new(&contract_id, &Bytes32::from([2; 32])),
+                &Bytes32::from([3; 32]),
+            )
+            .unwrap();
+
+        let utxo_id = UtxoId::new(TxId::from([4; 32]), 0);
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(Address::from([5; 32]));
+        database
+            .on_chain_mut()
+            .storage_as_mut::<Coins>()
+            .insert(&utxo_id, &coin)
+            .unwrap();
+        database
+            .off_chain_mut()
+            .storage_as_mut::<OwnedCoins>()
+            .insert(&owner_coin_id_key(coin.owner(), &utxo_id), &())
+            .unwrap();
+        database
+    }
+
+    #[test]
+    fn consistent_database_has_no_discrepancies() {
+        let mut database = database_with_state();
+
+        let report = database.check(false).unwrap();
+
+        assert!(report.is_consistent(), "{:?}", report.discrepancies);
+    }
+
+    #[test]
+    fn detects_and_repairs_off_chain_coins() {
+        let mut database = database_with_state();
+        let dangling_owner = Address::from([6; 32]);
+        let dangling_utxo_id = UtxoId::new(TxId::from([7; 32]), 1);
+        database
+            .off_chain_mut()
+            .storage_as_mut::<OwnedCoins>()
+            .insert(&owner_coin_id_key(&dangling_owner, &dangling_utxo_id), &())
+            .unwrap();
+        let missing_utxo_id = UtxoId::new(TxId::from([4; 32]), 0);
+        database
+            .off_chain_mut()
+            .storage_as_mut::<OwnedCoins>()
+            .remove(&owner_coin_id_key(
+                &Address::from([5; 32]),
+                &missing_utxo_id,
+            ))
+            .unwrap();
+
+        let report = database.check(true).unwrap();
+
+        assert_eq!(
+            report.discrepancies,
+            vec![
+                Discrepancy::MissingOwnedCoin {
+                    owner: Address::from([5; 32]),
+                    utxo_id: missing_utxo_id,
+                },
+                Discrepancy::DanglingOwnedCoin {
+                    owner: dangling_owner,
+                    utxo_id: dangling_utxo_id,
+                },
+            ]
+        );
+        assert_eq!(report.repaired, 2);
+        assert!(database.check(false).unwrap().is_consistent());
+    }
+
+    #[test]
+    fn detects_and_repairs_contract_state_root() {
+        let mut database = database_with_state();
+        let contract_id = ContractId::from([1; 32]);
+        database
+            .on_chain_mut()
+            .storage_as_mut::<ContractsStateMerkleMetadata>()
+            .insert(&contract_id, &SparseMerkleMetadata::default())
+            .unwrap();
+
+        let report = database.check(true).unwrap();
+
+        assert_eq!(report.discrepancies.len(), 1);
+        assert!(matches!(
+            report.discrepancies[0],
+            Discrepancy::ContractStateRootMismatch { .. }
+        ));
+        assert_eq!(report.repaired, 1);
+        assert!(database.check(false).unwrap().is_consistent());
+    }
+
+    #[test]
+    fn detects_dangling_owned_message() {
+        let mut database = database_with_state();
+        let key = OwnedMessageKey::new(&Address::from([8; 32]), &Nonce::from([9; 32]));
+        database
+            .off_chain_mut()
+            .storage_as_mut::<OwnedMessageIds>()
+            .insert(&key, &())
+            .unwrap();
+
+        let report = database.check(false).unwrap();
+
+        assert_eq!(
+            report.discrepancies,
+            vec![Discrepancy::DanglingOwnedMessage {
+                recipient: Address::from([8; 32]),
+                nonce: Nonce::from([9; 32]),
+            }]
+        );
+        assert_eq!(report.repaired, 0);
+    }
+
+    #[test]
+    fn detects_and_repairs_balances() {
+        let mut database = database_with_state();
+        let owner = Address::from([5; 32]);
+        let utxo_id = UtxoId::new(TxId::from([10; 32]), 0);
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(owner);
+        coin.set_amount(10);
+        database
+            .on_chain_mut()
+            .storage_as_mut::<Coins>()
+            .insert(&utxo_id, &coin)
+            .unwrap();
+        database
+            .off_chain_mut()
+            .storage_as_mut::<OwnedCoins>()
+            .insert(&owner_coin_id_key(&owner, &utxo_id), &())
+            .unwrap();
+        let stale_owner = Address::from([8; 32]);
+        database
+            .off_chain_mut()
+            .storage_as_mut::<MessageBalances>()
+            .insert(&stale_owner, &3)
+            .unwrap();
+
+        let report = database.check(true).unwrap();
+
+        assert_eq!(
+            report.discrepancies,
+            vec![
+                Discrepancy::CoinBalanceMismatch {
+                    owner,
+                    asset_id: AssetId::default(),
+                    expected: 10,
+                    actual: 0,
+                },
+                Discrepancy::MessageBalanceMismatch {
+                    owner: stale_owner,
+                    expected: 0,
+                    actual: 3,
+                },
+            ]
+        );
+        assert_eq!(report.repaired, 2);
+        assert!(database.check(false).unwrap().is_consistent());
+    }
+
+    #[test]
+    fn migrates_off_chain_database_of_version_0() {
+        let mut database = database_with_state();
+        let owner = Address::from([5; 32]);
+        let utxo_id = UtxoId::new(TxId::from([10; 32]), 0);
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(owner);
+        coin.set_amount(10);
+        database
+            .on_chain_mut()
+            .storage_as_mut::<Coins>()
+            .insert(&utxo_id, &coin)
+            .unwrap();
+
+        let sender = Address::from([6; 32]);
+        let mut tx = TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::coin_signed(
+                UtxoId::new(TxId::from([11; 32]), 0),
+                sender,
+                100,
+                AssetId::default(),
+                Default::default(),
+                0,
+                Default::default(),
+            ))
+            .add_output(Output::coin(owner, 30, AssetId::default()))
+            .add_output(Output::change(sender, 70, AssetId::default()))
+            .add_output(Output::variable(owner, 0, AssetId::default()))
+            .add_witness(Default::default())
+            .finalize();
+        // The stored transaction contains the change amounts calculated by the executor.
+        tx.outputs_mut()[1] = Output::change(sender, 70, AssetId::default());
+        let tx_id = tx.id(&ChainId::default());
+        let tx: Transaction = tx.into();
+        database
+            .on_chain_mut()
+            .storage_as_mut::<Transactions>()
+            .insert(&tx_id, &tx)
+            .unwrap();
+        let mut block = CompressedBlock::default();
+        block.transactions_mut().push(tx_id);
+        database
+            .on_chain_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&0.into(), &block)
+            .unwrap();
+
+        database
+            .off_chain_mut()
+            .storage_as_mut::<MetadataTable<OffChain>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V1 {
+                    version: 0,
+                    height: 7u32.into(),
+                },
+            )
+            .unwrap();
+
+        database.migrate_off_chain(&AssetId::default()).unwrap();
+
+        database.init(&7u32.into(), &0u64.into()).unwrap();
+        let metadata = database
+            .off_chain()
+            .storage::<MetadataTable<OffChain>>()
+            .get(&())
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(metadata.version(), OffChain::version());
+        assert_eq!(*metadata.height(), 7u32.into());
+        let balance = database
+            .off_chain()
+            .storage::<CoinBalances>()
+            .get(&CoinBalancesKey::new(&owner, &AssetId::default()))
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(balance, 10);
+        let flows = |owner: &Address| {
+            database
+                .off_chain()
+                .storage::<OwnedTransactionFlows>()
+                .get(&OwnedTransactionFlowsKey::new(owner, &tx_id))
+                .unwrap()
+                .unwrap()
+                .into_owned()
+        };
+        assert_eq!(
+            flows(&owner),
+            TransactionFlows {
+                is_sender: false,
+                assets: vec![AssetFlow {
+                    asset_id: AssetId::default(),
+                    spent: 0,
+                    received: 30,
+                }],
+            }
+        );
+        assert_eq!(
+            flows(&sender),
+            TransactionFlows {
+                is_sender: true,
+                assets: vec![AssetFlow {
+                    asset_id: AssetId::default(),
+                    spent: 100,
+                    received: 70,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn detects_broken_block_linkage() {
+        let mut database = CombinedDatabase::in_memory();
+        let mut block = CompressedBlock::default();
+        database
+            .on_chain_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&0.into(), &block)
+            .unwrap();
+        let root: Bytes32 = database
+            .on_chain()
+            .storage::<FuelBlocks>()
+            .root(&0.into())
+            .unwrap()
+            .into();
+
+        block.header_mut().set_block_height(1.into());
+        block.header_mut().set_previous_root(root);
+        block.header_mut().recalculate_metadata();
+        database
+            .on_chain_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&1.into(), &block)
+            .unwrap();
+        assert!(database.check(false).unwrap().is_consistent());
+
+        block.header_mut().set_block_height(2.into());
+        block.header_mut().recalculate_metadata();
+        database
+            .on_chain_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&3.into(), &block)
+            .unwrap();
+
+        let report = database.check(false).unwrap();
+
+        assert_eq!(
+            report.discrepancies,
+            vec![
+                Discrepancy::BlockHeightMismatch {
+                    key: 3.into(),
+                    height: 2.into(),
+                },
+                Discrepancy::BlockGap {
+                    previous: 1.into(),
+                    next: 3.into(),
+                },
+                Discrepancy::BlockPrevRootMismatch {
+                    height: 3.into(),
+                    expected: database
+                        .on_chain()
+                        .storage::<FuelBlocks>()
+                        .root(&1.into())
+                        .unwrap()
+                        .into(),
+                    actual: root,
+                },
+                // The metadata was stored at the height from the header.
+                Discrepancy::BlockMerkleMetadataMismatch { height: 3.into() },
+            ]
+        );
+    }
+}
@@ -66,11 +66,16 @@ pub type Result<T> = core::result::Result<T, Error>;
 type DatabaseResult<T> = Result<T>;
 
 // TODO: Extract `Database` and all belongs into `fuel-core-database`.
+#[cfg(feature = "redb")]
+use crate::state::redb_store::RedbStore;
 #[cfg(feature = "rocksdb")]
-use crate::state::rocks_db::RocksDb;
-#[cfg(feature = "rocksdb")]
+use crate::state::{
+    rocks_db::RocksDb,
+    rocks_db_config::RocksDbConfig,
+};
+#[cfg(any(feature = "rocksdb", feature = "redb"))]
 use std::path::Path;
-#[cfg(feature = "rocksdb")]
+#[cfg(any(feature = "rocksdb", feature = "redb"))]
 use tempfile::TempDir;
 
 // Storages implementation
@@ -81,7 +86,9 @@ pub mod contracts;
 pub mod database_description;
 pub mod message;
 pub mod metadata;
+pub mod peer_reputation;
 pub mod sealed_block;
+pub mod snapshot;
 pub mod state;
 pub mod statistic;
 pub mod storage;
@@ -148,8 +155,17 @@ where
 
     #[cfg(feature = "rocksdb")]
     pub fn open(path: &Path, capacity: impl Into<Option<usize>>) -> DatabaseResult<Self> {
+        Self::open_with_config(path, capacity, &RocksDbConfig::default())
+    }
+
+    #[cfg(feature = "rocksdb")]
+    pub fn open_with_config(
+        path: &Path,
+        capacity: impl Into<Option<usize>>,
+        config: &RocksDbConfig,
+    ) -> DatabaseResult<Self> {
         use anyhow::Context;
-        let db = RocksDb::<Description>::default_open(path, capacity.into()).map_err(Into::<anyhow::Error>::into).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
+        let db = RocksDb::<Description>::default_open_with_config(path, capacity.into(), config).map_err(Into::<anyhow::Error>::into).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
 
         Ok(Database {
             data: StructuredStorage::new(Arc::new(db).into()),
@@ -182,6 +198,37 @@ where
         }
     }
 
+    #[cfg(feature = "redb")]
+    pub fn open_redb(path: &Path) -> DatabaseResult<Self> {
+        use anyhow::Context;
+        let db = RedbStore::<Description>::default_open(path)
+            .map_err(Into::<anyhow::Error>::into)
+            .context("Failed to open redb database")?;
+
+        Ok(Database {
+            data: StructuredStorage::new(Arc::new(db).into()),
+            _drop: Default::default(),
+        })
+    }
+
+    #[cfg(feature = "redb")]
+    pub fn redb() -> Self {
+        let tmp_dir = TempDir::new().unwrap();
+        let db = RedbStore::<Description>::default_open(tmp_dir.path()).unwrap();
+        Self {
+            data: StructuredStorage::new(Arc::new(db).into()),
+            _drop: Arc::new(
+                {
+                    move || {
+                        // cleanup temp dir
+                        drop(tmp_dir);
+                    }
+                }
+                .into(),
+            ),
+        }
+    }
+
     pub fn transaction(&self) -> DatabaseTransaction<Description> {
         self.into()
     }
@@ -189,6 +236,11 @@ where
     pub fn flush(self) -> DatabaseResult<()> {
         self.data.as_ref().flush()
     }
+
+    /// Creates a checkpoint of the database inside of the `path` directory.
+    pub fn checkpoint(&self, path: &std::path::Path) -> DatabaseResult<()> {
+        self.data.as_ref().checkpoint(path)
+    }
 }
 
 impl<Description> KeyValueStore for DataSource<Description>
@@ -227,6 +279,16 @@ where
         self.as_ref().delete(key, column)
     }
 
+    fn compare_and_swap(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        expected: Option<&[u8]>,
+        new: Option<Value>,
+    ) -> StorageResult<core::result::Result<(), Option<Value>>> {
+        self.as_ref().compare_and_swap(key, column, expected, new)
+    }
+
     fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
         self.as_ref().exists(key, column)
     }
@@ -1,13 +1,26 @@
-use crate::database::Database;
+use crate::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    fuel_core_graphql_api::storage::balances::{
+        CoinBalances,
+        CoinBalancesKey,
+        TotalBalanceAmount,
+    },
+};
 use fuel_core_storage::{
+    iter::IterDirection,
     tables::ContractsAssets,
     ContractsAssetKey,
     Error as StorageError,
+    Result as StorageResult,
     StorageBatchMutate,
 };
 use fuel_core_types::{
     fuel_asm::Word,
     fuel_types::{
+        Address,
         AssetId,
         ContractId,
     },
@@ -38,6 +51,26 @@ impl Database {
     }
 }
 
+impl Database<OffChain> {
+    /// Returns the total amounts of coins owned by the `owner` per asset,
+    /// starting from the `start_asset`.
+    pub fn coin_balances(
+        &self,
+        owner: &Address,
+        start_asset: Option<AssetId>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(AssetId, TotalBalanceAmount)>> + '_ {
+        let start_asset =
+            start_asset.map(|asset_id| CoinBalancesKey::new(owner, &asset_id));
+        self.iter_all_filtered::<CoinBalances, _>(
+            Some(*owner),
+            start_asset.as_ref(),
+            direction,
+        )
+        .map(|res| res.map(|(key, amount)| (*key.asset_id(), amount)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,12 +139,13 @@ impl StorageMutate<FuelBlocks> for Database {
         // TODO: Temporary solution to store the block height in the database manually here.
         //  Later it will be controlled by the `commit_changes` function on the `Database` side.
         //  https://github.com/FuelLabs/fuel-core/issues/1589
+        let light_client = self
+            .storage::<MetadataTable<OnChain>>()
+            .get(&())?
+            .is_some_and(|metadata| metadata.light_client());
         self.storage::<MetadataTable<OnChain>>().insert(
             &(),
-            &DatabaseMetadata::V1 {
-                version: OnChain::version(),
-                height: *height,
-            },
+            &DatabaseMetadata::new(OnChain::version(), *height, light_client),
         )?;
 
         Ok(prev)
@@ -199,7 +200,8 @@ impl Database {
         height: &BlockHeight,
     ) -> StorageResult<Option<Block>> {
         let db_block = self.storage::<FuelBlocks>().get(height)?;
-        if let Some(block) = db_block {
+        // The light client stores only headers, so it doesn't have full blocks.
+        if let Some(block) = db_block.filter(|block| !block.is_header_only()) {
             // fetch all the transactions
             // TODO: optimize with multi-key get
             let txs = block
@@ -2,7 +2,14 @@ use crate::database::Database;
 use fuel_core_chain_config::ContractConfig;
 use fuel_core_storage::{
     iter::IterDirection,
+    not_found,
     tables::{
+        merkle::{
+            ContractsAssetsMerkleData,
+            ContractsAssetsMerkleMetadata,
+            ContractsStateMerkleData,
+            ContractsStateMerkleMetadata,
+        },
         ContractsAssets,
         ContractsInfo,
         ContractsLatestUtxo,
@@ -10,11 +17,20 @@ use fuel_core_storage::{
         ContractsState,
     },
     ContractsAssetKey,
+    ContractsStateKey,
+    Error as StorageError,
+    Mappable,
     Result as StorageResult,
     StorageAsRef,
+    StorageInspect,
 };
 use fuel_core_types::{
-    entities::contract::ContractUtxoInfo,
+    entities::contract::{
+        ContractUtxoInfo,
+        SparseMerkleProof,
+        SparseMerkleProofNode,
+    },
+    fuel_merkle::sparse,
     fuel_types::{
         AssetId,
         Bytes32,
@@ -103,6 +119,116 @@ impl Database {
         .map(|res| res.map(|(key, balance)| (*key.asset_id(), balance)))
     }
 
+    pub fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        prefix: Vec<u8>,
+        start_key: Option<Bytes32>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(Bytes32, Bytes32)>> + '_ {
+        let mut full_prefix = contract.to_vec();
+        full_prefix.extend_from_slice(&prefix);
+        let start_key = start_key.map(|key| ContractsStateKey::new(&contract, &key));
+        self.iter_all_filtered::<ContractsState, _>(
+            Some(full_prefix),
+            start_key.as_ref(),
+            direction,
+        )
+        // The prefix iteration is limited by the length of the contract id,
+        // so the slots outside of the requested prefix are cut here.
+        .take_while(move |res| {
+            res.as_ref()
+                .map(|(key, _)| key.state_key().starts_with(&prefix))
+                .unwrap_or(true)
+        })
+        .map(|res| res.map(|(key, value)| (*key.state_key(), value)))
+    }
+
+    /// Returns the inclusion proof of the `key` in the state of the `contract`.
+    /// Returns `None` if the slot is not set.
+    pub fn contract_state_proof(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> StorageResult<Option<SparseMerkleProof>> {
+        let Some(metadata) = self
+            .storage::<ContractsStateMerkleMetadata>()
+            .get(contract)?
+        else {
+            return Ok(None)
+        };
+        let root = (*metadata.root()).into();
+        let key = ContractsStateKey::new(contract, key);
+        self.sparse_merkle_proof::<ContractsStateMerkleData>(root, key.as_ref())
+    }
+
+    /// Returns the inclusion proof of the balance of the `asset_id` in the assets
+    /// of the `contract`. Returns `None` if the balance is not set.
+    pub fn contract_balance_proof(
+        &self,
+        contract: &ContractId,
+        asset_id: &AssetId,
+    ) -> StorageResult<Option<SparseMerkleProof>> {
+        let Some(metadata) = self
+            .storage::<ContractsAssetsMerkleMetadata>()
+            .get(contract)?
+        else {
+            return Ok(None)
+        };
+        let root = (*metadata.root()).into();
+        let key = ContractsAssetKey::new(contract, asset_id);
+        self.sparse_merkle_proof::<ContractsAssetsMerkleData>(root, key.as_ref())
+    }
+
+    /// Walks the sparse merkle tree from the `root` to the leaf of the `key`
+    /// and collects the siblings on the way.
+    fn sparse_merkle_proof<Nodes>(
+        &self,
+        root: Bytes32,
+        key: &[u8],
+    ) -> StorageResult<Option<SparseMerkleProof>>
+    where
+        Nodes: Mappable<Key = [u8; 32], OwnedValue = sparse::Primitive>,
+        Self: StorageInspect<Nodes, Error = StorageError>,
+    {
+        const LEAF_PREFIX: u8 = 0x00;
+
+        let leaf_key = SparseMerkleProof::leaf_key(key);
+        let mut path = vec![];
+        let mut current = *root;
+
+        while current != [0; 32] {
+            let (height, prefix, bytes_lo, bytes_hi) = self
+                .storage::<Nodes>()
+                .get(&current)?
+                .ok_or(not_found!(Nodes))?
+                .into_owned();
+
+            if prefix == LEAF_PREFIX {
+                if bytes_lo != *leaf_key {
+                    break
+                }
+                path.reverse();
+                return Ok(Some(SparseMerkleProof { root, path }))
+            }
+
+            let is_right = SparseMerkleProof::is_right_child(&leaf_key, height)
+                .ok_or_else(|| anyhow::anyhow!("The node has invalid height {height}"))?;
+            let (next, sibling) = if is_right {
+                (bytes_hi, bytes_lo)
+            } else {
+                (bytes_lo, bytes_hi)
+            };
+            path.push(SparseMerkleProofNode {
+                height,
+                sibling: sibling.into(),
+            });
+            current = next;
+        }
+
+        Ok(None)
+    }
+
     pub fn get_contract_config(&self) -> StorageResult<Option<Vec<ContractConfig>>> {
         let configs = self
             .iter_all::<ContractsRawCode>(None)
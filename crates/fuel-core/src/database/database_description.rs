@@ -28,21 +28,52 @@ pub trait DatabaseDescription: 'static + Clone + Debug + Send + Sync {
 /// The metadata of the database contains information about the version and its height.
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub enum DatabaseMetadata<Height> {
-    V1 { version: u32, height: Height },
+    V1 {
+        version: u32,
+        height: Height,
+    },
+    /// Additionally marks the database of the light client, which stores only
+    /// the headers of blocks. The full node keeps using the `V1`.
+    V2 {
+        version: u32,
+        height: Height,
+        light_client: bool,
+    },
 }
 
 impl<Height> DatabaseMetadata<Height> {
     /// Returns the version of the database.
     pub fn version(&self) -> u32 {
         match self {
-            Self::V1 { version, .. } => *version,
+            Self::V1 { version, .. } | Self::V2 { version, .. } => *version,
         }
     }
 
     /// Returns the height of the database.
     pub fn height(&self) -> &Height {
         match self {
-            Self::V1 { height, .. } => height,
+            Self::V1 { height, .. } | Self::V2 { height, .. } => height,
+        }
+    }
+
+    /// Returns `true` if the database belongs to the light client.
+    pub fn light_client(&self) -> bool {
+        match self {
+            Self::V1 { .. } => false,
+            Self::V2 { light_client, .. } => *light_client,
+        }
+    }
+
+    /// Creates the metadata of the database, using the `V2` only for the light client.
+    pub fn new(version: u32, height: Height, light_client: bool) -> Self {
+        if light_client {
+            Self::V2 {
+                version,
+                height,
+                light_client,
+            }
+        } else {
+            Self::V1 { version, height }
         }
     }
 }
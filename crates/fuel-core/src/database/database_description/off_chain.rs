@@ -12,7 +12,9 @@ impl DatabaseDescription for OffChain {
     type Height = BlockHeight;
 
     fn version() -> u32 {
-        0
+        // The version `1` added the indexation of balances.
+        // The version `2` added the indexation of the asset flows of transactions.
+        2
     }
 
     fn name() -> &'static str {
@@ -27,7 +29,8 @@ impl DatabaseDescription for OffChain {
         match column {
             Self::Column::OwnedCoins
             | Self::Column::TransactionsByOwnerBlockIdx
-            | Self::Column::OwnedMessageIds => {
+            | Self::Column::OwnedMessageIds
+            | Self::Column::CoinBalances => {
                 // prefix is address length
                 Some(32)
             }
@@ -0,0 +1,127 @@
+use crate::{
+    database::{
+        database_description::off_chain::OffChain,
+        storage::UseStructuredImplementation,
+        Database,
+    },
+    fuel_core_graphql_api,
+    state::DataSource,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::postcard::Postcard,
+    structured_storage::{
+        StructuredStorage,
+        TableWithBlueprint,
+    },
+    transactional::Transaction,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+};
+use fuel_core_types::services::p2p::{
+    peer_reputation::PeerReputation,
+    PeerId,
+};
+
+/// The table that stores reputations of peers, so they survive restarts of the node.
+pub struct PeerReputations;
+
+impl Mappable for PeerReputations {
+    type Key = Self::OwnedKey;
+    type OwnedKey = PeerId;
+    type Value = Self::OwnedValue;
+    type OwnedValue = PeerReputation;
+}
+
+impl TableWithBlueprint for PeerReputations {
+    type Blueprint = Plain<Postcard, Postcard>;
+    type Column = fuel_core_graphql_api::storage::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::PeerReputations
+    }
+}
+
+impl UseStructuredImplementation<PeerReputations>
+    for StructuredStorage<DataSource<OffChain>>
+{
+}
+
+impl Database<OffChain> {
+    pub fn get_peer_reputations(&self) -> StorageResult<Vec<(PeerId, PeerReputation)>> {
+        self.iter_all::<PeerReputations>(None).collect()
+    }
+
+    /// Stores the `updated` reputations and removes the reputations of the `removed` peers.
+    pub fn update_peer_reputations(
+        &mut self,
+        updated: Vec<(PeerId, PeerReputation)>,
+        removed: Vec<PeerId>,
+    ) -> StorageResult<()> {
+        let mut transaction = self.transaction();
+        let database = transaction.as_mut();
+
+        for peer_id in removed {
+            database
+                .storage_as_mut::<PeerReputations>()
+                .remove(&peer_id)?;
+        }
+
+        for (peer_id, reputation) in updated {
+            database
+                .storage_as_mut::<PeerReputations>()
+                .insert(&peer_id, &reputation)?;
+        }
+
+        transaction.commit()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::services::p2p::peer_reputation::PeerBan;
+
+    #[test]
+    fn only_updated_and_removed_reputations_are_changed() {
+        let mut database = Database::<OffChain>::default();
+        let reputation = |score| PeerReputation {
+            score,
+            ban: Some(PeerBan {
+                reason: "test".to_string(),
+                expires_at: None,
+            }),
+        };
+
+        // Given
+        database
+            .update_peer_reputations(
+                vec![
+                    (PeerId::from(vec![1]), reputation(1.0)),
+                    (PeerId::from(vec![2]), reputation(2.0)),
+                    (PeerId::from(vec![3]), reputation(3.0)),
+                ],
+                vec![],
+            )
+            .unwrap();
+
+        // When
+        database
+            .update_peer_reputations(
+                vec![(PeerId::from(vec![2]), reputation(4.0))],
+                vec![PeerId::from(vec![1])],
+            )
+            .unwrap();
+
+        // Then
+        assert_eq!(
+            database.get_peer_reputations().unwrap(),
+            vec![
+                (PeerId::from(vec![2]), reputation(4.0)),
+                (PeerId::from(vec![3]), reputation(3.0)),
+            ]
+        );
+    }
+}
@@ -74,6 +74,20 @@ impl Database {
         Ok(headers)
     }
 
+    pub fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> StorageResult<Vec<SealedBlock>> {
+        let blocks = block_height_range
+            .map(BlockHeight::from)
+            .map(|height| self.get_sealed_block_by_height(&height))
+            .collect::<StorageResult<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+        Ok(blocks)
+    }
+
     pub fn get_sealed_block_header(
         &self,
         height: &BlockHeight,
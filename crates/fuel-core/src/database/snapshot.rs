@@ -0,0 +1,460 @@
+//! Splitting of the on-chain state into verifiable chunks for the snap-sync
+//! and importing of these chunks on the syncing node.
+//!
+//! The snapshot contains all entries of the [`SNAPSHOT_COLUMNS`] and the entries
+//! of the latest block required to continue the block import from it.
+
+use crate::{
+    database::{
+        block::FuelBlockSecondaryKeyBlockHeights,
+        database_description::{
+            on_chain::OnChain,
+            DatabaseDescription,
+            DatabaseMetadata,
+        },
+        metadata::MetadataTable,
+        Database,
+    },
+    state::DataSource,
+};
+use anyhow::anyhow;
+use fuel_core_storage::{
+    blueprint::Blueprint,
+    codec::{
+        Encode,
+        Encoder,
+    },
+    column::Column,
+    iter::IterDirection,
+    kv_store::{
+        BatchOperations,
+        KeyValueStore,
+        WriteOperation,
+    },
+    not_found,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        merkle::FuelBlockMerkleMetadata,
+        FuelBlocks,
+        SealedBlockConsensus,
+    },
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+};
+use fuel_core_types::{
+    fuel_merkle::binary::in_memory::MerkleTree,
+    services::p2p::snapshot::{
+        chunk_hash,
+        SnapshotChunk,
+        SnapshotManifest,
+    },
+};
+use std::{
+    ops::Range,
+    sync::Arc,
+};
+
+/// The approximate size of the chunk in bytes.
+pub const SNAPSHOT_CHUNK_SIZE: usize = 512 * 1024;
+
+/// The columns transferred by the snapshot in full.
+/// They are cleared on the syncing node before the import.
+pub const SNAPSHOT_COLUMNS: [Column; 14] = [
+    Column::ContractsRawCode,
+    Column::ContractsInfo,
+    Column::ContractsState,
+    Column::ContractsLatestUtxo,
+    Column::ContractsAssets,
+    Column::Coins,
+    Column::Messages,
+    Column::SpentMessages,
+    Column::ProcessedTransactions,
+    Column::ContractsAssetsMerkleData,
+    Column::ContractsAssetsMerkleMetadata,
+    Column::ContractsStateMerkleData,
+    Column::ContractsStateMerkleMetadata,
+    Column::FuelBlockMerkleData,
+];
+
+/// The columns that contain only the entries of the latest block in the snapshot.
+const SNAPSHOT_TIP_COLUMNS: [Column; 4] = [
+    Column::FuelBlocks,
+    Column::FuelBlockConsensus,
+    Column::FuelBlockMerkleMetadata,
+    Column::FuelBlockSecondaryKeyBlockHeights,
+];
+
+/// The location of the chunk entries in the database.
+#[derive(Debug, Clone)]
+struct ChunkLocation {
+    column: Column,
+    /// The key of the first entry.
+    start: Vec<u8>,
+    /// The number of entries.
+    len: usize,
+}
+
+/// The snapshot of the on-chain database at the latest height.
+pub struct Snapshot {
+    database: Database,
+    manifest: SnapshotManifest,
+    chunks: Vec<ChunkLocation>,
+    tree: MerkleTree,
+}
+
+impl Snapshot {
+    /// Splits the state of the `database` into chunks.
+    ///
+    /// The `database` must not be modified while the snapshot is in use,
+    /// so usually it is a checkpoint of the node's database.
+    pub fn new(database: Database) -> StorageResult<Self> {
+        let height = database.latest_height()?;
+        let header = database
+            .get_sealed_block_header(&height)?
+            .ok_or(not_found!(FuelBlocks))?;
+
+        let mut builder = SnapshotBuilder::default();
+        for column in SNAPSHOT_COLUMNS {
+            let mut entries = vec![];
+            let mut size = 0usize;
+            for result in database.data.as_ref().iter_all(
+                column,
+                None,
+                None,
+                IterDirection::Forward,
+            ) {
+                let (key, value) = result?;
+                size = size.saturating_add(key.len()).saturating_add(value.len());
+                entries.push((key, value.to_vec()));
+                if size >= SNAPSHOT_CHUNK_SIZE {
+                    builder.push(column, core::mem::take(&mut entries))?;
+                    size = 0;
+                }
+            }
+            if !entries.is_empty() {
+                builder.push(column, entries)?;
+            }
+        }
+
+        let tip = [
+            (Column::FuelBlocks, encode_key::<FuelBlocks>(&height)),
+            (
+                Column::FuelBlockConsensus,
+                encode_key::<SealedBlockConsensus>(&height),
+            ),
+            (
+                Column::FuelBlockMerkleMetadata,
+                encode_key::<FuelBlockMerkleMetadata>(&height),
+            ),
+            (
+                Column::FuelBlockSecondaryKeyBlockHeights,
+                encode_key::<FuelBlockSecondaryKeyBlockHeights>(&header.entity.id()),
+            ),
+        ];
+        for (column, key) in tip {
+            let value =
+                database.data.as_ref().get(&key, column)?.ok_or_else(|| {
+                    anyhow!("The snapshot misses the entry of {column:?}")
+                })?;
+            builder.push(column, vec![(key, value.to_vec())])?;
+        }
+
+        let SnapshotBuilder { chunks, tree } = builder;
+        let manifest = SnapshotManifest {
+            header,
+            root: tree.root().into(),
+            chunks_count: u32::try_from(chunks.len())
+                .map_err(|_| anyhow!("The snapshot has too many chunks"))?,
+        };
+
+        Ok(Self {
+            database,
+            manifest,
+            chunks,
+            tree,
+        })
+    }
+
+    /// Returns the manifest of the snapshot.
+    pub fn manifest(&self) -> &SnapshotManifest {
+        &self.manifest
+    }
+
+    /// Returns chunks with proofs for the `range` of indexes,
+    /// or `None` if the range is out of the snapshot.
+    pub fn chunks(&self, range: Range<u32>) -> StorageResult<Option<Vec<SnapshotChunk>>> {
+        let mut chunks = vec![];
+        for index in range {
+            let Some(location) = self.chunks.get(index as usize) else {
+                return Ok(None)
+            };
+            let entries = self
+                .database
+                .data
+                .as_ref()
+                .iter_all(
+                    location.column,
+                    None,
+                    Some(&location.start),
+                    IterDirection::Forward,
+                )
+                .take(location.len)
+                .map(|result| result.map(|(key, value)| (key, value.to_vec())))
+                .collect::<StorageResult<Vec<_>>>()?;
+            let (_, proof) = self
+                .tree
+                .prove(u64::from(index))
+                .ok_or_else(|| anyhow!("Unable to prove the chunk {index}"))?;
+            chunks.push(SnapshotChunk {
+                index,
+                column: location.column.as_u32(),
+                entries,
+                proof,
+            });
+        }
+        Ok(Some(chunks))
+    }
+}
+
+#[derive(Default)]
+struct SnapshotBuilder {
+    chunks: Vec<ChunkLocation>,
+    tree: MerkleTree,
+}
+
+impl SnapshotBuilder {
+    fn push(
+        &mut self,
+        column: Column,
+        entries: Vec<(Vec<u8>, Vec<u8>)>,
+    ) -> StorageResult<()> {
+        let index = u32::try_from(self.chunks.len())
+            .map_err(|_| anyhow!("The snapshot has too many chunks"))?;
+        let hash = chunk_hash(index, column.as_u32(), &entries);
+        self.tree.push(hash.as_ref());
+        self.chunks.push(ChunkLocation {
+            column,
+            start: entries
+                .first()
+                .map(|(key, _)| key.clone())
+                .unwrap_or_default(),
+            len: entries.len(),
+        });
+        Ok(())
+    }
+}
+
+fn encode_key<M>(key: &M::Key) -> Vec<u8>
+where
+    M: Mappable + TableWithBlueprint<Column = Column>,
+    M::Blueprint: Blueprint<M, DataSource>,
+{
+    <M::Blueprint as Blueprint<M, DataSource>>::KeyCodec::encode(key)
+        .as_bytes()
+        .to_vec()
+}
+
+/// Import of the snapshot.
+impl Database {
+    /// Removes all entries of the [`SNAPSHOT_COLUMNS`] before the import of the snapshot.
+    pub fn clear_snapshot_columns(&self) -> StorageResult<()> {
+        let data = self.data.as_ref();
+        for column in SNAPSHOT_COLUMNS {
+            let keys = data
+                .iter_all(column, None, None, IterDirection::Forward)
+                .map(|result| result.map(|(key, _)| key))
+                .collect::<StorageResult<Vec<_>>>()?;
+            data.batch_write(
+                &mut keys
+                    .into_iter()
+                    .map(|key| (key, column, WriteOperation::Remove)),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes entries of the verified snapshot chunk.
+    pub fn import_snapshot_chunk(&self, chunk: SnapshotChunk) -> StorageResult<()> {
+        let column = SNAPSHOT_COLUMNS
+            .into_iter()
+            .chain(SNAPSHOT_TIP_COLUMNS)
+            .find(|column| column.as_u32() == chunk.column)
+            .ok_or_else(|| {
+                anyhow!("The column {} is not a part of the snapshot", chunk.column)
+            })?;
+        self.data.as_ref().batch_write(
+            &mut chunk.entries.into_iter().map(|(key, value)| {
+                (key, column, WriteOperation::Insert(Arc::new(value)))
+            }),
+        )
+    }
+
+    /// Verifies that the block of the snapshot is imported and moves
+    /// the latest height of the database to the height of the snapshot.
+    pub fn finalize_snapshot(
+        &mut self,
+        manifest: &SnapshotManifest,
+    ) -> StorageResult<()> {
+        let height = manifest.height();
+        let header = self
+            .get_sealed_block_header(&height)?
+            .ok_or(not_found!(FuelBlocks))?;
+        if header.entity.id() != manifest.header.entity.id() {
+            return Err(anyhow!(
+                "The imported block at {height} doesn't match the snapshot manifest"
+            )
+            .into())
+        }
+
+        self.storage_as_mut::<MetadataTable<OnChain>>().insert(
+            &(),
+            &DatabaseMetadata::V1 {
+                version: OnChain::version(),
+                height,
+            },
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::Coins,
+        StorageAsRef,
+        StorageMutate,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            consensus::Consensus,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::UtxoId,
+        fuel_types::{
+            BlockHeight,
+            ChainId,
+        },
+    };
+
+    const HEIGHT: u32 = 10;
+    const COINS: u8 = 200;
+
+    fn database_with_state() -> Database {
+        let mut database = Database::default();
+        for height in 0..=HEIGHT {
+            let header = PartialBlockHeader {
+                application: Default::default(),
+                consensus: ConsensusHeader::<Empty> {
+                    height: height.into(),
+                    ..Default::default()
+                },
+            };
+            let block = PartialFuelBlock::new(header, vec![]).generate(&[]);
+            StorageMutate::<FuelBlocks>::insert(
+                &mut database,
+                &height.into(),
+                &block.compress(&ChainId::default()),
+            )
+            .unwrap();
+            database
+                .storage_as_mut::<SealedBlockConsensus>()
+                .insert(&height.into(), &Consensus::default())
+                .unwrap();
+        }
+        for i in 0..COINS {
+            let mut coin = CompressedCoin::default();
+            coin.set_amount(u64::from(i));
+            database
+                .storage_as_mut::<Coins>()
+                .insert(&UtxoId::new([i; 32].into(), 0), &coin)
+                .unwrap();
+        }
+        database
+    }
+
+    #[test]
+    fn snapshot_chunks_are_verified_by_manifest() {
+        let snapshot = Snapshot::new(database_with_state()).unwrap();
+        let manifest = snapshot.manifest().clone();
+
+        let chunks = snapshot.chunks(0..manifest.chunks_count).unwrap().unwrap();
+
+        assert_eq!(*manifest.height(), HEIGHT);
+        assert_eq!(chunks.len(), manifest.chunks_count as usize);
+        assert!(chunks.iter().all(|chunk| chunk.verify(&manifest)));
+    }
+
+    #[test]
+    fn modified_chunk_is_not_verified() {
+        let snapshot = Snapshot::new(database_with_state()).unwrap();
+        let manifest = snapshot.manifest().clone();
+
+        let mut chunk = snapshot.chunks(0..1).unwrap().unwrap().remove(0);
+        chunk.entries[0].1.push(0);
+
+        assert!(!chunk.verify(&manifest));
+    }
+
+    #[test]
+    fn chunks_out_of_snapshot_are_not_returned() {
+        let snapshot = Snapshot::new(database_with_state()).unwrap();
+        let count = snapshot.manifest().chunks_count;
+
+        assert_eq!(snapshot.chunks(count..count + 1).unwrap(), None);
+    }
+
+    #[test]
+    fn imported_snapshot_restores_the_state() {
+        let source = database_with_state();
+        let snapshot = Snapshot::new(source.clone()).unwrap();
+        let manifest = snapshot.manifest().clone();
+        let mut target = Database::default();
+        target
+            .storage_as_mut::<Coins>()
+            .insert(
+                &UtxoId::new([255; 32].into(), 0),
+                &CompressedCoin::default(),
+            )
+            .unwrap();
+
+        target.clear_snapshot_columns().unwrap();
+        for chunk in snapshot.chunks(0..manifest.chunks_count).unwrap().unwrap() {
+            target.import_snapshot_chunk(chunk).unwrap();
+        }
+        target.finalize_snapshot(&manifest).unwrap();
+
+        assert_eq!(target.latest_height().unwrap(), BlockHeight::from(HEIGHT));
+        for result in source.iter_all::<Coins>(None) {
+            let (utxo_id, coin) = result.unwrap();
+            let imported = target.storage::<Coins>().get(&utxo_id).unwrap();
+            assert_eq!(imported.map(|coin| coin.into_owned()), Some(coin));
+        }
+        let stale = UtxoId::new([255; 32].into(), 0);
+        assert!(!target.storage::<Coins>().contains_key(&stale).unwrap());
+        assert_eq!(
+            target.storage::<FuelBlocks>().root(&HEIGHT.into()).unwrap(),
+            source.storage::<FuelBlocks>().root(&HEIGHT.into()).unwrap()
+        );
+    }
+
+    #[test]
+    fn chunk_of_unknown_column_is_rejected() {
+        let database = Database::default();
+        let chunk = SnapshotChunk {
+            index: 0,
+            column: Column::Transactions.as_u32(),
+            entries: vec![(vec![1], vec![2])],
+            proof: vec![],
+        };
+
+        assert!(database.import_snapshot_chunk(chunk).is_err());
+    }
+}
@@ -5,9 +5,14 @@ use crate::{
         Database,
     },
     fuel_core_graphql_api::storage::{
+        balances::{
+            CoinBalances,
+            MessageBalances,
+        },
         coins::OwnedCoins,
         messages::OwnedMessageIds,
         transactions::{
+            OwnedTransactionFlows,
             OwnedTransactions,
             TransactionStatuses,
         },
@@ -93,7 +98,10 @@ use_structured_implementation!(
     OwnedCoins,
     Messages,
     OwnedMessageIds,
+    CoinBalances,
+    MessageBalances,
     OwnedTransactions,
+    OwnedTransactionFlows,
     TransactionStatuses,
     FuelBlockSecondaryKeyBlockHeights,
     FuelBlockMerkleData,
@@ -1,3 +1,5 @@
+use crate::state::rocks_db_config::RocksDbConfig;
+use auth::AuthConfig;
 use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
@@ -7,12 +9,21 @@ use fuel_core_types::{
     fuel_tx::ConsensusParameters,
     secrecy::Secret,
 };
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+};
 
 pub mod api_service;
+pub mod auth;
 pub mod database;
+pub mod health;
+pub(crate) mod json_rpc;
+pub(crate) mod limits_extension;
 pub(crate) mod metrics_extension;
+pub(crate) mod persisted_queries;
 pub mod ports;
+pub(crate) mod rate_limit;
 pub mod storage;
 pub(crate) mod view_extension;
 pub mod worker_service;
@@ -29,6 +40,63 @@ pub struct Config {
     pub chain_name: String,
     pub consensus_parameters: ConsensusParameters,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// The configuration of RocksDB, if the node uses it as a database.
+    pub database_config: Option<RocksDbConfig>,
+    pub query_limits: QueryLimits,
+    /// The rate limit of the requests per client. The rate limiting is disabled if `None`.
+    pub rate_limit: Option<RateLimit>,
+    /// The credentials of the clients of the `addr` listener.
+    /// If both `auth` and `admin_addr` are `None`, all clients have the admin role.
+    pub auth: Option<AuthConfig>,
+    /// The address of the listener that grants the admin role to all clients.
+    /// It should be reachable only from the private network, so it doesn't allow
+    /// cross-origin requests.
+    pub admin_addr: Option<SocketAddr>,
+    /// The origin allowed to make cross-origin requests to the `addr` listener.
+    pub cors_origin: String,
+    /// Enables the JSON-RPC API at the `/jsonrpc` endpoint.
+    pub json_rpc: bool,
+    /// The number of parsed persisted queries cached by the node.
+    /// The persisted queries are disabled if it is `0`.
+    pub persisted_queries_cache_size: usize,
+    /// The directory where the `backupDatabase` mutation creates backups.
+    /// The mutation is disabled if `None`.
+    pub backup_root: Option<PathBuf>,
+}
+
+/// The limits that protect the node from expensive queries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueryLimits {
+    /// The maximum depth of the query.
+    pub max_depth: usize,
+    /// The maximum complexity of the query. Each field costs one point,
+    /// and the cost of the connection entries is multiplied by the number of entries.
+    pub max_complexity: usize,
+    /// The maximum number of entries requested by `first` or `last` from the connection.
+    pub max_page_size: usize,
+    /// The maximum number of queries in one batch request.
+    pub max_batch_size: usize,
+}
+
+impl Default for QueryLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 16,
+            max_complexity: 100_000,
+            max_page_size: 1024,
+            max_batch_size: 32,
+        }
+    }
+}
+
+/// The token bucket limit of the requests per client. The client is identified
+/// by the API key from the `X-Api-Key` header, or by the IP address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The average number of requests per second.
+    pub requests_per_sec: u32,
+    /// The number of requests that the client can make at once.
+    pub burst: u32,
 }
 
 pub trait IntoApiResult<T> {
@@ -1,16 +1,35 @@
 use crate::{
     fuel_core_graphql_api::{
+        auth::{
+            authenticate,
+            Authenticator,
+            Role,
+        },
+        health::HealthCheck,
+        json_rpc::{
+            self,
+            JsonRpc,
+        },
+        limits_extension::LimitsExtension,
         metrics_extension::MetricsExtension,
+        persisted_queries::PersistedQueriesExtension,
         ports::{
             BlockProducerPort,
             ConsensusModulePort,
+            LightClientPort,
             OffChainDatabase,
             OnChainDatabase,
             P2pPort,
             TxPoolPort,
         },
+        rate_limit::{
+            rate_limit,
+            ClientRateLimit,
+            RateLimiter,
+        },
         view_extension::ViewExtension,
         Config,
+        QueryLimits,
     },
     schema::{
         CoreSchema,
@@ -23,8 +42,11 @@ use async_graphql::{
         playground_source,
         GraphQLPlaygroundConfig,
     },
+    BatchRequest,
+    BatchResponse,
     Request,
     Response,
+    ServerError,
 };
 use axum::{
     extract::{
@@ -38,7 +60,9 @@ use axum::{
             ACCESS_CONTROL_ALLOW_ORIGIN,
         },
         HeaderValue,
+        StatusCode,
     },
+    middleware,
     response::{
         sse::Event,
         Html,
@@ -52,6 +76,7 @@ use axum::{
     Json,
     Router,
 };
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_services::{
     RunnableService,
     RunnableTask,
@@ -67,7 +92,9 @@ use std::{
         SocketAddr,
         TcpListener,
     },
+    num::NonZeroUsize,
     pin::Pin,
+    sync::Arc,
     time::Duration,
 };
 use tokio_stream::StreamExt;
@@ -81,25 +108,31 @@ pub type Service = fuel_core_services::ServiceRunner<GraphqlService>;
 
 pub use super::database::ReadDatabase;
 
-pub type BlockProducer = Box<dyn BlockProducerPort>;
+pub type BlockProducer = Arc<dyn BlockProducerPort>;
 // In the future GraphQL should not be aware of `TxPool`. It should
 //  use only `Database` to receive all information about transactions.
-pub type TxPool = Box<dyn TxPoolPort>;
+pub type TxPool = Arc<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
 pub type P2pService = Box<dyn P2pPort>;
+/// Present only if the node is the light client.
+pub type LightClient = Arc<dyn LightClientPort>;
 
 #[derive(Clone)]
 pub struct SharedState {
     pub bound_address: SocketAddr,
+    /// The address of the admin listener, if it is enabled.
+    pub admin_bound_address: Option<SocketAddr>,
 }
 
 pub struct GraphqlService {
     bound_address: SocketAddr,
+    admin_bound_address: Option<SocketAddr>,
 }
 
 pub struct ServerParams {
     router: Router,
     listener: TcpListener,
+    admin: Option<(Router, TcpListener)>,
 }
 
 pub struct Task {
@@ -118,6 +151,7 @@ impl RunnableService for GraphqlService {
     fn shared_data(&self) -> Self::SharedData {
         SharedState {
             bound_address: self.bound_address,
+            admin_bound_address: self.admin_bound_address,
         }
     }
 
@@ -126,22 +160,39 @@ impl RunnableService for GraphqlService {
         state: &StateWatcher,
         params: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
-        let mut state = state.clone();
-        let ServerParams { router, listener } = params;
-
-        let server = axum::Server::from_tcp(listener)
-            .unwrap()
-            .serve(router.into_make_service())
-            .with_graceful_shutdown(async move {
-                state
-                    .while_started()
-                    .await
-                    .expect("The service is destroyed");
-            });
-
-        Ok(Task {
-            server: Box::pin(server),
-        })
+        let ServerParams {
+            router,
+            listener,
+            admin,
+        } = params;
+
+        let serve = |router: Router, listener: TcpListener| {
+            let mut state = state.clone();
+            axum::Server::from_tcp(listener)
+                .unwrap()
+                .serve(router.into_make_service_with_connect_info::<SocketAddr>())
+                .with_graceful_shutdown(async move {
+                    state
+                        .while_started()
+                        .await
+                        .expect("The service is destroyed");
+                })
+        };
+
+        let server = serve(router, listener);
+        let server: Pin<Box<dyn Future<Output = hyper::Result<()>> + Send>> = match admin
+        {
+            Some((admin_router, admin_listener)) => {
+                let admin_server = serve(admin_router, admin_listener);
+                Box::pin(async move {
+                    futures::try_join!(server, admin_server)?;
+                    Ok(())
+                })
+            }
+            None => Box::pin(server),
+        };
+
+        Ok(Task { server })
     }
 }
 
@@ -173,6 +224,7 @@ pub fn new_service<OnChain, OffChain>(
     producer: BlockProducer,
     consensus_module: ConsensusModule,
     p2p_service: P2pService,
+    health_check: HealthCheck,
     log_threshold_ms: Duration,
     request_timeout: Duration,
 ) -> anyhow::Result<Service>
@@ -183,54 +235,145 @@ where
     OffChain::View: OffChainDatabase,
 {
     let network_addr = config.addr;
+    let admin_addr = config.admin_addr;
+    // Without the authentication and the admin listener, the node is trusted by all clients.
+    let authenticator = match (config.auth.clone(), admin_addr) {
+        (None, None) => Authenticator::Trusted,
+        (auth, _) => Authenticator::new(auth.unwrap_or_default()),
+    };
+    let cors_origin = HeaderValue::from_str(&config.cors_origin).map_err(|e| {
+        anyhow::anyhow!("Invalid CORS origin `{}`: {e}", config.cors_origin)
+    })?;
+    let query_limits = config.query_limits;
+    let persisted_queries_cache_size =
+        NonZeroUsize::new(config.persisted_queries_cache_size);
+    let rate_limiter = config
+        .rate_limit
+        .map(|limit| Arc::new(RateLimiter::new(limit)));
+    let health_check = Arc::new(health_check);
     let combined_read_database = ReadDatabase::new(on_database, off_database);
+    let json_rpc = config.json_rpc.then(|| {
+        Arc::new(JsonRpc {
+            database: combined_read_database.clone(),
+            txpool: txpool.clone(),
+            producer: producer.clone(),
+            chain_id: config.consensus_parameters.chain_id,
+            base_asset_id: *config.consensus_parameters.base_asset_id(),
+            max_batch_size: query_limits.max_batch_size,
+        })
+    });
 
-    let schema = schema
+    let mut schema = schema
         .data(config)
         .data(combined_read_database)
         .data(txpool)
         .data(producer)
         .data(consensus_module)
         .data(p2p_service)
+        .data(health_check.clone())
         .extension(async_graphql::extensions::Tracing)
         .extension(MetricsExtension::new(log_threshold_ms))
         .extension(ViewExtension::new())
-        .finish();
-
-    let router = Router::new()
-        .route("/playground", get(graphql_playground))
-        .route("/graphql", post(graphql_handler).options(ok))
-        .route(
-            "/graphql-sub",
-            post(graphql_subscription_handler).options(ok),
-        )
-        .route("/metrics", get(metrics))
-        .route("/health", get(health))
-        .layer(Extension(schema))
-        .layer(TraceLayer::new_for_http())
-        .layer(TimeoutLayer::new(request_timeout))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_ORIGIN,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_METHODS,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_HEADERS,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(DefaultBodyLimit::disable());
+        .extension(LimitsExtension::new(query_limits));
+    if let Some(cache_size) = persisted_queries_cache_size {
+        schema = schema.extension(PersistedQueriesExtension::new(cache_size));
+    }
+    let schema = schema.finish();
+
+    // Only the public listener allows cross-origin requests.
+    let router = |authenticator: Authenticator, cors_origin: Option<HeaderValue>| {
+        let mut graphql_router = Router::new()
+            .route("/graphql", post(graphql_handler).options(ok))
+            .route(
+                "/graphql-sub",
+                post(graphql_subscription_handler).options(ok),
+            );
+        if let Some(json_rpc) = json_rpc.clone() {
+            graphql_router = graphql_router
+                .route(
+                    "/jsonrpc",
+                    post(json_rpc::http_handler)
+                        .get(json_rpc::ws_handler)
+                        .options(ok),
+                )
+                .layer(Extension(json_rpc));
+        }
+        if let Some(rate_limiter) = rate_limiter.clone() {
+            graphql_router =
+                graphql_router.layer(middleware::from_fn(move |req, next| {
+                    rate_limit(rate_limiter.clone(), req, next)
+                }));
+        }
+        let authenticator = Arc::new(authenticator);
+        let graphql_router =
+            graphql_router.layer(middleware::from_fn(move |req, next| {
+                authenticate(authenticator.clone(), req, next)
+            }));
+
+        let router = Router::new()
+            .route("/playground", get(graphql_playground))
+            .merge(graphql_router)
+            .route("/metrics", get(metrics))
+            .route("/health", get(health))
+            .route("/health/live", get(health_live))
+            .route("/health/ready", get(health_ready))
+            .layer(Extension(schema.clone()))
+            .layer(Extension(health_check.clone()))
+            .layer(Extension(query_limits))
+            .layer(TraceLayer::new_for_http())
+            .layer(TimeoutLayer::new(request_timeout));
+        let router = match cors_origin {
+            Some(cors_origin) => router
+                .layer(SetResponseHeaderLayer::<_>::overriding(
+                    ACCESS_CONTROL_ALLOW_ORIGIN,
+                    cors_origin,
+                ))
+                .layer(SetResponseHeaderLayer::<_>::overriding(
+                    ACCESS_CONTROL_ALLOW_METHODS,
+                    HeaderValue::from_static("*"),
+                ))
+                .layer(SetResponseHeaderLayer::<_>::overriding(
+                    ACCESS_CONTROL_ALLOW_HEADERS,
+                    HeaderValue::from_static("*"),
+                )),
+            None => router,
+        };
+        router.layer(DefaultBodyLimit::disable())
+    };
 
     let listener = TcpListener::bind(network_addr)?;
     let bound_address = listener.local_addr()?;
 
     tracing::info!("Binding GraphQL provider to {}", bound_address);
 
+    let admin = admin_addr
+        .map(|admin_addr| {
+            let admin_listener = TcpListener::bind(admin_addr)?;
+            tracing::info!(
+                "Binding GraphQL admin provider to {}",
+                admin_listener.local_addr()?
+            );
+            Ok::<_, std::io::Error>((
+                router(Authenticator::Trusted, None),
+                admin_listener,
+            ))
+        })
+        .transpose()?;
+    let admin_bound_address = admin
+        .as_ref()
+        .map(|(_, admin_listener)| admin_listener.local_addr())
+        .transpose()?;
+
     Ok(Service::new_with_params(
-        GraphqlService { bound_address },
-        ServerParams { router, listener },
+        GraphqlService {
+            bound_address,
+            admin_bound_address,
+        },
+        ServerParams {
+            router: router(authenticator, Some(cors_origin)),
+            listener,
+            admin,
+        },
     ))
 }
 
@@ -242,19 +385,70 @@ async fn health() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
+/// Responds with `503 Service Unavailable` if any service of the node has stopped.
+async fn health_live(health_check: Extension<Arc<HealthCheck>>) -> impl IntoResponse {
+    let status = health_check.status().await;
+    let code = if status.live {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (code, Json(status))
+}
+
+/// Responds with `503 Service Unavailable` until the node is able to serve up-to-date data.
+async fn health_ready(health_check: Extension<Arc<HealthCheck>>) -> impl IntoResponse {
+    let status = health_check.status().await;
+    let code = if status.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (code, Json(status))
+}
+
+/// Executes the single request, or the batch of requests sent as an array.
+/// Each request of the batch is charged against the rate limit of the client.
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
-    req: Json<Request>,
-) -> Json<Response> {
-    schema.execute(req.0).await.into()
+    query_limits: Extension<QueryLimits>,
+    role: Extension<Role>,
+    rate_limit: Option<Extension<ClientRateLimit>>,
+    req: Json<BatchRequest>,
+) -> axum::response::Response {
+    if let BatchRequest::Batch(requests) = &req.0 {
+        if requests.len() > query_limits.max_batch_size {
+            graphql_metrics().graphql_request_rejected("batch_size");
+            let error = ServerError::new(
+                format!(
+                    "The batch size `{}` exceeds the limit `{}`",
+                    requests.len(),
+                    query_limits.max_batch_size
+                ),
+                None,
+            );
+            return Json(BatchResponse::Single(Response::from_errors(vec![error])))
+                .into_response()
+        }
+        if let Some(rate_limit) = rate_limit {
+            if !rate_limit.check_batch(requests.len()) {
+                graphql_metrics().graphql_request_rejected("rate_limit");
+                return (StatusCode::TOO_MANY_REQUESTS, "Too many requests")
+                    .into_response()
+            }
+        }
+    }
+
+    Json(schema.execute_batch(req.0.data(role.0)).await).into_response()
 }
 
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
+    role: Extension<Role>,
     req: Json<Request>,
 ) -> Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>> {
     let stream = schema
-        .execute_stream(req.0)
+        .execute_stream(req.0.data(role.0))
         .map(|r| Ok(Event::default().json_data(r).unwrap()));
     Sse::new(stream)
         .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text"))
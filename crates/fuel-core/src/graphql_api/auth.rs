@@ -0,0 +1,378 @@
+//! The authentication of the GraphQL requests by API keys and JWTs,
+//! and the [`RoleGuard`] that restricts the fields to the [`Role`]s.
+
+use async_graphql::{
+    Context,
+    Guard,
+};
+use axum::{
+    http::{
+        header::AUTHORIZATION,
+        Request,
+        StatusCode,
+    },
+    middleware::Next,
+    response::{
+        IntoResponse,
+        Response,
+    },
+};
+use fuel_core_types::secrecy::{
+    ExposeSecret,
+    SecretString,
+};
+use jsonwebtoken::{
+    Algorithm,
+    DecodingKey,
+    Validation,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    str::FromStr,
+    sync::Arc,
+};
+
+/// The header with the API key of the client.
+pub(crate) const API_KEY_HEADER: &str = "x-api-key";
+
+/// The role of the client. Each role has the permissions of the previous ones.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Can query the chain and dry-run transactions.
+    Public,
+    /// Can submit transactions.
+    Submitter,
+    /// Can produce blocks, debug transactions and manage the node.
+    Admin,
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Role::Public => f.write_str("public"),
+            Role::Submitter => f.write_str("submitter"),
+            Role::Admin => f.write_str("admin"),
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "public" => Ok(Role::Public),
+            "submitter" => Ok(Role::Submitter),
+            "admin" => Ok(Role::Admin),
+            _ => Err(anyhow::anyhow!(
+                "Unknown role `{s}`, expected `public`, `submitter` or `admin`"
+            )),
+        }
+    }
+}
+
+/// The identity of the client authenticated by its credentials.
+/// Clients without credentials don't have an identity.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Identity {
+    /// The client with the API key.
+    ApiKey(String),
+    /// The client with the JWT, identified by the `sub` claim
+    /// or by the token itself if it doesn't have the subject.
+    Jwt(String),
+}
+
+/// The credentials accepted by the public listener of the GraphQL API.
+/// Requests without credentials have the [`Role::Public`].
+#[derive(Clone, Default)]
+pub struct AuthConfig {
+    /// The roles of the API keys passed in the `X-Api-Key` header.
+    pub api_keys: HashMap<String, Role>,
+    /// The secret used to verify the HS256 JWTs passed in the
+    /// `Authorization: Bearer <token>` header. The `role` claim
+    /// of the token is the role of the client, and the optional
+    /// `sub` claim identifies the client.
+    pub jwt_secret: Option<SecretString>,
+}
+
+impl fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthConfig")
+            .field("api_keys", &self.api_keys.len())
+            .field(
+                "jwt_secret",
+                &self.jwt_secret.as_ref().map(|_| "[REDACTED]"),
+            )
+            .finish()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Claims {
+    role: Role,
+    sub: Option<String>,
+}
+
+/// Resolves the [`Role`] of the requests.
+pub(crate) enum Authenticator {
+    /// Every request has the [`Role::Admin`]. Used by the admin listener,
+    /// and by the public listener if the authentication is disabled.
+    Trusted,
+    /// The role is resolved from the credentials of the request.
+    Credentials {
+        api_keys: HashMap<String, Role>,
+        jwt: Option<Box<(DecodingKey, Validation)>>,
+    },
+}
+
+impl Authenticator {
+    pub fn new(config: AuthConfig) -> Self {
+        let jwt = config.jwt_secret.map(|secret| {
+            Box::new((
+                DecodingKey::from_secret(secret.expose_secret().as_bytes()),
+                Validation::new(Algorithm::HS256),
+            ))
+        });
+        Self::Credentials {
+            api_keys: config.api_keys,
+            jwt,
+        }
+    }
+
+    /// Returns the role and the identity of the request,
+    /// or an error if the credentials are invalid.
+    fn credentials<B>(
+        &self,
+        request: &Request<B>,
+    ) -> Result<(Role, Option<Identity>), &'static str> {
+        let Self::Credentials { api_keys, jwt } = self else {
+            return Ok((Role::Admin, None))
+        };
+
+        let headers = request.headers();
+        if let Some(api_key) = headers.get(API_KEY_HEADER) {
+            let api_key = api_key.to_str().map_err(|_| "Invalid API key")?;
+            let role = api_keys.get(api_key).ok_or("Invalid API key")?;
+            return Ok((*role, Some(Identity::ApiKey(api_key.to_string()))))
+        }
+
+        if let Some(authorization) = headers.get(AUTHORIZATION) {
+            let token = authorization
+                .to_str()
+                .ok()
+                .and_then(|value| value.strip_prefix("Bearer "))
+                .ok_or("Invalid authorization header")?;
+            let (key, validation) = jwt.as_deref().ok_or("JWT is not supported")?;
+            let claims = jsonwebtoken::decode::<Claims>(token, key, validation)
+                .map_err(|_| "Invalid JWT")?
+                .claims;
+            let subject = claims.sub.unwrap_or_else(|| token.to_string());
+            return Ok((claims.role, Some(Identity::Jwt(subject))))
+        }
+
+        Ok((Role::Public, None))
+    }
+
+    #[cfg(test)]
+    fn role<B>(&self, request: &Request<B>) -> Result<Role, &'static str> {
+        self.credentials(request).map(|(role, _)| role)
+    }
+}
+
+/// The middleware that adds the [`Role`] and the [`Identity`] of the client to the request,
+/// or responds with `401 Unauthorized` if the credentials are invalid.
+pub(crate) async fn authenticate<B>(
+    authenticator: Arc<Authenticator>,
+    mut request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    match authenticator.credentials(&request) {
+        Ok((role, identity)) => {
+            if let Some(identity) = identity {
+                request.extensions_mut().insert(identity);
+            }
+            request.extensions_mut().insert(role);
+            next.run(request).await
+        }
+        Err(reason) => (StatusCode::UNAUTHORIZED, reason).into_response(),
+    }
+}
+
+/// Allows access to the field only for clients with the `role` or a higher one.
+pub(crate) struct RoleGuard {
+    role: Role,
+}
+
+impl RoleGuard {
+    pub fn new(role: Role) -> Self {
+        Self { role }
+    }
+}
+
+#[async_trait::async_trait]
+impl Guard for RoleGuard {
+    async fn check(&self, ctx: &Context<'_>) -> async_graphql::Result<()> {
+        match ctx.data_opt::<Role>() {
+            Some(role) if *role >= self.role => Ok(()),
+            _ => Err(async_graphql::Error::new(format!(
+                "The `{}` role is required",
+                self.role
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use jsonwebtoken::{
+        EncodingKey,
+        Header,
+    };
+
+    const SECRET: &str = "secret";
+
+    fn authenticator() -> Authenticator {
+        Authenticator::new(AuthConfig {
+            api_keys: [
+                ("submitter-key".to_string(), Role::Submitter),
+                ("admin-key".to_string(), Role::Admin),
+            ]
+            .into_iter()
+            .collect(),
+            jwt_secret: Some(SecretString::new(SECRET.to_string())),
+        })
+    }
+
+    fn token(role: &str, secret: &str) -> String {
+        let claims = serde_json::json!({ "role": role, "exp": u32::MAX });
+        jsonwebtoken::encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(secret.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    fn request(header: Option<(&str, &str)>) -> Request<()> {
+        let mut request = Request::builder();
+        if let Some((name, value)) = header {
+            request = request.header(name, value);
+        }
+        request.body(()).unwrap()
+    }
+
+    #[test]
+    fn role__is_public_without_credentials() {
+        assert_eq!(authenticator().role(&request(None)), Ok(Role::Public));
+    }
+
+    #[test]
+    fn role__is_resolved_from_api_key() {
+        let authenticator = authenticator();
+
+        assert_eq!(
+            authenticator.role(&request(Some((API_KEY_HEADER, "submitter-key")))),
+            Ok(Role::Submitter)
+        );
+        assert_eq!(
+            authenticator.role(&request(Some((API_KEY_HEADER, "admin-key")))),
+            Ok(Role::Admin)
+        );
+        assert!(authenticator
+            .role(&request(Some((API_KEY_HEADER, "unknown-key"))))
+            .is_err());
+    }
+
+    #[test]
+    fn role__is_resolved_from_jwt() {
+        let authenticator = authenticator();
+        let bearer = |token: String| format!("Bearer {token}");
+
+        assert_eq!(
+            authenticator.role(&request(Some((
+                "authorization",
+                &bearer(token("admin", SECRET))
+            )))),
+            Ok(Role::Admin)
+        );
+        assert!(authenticator
+            .role(&request(Some((
+                "authorization",
+                &bearer(token("admin", "wrong secret"))
+            ))))
+            .is_err());
+        assert!(authenticator
+            .role(&request(Some(("authorization", &token("admin", SECRET)))))
+            .is_err());
+    }
+
+    #[test]
+    fn role__is_admin_for_trusted_requests() {
+        assert_eq!(Authenticator::Trusted.role(&request(None)), Ok(Role::Admin));
+    }
+
+    #[test]
+    fn credentials__identify_clients() {
+        let authenticator = authenticator();
+        let identity = |authenticator: &Authenticator, header| {
+            authenticator.credentials(&request(header)).unwrap().1
+        };
+        let bearer = |claims: serde_json::Value| {
+            let token = jsonwebtoken::encode(
+                &Header::default(),
+                &claims,
+                &EncodingKey::from_secret(SECRET.as_bytes()),
+            )
+            .unwrap();
+            format!("Bearer {token}")
+        };
+        let first = bearer(
+            serde_json::json!({ "role": "admin", "sub": "first", "exp": u32::MAX }),
+        );
+        let second = bearer(
+            serde_json::json!({ "role": "admin", "sub": "second", "exp": u32::MAX }),
+        );
+        let anonymous = format!("Bearer {}", token("admin", SECRET));
+
+        assert_eq!(identity(&authenticator, None), None);
+        assert_eq!(
+            identity(&authenticator, Some((API_KEY_HEADER, "admin-key"))),
+            Some(Identity::ApiKey("admin-key".to_string()))
+        );
+        assert_eq!(
+            identity(&authenticator, Some(("authorization", &first))),
+            Some(Identity::Jwt("first".to_string()))
+        );
+        assert_eq!(
+            identity(&authenticator, Some(("authorization", &second))),
+            Some(Identity::Jwt("second".to_string()))
+        );
+        assert_eq!(
+            identity(&authenticator, Some(("authorization", &anonymous))),
+            Some(Identity::Jwt(token("admin", SECRET)))
+        );
+        assert_eq!(
+            identity(&Authenticator::Trusted, Some((API_KEY_HEADER, "admin-key"))),
+            None
+        );
+    }
+
+    #[test]
+    fn roles_are_ordered_by_permissions() {
+        assert!(Role::Public < Role::Submitter);
+        assert!(Role::Submitter < Role::Admin);
+        assert_eq!("submitter".parse::<Role>().unwrap(), Role::Submitter);
+        assert!("root".parse::<Role>().is_err());
+    }
+}
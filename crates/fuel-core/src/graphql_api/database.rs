@@ -9,6 +9,10 @@ use crate::fuel_core_graphql_api::{
         OffChainDatabase,
         OnChainDatabase,
     },
+    storage::{
+        balances::TotalBalanceAmount,
+        transactions::TransactionFlows,
+    },
 };
 use fuel_core_storage::{
     iter::{
@@ -33,9 +37,12 @@ use fuel_core_types::{
             DaBlockHeight,
         },
     },
-    entities::message::{
-        MerkleProof,
-        Message,
+    entities::{
+        contract::SparseMerkleProof,
+        message::{
+            MerkleProof,
+            Message,
+        },
     },
     fuel_tx::{
         Address,
@@ -45,10 +52,14 @@ use fuel_core_types::{
     },
     fuel_types::{
         BlockHeight,
+        Bytes32,
         Nonce,
     },
     services::{
-        graphql_api::ContractBalance,
+        graphql_api::{
+            ContractBalance,
+            ContractStorageSlot,
+        },
         txpool::TransactionStatus,
     },
 };
@@ -65,12 +76,13 @@ pub type OnChainView = Arc<dyn OnChainDatabase>;
 pub type OffChainView = Arc<dyn OffChainDatabase>;
 
 /// The container of the on-chain and off-chain database view provides.
-/// It is used only by `ViewExtension` to create a [`ReadView`].
+/// It is used by `ViewExtension` and the JSON-RPC API to create a [`ReadView`].
+#[derive(Clone)]
 pub struct ReadDatabase {
     /// The on-chain database view provider.
-    on_chain: Box<dyn AtomicView<View = OnChainView, Height = BlockHeight>>,
+    on_chain: Arc<dyn AtomicView<View = OnChainView, Height = BlockHeight>>,
     /// The off-chain database view provider.
-    off_chain: Box<dyn AtomicView<View = OffChainView, Height = BlockHeight>>,
+    off_chain: Arc<dyn AtomicView<View = OffChainView, Height = BlockHeight>>,
 }
 
 impl ReadDatabase {
@@ -83,8 +95,8 @@ impl ReadDatabase {
         OffChain::View: OffChainDatabase,
     {
         Self {
-            on_chain: Box::new(ArcWrapper::new(on_chain)),
-            off_chain: Box::new(ArcWrapper::new(off_chain)),
+            on_chain: Arc::new(ArcWrapper::new(on_chain)),
+            off_chain: Arc::new(ArcWrapper::new(off_chain)),
         }
     }
 
@@ -167,6 +179,33 @@ impl DatabaseContracts for ReadView {
         self.on_chain
             .contract_balances(contract, start_asset, direction)
     }
+
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        prefix: Vec<u8>,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractStorageSlot>> {
+        self.on_chain
+            .contract_storage_slots(contract, prefix, start_key, direction)
+    }
+
+    fn contract_storage_slot_proof(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> StorageResult<Option<SparseMerkleProof>> {
+        self.on_chain.contract_storage_slot_proof(contract, key)
+    }
+
+    fn contract_balance_proof(
+        &self,
+        contract: &ContractId,
+        asset_id: &AssetId,
+    ) -> StorageResult<Option<SparseMerkleProof>> {
+        self.on_chain.contract_balance_proof(contract, asset_id)
+    }
 }
 
 impl DatabaseChain for ReadView {
@@ -221,4 +260,33 @@ impl OffChainDatabase for ReadView {
         self.off_chain
             .owned_transactions_ids(owner, start, direction)
     }
+
+    fn owned_transaction_flows(
+        &self,
+        owner: &Address,
+        tx_id: &TxId,
+    ) -> StorageResult<Option<TransactionFlows>> {
+        self.off_chain.owned_transaction_flows(owner, tx_id)
+    }
+
+    fn coin_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount> {
+        self.off_chain.coin_balance(owner, asset_id)
+    }
+
+    fn message_balance(&self, owner: &Address) -> StorageResult<TotalBalanceAmount> {
+        self.off_chain.message_balance(owner)
+    }
+
+    fn coin_balances(
+        &self,
+        owner: &Address,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, TotalBalanceAmount)>> {
+        self.off_chain.coin_balances(owner, start_asset, direction)
+    }
 }
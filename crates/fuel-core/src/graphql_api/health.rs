@@ -0,0 +1,390 @@
+//! The health checks of the node, served by the `/health/live` and `/health/ready`
+//! endpoints and by the `nodeStatus` query.
+
+use crate::fuel_core_graphql_api::ports::NodeStatusPort;
+use fuel_core_services::State;
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::BlockHeight,
+};
+use serde::Serialize;
+
+/// The thresholds used to decide whether the node is ready to serve requests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReadinessConfig {
+    /// The maximum number of blocks the node can be behind the best peer.
+    pub max_block_lag: u32,
+    /// The maximum number of DA blocks the relayer can be behind the DA layer.
+    pub max_da_lag: u64,
+}
+
+impl Default for ReadinessConfig {
+    fn default() -> Self {
+        Self {
+            max_block_lag: 10,
+            max_da_lag: 10,
+        }
+    }
+}
+
+/// The DA heights known by the relayer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RelayerHeights {
+    /// The latest DA height processed by the relayer,
+    /// or `None` if it is unavailable.
+    pub local: Option<DaBlockHeight>,
+    /// The latest finalized height of the DA layer observed by the relayer,
+    /// or `None` if the relayer didn't receive it yet.
+    pub remote: Option<DaBlockHeight>,
+}
+
+/// The lifecycle state of the service.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceState {
+    NotStarted,
+    Starting,
+    Started,
+    Stopping,
+    Stopped,
+    Failed,
+}
+
+/// The status of one of the node's services.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ServiceStatus {
+    pub name: &'static str,
+    pub state: ServiceState,
+    /// The error that stopped the service, if any.
+    pub error: Option<String>,
+}
+
+impl ServiceStatus {
+    fn new(name: &'static str, state: State) -> Self {
+        let (state, error) = match state {
+            State::NotStarted => (ServiceState::NotStarted, None),
+            State::Starting => (ServiceState::Starting, None),
+            State::Started => (ServiceState::Started, None),
+            State::Stopping => (ServiceState::Stopping, None),
+            State::Stopped => (ServiceState::Stopped, None),
+            State::StoppedWithError(error) => (ServiceState::Failed, Some(error)),
+        };
+        Self { name, state, error }
+    }
+}
+
+/// The status of the database.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DatabaseStatus {
+    pub reachable: bool,
+    /// The error returned by the database, if it is unreachable.
+    pub error: Option<String>,
+}
+
+/// The status of the synchronization with the network.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SyncStatus {
+    /// The height of the latest local block.
+    pub local_height: Option<u32>,
+    /// The highest block height advertised by the connected peers.
+    pub best_peer_height: Option<u32>,
+    /// The number of blocks the node is behind the best peer.
+    pub block_lag: Option<u32>,
+    pub synced: bool,
+}
+
+/// The status of the synchronization of the relayer with the DA layer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RelayerStatus {
+    /// The latest DA height processed by the relayer.
+    pub local_da_height: Option<u64>,
+    /// The latest finalized height of the DA layer.
+    pub remote_da_height: Option<u64>,
+    /// The number of DA blocks the relayer is behind the DA layer.
+    pub da_lag: Option<u64>,
+    pub synced: bool,
+}
+
+/// The status of the node and its components.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct NodeStatus {
+    /// `true` if none of the services has stopped.
+    pub live: bool,
+    /// `true` if the node is live, all services are started, the database is reachable,
+    /// and the node is in sync with the network and the DA layer.
+    pub ready: bool,
+    pub services: Vec<ServiceStatus>,
+    pub database: DatabaseStatus,
+    pub sync: SyncStatus,
+    /// `None` if the relayer is disabled.
+    pub relayer: Option<RelayerStatus>,
+}
+
+/// Collects the [`NodeStatus`] from the node's components.
+pub struct HealthCheck {
+    node: Box<dyn NodeStatusPort>,
+    config: ReadinessConfig,
+}
+
+impl HealthCheck {
+    pub fn new(node: Box<dyn NodeStatusPort>, config: ReadinessConfig) -> Self {
+        Self { node, config }
+    }
+
+    pub async fn status(&self) -> NodeStatus {
+        let services = self
+            .node
+            .service_states()
+            .into_iter()
+            .map(|(name, state)| ServiceStatus::new(name, state))
+            .collect::<Vec<_>>();
+
+        let latest_height = self.node.latest_height();
+        let database = DatabaseStatus {
+            reachable: latest_height.is_ok(),
+            error: latest_height.as_ref().err().map(|e| e.to_string()),
+        };
+
+        let best_peer_height = self.node.best_peer_height().await;
+        let sync = sync_status(
+            latest_height.ok(),
+            best_peer_height,
+            self.config.max_block_lag,
+        );
+
+        let relayer = self
+            .node
+            .relayer_heights()
+            .map(|heights| relayer_status(heights, self.config.max_da_lag));
+
+        let live = services.iter().all(|service| {
+            !matches!(service.state, ServiceState::Stopped | ServiceState::Failed)
+        });
+        let ready = live
+            && services
+                .iter()
+                .all(|service| service.state == ServiceState::Started)
+            && database.reachable
+            && sync.synced
+            && relayer.as_ref().map_or(true, |relayer| relayer.synced);
+
+        NodeStatus {
+            live,
+            ready,
+            services,
+            database,
+            sync,
+            relayer,
+        }
+    }
+}
+
+fn sync_status(
+    local_height: Option<BlockHeight>,
+    best_peer_height: anyhow::Result<Option<BlockHeight>>,
+    max_block_lag: u32,
+) -> SyncStatus {
+    let local_height = local_height.map(|height| *height);
+    let (best_peer_height, known) = match best_peer_height {
+        Ok(height) => (height.map(|height| *height), true),
+        Err(e) => {
+            tracing::warn!("Unable to get the heights of the peers: {e}");
+            (None, false)
+        }
+    };
+    let block_lag = local_height
+        .zip(best_peer_height)
+        .map(|(local, best)| best.saturating_sub(local));
+    // Without peers, the node has nothing to sync with.
+    let synced = known
+        && local_height.is_some()
+        && block_lag.map_or(true, |lag| lag <= max_block_lag);
+
+    SyncStatus {
+        local_height,
+        best_peer_height,
+        block_lag,
+        synced,
+    }
+}
+
+fn relayer_status(heights: RelayerHeights, max_da_lag: u64) -> RelayerStatus {
+    let local_da_height = heights.local.map(|height| *height);
+    let remote_da_height = heights.remote.map(|height| *height);
+    let da_lag = local_da_height
+        .zip(remote_da_height)
+        .map(|(local, remote)| remote.saturating_sub(local));
+    let synced = da_lag.map_or(false, |lag| lag <= max_da_lag);
+
+    RelayerStatus {
+        local_da_height,
+        remote_da_height,
+        da_lag,
+        synced,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    struct FakeNode {
+        services: Vec<(&'static str, State)>,
+        latest_height: Option<u32>,
+        best_peer_height: Option<u32>,
+        relayer: Option<RelayerHeights>,
+    }
+
+    impl Default for FakeNode {
+        fn default() -> Self {
+            Self {
+                services: vec![("TxPool", State::Started), ("Sync", State::Started)],
+                latest_height: Some(100),
+                best_peer_height: Some(100),
+                relayer: None,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl NodeStatusPort for FakeNode {
+        fn service_states(&self) -> Vec<(&'static str, State)> {
+            self.services.clone()
+        }
+
+        fn latest_height(&self) -> anyhow::Result<BlockHeight> {
+            self.latest_height
+                .map(Into::into)
+                .ok_or_else(|| anyhow::anyhow!("Database is closed"))
+        }
+
+        async fn best_peer_height(&self) -> anyhow::Result<Option<BlockHeight>> {
+            Ok(self.best_peer_height.map(Into::into))
+        }
+
+        fn relayer_heights(&self) -> Option<RelayerHeights> {
+            self.relayer
+        }
+    }
+
+    async fn status(node: FakeNode) -> NodeStatus {
+        HealthCheck::new(Box::new(node), ReadinessConfig::default())
+            .status()
+            .await
+    }
+
+    #[tokio::test]
+    async fn healthy_node_is_live_and_ready() {
+        let status = status(FakeNode::default()).await;
+
+        assert!(status.live);
+        assert!(status.ready);
+        assert_eq!(status.sync.block_lag, Some(0));
+    }
+
+    #[tokio::test]
+    async fn failed_service_makes_node_not_live() {
+        let node = FakeNode {
+            services: vec![
+                ("TxPool", State::Started),
+                ("Sync", State::StoppedWithError("panic".to_string())),
+            ],
+            ..Default::default()
+        };
+
+        let status = status(node).await;
+
+        assert!(!status.live);
+        assert!(!status.ready);
+        assert_eq!(status.services[1].state, ServiceState::Failed);
+        assert_eq!(status.services[1].error.as_deref(), Some("panic"));
+    }
+
+    #[tokio::test]
+    async fn starting_service_makes_node_live_but_not_ready() {
+        let node = FakeNode {
+            services: vec![("TxPool", State::Starting)],
+            ..Default::default()
+        };
+
+        let status = status(node).await;
+
+        assert!(status.live);
+        assert!(!status.ready);
+    }
+
+    #[tokio::test]
+    async fn node_behind_peers_is_not_ready() {
+        let node = FakeNode {
+            best_peer_height: Some(111),
+            ..Default::default()
+        };
+
+        let status = status(node).await;
+
+        assert!(status.live);
+        assert!(!status.ready);
+        assert_eq!(status.sync.block_lag, Some(11));
+    }
+
+    #[tokio::test]
+    async fn node_without_peers_is_ready() {
+        let node = FakeNode {
+            best_peer_height: None,
+            ..Default::default()
+        };
+
+        let status = status(node).await;
+
+        assert!(status.ready);
+        assert_eq!(status.sync.block_lag, None);
+    }
+
+    #[tokio::test]
+    async fn unreachable_database_makes_node_not_ready() {
+        let node = FakeNode {
+            latest_height: None,
+            ..Default::default()
+        };
+
+        let status = status(node).await;
+
+        assert!(status.live);
+        assert!(!status.ready);
+        assert!(!status.database.reachable);
+        assert_eq!(status.database.error.as_deref(), Some("Database is closed"));
+    }
+
+    #[tokio::test]
+    async fn relayer_behind_da_layer_is_not_ready() {
+        let relayer = |local: u64, remote: u64| RelayerHeights {
+            local: Some(local.into()),
+            remote: Some(remote.into()),
+        };
+
+        let synced = status(FakeNode {
+            relayer: Some(relayer(90, 100)),
+            ..Default::default()
+        })
+        .await;
+        let behind = status(FakeNode {
+            relayer: Some(relayer(89, 100)),
+            ..Default::default()
+        })
+        .await;
+        let unknown_remote = status(FakeNode {
+            relayer: Some(RelayerHeights {
+                local: Some(100u64.into()),
+                remote: None,
+            }),
+            ..Default::default()
+        })
+        .await;
+
+        assert!(synced.ready);
+        assert!(!behind.ready);
+        assert_eq!(behind.relayer.unwrap().da_lag, Some(11));
+        assert!(!unknown_remote.ready);
+    }
+}
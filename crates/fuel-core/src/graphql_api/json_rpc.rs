@@ -0,0 +1,593 @@
+//! The JSON-RPC 2.0 API of the node over HTTP and WebSocket.
+//!
+//! The methods are served by the same ports as the GraphQL schema, see [`methods`].
+//! The HTTP endpoint supports single and batch requests. The WebSocket endpoint
+//! accepts single requests and additionally supports the subscriptions, which push the `fuel_subscription`
+//! notifications until the subscription ends or is cancelled by `fuel_unsubscribe`.
+//!
+//! The batch size is limited by the [`QueryLimits`](crate::graphql_api::QueryLimits).
+//! Each request of the batch and each WebSocket message is charged against
+//! the rate limit of the client, and
+//! one connection can have at most [`MAX_SUBSCRIPTIONS_PER_CONNECTION`] subscriptions.
+
+use crate::fuel_core_graphql_api::{
+    api_service::{
+        BlockProducer,
+        ReadDatabase,
+        TxPool,
+    },
+    auth::Role,
+    rate_limit::ClientRateLimit,
+};
+use axum::{
+    body::Bytes,
+    extract::{
+        ws::{
+            Message,
+            WebSocket,
+            WebSocketUpgrade,
+        },
+        Extension,
+    },
+    http::StatusCode,
+    response::{
+        IntoResponse,
+        Response as HttpResponse,
+    },
+    Json,
+};
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use fuel_core_storage::Error as StorageError;
+use fuel_core_types::fuel_types::{
+    AssetId,
+    ChainId,
+};
+use futures::{
+    stream::BoxStream,
+    StreamExt,
+};
+use serde::{
+    de::DeserializeOwned,
+    Deserialize,
+    Deserializer,
+    Serialize,
+};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+use tokio::{
+    sync::mpsc,
+    task::JoinHandle,
+};
+
+mod methods;
+
+const VERSION: &str = "2.0";
+
+/// The method of the notifications sent to the subscribers.
+const SUBSCRIPTION_METHOD: &str = "fuel_subscription";
+
+/// The maximum number of the active subscriptions of one WebSocket connection.
+pub(crate) const MAX_SUBSCRIPTIONS_PER_CONNECTION: usize = 16;
+
+/// The number of the responses and the notifications buffered for one WebSocket connection.
+/// The subscriptions wait until the client reads the buffered messages.
+const CONNECTION_BUFFER_SIZE: usize = 64;
+
+/// The error codes defined by the JSON-RPC 2.0 specification,
+/// and the server errors of the node.
+pub(crate) mod code {
+    pub const PARSE_ERROR: i64 = -32700;
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+    pub const INTERNAL_ERROR: i64 = -32603;
+    /// The request to the node failed, e.g. the transaction was rejected by the `TxPool`.
+    pub const SERVER_ERROR: i64 = -32000;
+    /// The client doesn't have the role required by the method.
+    pub const UNAUTHORIZED: i64 = -32001;
+    /// The client exceeded the rate limit or the number of the subscriptions.
+    pub const LIMIT_EXCEEDED: i64 = -32005;
+}
+
+/// The state shared by the JSON-RPC endpoints.
+pub(crate) struct JsonRpc {
+    pub database: ReadDatabase,
+    pub txpool: TxPool,
+    pub producer: BlockProducer,
+    pub chain_id: ChainId,
+    pub base_asset_id: AssetId,
+    pub max_batch_size: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    jsonrpc: String,
+    method: String,
+    #[serde(default)]
+    params: Value,
+    /// The request without `id` is a notification and doesn't receive a response.
+    /// The `null` id is a valid id, so it is distinguished from the absent one.
+    #[serde(default, deserialize_with = "present")]
+    id: Option<Value>,
+}
+
+fn present<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Value>, D::Error> {
+    Value::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    jsonrpc: &'static str,
+    #[serde(flatten)]
+    outcome: Outcome,
+    id: Value,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Outcome {
+    Result(Value),
+    Error(Error),
+}
+
+#[derive(Debug, Serialize)]
+struct Notification {
+    jsonrpc: &'static str,
+    method: &'static str,
+    params: SubscriptionResult,
+}
+
+#[derive(Debug, Serialize)]
+struct SubscriptionResult {
+    subscription: u64,
+    #[serde(flatten)]
+    outcome: Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct Error {
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl From<StorageError> for Error {
+    fn from(err: StorageError) -> Self {
+        Self::server(err)
+    }
+}
+
+impl Error {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    pub fn invalid_params(message: impl ToString) -> Self {
+        Self::new(code::INVALID_PARAMS, message.to_string())
+    }
+
+    pub fn server(message: impl ToString) -> Self {
+        Self::new(code::SERVER_ERROR, message.to_string())
+    }
+
+    pub fn required_role(role: Role) -> Self {
+        Self::new(code::UNAUTHORIZED, format!("The `{role}` role is required"))
+    }
+}
+
+/// Deserializes the positional `params` into the tuple `T` with `len` elements.
+/// The missing trailing params are `null`, so they can be deserialized as `Option`.
+pub(crate) fn params<T: DeserializeOwned>(params: Value, len: usize) -> Result<T, Error> {
+    let mut params = match params {
+        Value::Array(params) => params,
+        Value::Null => vec![],
+        _ => return Err(Error::invalid_params("The params must be an array")),
+    };
+    if params.len() > len {
+        return Err(Error::invalid_params(format!(
+            "Expected at most {len} params, got {}",
+            params.len()
+        )))
+    }
+    params.resize(len, Value::Null);
+    serde_json::from_value(Value::Array(params)).map_err(Error::invalid_params)
+}
+
+/// The subscriptions available to the caller of the method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Subscriber {
+    /// The request was sent over HTTP.
+    No,
+    /// The WebSocket connection can't have more subscriptions.
+    LimitReached,
+    Yes,
+}
+
+/// The result of the method called by the client.
+pub(crate) enum Call {
+    Result(Value),
+    /// The stream of the subscription results. Available only over WebSocket.
+    Subscription(BoxStream<'static, Result<Value, Error>>),
+    /// Cancels the subscription with the given id. Available only over WebSocket.
+    Unsubscribe(u64),
+}
+
+impl JsonRpc {
+    /// Handles the single or the batch request. Returns `None` if nothing should
+    /// be sent back, i.e. the request consists only of notifications.
+    async fn handle_http(
+        &self,
+        role: Role,
+        rate_limit: Option<&ClientRateLimit>,
+        body: &[u8],
+    ) -> Option<Value> {
+        let request = match serde_json::from_slice::<Value>(body) {
+            Ok(request) => request,
+            Err(err) => return Some(error_response(Value::Null, code::PARSE_ERROR, err)),
+        };
+
+        match request {
+            Value::Array(batch) if batch.is_empty() => Some(error_response(
+                Value::Null,
+                code::INVALID_REQUEST,
+                "The batch is empty",
+            )),
+            Value::Array(batch) if batch.len() > self.max_batch_size => {
+                graphql_metrics().graphql_request_rejected("batch_size");
+                Some(error_response(
+                    Value::Null,
+                    code::INVALID_REQUEST,
+                    format!(
+                        "The batch size `{}` exceeds the limit `{}`",
+                        batch.len(),
+                        self.max_batch_size
+                    ),
+                ))
+            }
+            Value::Array(batch)
+                if !rate_limit
+                    .map_or(true, |rate_limit| rate_limit.check_batch(batch.len())) =>
+            {
+                graphql_metrics().graphql_request_rejected("rate_limit");
+                Some(error_response(
+                    Value::Null,
+                    code::LIMIT_EXCEEDED,
+                    "Too many requests",
+                ))
+            }
+            Value::Array(batch) => {
+                let mut responses = vec![];
+                for request in batch {
+                    if let Some(response) = self.handle_single(role, request).await {
+                        responses.push(response);
+                    }
+                }
+                (!responses.is_empty()).then_some(Value::Array(responses))
+            }
+            request => self.handle_single(role, request).await,
+        }
+    }
+
+    async fn handle_single(&self, role: Role, request: Value) -> Option<Value> {
+        match self.call(role, request, Subscriber::No).await {
+            (id, Ok(Call::Result(result))) => {
+                id.map(|id| response(id, Outcome::Result(result)))
+            }
+            (id, Err(err)) => id.map(|id| response(id, Outcome::Error(err))),
+            (id, Ok(_)) => id.map(|id| {
+                response(
+                    id,
+                    Outcome::Error(Error::new(
+                        code::METHOD_NOT_FOUND,
+                        "The subscriptions are available only over WebSocket",
+                    )),
+                )
+            }),
+        }
+    }
+
+    /// Parses and executes the request. Returns the id of the request
+    /// along with the result, so the caller can respond to it.
+    async fn call(
+        &self,
+        role: Role,
+        request: Value,
+        subscriber: Subscriber,
+    ) -> (Option<Value>, Result<Call, Error>) {
+        // The id is extracted first to respond to the invalid request if possible.
+        let id = request.get("id").cloned();
+        let request = match serde_json::from_value::<Request>(request) {
+            Ok(request) if request.jsonrpc == VERSION => request,
+            Ok(_) => {
+                return (
+                    Some(id.unwrap_or_default()),
+                    Err(Error::new(
+                        code::INVALID_REQUEST,
+                        "The `jsonrpc` version must be \"2.0\"",
+                    )),
+                )
+            }
+            Err(err) => {
+                return (
+                    Some(id.unwrap_or_default()),
+                    Err(Error::new(code::INVALID_REQUEST, err.to_string())),
+                )
+            }
+        };
+
+        let result = self
+            .execute(role, &request.method, request.params, subscriber)
+            .await;
+        (request.id, result)
+    }
+}
+
+fn response(id: Value, outcome: Outcome) -> Value {
+    serde_json::to_value(Response {
+        jsonrpc: VERSION,
+        outcome,
+        id,
+    })
+    .expect("The response is serializable")
+}
+
+fn error_response(id: Value, code: i64, message: impl ToString) -> Value {
+    response(id, Outcome::Error(Error::new(code, message.to_string())))
+}
+
+/// Handles the JSON-RPC requests sent by `POST`.
+pub(crate) async fn http_handler(
+    json_rpc: Extension<Arc<JsonRpc>>,
+    role: Extension<Role>,
+    rate_limit: Option<Extension<ClientRateLimit>>,
+    body: Bytes,
+) -> HttpResponse {
+    let rate_limit = rate_limit.map(|rate_limit| rate_limit.0);
+    match json_rpc
+        .handle_http(role.0, rate_limit.as_ref(), &body)
+        .await
+    {
+        Some(response) => Json(response).into_response(),
+        None => StatusCode::NO_CONTENT.into_response(),
+    }
+}
+
+/// Upgrades the connection to WebSocket. Each text message is a JSON-RPC request.
+pub(crate) async fn ws_handler(
+    ws: WebSocketUpgrade,
+    json_rpc: Extension<Arc<JsonRpc>>,
+    role: Extension<Role>,
+    rate_limit: Option<Extension<ClientRateLimit>>,
+) -> HttpResponse {
+    let json_rpc = json_rpc.0;
+    let role = role.0;
+    let rate_limit = rate_limit.map(|rate_limit| rate_limit.0);
+    ws.on_upgrade(move |socket| serve_socket(json_rpc, role, rate_limit, socket))
+}
+
+async fn serve_socket(
+    json_rpc: Arc<JsonRpc>,
+    role: Role,
+    rate_limit: Option<ClientRateLimit>,
+    mut socket: WebSocket,
+) {
+    // The notifications are sent in the order of this channel. The responses are sent
+    // right away, so the id of the subscription is sent before its first notification.
+    let (sender, mut receiver) = mpsc::channel::<Value>(CONNECTION_BUFFER_SIZE);
+    let mut subscriptions = Subscriptions {
+        tasks: HashMap::new(),
+        next_id: 0,
+        sender,
+        rate_limit,
+    };
+
+    loop {
+        let message = tokio::select! {
+            message = socket.recv() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    match subscriptions.handle(&json_rpc, role, &text).await {
+                        Some(response) => response,
+                        None => continue,
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue,
+            },
+            Some(notification) = receiver.recv() => notification,
+        };
+        if socket
+            .send(Message::Text(message.to_string()))
+            .await
+            .is_err()
+        {
+            break
+        }
+    }
+}
+
+/// The subscriptions of one WebSocket connection.
+/// The tasks are aborted when the connection is closed.
+struct Subscriptions {
+    tasks: HashMap<u64, JoinHandle<()>>,
+    next_id: u64,
+    sender: mpsc::Sender<Value>,
+    rate_limit: Option<ClientRateLimit>,
+}
+
+impl Subscriptions {
+    /// Handles the request and returns the response to it, if any.
+    async fn handle(
+        &mut self,
+        json_rpc: &JsonRpc,
+        role: Role,
+        text: &str,
+    ) -> Option<Value> {
+        self.tasks.retain(|_, task| !task.is_finished());
+
+        let request = match serde_json::from_str::<Value>(text) {
+            Ok(request) => request,
+            Err(err) => return Some(error_response(Value::Null, code::PARSE_ERROR, err)),
+        };
+        if let Some(rate_limit) = &self.rate_limit {
+            if !rate_limit.check() {
+                graphql_metrics().graphql_request_rejected("rate_limit");
+                return request.get("id").cloned().map(|id| {
+                    error_response(id, code::LIMIT_EXCEEDED, "Too many requests")
+                })
+            }
+        }
+
+        let subscriber = if self.tasks.len() < MAX_SUBSCRIPTIONS_PER_CONNECTION {
+            Subscriber::Yes
+        } else {
+            Subscriber::LimitReached
+        };
+        let (id, result) = json_rpc.call(role, request, subscriber).await;
+        let outcome = match result {
+            Ok(Call::Result(result)) => Outcome::Result(result),
+            Ok(Call::Subscription(stream)) => {
+                let subscription = self.next_id;
+                self.next_id = self.next_id.wrapping_add(1);
+                let task =
+                    tokio::spawn(forward(subscription, stream, self.sender.clone()));
+                self.tasks.insert(subscription, task);
+                Outcome::Result(subscription.into())
+            }
+            Ok(Call::Unsubscribe(subscription)) => {
+                let task = self.tasks.remove(&subscription);
+                if let Some(task) = &task {
+                    task.abort();
+                }
+                Outcome::Result(task.is_some().into())
+            }
+            Err(err) => Outcome::Error(err),
+        };
+        id.map(|id| response(id, outcome))
+    }
+}
+
+impl Drop for Subscriptions {
+    fn drop(&mut self) {
+        for task in self.tasks.values() {
+            task.abort();
+        }
+    }
+}
+
+/// Sends the results of the subscription as notifications.
+async fn forward(
+    subscription: u64,
+    mut stream: BoxStream<'static, Result<Value, Error>>,
+    sender: mpsc::Sender<Value>,
+) {
+    while let Some(result) = stream.next().await {
+        let outcome = match result {
+            Ok(result) => Outcome::Result(result),
+            Err(err) => Outcome::Error(err),
+        };
+        let notification = Notification {
+            jsonrpc: VERSION,
+            method: SUBSCRIPTION_METHOD,
+            params: SubscriptionResult {
+                subscription,
+                outcome,
+            },
+        };
+        let notification =
+            serde_json::to_value(notification).expect("The notification is serializable");
+        if sender.send(notification).await.is_err() {
+            break
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn params__pads_missing_trailing_params_with_null() {
+        let (height, flag): (u32, Option<bool>) = params(json!([1]), 2).unwrap();
+
+        assert_eq!(height, 1);
+        assert_eq!(flag, None);
+    }
+
+    #[test]
+    fn params__rejects_invalid_params() {
+        let too_many = params::<(u32,)>(json!([1, 2]), 1).unwrap_err();
+        let not_array = params::<(u32,)>(json!({ "height": 1 }), 1).unwrap_err();
+        let wrong_type = params::<(u32,)>(json!(["1"]), 1).unwrap_err();
+        let missing = params::<(u32,)>(json!([]), 1).unwrap_err();
+
+        for err in [too_many, not_array, wrong_type, missing] {
+            assert_eq!(err.code, code::INVALID_PARAMS);
+        }
+    }
+
+    #[test]
+    fn request__distinguishes_null_id_from_notification() {
+        let request: Request = serde_json::from_value(
+            json!({ "jsonrpc": "2.0", "method": "fuel_latestBlockHeight", "id": null }),
+        )
+        .unwrap();
+        let notification: Request = serde_json::from_value(
+            json!({ "jsonrpc": "2.0", "method": "fuel_latestBlockHeight" }),
+        )
+        .unwrap();
+
+        assert_eq!(request.id, Some(Value::Null));
+        assert_eq!(request.params, Value::Null);
+        assert_eq!(notification.id, None);
+    }
+
+    #[test]
+    fn response__contains_either_result_or_error() {
+        let result = response(json!(1), Outcome::Result(json!(10)));
+        let error = error_response(json!("a"), code::METHOD_NOT_FOUND, "Not found");
+
+        assert_eq!(result, json!({ "jsonrpc": "2.0", "result": 10, "id": 1 }));
+        assert_eq!(
+            error,
+            json!({
+                "jsonrpc": "2.0",
+                "error": { "code": -32601, "message": "Not found" },
+                "id": "a",
+            })
+        );
+    }
+
+    #[test]
+    fn notification__contains_subscription_and_result() {
+        let notification = Notification {
+            jsonrpc: VERSION,
+            method: SUBSCRIPTION_METHOD,
+            params: SubscriptionResult {
+                subscription: 3,
+                outcome: Outcome::Result(json!("status")),
+            },
+        };
+
+        assert_eq!(
+            serde_json::to_value(notification).unwrap(),
+            json!({
+                "jsonrpc": "2.0",
+                "method": "fuel_subscription",
+                "params": { "subscription": 3, "result": "status" },
+            })
+        );
+    }
+}
@@ -0,0 +1,579 @@
+//! The methods of the JSON-RPC API. The params are positional, and the optional
+//! params can be omitted from the end. The ids and the binary data are `0x`-prefixed hex strings.
+//!
+//! | Method                            | Params                                   |
+//! |-----------------------------------|------------------------------------------|
+//! | `fuel_latestBlockHeight`          | -                                        |
+//! | `fuel_blockByHeight`              | `height`                                 |
+//! | `fuel_blockById`                  | `id`                                     |
+//! | `fuel_transaction`                | `id`                                     |
+//! | `fuel_transactionStatus`          | `id`                                     |
+//! | `fuel_receipts`                   | `id`                                     |
+//! | `fuel_getBalance`                 | `owner`, `assetId`                       |
+//! | `fuel_coinsToSpend`               | `owner`, `[{assetId, amount, max?}]`, `{utxos, messages}?` |
+//! | `fuel_submit`                     | `tx`                                     |
+//! | `fuel_dryRun`                     | `[tx]`, `utxoValidation?`                |
+//! | `fuel_subscribeTransactionStatus` | `id`, WebSocket only                     |
+//! | `fuel_submitAndAwait`             | `tx`, WebSocket only                     |
+//! | `fuel_unsubscribe`                | `subscription`, WebSocket only           |
+
+use super::{
+    code,
+    params,
+    Call,
+    Error,
+    JsonRpc,
+    Subscriber,
+    MAX_SUBSCRIPTIONS_PER_CONNECTION,
+};
+use crate::{
+    coins_query::{
+        random_improve,
+        SpendQuery,
+    },
+    fuel_core_graphql_api::{
+        auth::Role,
+        IntoApiResult,
+    },
+    query::{
+        asset_query::AssetSpendTarget,
+        transaction_status_updates,
+        BalanceQueryData,
+        BlockQueryData,
+        SimpleBlockData,
+        SimpleTransactionData,
+        TransactionQueryData,
+    },
+};
+use fuel_core_storage::{
+    Error as StorageError,
+    Result as StorageResult,
+};
+use fuel_core_txpool::service::TxStatusMessage;
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        primitives::BlockId,
+    },
+    entities::coins::CoinType,
+    fuel_tx::{
+        Cacheable,
+        Receipt,
+        Transaction as FuelTx,
+        TxId,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::{
+        canonical::{
+            Deserialize as _,
+            Serialize as _,
+        },
+        Address,
+        AssetId,
+        BlockHeight,
+        Nonce,
+    },
+    fuel_vm::ProgramState,
+    services::{
+        executor::TransactionExecutionResult,
+        txpool::TransactionStatus as TxPoolTxStatus,
+    },
+};
+use futures::StreamExt;
+use itertools::Itertools;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::Value;
+use std::{
+    fmt::LowerHex,
+    sync::Arc,
+};
+
+impl JsonRpc {
+    /// Executes the `method`. The subscriptions are available only to the `subscriber`
+    /// that hasn't reached the limit of the subscriptions.
+    pub(super) async fn execute(
+        &self,
+        role: Role,
+        method: &str,
+        params: Value,
+        subscriber: Subscriber,
+    ) -> Result<Call, Error> {
+        let result = match method {
+            "fuel_latestBlockHeight" => {
+                self::params::<[Value; 0]>(params, 0)?;
+                to_value(u32::from(self.database.view().latest_block_height()?))
+            }
+            "fuel_blockByHeight" => {
+                let (height,): (u32,) = self::params(params, 1)?;
+                to_value(self.block(BlockHeight::from(height))?)
+            }
+            "fuel_blockById" => {
+                let (id,): (BlockId,) = self::params(params, 1)?;
+                let view = self.database.view();
+                let block = optional(view.block_by_id(&id))?;
+                to_value(block.map(Block::from))
+            }
+            "fuel_transaction" => {
+                let (id,): (TxId,) = self::params(params, 1)?;
+                to_value(self.transaction(id)?)
+            }
+            "fuel_transactionStatus" => {
+                let (id,): (TxId,) = self::params(params, 1)?;
+                to_value(self.status(id)?.map(TransactionStatus::from))
+            }
+            "fuel_receipts" => {
+                let (id,): (TxId,) = self::params(params, 1)?;
+                let receipts = optional(self.database.view().receipts(&id))?;
+                to_value(receipts)
+            }
+            "fuel_getBalance" => {
+                let (owner, asset_id): (Address, AssetId) = self::params(params, 2)?;
+                let balance =
+                    self.database
+                        .view()
+                        .balance(owner, asset_id, self.base_asset_id)?;
+                to_value(balance.amount.to_string())
+            }
+            "fuel_coinsToSpend" => {
+                let (owner, query, excluded): (
+                    Address,
+                    Vec<SpendQueryElement>,
+                    Option<Exclude>,
+                ) = self::params(params, 3)?;
+                to_value(self.coins_to_spend(owner, query, excluded)?)
+            }
+            "fuel_submit" => {
+                require(role, Role::Submitter)?;
+                let (tx,): (HexBytes,) = self::params(params, 1)?;
+                let tx = parse_tx(&tx)?;
+                let id = tx.id(&self.chain_id);
+                self.insert(tx).await?;
+                to_value(hex(id))
+            }
+            "fuel_dryRun" => {
+                let (txs, utxo_validation): (Vec<HexBytes>, Option<bool>) =
+                    self::params(params, 2)?;
+                to_value(self.dry_run(txs, utxo_validation).await?)
+            }
+            "fuel_subscribeTransactionStatus" | "fuel_submitAndAwait"
+                if subscriber == Subscriber::LimitReached =>
+            {
+                return Err(Error::new(
+                    code::LIMIT_EXCEEDED,
+                    format!(
+                        "The connection can't have more than \
+                        {MAX_SUBSCRIPTIONS_PER_CONNECTION} subscriptions"
+                    ),
+                ))
+            }
+            "fuel_subscribeTransactionStatus" if subscriber == Subscriber::Yes => {
+                let (id,): (TxId,) = self::params(params, 1)?;
+                return self.subscribe_status(id)
+            }
+            "fuel_submitAndAwait" if subscriber == Subscriber::Yes => {
+                require(role, Role::Submitter)?;
+                let (tx,): (HexBytes,) = self::params(params, 1)?;
+                return self.submit_and_await(tx).await
+            }
+            "fuel_unsubscribe" if subscriber != Subscriber::No => {
+                let (subscription,): (u64,) = self::params(params, 1)?;
+                return Ok(Call::Unsubscribe(subscription))
+            }
+            "fuel_subscribeTransactionStatus"
+            | "fuel_submitAndAwait"
+            | "fuel_unsubscribe" => {
+                return Err(Error::new(
+                    code::METHOD_NOT_FOUND,
+                    format!("The method `{method}` is available only over WebSocket"),
+                ))
+            }
+            _ => {
+                return Err(Error::new(
+                    code::METHOD_NOT_FOUND,
+                    format!("The method `{method}` is not found"),
+                ))
+            }
+        };
+
+        result.map(Call::Result)
+    }
+
+    fn block(&self, height: BlockHeight) -> Result<Option<Block>, Error> {
+        let block = optional(self.database.view().block(&height))?;
+        Ok(block.map(Block::from))
+    }
+
+    fn transaction(&self, id: TxId) -> Result<Option<Transaction>, Error> {
+        let tx = match self.txpool.transaction(id) {
+            Some(tx) => Some(tx),
+            None => optional(self.database.view().transaction(&id))?,
+        };
+        let Some(tx) = tx else { return Ok(None) };
+
+        Ok(Some(Transaction {
+            id: hex(id),
+            raw_payload: hex_bytes(tx.to_bytes()),
+            status: self.status(id)?.map(TransactionStatus::from),
+        }))
+    }
+
+    fn status(&self, id: TxId) -> Result<Option<TxPoolTxStatus>, Error> {
+        let status = optional(self.database.view().status(&id))?;
+        Ok(status.or_else(|| {
+            self.txpool
+                .submission_time(id)
+                .map(|time| TxPoolTxStatus::Submitted { time })
+        }))
+    }
+
+    fn coins_to_spend(
+        &self,
+        owner: Address,
+        query: Vec<SpendQueryElement>,
+        excluded: Option<Exclude>,
+    ) -> Result<Vec<Vec<Coin>>, Error> {
+        let query = query
+            .into_iter()
+            .map(|e| {
+                let amount = e.amount.parse().map_err(Error::invalid_params)?;
+                let max = e.max.map(|max| max as usize).unwrap_or(usize::MAX);
+                Ok(AssetSpendTarget::new(e.asset_id, amount, max))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let excluded = excluded
+            .map(|excluded| {
+                let utxos = excluded
+                    .utxos
+                    .iter()
+                    .map(|utxo| {
+                        utxo.parse::<UtxoId>()
+                            .map(fuel_core_types::entities::coins::CoinId::Utxo)
+                            .map_err(Error::invalid_params)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let messages = excluded
+                    .messages
+                    .into_iter()
+                    .map(fuel_core_types::entities::coins::CoinId::Message);
+                Ok::<_, Error>(utxos.into_iter().chain(messages).collect())
+            })
+            .transpose()?;
+
+        let spend_query = SpendQuery::new(owner, &query, excluded, self.base_asset_id)
+            .map_err(Error::invalid_params)?;
+        let coins = random_improve(&self.database.view(), &spend_query)
+            .map_err(Error::server)?
+            .into_iter()
+            .map(|coins| coins.into_iter().map(Coin::from).collect())
+            .collect();
+        Ok(coins)
+    }
+
+    async fn insert(&self, tx: FuelTx) -> Result<(), Error> {
+        let _: Vec<_> = self
+            .txpool
+            .insert(vec![Arc::new(tx)])
+            .await
+            .into_iter()
+            .try_collect()
+            .map_err(Error::server)?;
+        Ok(())
+    }
+
+    async fn dry_run(
+        &self,
+        txs: Vec<HexBytes>,
+        utxo_validation: Option<bool>,
+    ) -> Result<Vec<DryRunStatus>, Error> {
+        let mut transactions = txs.iter().map(parse_tx).collect::<Result<Vec<_>, _>>()?;
+        for transaction in &mut transactions {
+            transaction
+                .precompute(&self.chain_id)
+                .map_err(Error::invalid_params)?;
+        }
+
+        let statuses = self
+            .producer
+            .dry_run_txs(transactions, None, utxo_validation)
+            .await
+            .map_err(Error::server)?;
+        Ok(statuses
+            .into_iter()
+            .map(|status| {
+                let (result, receipts, success) = match status.result {
+                    TransactionExecutionResult::Success { result, receipts } => {
+                        (result, receipts, true)
+                    }
+                    TransactionExecutionResult::Failed { result, receipts } => {
+                        (result, receipts, false)
+                    }
+                };
+                DryRunStatus {
+                    id: hex(status.id),
+                    success,
+                    program_state: result,
+                    receipts,
+                }
+            })
+            .collect())
+    }
+
+    fn subscribe_status(&self, id: TxId) -> Result<Call, Error> {
+        let stream = self.txpool.tx_update_subscribe(id).map_err(Error::server)?;
+        let view = self.database.view();
+        let txpool = self.txpool.clone();
+        let stream = transaction_status_updates(
+            move |id| match view.status(&id) {
+                Ok(status) => Ok(Some(status)),
+                Err(StorageError::NotFound(_, _)) => Ok(txpool
+                    .submission_time(id)
+                    .map(|time| TxPoolTxStatus::Submitted { time })),
+                Err(err) => Err(err),
+            },
+            stream,
+            id,
+        )
+        .map(|status| match status {
+            Ok(status) => to_value(TransactionStatus::from(status)),
+            Err(err) => Err(Error::server(err)),
+        });
+        Ok(Call::Subscription(stream.boxed()))
+    }
+
+    async fn submit_and_await(&self, tx: HexBytes) -> Result<Call, Error> {
+        let tx = parse_tx(&tx)?;
+        let id = tx.id(&self.chain_id);
+        // Subscribes before the insertion to not miss the status update.
+        let subscription = self.txpool.tx_update_subscribe(id).map_err(Error::server)?;
+        self.insert(tx).await?;
+
+        let stream = subscription
+            .skip_while(|event| {
+                futures::future::ready(matches!(
+                    event,
+                    TxStatusMessage::Status(TxPoolTxStatus::Submitted { .. })
+                ))
+            })
+            .map(|event| match event {
+                TxStatusMessage::Status(status) => {
+                    to_value(TransactionStatus::from(status))
+                }
+                TxStatusMessage::FailedStatus => {
+                    Err(Error::server("Failed to get transaction status"))
+                }
+            })
+            .take(1);
+        Ok(Call::Subscription(stream.boxed()))
+    }
+}
+
+fn parse_tx(tx: &HexBytes) -> Result<FuelTx, Error> {
+    FuelTx::from_bytes(&tx.0).map_err(|err| Error::invalid_params(format!("{err:?}")))
+}
+
+/// Returns `None` if the entity is not found.
+fn optional<T>(result: StorageResult<T>) -> Result<Option<T>, Error> {
+    result.into_api_result::<T, Error>()
+}
+
+fn require(role: Role, required: Role) -> Result<(), Error> {
+    if role >= required {
+        Ok(())
+    } else {
+        Err(Error::required_role(required))
+    }
+}
+
+fn to_value<T: Serialize>(value: T) -> Result<Value, Error> {
+    serde_json::to_value(value)
+        .map_err(|err| Error::new(code::INTERNAL_ERROR, err.to_string()))
+}
+
+fn hex(value: impl LowerHex) -> String {
+    format!("{value:#x}")
+}
+
+fn hex_bytes(bytes: impl AsRef<[u8]>) -> String {
+    format!("0x{}", ::hex::encode(bytes))
+}
+
+/// The binary data encoded as a hex string with the optional `0x` prefix.
+struct HexBytes(Vec<u8>);
+
+impl<'de> Deserialize<'de> for HexBytes {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let value = value.strip_prefix("0x").unwrap_or(&value);
+        ::hex::decode(value)
+            .map(HexBytes)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SpendQueryElement {
+    asset_id: AssetId,
+    /// The amount is a string, because it may exceed the safe integer of JSON.
+    amount: String,
+    max: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct Exclude {
+    #[serde(default)]
+    utxos: Vec<String>,
+    #[serde(default)]
+    messages: Vec<Nonce>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Block {
+    id: String,
+    height: u32,
+    da_height: u64,
+    /// The time of the block in the TAI64 format.
+    time: u64,
+    transactions_root: String,
+    prev_root: String,
+    application_hash: String,
+    transaction_ids: Vec<String>,
+}
+
+impl From<CompressedBlock> for Block {
+    fn from(block: CompressedBlock) -> Self {
+        let header = block.header();
+        Self {
+            id: hex(header.id()),
+            height: (*header.height()).into(),
+            da_height: header.application().da_height.0,
+            time: header.time().0,
+            transactions_root: hex(header.application().transactions_root),
+            prev_root: hex(header.prev_root()),
+            application_hash: hex(header.application_hash()),
+            transaction_ids: block.transactions().iter().map(hex).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Transaction {
+    id: String,
+    raw_payload: String,
+    status: Option<TransactionStatus>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum TransactionStatus {
+    Submitted {
+        time: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    Success {
+        block_id: String,
+        time: u64,
+        program_state: Option<ProgramState>,
+        receipts: Vec<Receipt>,
+    },
+    SqueezedOut {
+        reason: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Failed {
+        block_id: String,
+        time: u64,
+        program_state: Option<ProgramState>,
+        receipts: Vec<Receipt>,
+    },
+}
+
+impl From<TxPoolTxStatus> for TransactionStatus {
+    fn from(status: TxPoolTxStatus) -> Self {
+        match status {
+            TxPoolTxStatus::Submitted { time } => Self::Submitted { time: time.0 },
+            TxPoolTxStatus::Success {
+                block_id,
+                time,
+                result,
+                receipts,
+            } => Self::Success {
+                block_id: hex(block_id),
+                time: time.0,
+                program_state: result,
+                receipts,
+            },
+            TxPoolTxStatus::SqueezedOut { reason } => Self::SqueezedOut { reason },
+            TxPoolTxStatus::Failed {
+                block_id,
+                time,
+                result,
+                receipts,
+            } => Self::Failed {
+                block_id: hex(block_id),
+                time: time.0,
+                program_state: result,
+                receipts,
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DryRunStatus {
+    id: String,
+    success: bool,
+    program_state: Option<ProgramState>,
+    receipts: Vec<Receipt>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum Coin {
+    #[serde(rename_all = "camelCase")]
+    Coin {
+        utxo_id: String,
+        owner: String,
+        amount: String,
+        asset_id: String,
+        maturity: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    MessageCoin {
+        nonce: String,
+        sender: String,
+        recipient: String,
+        amount: String,
+        da_height: u64,
+    },
+}
+
+impl From<CoinType> for Coin {
+    fn from(coin: CoinType) -> Self {
+        match coin {
+            CoinType::Coin(coin) => Self::Coin {
+                utxo_id: hex(coin.utxo_id),
+                owner: hex(coin.owner),
+                amount: coin.amount.to_string(),
+                asset_id: hex(coin.asset_id),
+                maturity: coin.maturity.into(),
+            },
+            CoinType::MessageCoin(coin) => Self::MessageCoin {
+                nonce: hex(coin.nonce),
+                sender: hex(coin.sender),
+                recipient: hex(coin.recipient),
+                amount: coin.amount.to_string(),
+                da_height: coin.da_height.0,
+            },
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::graphql_api::QueryLimits;
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextPrepareRequest,
+        NextValidation,
+    },
+    Request,
+    ServerError,
+    ServerResult,
+    ValidationResult,
+};
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use std::sync::{
+    atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    Arc,
+};
+
+/// The maximum number of blocks per request whose transactions the light client
+/// fetches from peers. Each fetch is a request to the network, which costs much
+/// more than the complexity of the field accounts for.
+pub(crate) const MAX_FETCHED_BLOCKS_PER_REQUEST: usize = 10;
+
+/// The number of blocks left whose transactions the request can fetch from peers.
+pub(crate) struct FetchBudget(AtomicUsize);
+
+impl FetchBudget {
+    pub fn new(blocks: usize) -> Self {
+        Self(AtomicUsize::new(blocks))
+    }
+
+    /// Takes one block from the budget. Returns `false` if the budget is exhausted.
+    pub fn take(&self) -> bool {
+        self.0
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |left| {
+                left.checked_sub(1)
+            })
+            .is_ok()
+    }
+}
+
+/// The extension that rejects queries exceeding the depth or the complexity
+/// from the [`QueryLimits`], and reports them to the metrics. It also adds
+/// the [`FetchBudget`] to the request context.
+pub(crate) struct LimitsExtension {
+    limits: QueryLimits,
+}
+
+impl LimitsExtension {
+    pub fn new(limits: QueryLimits) -> Self {
+        Self { limits }
+    }
+}
+
+impl ExtensionFactory for LimitsExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(LimitsExtension::new(self.limits))
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for LimitsExtension {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let request = request.data(FetchBudget::new(MAX_FETCHED_BLOCKS_PER_REQUEST));
+        next.run(ctx, request).await
+    }
+
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> Result<ValidationResult, Vec<ServerError>> {
+        let result = next.run(ctx).await?;
+
+        if result.depth > self.limits.max_depth {
+            graphql_metrics().graphql_request_rejected("depth");
+            return Err(vec![ServerError::new(
+                format!(
+                    "The query depth {} exceeds the limit {}",
+                    result.depth, self.limits.max_depth
+                ),
+                None,
+            )])
+        }
+
+        if result.complexity > self.limits.max_complexity {
+            graphql_metrics().graphql_request_rejected("complexity");
+            return Err(vec![ServerError::new(
+                format!(
+                    "The query complexity {} exceeds the limit {}",
+                    result.complexity, self.limits.max_complexity
+                ),
+                None,
+            )])
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    #[test]
+    fn fetch_budget__take__fails_when_exhausted() {
+        let budget = FetchBudget::new(2);
+
+        assert!(budget.take());
+        assert!(budget.take());
+        assert!(!budget.take());
+    }
+}
@@ -71,7 +71,14 @@ impl Extension for MetricsExtInner {
         variables: &Variables,
         next: NextParseQuery<'_>,
     ) -> ServerResult<ExecutableDocument> {
+        // The query of the persisted query request is empty, and the parsed query
+        // is taken from the cache, so the metrics show the time saved on parsing.
+        let persisted = query.is_empty();
+        let start_time = Instant::now();
         let doc = next.run(ctx, query, variables).await?;
+        graphql_metrics()
+            .graphql_parse_observe(persisted, start_time.elapsed().as_secs_f64());
+
         let set_query_res = self.current_query.set(query.to_string());
         if set_query_res.is_err() {
             tracing::warn!("Failed to save current query {query:?}");
@@ -0,0 +1,154 @@
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextParseQuery,
+        NextPrepareRequest,
+    },
+    parser::types::ExecutableDocument,
+    Request,
+    ServerError,
+    ServerResult,
+    Variables,
+};
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::Bytes32,
+};
+use lru::LruCache;
+use serde::Deserialize;
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        Arc,
+        Mutex,
+        OnceLock,
+    },
+};
+
+/// The name of the request extension with the hash of the persisted query.
+const EXTENSION: &str = "persistedQuery";
+
+/// The error returned if the query with the hash is not in the cache.
+/// The client should retry the request with the query.
+pub(crate) const NOT_FOUND: &str = "PersistedQueryNotFound";
+
+#[derive(Deserialize)]
+struct PersistedQuery {
+    version: u32,
+    #[serde(rename = "sha256Hash")]
+    sha256_hash: String,
+}
+
+type Cache = Arc<Mutex<LruCache<Bytes32, ExecutableDocument>>>;
+
+/// The extension that implements the [automatic persisted queries] protocol.
+/// The client sends the SHA-256 hash of the query instead of the query.
+/// If the hash is unknown, the client repeats the request with the query and the hash,
+/// and the parsed query is cached under the hash.
+/// The requests with the cached hash skip the parsing of the query.
+///
+/// [automatic persisted queries]: https://www.apollographql.com/docs/apollo-server/performance/apq/
+pub(crate) struct PersistedQueriesExtension {
+    cache: Cache,
+}
+
+impl PersistedQueriesExtension {
+    pub fn new(cache_size: NonZeroUsize) -> Self {
+        Self {
+            cache: Arc::new(Mutex::new(LruCache::new(cache_size))),
+        }
+    }
+}
+
+impl ExtensionFactory for PersistedQueriesExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(PersistedQueriesExtInner {
+            cache: self.cache.clone(),
+            cached_document: OnceLock::new(),
+            registered_hash: OnceLock::new(),
+        })
+    }
+}
+
+struct PersistedQueriesExtInner {
+    cache: Cache,
+    /// The parsed query from the cache that replaces the parsing.
+    cached_document: OnceLock<ExecutableDocument>,
+    /// The hash of the query that should be cached after parsing.
+    registered_hash: OnceLock<Bytes32>,
+}
+
+#[async_trait::async_trait]
+impl Extension for PersistedQueriesExtInner {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        mut request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let Some(persisted_query) = request.extensions.remove(EXTENSION) else {
+            return next.run(ctx, request).await
+        };
+        let persisted_query: PersistedQuery = async_graphql::from_value(persisted_query)
+            .map_err(|_| ServerError::new("Invalid `persistedQuery` extension", None))?;
+        if persisted_query.version != 1 {
+            return Err(ServerError::new(
+                format!(
+                    "The version `{}` of the persisted queries is not supported",
+                    persisted_query.version
+                ),
+                None,
+            ))
+        }
+        let hash = persisted_query
+            .sha256_hash
+            .parse::<Bytes32>()
+            .map_err(|_| ServerError::new("Invalid `sha256Hash` of the query", None))?;
+
+        if request.query.is_empty() {
+            let document = self
+                .cache
+                .lock()
+                .expect("The lock is poisoned")
+                .get(&hash)
+                .cloned();
+            let Some(document) = document else {
+                return Err(ServerError::new(NOT_FOUND, None))
+            };
+            let _ = self.cached_document.set(document);
+        } else {
+            if Hasher::hash(request.query.as_bytes()) != hash {
+                return Err(ServerError::new(
+                    "The `sha256Hash` doesn't match the query",
+                    None,
+                ))
+            }
+            let _ = self.registered_hash.set(hash);
+        }
+
+        next.run(ctx, request).await
+    }
+
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        if let Some(document) = self.cached_document.get() {
+            return Ok(document.clone())
+        }
+
+        let document = next.run(ctx, query, variables).await?;
+        if let Some(hash) = self.registered_hash.get() {
+            self.cache
+                .lock()
+                .expect("The lock is poisoned")
+                .put(*hash, document.clone());
+        }
+        Ok(document)
+    }
+}
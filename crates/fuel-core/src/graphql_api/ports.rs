@@ -1,5 +1,15 @@
+use crate::fuel_core_graphql_api::{
+    health::RelayerHeights,
+    storage::{
+        balances::TotalBalanceAmount,
+        transactions::TransactionFlows,
+    },
+};
 use async_trait::async_trait;
-use fuel_core_services::stream::BoxStream;
+use fuel_core_services::{
+    stream::BoxStream,
+    State,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -10,6 +20,7 @@ use fuel_core_storage::{
         ContractsAssets,
         ContractsInfo,
         ContractsRawCode,
+        ContractsState,
         FuelBlocks,
         Messages,
         SealedBlockConsensus,
@@ -23,14 +34,18 @@ use fuel_core_txpool::service::TxStatusMessage;
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
+        header::BlockHeader,
         primitives::{
             BlockId,
             DaBlockHeight,
         },
     },
-    entities::message::{
-        MerkleProof,
-        Message,
+    entities::{
+        contract::SparseMerkleProof,
+        message::{
+            MerkleProof,
+            Message,
+        },
     },
     fuel_tx::{
         Transaction,
@@ -42,13 +57,26 @@ use fuel_core_types::{
         Address,
         AssetId,
         BlockHeight,
+        Bytes32,
         ContractId,
         Nonce,
     },
     services::{
         executor::TransactionExecutionStatus,
-        graphql_api::ContractBalance,
-        p2p::PeerInfo,
+        graphql_api::{
+            ContractBalance,
+            ContractStorageSlot,
+        },
+        p2p::{
+            peer_reputation::{
+                AppScore,
+                PeerBan,
+                PeerReputation,
+            },
+            NetworkReachability,
+            PeerId,
+            PeerInfo,
+        },
         txpool::{
             InsertionResult,
             TransactionStatus,
@@ -81,6 +109,32 @@ pub trait OffChainDatabase: Send + Sync {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Returns the flows of assets of the transaction for the `owner`,
+    /// or `None` if the transaction is not owned by the `owner`.
+    fn owned_transaction_flows(
+        &self,
+        owner: &Address,
+        tx_id: &TxId,
+    ) -> StorageResult<Option<TransactionFlows>>;
+
+    /// Returns the total amount of the `asset_id` coins owned by the `owner`.
+    fn coin_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount>;
+
+    /// Returns the total amount of spendable messages owned by the `owner`.
+    fn message_balance(&self, owner: &Address) -> StorageResult<TotalBalanceAmount>;
+
+    /// Returns the total amounts of coins owned by the `owner` per asset.
+    fn coin_balances(
+        &self,
+        owner: &Address,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, TotalBalanceAmount)>>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -131,6 +185,7 @@ pub trait DatabaseContracts:
     StorageInspect<ContractsRawCode, Error = StorageError>
     + StorageInspect<ContractsInfo, Error = StorageError>
     + StorageInspect<ContractsAssets, Error = StorageError>
+    + StorageInspect<ContractsState, Error = StorageError>
 {
     fn contract_balances(
         &self,
@@ -138,6 +193,29 @@ pub trait DatabaseContracts:
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    /// Returns the storage slots of the `contract` with keys starting with the `prefix`.
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        prefix: Vec<u8>,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractStorageSlot>>;
+
+    /// Returns the inclusion proof of the storage slot against the state root of the `contract`.
+    fn contract_storage_slot_proof(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> StorageResult<Option<SparseMerkleProof>>;
+
+    /// Returns the inclusion proof of the balance against the assets root of the `contract`.
+    fn contract_balance_proof(
+        &self,
+        contract: &ContractId,
+        asset_id: &AssetId,
+    ) -> StorageResult<Option<SparseMerkleProof>>;
 }
 
 /// Trait that specifies all the getters required for chain metadata.
@@ -195,6 +273,53 @@ pub trait DatabaseMessageProof: Send + Sync {
 #[async_trait::async_trait]
 pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
+
+    /// Returns reputations of all known peers, including banned ones.
+    async fn peer_reputations(&self) -> anyhow::Result<Vec<(PeerId, PeerReputation)>>;
+
+    /// Returns the reachability of the node from the network,
+    /// or `None` if the P2P service is not running.
+    async fn reachability(&self) -> anyhow::Result<Option<NetworkReachability>>;
+
+    /// Overrides the application score of the peer.
+    async fn set_peer_app_score(
+        &self,
+        peer_id: PeerId,
+        score: AppScore,
+    ) -> anyhow::Result<()>;
+
+    /// Bans the peer and disconnects from it.
+    async fn ban_peer(&self, peer_id: PeerId, ban: PeerBan) -> anyhow::Result<()>;
+
+    /// Removes the ban of the peer. Returns `false` if the peer was not banned.
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<bool>;
+}
+
+/// Fetches the data of blocks that the light client doesn't store.
+#[async_trait]
+pub trait LightClientPort: Send + Sync {
+    /// Returns transactions of the block verified against its `header`.
+    async fn transactions(
+        &self,
+        header: &BlockHeader,
+    ) -> anyhow::Result<Vec<Transaction>>;
+}
+
+/// Trait for providing the state of the node's components to the health checks.
+#[async_trait]
+pub trait NodeStatusPort: Send + Sync {
+    /// Returns the name and the lifecycle state of each service of the node.
+    fn service_states(&self) -> Vec<(&'static str, State)>;
+
+    /// Returns the height of the latest block. Fails if the database is unreachable.
+    fn latest_height(&self) -> anyhow::Result<BlockHeight>;
+
+    /// Returns the highest block height advertised by the connected peers,
+    /// or `None` if there are no such peers.
+    async fn best_peer_height(&self) -> anyhow::Result<Option<BlockHeight>>;
+
+    /// Returns the DA heights known by the relayer, or `None` if the relayer is disabled.
+    fn relayer_heights(&self) -> Option<RelayerHeights>;
 }
 
 pub mod worker {
@@ -204,8 +329,13 @@ pub mod worker {
             metadata::MetadataTable,
         },
         fuel_core_graphql_api::storage::{
+            balances::{
+                CoinBalances,
+                MessageBalances,
+            },
             coins::OwnedCoins,
             messages::OwnedMessageIds,
+            transactions::OwnedTransactionFlows,
         },
     };
     use fuel_core_services::stream::BoxStream;
@@ -232,6 +362,9 @@ pub mod worker {
         + Sync
         + StorageMutate<OwnedMessageIds, Error = StorageError>
         + StorageMutate<OwnedCoins, Error = StorageError>
+        + StorageMutate<CoinBalances, Error = StorageError>
+        + StorageMutate<MessageBalances, Error = StorageError>
+        + StorageMutate<OwnedTransactionFlows, Error = StorageError>
         + StorageMutate<MetadataTable<OffChain>, Error = StorageError>
         + Transactional<Storage = Self>
     {
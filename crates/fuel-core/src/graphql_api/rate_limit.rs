@@ -0,0 +1,269 @@
+use crate::graphql_api::{
+    auth::Identity,
+    RateLimit,
+};
+use axum::{
+    extract::ConnectInfo,
+    http::{
+        Request,
+        StatusCode,
+    },
+    middleware::Next,
+    response::{
+        IntoResponse,
+        Response,
+    },
+};
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use lru::LruCache;
+use std::{
+    net::{
+        IpAddr,
+        SocketAddr,
+    },
+    num::NonZeroUsize,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Instant,
+};
+
+/// The maximum number of tracked clients. When it is reached,
+/// the least recently seen client is forgotten.
+const MAX_CLIENTS: usize = 100_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Client {
+    Identity(Identity),
+    Ip(IpAddr),
+}
+
+impl Client {
+    /// The client is identified by the credentials verified by the
+    /// [`authenticate`](crate::graphql_api::auth::authenticate) middleware,
+    /// or by the IP address otherwise.
+    fn from_request<B>(request: &Request<B>) -> Option<Self> {
+        if let Some(identity) = request.extensions().get::<Identity>() {
+            return Some(Client::Identity(identity.clone()))
+        }
+
+        request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| Client::Ip(addr.ip()))
+    }
+}
+
+/// Tokens are refilled at `requests_per_sec` per second up to the `burst`.
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Tracks the requests of each client against the [`RateLimit`].
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    clients: Mutex<LruCache<Client, TokenBucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        let max_clients =
+            NonZeroUsize::new(MAX_CLIENTS).expect("The maximum number is not zero");
+        Self {
+            limit,
+            clients: Mutex::new(LruCache::new(max_clients)),
+        }
+    }
+
+    fn refill(&self, bucket: &mut TokenBucket, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(bucket.last_refill)
+            .as_secs_f64();
+        bucket.tokens = f64::from(self.limit.burst)
+            .min(bucket.tokens + elapsed * f64::from(self.limit.requests_per_sec));
+        bucket.last_refill = now;
+    }
+
+    /// Returns `true` if the request of the `client` is allowed.
+    fn check(&self, client: Client, now: Instant) -> bool {
+        self.check_requests(client, 1, now)
+    }
+
+    /// Returns `true` if the `requests` of the `client` are allowed. Either all
+    /// of them are charged, or none if the client doesn't have enough tokens.
+    fn check_requests(&self, client: Client, requests: usize, now: Instant) -> bool {
+        let mut clients = self.clients.lock().expect("The lock is not poisoned");
+
+        let bucket = clients.get_or_insert_mut(client, || TokenBucket {
+            tokens: f64::from(self.limit.burst),
+            last_refill: now,
+        });
+        self.refill(bucket, now);
+        let cost = f64::from(u32::try_from(requests).unwrap_or(u32::MAX));
+        if bucket.tokens >= cost {
+            bucket.tokens -= cost;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// The rate limit of the client that sent the request. The long-lived connections,
+/// like WebSocket, check it for each message received after the upgrade.
+#[derive(Debug, Clone)]
+pub(crate) struct ClientRateLimit {
+    limiter: Arc<RateLimiter>,
+    client: Client,
+}
+
+impl ClientRateLimit {
+    /// Returns `true` if one more request of the client is allowed.
+    pub fn check(&self) -> bool {
+        self.limiter.check(self.client.clone(), Instant::now())
+    }
+
+    /// Returns `true` if the rest of the batch is allowed. The middleware charges
+    /// the HTTP request as one request, so only `batch_size - 1` entries are charged here.
+    pub fn check_batch(&self, batch_size: usize) -> bool {
+        self.limiter.check_requests(
+            self.client.clone(),
+            batch_size.saturating_sub(1),
+            Instant::now(),
+        )
+    }
+}
+
+/// The middleware that responds with `429 Too Many Requests`
+/// when the client exceeds the rate limit. Otherwise, it inserts
+/// the [`ClientRateLimit`] into the extensions of the request.
+pub(crate) async fn rate_limit<B>(
+    limiter: Arc<RateLimiter>,
+    mut request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    if let Some(client) = Client::from_request(&request) {
+        if !limiter.check(client.clone(), Instant::now()) {
+            graphql_metrics().graphql_request_rejected("rate_limit");
+            return (StatusCode::TOO_MANY_REQUESTS, "Too many requests").into_response()
+        }
+        request
+            .extensions_mut()
+            .insert(ClientRateLimit { limiter, client });
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use std::time::Duration;
+
+    fn limiter() -> RateLimiter {
+        RateLimiter::new(RateLimit {
+            requests_per_sec: 1,
+            burst: 2,
+        })
+    }
+
+    #[test]
+    fn check__allows_burst_and_then_rejects() {
+        let limiter = limiter();
+        let client = Client::Identity(Identity::ApiKey("key".to_string()));
+        let now = Instant::now();
+
+        assert!(limiter.check(client.clone(), now));
+        assert!(limiter.check(client.clone(), now));
+        assert!(!limiter.check(client, now));
+    }
+
+    #[test]
+    fn check_requests__charges_all_requests_or_none() {
+        let limiter = RateLimiter::new(RateLimit {
+            requests_per_sec: 1,
+            burst: 3,
+        });
+        let client = Client::Ip([127, 0, 0, 1].into());
+        let now = Instant::now();
+
+        assert!(limiter.check_requests(client.clone(), 2, now));
+        assert!(!limiter.check_requests(client.clone(), 2, now));
+        assert!(limiter.check(client.clone(), now));
+        assert!(!limiter.check(client, now));
+    }
+
+    #[test]
+    fn check__refills_tokens_over_time() {
+        let limiter = limiter();
+        let client = Client::Ip([127, 0, 0, 1].into());
+        let now = Instant::now();
+        assert!(limiter.check(client.clone(), now));
+        assert!(limiter.check(client.clone(), now));
+
+        let later = now + Duration::from_secs(1);
+
+        assert!(limiter.check(client.clone(), later));
+        assert!(!limiter.check(client, later));
+    }
+
+    #[test]
+    fn check__tracks_clients_separately() {
+        let limiter = limiter();
+        let now = Instant::now();
+        let first = Client::Identity(Identity::ApiKey("first".to_string()));
+        let second = Client::Identity(Identity::ApiKey("second".to_string()));
+        assert!(limiter.check(first.clone(), now));
+        assert!(limiter.check(first.clone(), now));
+
+        assert!(!limiter.check(first, now));
+        assert!(limiter.check(second, now));
+    }
+
+    #[test]
+    fn check__forgets_least_recently_seen_client_when_full() {
+        let limiter = RateLimiter {
+            limit: RateLimit {
+                requests_per_sec: 1,
+                burst: 1,
+            },
+            clients: Mutex::new(LruCache::new(NonZeroUsize::new(2).unwrap())),
+        };
+        let now = Instant::now();
+        let first = Client::Ip([127, 0, 0, 1].into());
+        let second = Client::Ip([127, 0, 0, 2].into());
+        let third = Client::Ip([127, 0, 0, 3].into());
+        assert!(limiter.check(first.clone(), now));
+        assert!(limiter.check(second.clone(), now));
+
+        assert!(limiter.check(third, now));
+        assert!(!limiter.check(second, now));
+        assert!(limiter.check(first, now));
+    }
+
+    #[test]
+    fn from_request__prefers_authenticated_identity_over_ip() {
+        let mut request = Request::builder().body(()).unwrap();
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 4000))));
+        assert_eq!(
+            Client::from_request(&request),
+            Some(Client::Ip([127, 0, 0, 1].into()))
+        );
+
+        request
+            .extensions_mut()
+            .insert(Identity::ApiKey("key".to_string()));
+        assert_eq!(
+            Client::from_request(&request),
+            Some(Client::Identity(Identity::ApiKey("key".to_string())))
+        );
+    }
+}
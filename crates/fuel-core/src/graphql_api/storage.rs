@@ -1,5 +1,6 @@
 use fuel_core_storage::kv_store::StorageColumn;
 
+pub mod balances;
 pub mod coins;
 pub mod messages;
 pub mod transactions;
@@ -30,6 +31,14 @@ pub enum Column {
     OwnedMessageIds = 4,
     /// The column of the table that stores statistic about the blockchain.
     Statistic = 5,
+    /// The column of the table that stores reputations of peers.
+    PeerReputations = 6,
+    /// The column of the table that stores the total amount of coins per `owner` and `asset_id`.
+    CoinBalances = 7,
+    /// The column of the table that stores the total amount of spendable messages per `owner`.
+    MessageBalances = 8,
+    /// The column of the table that stores the asset flows of the transaction per owner.
+    TransactionFlowsByOwner = 9,
 }
 
 impl Column {
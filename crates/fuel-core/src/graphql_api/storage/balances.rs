@@ -0,0 +1,109 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        manual::Manual,
+        postcard::Postcard,
+        raw::Raw,
+        Decode,
+        Encode,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::fuel_types::{
+    Address,
+    AssetId,
+};
+use rand::{
+    distributions::{
+        Distribution,
+        Standard,
+    },
+    Rng,
+};
+use std::borrow::Cow;
+
+/// The total amount of the asset owned by the address.
+/// It is wider than `u64` because the sum of coins may exceed the limit of one coin.
+pub type TotalBalanceAmount = u128;
+
+fuel_core_types::fuel_vm::double_key!(CoinBalancesKey, Address, owner, AssetId, asset_id);
+
+impl Distribution<CoinBalancesKey> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CoinBalancesKey {
+        let mut bytes = [0u8; CoinBalancesKey::LEN];
+
+        rng.fill_bytes(bytes.as_mut());
+
+        CoinBalancesKey::from_array(bytes)
+    }
+}
+
+/// The table that stores the total amount of unspent coins per owner and asset.
+pub struct CoinBalances;
+
+impl Mappable for CoinBalances {
+    type Key = CoinBalancesKey;
+    type OwnedKey = Self::Key;
+    type Value = TotalBalanceAmount;
+    type OwnedValue = Self::Value;
+}
+
+impl Encode<CoinBalancesKey> for Manual<CoinBalancesKey> {
+    type Encoder<'a> = Cow<'a, [u8]>;
+
+    fn encode(t: &CoinBalancesKey) -> Self::Encoder<'_> {
+        Cow::Borrowed(t.as_ref())
+    }
+}
+
+impl Decode<CoinBalancesKey> for Manual<CoinBalancesKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<CoinBalancesKey> {
+        CoinBalancesKey::from_slice(bytes)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))
+    }
+}
+
+impl TableWithBlueprint for CoinBalances {
+    type Blueprint = Plain<Manual<CoinBalancesKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::CoinBalances
+    }
+}
+
+/// The table that stores the total amount of unspent messages per owner.
+/// Only messages without data are counted, because only they are spendable as coins
+/// of the base asset.
+pub struct MessageBalances;
+
+impl Mappable for MessageBalances {
+    type Key = Address;
+    type OwnedKey = Self::Key;
+    type Value = TotalBalanceAmount;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for MessageBalances {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::MessageBalances
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    CoinBalances,
+    <CoinBalances as Mappable>::Key::default(),
+    <CoinBalances as Mappable>::Value::default()
+);
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    MessageBalances,
+    <MessageBalances as Mappable>::Key::default(),
+    <MessageBalances as Mappable>::Value::default()
+);
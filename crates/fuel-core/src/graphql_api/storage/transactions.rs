@@ -1,3 +1,4 @@
+use crate::fuel_core_graphql_api::storage::balances::TotalBalanceAmount;
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
@@ -13,13 +14,22 @@ use fuel_core_storage::{
 use fuel_core_types::{
     fuel_tx::{
         Address,
+        AssetId,
         Bytes32,
     },
     fuel_types::BlockHeight,
     services::txpool::TransactionStatus,
 };
+use rand::{
+    distributions::{
+        Distribution,
+        Standard,
+    },
+    Rng,
+};
 use std::{
     array::TryFromSliceError,
+    borrow::Cow,
     mem::size_of,
 };
 
@@ -61,6 +71,58 @@ impl TableWithBlueprint for TransactionStatuses {
     }
 }
 
+fuel_core_types::fuel_vm::double_key!(
+    OwnedTransactionFlowsKey,
+    Address,
+    owner,
+    Bytes32,
+    tx_id
+);
+
+impl Distribution<OwnedTransactionFlowsKey> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> OwnedTransactionFlowsKey {
+        let mut bytes = [0u8; OwnedTransactionFlowsKey::LEN];
+
+        rng.fill_bytes(bytes.as_mut());
+
+        OwnedTransactionFlowsKey::from_array(bytes)
+    }
+}
+
+/// The table stores the flows of assets of the transaction for each of its owners.
+pub struct OwnedTransactionFlows;
+
+impl Mappable for OwnedTransactionFlows {
+    type Key = OwnedTransactionFlowsKey;
+    type OwnedKey = Self::Key;
+    type Value = TransactionFlows;
+    type OwnedValue = Self::Value;
+}
+
+impl Encode<OwnedTransactionFlowsKey> for Manual<OwnedTransactionFlowsKey> {
+    type Encoder<'a> = Cow<'a, [u8]>;
+
+    fn encode(t: &OwnedTransactionFlowsKey) -> Self::Encoder<'_> {
+        Cow::Borrowed(t.as_ref())
+    }
+}
+
+impl Decode<OwnedTransactionFlowsKey> for Manual<OwnedTransactionFlowsKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<OwnedTransactionFlowsKey> {
+        OwnedTransactionFlowsKey::from_slice(bytes)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))
+    }
+}
+
+impl TableWithBlueprint for OwnedTransactionFlows {
+    type Blueprint = Plain<Manual<OwnedTransactionFlowsKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TransactionFlowsByOwner
+    }
+}
+
 const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
 const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
 const INDEX_SIZE: usize = Address::LEN + BLOCK_HEIGHT + TX_INDEX_SIZE;
@@ -184,6 +246,32 @@ impl From<OwnedTransactionIndexCursor> for Vec<u8> {
     }
 }
 
+/// The flows of assets of the transaction for one of its owners.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TransactionFlows {
+    /// `true` if the owner spent at least one input of the transaction.
+    pub is_sender: bool,
+    /// The spent and received amounts per asset, sorted by the asset id.
+    pub assets: Vec<AssetFlow>,
+}
+
+/// The amounts of the asset spent and received by the owner in the transaction.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AssetFlow {
+    pub asset_id: AssetId,
+    pub spent: TotalBalanceAmount,
+    pub received: TotalBalanceAmount,
+}
+
+impl AssetFlow {
+    /// Returns the net change of the owner's balance, saturated to the `i128` range.
+    pub fn delta(&self) -> i128 {
+        let received = i128::try_from(self.received).unwrap_or(i128::MAX);
+        let spent = i128::try_from(self.spent).unwrap_or(i128::MAX);
+        received.saturating_sub(spent)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -209,4 +297,17 @@ mod test {
             time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
         }
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        OwnedTransactionFlows,
+        <OwnedTransactionFlows as Mappable>::Key::default(),
+        TransactionFlows {
+            is_sender: true,
+            assets: vec![AssetFlow {
+                asset_id: AssetId::default(),
+                spent: 10,
+                received: 3,
+            }],
+        }
+    );
 }
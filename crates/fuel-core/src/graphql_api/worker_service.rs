@@ -10,6 +10,12 @@ use crate::{
     fuel_core_graphql_api::{
         ports,
         storage::{
+            balances::{
+                CoinBalances,
+                CoinBalancesKey,
+                MessageBalances,
+                TotalBalanceAmount,
+            },
             coins::{
                 owner_coin_id_key,
                 OwnedCoins,
@@ -18,6 +24,12 @@ use crate::{
                 OwnedMessageIds,
                 OwnedMessageKey,
             },
+            transactions::{
+                AssetFlow,
+                OwnedTransactionFlows,
+                OwnedTransactionFlowsKey,
+                TransactionFlows,
+            },
         },
     },
 };
@@ -31,23 +43,35 @@ use fuel_core_services::{
     StateWatcher,
 };
 use fuel_core_storage::{
+    Error as StorageError,
+    Mappable,
     Result as StorageResult,
     StorageAsMut,
+    StorageMutate,
 };
 use fuel_core_types::{
-    blockchain::block::Block,
+    entities::coins::coin::Coin,
     fuel_tx::{
         field::{
             Inputs,
             Outputs,
         },
-        input::coin::{
-            CoinPredicate,
-            CoinSigned,
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+            },
         },
+        Address,
+        AssetId,
         Input,
         Output,
         Transaction,
+        TxId,
         UniqueIdentifier,
     },
     fuel_types::{
@@ -72,6 +96,10 @@ use futures::{
 };
 use std::{
     borrow::Cow,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
     ops::Deref,
 };
 
@@ -80,6 +108,8 @@ use std::{
 pub struct Task<D> {
     block_importer: BoxStream<SharedImportResult>,
     database: D,
+    /// The asset of the coins bridged from the DA layer by messages.
+    base_asset_id: AssetId,
 }
 
 impl<D> Task<D>
@@ -95,7 +125,7 @@ where
         self.persist_transaction_status(&result, transaction.as_mut())?;
 
         // save the associated owner for each transaction in the block
-        self.index_tx_owners_for_block(block, transaction.as_mut())?;
+        self.index_tx_owners_for_block(&result, transaction.as_mut())?;
         let total_tx_count = transaction
             .as_mut()
             .increase_tx_count(block.transactions().len() as u64)
@@ -128,7 +158,7 @@ where
         Ok(())
     }
 
-    /// Process the executor events and update the indexes for the messages and coins.
+    /// Process the executor events and update the indexes for the messages, coins and balances.
     pub fn process_executor_events<'a, Iter>(
         events: Iter,
         block_st_transaction: &mut D,
@@ -145,26 +175,54 @@ where
                             &OwnedMessageKey::new(message.recipient(), message.nonce()),
                             &(),
                         )?;
+                    if message.data().is_empty() {
+                        update_balance::<MessageBalances, _>(
+                            block_st_transaction,
+                            message.recipient(),
+                            |balance| balance.checked_add(message.amount().into()),
+                        )?;
+                    }
                 }
                 Event::MessageConsumed(message) => {
-                    block_st_transaction
+                    let removed = block_st_transaction
                         .storage_as_mut::<OwnedMessageIds>()
                         .remove(&OwnedMessageKey::new(
                             message.recipient(),
                             message.nonce(),
                         ))?;
+                    // The balance includes only indexed messages.
+                    if removed.is_some() && message.data().is_empty() {
+                        update_balance::<MessageBalances, _>(
+                            block_st_transaction,
+                            message.recipient(),
+                            |balance| balance.checked_sub(message.amount().into()),
+                        )?;
+                    }
                 }
                 Event::CoinCreated(coin) => {
                     let coin_by_owner = owner_coin_id_key(&coin.owner, &coin.utxo_id);
                     block_st_transaction
                         .storage_as_mut::<OwnedCoins>()
                         .insert(&coin_by_owner, &())?;
+                    update_balance::<CoinBalances, _>(
+                        block_st_transaction,
+                        &CoinBalancesKey::new(&coin.owner, &coin.asset_id),
+                        |balance| balance.checked_add(coin.amount.into()),
+                    )?;
                 }
                 Event::CoinConsumed(coin) => {
                     let key = owner_coin_id_key(&coin.owner, &coin.utxo_id);
-                    block_st_transaction
+                    let removed = block_st_transaction
                         .storage_as_mut::<OwnedCoins>()
                         .remove(&key)?;
+                    // The balance includes only indexed coins.
+                    if removed.is_some() {
+                        update_balance::<CoinBalances, _>(
+                            block_st_transaction,
+                            &CoinBalancesKey::new(&coin.owner, &coin.asset_id),
+                            |balance| balance.checked_sub(coin.amount.into()),
+                        )?;
+                    }
                 }
             }
         }
@@ -172,11 +230,25 @@ where
     }
 
     /// Associate all transactions within a block to their respective UTXO owners
+    /// and record the flows of assets of each transaction per owner.
     fn index_tx_owners_for_block(
         &self,
-        block: &Block,
+        import_result: &ImportResult,
         block_st_transaction: &mut D,
     ) -> anyhow::Result<()> {
+        let block = &import_result.sealed_block.entity;
+        // The received amounts are taken from the executor events instead of the outputs,
+        // because the failed transactions don't create coins from all of their outputs.
+        let mut created_coins = HashMap::<TxId, Vec<&Coin>>::new();
+        for event in import_result.events.iter() {
+            if let Event::CoinCreated(coin) = event {
+                created_coins
+                    .entry(*coin.utxo_id.tx_id())
+                    .or_default()
+                    .push(coin);
+            }
+        }
+
         for (tx_idx, tx) in block.transactions().iter().enumerate() {
             let block_height = *block.header().height();
             let inputs;
@@ -202,6 +274,7 @@ where
                 block_height,
                 inputs,
                 outputs,
+                created_coins.remove(&tx_id).unwrap_or_default().as_slice(),
                 &tx_id,
                 tx_idx,
                 block_st_transaction,
@@ -210,42 +283,24 @@ where
         Ok(())
     }
 
-    /// Index the tx id by owner for all of the inputs and outputs
+    /// Index the tx id and the asset flows by owner for all of the inputs and outputs
+    #[allow(clippy::too_many_arguments)]
     fn persist_owners_index(
         &self,
         block_height: BlockHeight,
         inputs: &[Input],
         outputs: &[Output],
+        created_coins: &[&Coin],
         tx_id: &Bytes32,
         tx_idx: u16,
         db: &mut D,
     ) -> StorageResult<()> {
-        let mut owners = vec![];
-        for input in inputs {
-            if let Input::CoinSigned(CoinSigned { owner, .. })
-            | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
-            {
-                owners.push(owner);
-            }
-        }
-
-        for output in outputs {
-            match output {
-                Output::Coin { to, .. }
-                | Output::Change { to, .. }
-                | Output::Variable { to, .. } => {
-                    owners.push(to);
-                }
-                Output::Contract(_) | Output::ContractCreated { .. } => {}
-            }
-        }
-
-        // dedupe owners from inputs and outputs prior to indexing
-        owners.sort();
-        owners.dedup();
-
-        for owner in owners {
+        for (owner, flows) in
+            owners_flows(inputs, outputs, created_coins, &self.base_asset_id)
+        {
             db.record_tx_id_owner(owner, block_height, tx_idx, tx_id)?;
+            db.storage_as_mut::<OwnedTransactionFlows>()
+                .insert(&OwnedTransactionFlowsKey::new(owner, tx_id), &flows)?;
         }
 
         Ok(())
@@ -274,6 +329,141 @@ where
     }
 }
 
+/// Returns the owners of the inputs and outputs of the transaction
+/// with the flows of assets of each owner.
+pub(crate) fn owners_flows<'a>(
+    inputs: &'a [Input],
+    outputs: &'a [Output],
+    created_coins: &[&Coin],
+    base_asset_id: &'a AssetId,
+) -> Vec<(&'a Address, TransactionFlows)> {
+    let mut owners = vec![];
+    let mut spent = vec![];
+    for input in inputs {
+        match input {
+            Input::CoinSigned(CoinSigned {
+                owner,
+                asset_id,
+                amount,
+                ..
+            })
+            | Input::CoinPredicate(CoinPredicate {
+                owner,
+                asset_id,
+                amount,
+                ..
+            }) => {
+                owners.push(owner);
+                spent.push((owner, asset_id, *amount));
+            }
+            Input::MessageCoinSigned(MessageCoinSigned {
+                recipient, amount, ..
+            })
+            | Input::MessageCoinPredicate(MessageCoinPredicate {
+                recipient,
+                amount,
+                ..
+            }) => {
+                owners.push(recipient);
+                spent.push((recipient, base_asset_id, *amount));
+            }
+            Input::Contract(_)
+            | Input::MessageDataSigned(_)
+            | Input::MessageDataPredicate(_) => {}
+        }
+    }
+
+    for output in outputs {
+        match output {
+            Output::Coin { to, .. }
+            | Output::Change { to, .. }
+            | Output::Variable { to, .. } => {
+                owners.push(to);
+            }
+            Output::Contract(_) | Output::ContractCreated { .. } => {}
+        }
+    }
+
+    // dedupe owners from inputs and outputs prior to indexing
+    owners.sort();
+    owners.dedup();
+
+    owners
+        .into_iter()
+        .map(|owner| (owner, transaction_flows(owner, &spent, created_coins)))
+        .collect()
+}
+
+/// Sums up the amounts of each asset spent and received by the `owner` in the transaction.
+fn transaction_flows(
+    owner: &Address,
+    spent: &[(&Address, &AssetId, u64)],
+    created_coins: &[&Coin],
+) -> TransactionFlows {
+    let mut is_sender = false;
+    let mut assets = BTreeMap::<AssetId, AssetFlow>::new();
+    fn flow<'a>(
+        assets: &'a mut BTreeMap<AssetId, AssetFlow>,
+        asset_id: &AssetId,
+    ) -> &'a mut AssetFlow {
+        assets.entry(*asset_id).or_insert_with(|| AssetFlow {
+            asset_id: *asset_id,
+            ..Default::default()
+        })
+    }
+
+    for (_, asset_id, amount) in spent.iter().filter(|(from, ..)| *from == owner) {
+        is_sender = true;
+        let flow = flow(&mut assets, asset_id);
+        flow.spent = flow.spent.saturating_add((*amount).into());
+    }
+    for coin in created_coins.iter().filter(|coin| &coin.owner == owner) {
+        let flow = flow(&mut assets, &coin.asset_id);
+        flow.received = flow.received.saturating_add(coin.amount.into());
+    }
+
+    TransactionFlows {
+        is_sender,
+        assets: assets
+            .into_values()
+            .filter(|flow| flow.spent != 0 || flow.received != 0)
+            .collect(),
+    }
+}
+
+/// Applies the `update` to the balance stored under the `key`.
+/// The entry is removed when the balance drops to zero.
+///
+/// The `update` returns `None` if the balance overflows or underflows. It can't happen
+/// for the consistent index, because only the indexed coins and messages are subtracted.
+fn update_balance<M, S>(
+    storage: &mut S,
+    key: &M::Key,
+    update: impl FnOnce(TotalBalanceAmount) -> Option<TotalBalanceAmount>,
+) -> anyhow::Result<()>
+where
+    M: Mappable<Value = TotalBalanceAmount, OwnedValue = TotalBalanceAmount>,
+    S: StorageMutate<M, Error = StorageError>,
+{
+    let current = storage
+        .storage::<M>()
+        .get(key)?
+        .map(|balance| balance.into_owned())
+        .unwrap_or_default();
+    let new = update(current).ok_or_else(|| {
+        anyhow::anyhow!(
+            "The balance {current} overflowed or underflowed after the update"
+        )
+    })?;
+
+    if new == 0 {
+        storage.storage_as_mut::<M>().remove(key)?;
+    } else {
+        storage.storage_as_mut::<M>().insert(key, &new)?;
+    }
+    Ok(())
+}
+
 #[async_trait::async_trait]
 impl<D> RunnableService for Task<D>
 where
@@ -349,7 +539,11 @@ where
     }
 }
 
-pub fn new_service<I, D>(block_importer: I, database: D) -> ServiceRunner<Task<D>>
+pub fn new_service<I, D>(
+    block_importer: I,
+    database: D,
+    base_asset_id: AssetId,
+) -> ServiceRunner<Task<D>>
 where
     I: ports::worker::BlockImporter,
     D: ports::worker::OffChainDatabase,
@@ -358,5 +552,6 @@ where
     ServiceRunner::new(Task {
         block_importer,
         database,
+        base_asset_id,
     })
 }
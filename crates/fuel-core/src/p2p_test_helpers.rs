@@ -96,6 +96,8 @@ pub struct ValidatorSetup {
     pub utxo_validation: bool,
     /// Indicates the type of initial connections.
     pub bootstrap_type: BootstrapType,
+    /// Run the validator as the light client that syncs only headers.
+    pub light_client: bool,
 }
 
 #[derive(Clone)]
@@ -340,10 +342,16 @@ pub async fn make_nodes(
             pub_key,
             utxo_validation,
             bootstrap_type,
+            light_client,
             ..
         }) = s
         {
             node_config.utxo_validation = utxo_validation;
+            if light_client {
+                node_config.light_client = Some(Default::default());
+                // Requested to check that the light client doesn't serve it.
+                node_config.json_rpc = true;
+            }
 
             match bootstrap_type {
                 BootstrapType::BootstrapNodes => {
@@ -564,6 +572,7 @@ impl ValidatorSetup {
             name: Default::default(),
             utxo_validation: true,
             bootstrap_type: BootstrapType::BootstrapNodes,
+            light_client: false,
         }
     }
 
@@ -587,6 +596,13 @@ impl ValidatorSetup {
             ..self
         }
     }
+
+    pub fn light_client(self, light_client: bool) -> Self {
+        Self {
+            light_client,
+            ..self
+        }
+    }
 }
 impl BootstrapSetup {
     pub fn new(pub_key: Address) -> Self {
@@ -1,8 +1,7 @@
-use crate::fuel_core_graphql_api::database::ReadView;
-use asset_query::{
-    AssetQuery,
-    AssetSpendTarget,
-    AssetsQuery,
+use crate::fuel_core_graphql_api::{
+    database::ReadView,
+    ports::OffChainDatabase,
+    storage::balances::TotalBalanceAmount,
 };
 use fuel_core_storage::{
     iter::{
@@ -19,11 +18,11 @@ use fuel_core_types::{
     },
     services::graphql_api::AddressBalance,
 };
-use itertools::Itertools;
-use std::{
-    cmp::Ordering,
-    collections::HashMap,
+use itertools::{
+    EitherOrBoth,
+    Itertools,
 };
+use std::cmp::Ordering;
 
 pub mod asset_query;
 
@@ -38,6 +37,7 @@ pub trait BalanceQueryData: Send + Sync {
     fn balances(
         &self,
         owner: Address,
+        start_asset: Option<AssetId>,
         direction: IterDirection,
         base_asset_id: AssetId,
     ) -> BoxedIter<StorageResult<AddressBalance>>;
@@ -50,27 +50,15 @@ impl BalanceQueryData for ReadView {
         asset_id: AssetId,
         base_asset_id: AssetId,
     ) -> StorageResult<AddressBalance> {
-        let amount = AssetQuery::new(
-            &owner,
-            &AssetSpendTarget::new(asset_id, u64::MAX, usize::MAX),
-            &base_asset_id,
-            None,
-            self,
-        )
-        .coins()
-        .map(|res| res.map(|coins| coins.amount()))
-        .try_fold(0u64, |mut balance, res| -> StorageResult<_> {
-            let amount = res?;
+        let mut amount = self.coin_balance(&owner, &asset_id)?;
 
-            // Increase the balance
-            balance = balance.saturating_add(amount);
-
-            Ok(balance)
-        })?;
+        if asset_id == base_asset_id {
+            amount = amount.saturating_add(self.message_balance(&owner)?);
+        }
 
         Ok(AddressBalance {
             owner,
-            amount,
+            amount: saturate_to_u64(amount),
             asset_id,
         })
     }
@@ -78,51 +66,56 @@ impl BalanceQueryData for ReadView {
     fn balances(
         &self,
         owner: Address,
+        start_asset: Option<AssetId>,
         direction: IterDirection,
         base_asset_id: AssetId,
     ) -> BoxedIter<StorageResult<AddressBalance>> {
-        let mut amounts_per_asset = HashMap::new();
-        let mut errors = vec![];
+        let order = move |asset_id: &AssetId, other: &AssetId| match direction {
+            IterDirection::Forward => asset_id.cmp(other),
+            IterDirection::Reverse => other.cmp(asset_id),
+        };
 
-        for coin in AssetsQuery::new(&owner, None, None, self, &base_asset_id).coins() {
-            match coin {
-                Ok(coin) => {
-                    let amount: &mut u64 = amounts_per_asset
-                        .entry(*coin.asset_id(&base_asset_id))
-                        .or_default();
-                    *amount = amount.saturating_add(coin.amount());
-                }
-                Err(err) => {
-                    errors.push(err);
-                }
+        // Messages are the base asset, so they are merged into the coins
+        // if the base asset is inside the requested range.
+        let base_asset_in_range = start_asset
+            .map(|start| order(&start, &base_asset_id) != Ordering::Greater)
+            .unwrap_or(true);
+        let messages = if base_asset_in_range {
+            match self.message_balance(&owner) {
+                Ok(amount) => Some(amount).filter(|amount| *amount > 0),
+                Err(err) => return core::iter::once(Err(err)).into_boxed(),
             }
-        }
+        } else {
+            None
+        };
 
-        let mut balances = amounts_per_asset
-            .into_iter()
-            .map(|(asset_id, amount)| AddressBalance {
-                owner,
-                amount,
-                asset_id,
+        self.coin_balances(&owner, start_asset, direction)
+            .merge_join_by(messages, move |coins, _| match coins {
+                Ok((asset_id, _)) => order(asset_id, &base_asset_id),
+                Err(_) => Ordering::Less,
             })
-            .collect_vec();
-
-        balances.sort_by(|l, r| {
-            if l.asset_id < r.asset_id {
-                Ordering::Less
-            } else {
-                Ordering::Greater
-            }
-        });
+            .map(move |entry| {
+                let (asset_id, amount) = match entry {
+                    EitherOrBoth::Left(coins) => coins?,
+                    EitherOrBoth::Right(messages) => (base_asset_id, messages),
+                    EitherOrBoth::Both(coins, messages) => {
+                        let (asset_id, amount) = coins?;
+                        (asset_id, amount.saturating_add(messages))
+                    }
+                };
 
-        if direction == IterDirection::Reverse {
-            balances.reverse();
-        }
-
-        balances
-            .into_iter()
-            .map(Ok)
-            .chain(errors.into_iter().map(Err))
+                Ok(AddressBalance {
+                    owner,
+                    amount: saturate_to_u64(amount),
+                    asset_id,
+                })
+            })
             .into_boxed()
     }
 }
+
+/// The GraphQL API represents the balance as `u64`,
+/// so the total amount above `u64::MAX` is reported as `u64::MAX`.
+fn saturate_to_u64(amount: TotalBalanceAmount) -> u64 {
+    u64::try_from(amount).unwrap_or(u64::MAX)
+}
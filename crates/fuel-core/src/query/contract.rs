@@ -9,17 +9,23 @@ use fuel_core_storage::{
         ContractsAssets,
         ContractsInfo,
         ContractsRawCode,
+        ContractsState,
     },
     Result as StorageResult,
     StorageAsRef,
 };
 use fuel_core_types::{
+    entities::contract::SparseMerkleProof,
     fuel_types::{
         AssetId,
+        Bytes32,
         ContractId,
     },
     fuel_vm::Salt,
-    services::graphql_api::ContractBalance,
+    services::graphql_api::{
+        ContractBalance,
+        ContractStorageSlot,
+    },
 };
 
 pub trait ContractQueryData: Send + Sync {
@@ -41,6 +47,32 @@ pub trait ContractQueryData: Send + Sync {
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_storage_slot(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<ContractStorageSlot>;
+
+    fn contract_storage_slots(
+        &self,
+        contract_id: ContractId,
+        prefix: Vec<u8>,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractStorageSlot>>;
+
+    fn contract_storage_slot_proof(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<SparseMerkleProof>;
+
+    fn contract_balance_proof(
+        &self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+    ) -> StorageResult<SparseMerkleProof>;
 }
 
 impl<D: OnChainDatabase + ?Sized> ContractQueryData for D {
@@ -99,4 +131,49 @@ impl<D: OnChainDatabase + ?Sized> ContractQueryData for D {
     ) -> BoxedIter<StorageResult<ContractBalance>> {
         self.contract_balances(contract_id, start_asset, direction)
     }
+    fn contract_storage_slot(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<ContractStorageSlot> {
+        let value = self
+            .storage::<ContractsState>()
+            .get(&(&contract_id, &key).into())?
+            .ok_or(not_found!(ContractsState))?
+            .into_owned();
+
+        Ok(ContractStorageSlot {
+            contract: contract_id,
+            key,
+            value,
+        })
+    }
+
+    fn contract_storage_slots(
+        &self,
+        contract_id: ContractId,
+        prefix: Vec<u8>,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractStorageSlot>> {
+        self.contract_storage_slots(contract_id, prefix, start_key, direction)
+    }
+
+    fn contract_storage_slot_proof(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<SparseMerkleProof> {
+        self.contract_storage_slot_proof(&contract_id, &key)?
+            .ok_or(not_found!(ContractsState))
+    }
+
+    fn contract_balance_proof(
+        &self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+    ) -> StorageResult<SparseMerkleProof> {
+        self.contract_balance_proof(&contract_id, &asset_id)?
+            .ok_or(not_found!(ContractsAssets))
+    }
 }
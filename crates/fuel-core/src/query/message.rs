@@ -32,7 +32,9 @@ use fuel_core_types::{
         MerkleProof,
         Message,
         MessageProof,
+        MessageReceiptProof,
         MessageStatus,
+        TransactionProof,
     },
     fuel_merkle::binary::in_memory::MerkleTree,
     fuel_tx::{
@@ -41,6 +43,7 @@ use fuel_core_types::{
         TxId,
     },
     fuel_types::{
+        canonical::Serialize,
         Address,
         BlockHeight,
         Bytes32,
@@ -232,6 +235,101 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     }))
 }
 
+/// Generate a proof that the transaction is included in the block
+/// against the `transactions_root` of the block.
+pub fn transaction_proof<T: MessageProofData + ?Sized>(
+    database: &T,
+    transaction_id: Bytes32,
+) -> StorageResult<Option<TransactionProof>> {
+    // Get the block id from the transaction status if it's included into the block.
+    let block_id = match database
+        .transaction_status(&transaction_id)
+        .into_api_result::<TransactionStatus, StorageError>()?
+    {
+        Some(TransactionStatus::Success { block_id, .. })
+        | Some(TransactionStatus::Failed { block_id, .. }) => block_id,
+        _ => return Ok(None),
+    };
+
+    let (block_header, block_txs) = match database
+        .block_by_id(&block_id)
+        .into_api_result::<CompressedBlock, StorageError>(
+    )? {
+        Some(t) => t.into_inner(),
+        None => return Ok(None),
+    };
+
+    let Some(proof_index) = block_txs.iter().position(|id| id == &transaction_id) else {
+        return Ok(None)
+    };
+
+    // The leaves of the transactions tree are the serialized transactions.
+    let mut tree = MerkleTree::new();
+    for id in &block_txs {
+        let transaction = database.transaction(id)?;
+        tree.push(transaction.to_bytes().as_ref());
+    }
+
+    let proof_index = proof_index as u64;
+    match tree.prove(proof_index) {
+        Some((_, proof_set)) => Ok(Some(TransactionProof {
+            transaction_proof: MerkleProof {
+                proof_set,
+                proof_index,
+            },
+            block_header,
+        })),
+        None => Ok(None),
+    }
+}
+
+/// Generate a proof that the `MessageOut` receipt with the `nonce` was produced
+/// by the transaction against the `message_receipt_root` of the block.
+pub fn message_receipt_proof<T: MessageProofData + ?Sized>(
+    database: &T,
+    transaction_id: Bytes32,
+    nonce: Nonce,
+) -> StorageResult<Option<MessageReceiptProof>> {
+    let message_id = database
+        .receipts(&transaction_id)
+        .into_api_result::<Vec<Receipt>, StorageError>()?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|r| r.nonce() == Some(&nonce))
+        .find_map(|r| r.message_id());
+
+    let Some(message_id) = message_id else {
+        return Ok(None)
+    };
+
+    let block_id = match database
+        .transaction_status(&transaction_id)
+        .into_api_result::<TransactionStatus, StorageError>()?
+    {
+        Some(TransactionStatus::Success { block_id, .. }) => block_id,
+        _ => return Ok(None),
+    };
+
+    let (block_header, block_txs) = match database
+        .block_by_id(&block_id)
+        .into_api_result::<CompressedBlock, StorageError>(
+    )? {
+        Some(t) => t.into_inner(),
+        None => return Ok(None),
+    };
+
+    let message_proof = match message_receipts_proof(database, message_id, &block_txs)? {
+        Some(proof) => proof,
+        None => return Ok(None),
+    };
+
+    Ok(Some(MessageReceiptProof {
+        message_id,
+        message_proof,
+        block_header,
+    }))
+}
+
 fn message_receipts_proof<T: MessageProofData + ?Sized>(
     database: &T,
     message_id: MessageId,
@@ -35,6 +35,20 @@ pub(crate) fn transaction_status_change<'a, State>(
     stream: BoxStream<'a, TxStatusMessage>,
     transaction_id: Bytes32,
 ) -> impl Stream<Item = anyhow::Result<ApiTxStatus>> + 'a
+where
+    State: TxnStatusChangeState + Send + Sync + 'a,
+{
+    transaction_status_updates(state, stream, transaction_id)
+        .map(move |status| status.map(|status| ApiTxStatus::new(transaction_id, status)))
+}
+
+/// Returns the current status of the transaction followed by its updates.
+/// The stream ends after the first status other than `Submitted`.
+pub(crate) fn transaction_status_updates<'a, State>(
+    state: State,
+    stream: BoxStream<'a, TxStatusMessage>,
+    transaction_id: Bytes32,
+) -> impl Stream<Item = anyhow::Result<TxPoolTxStatus>> + 'a
 where
     State: TxnStatusChangeState + Send + Sync + 'a,
 {
@@ -68,10 +82,7 @@ where
             }
 
             match status {
-                TxStatusMessage::Status(status) => {
-                    let status = ApiTxStatus::new(transaction_id, status);
-                    Ok(status)
-                },
+                TxStatusMessage::Status(status) => Ok(status),
                 // Map a failed status to an error for the api.
                 TxStatusMessage::FailedStatus => {
                     Err(anyhow::anyhow!("Failed to get transaction status"))
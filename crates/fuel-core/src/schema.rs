@@ -1,3 +1,7 @@
+use crate::fuel_core_graphql_api::{
+    api_service::LightClient,
+    Config,
+};
 use anyhow::anyhow;
 use async_graphql::{
     connection::{
@@ -7,12 +11,15 @@ use async_graphql::{
         Edge,
         EmptyFields,
     },
+    Context,
+    Guard,
     MergedObject,
     MergedSubscription,
     OutputType,
     Schema,
     SchemaBuilder,
 };
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_storage::{
     iter::IterDirection,
     Result as StorageResult,
@@ -25,6 +32,7 @@ pub mod chain;
 pub mod coins;
 pub mod contract;
 pub mod dap;
+pub mod database;
 pub mod health;
 pub mod message;
 pub mod node_info;
@@ -51,7 +59,13 @@ pub struct Query(
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    database::DatabaseMutation,
+    node_info::NodeMutation,
+);
 
 #[derive(MergedSubscription, Default)]
 pub struct Subscription(tx::TxStatusSubscription);
@@ -68,7 +82,38 @@ pub fn build_schema() -> CoreSchemaBuilder {
     )
 }
 
+/// The complexity of the connection is the complexity of one entry
+/// multiplied by the number of requested entries.
+fn connection_complexity(
+    first: Option<i32>,
+    last: Option<i32>,
+    child_complexity: usize,
+) -> usize {
+    let entries = first.or(last).unwrap_or_default();
+    let entries = usize::try_from(entries).unwrap_or_default();
+    child_complexity.saturating_mul(entries.max(1))
+}
+
+/// Restricts the fields to the full node. The light client stores only the headers
+/// of blocks, so it doesn't have the state, transactions and receipts to serve them.
+pub(crate) struct FullNodeGuard;
+
+#[async_trait::async_trait]
+impl Guard for FullNodeGuard {
+    async fn check(&self, ctx: &Context<'_>) -> async_graphql::Result<()> {
+        if ctx.data_opt::<LightClient>().is_some() {
+            return Err(anyhow!(
+                "The `{}` is not available in the light client mode",
+                ctx.field().name()
+            )
+            .into())
+        }
+        Ok(())
+    }
+}
+
 async fn query_pagination<F, Entries, SchemaKey, SchemaValue>(
+    ctx: &Context<'_>,
     after: Option<String>,
     before: Option<String>,
     first: Option<i32>,
@@ -111,6 +156,17 @@ where
         (_, _, _, _) => { /* Other combinations are allowed */ }
     };
 
+    let max_page_size = ctx.data_unchecked::<Config>().query_limits.max_page_size;
+    if let Some(page_size) = first.or(last) {
+        if usize::try_from(page_size).map_or(false, |size| size > max_page_size) {
+            graphql_metrics().graphql_request_rejected("page_size");
+            return Err(anyhow!(
+                "The page size `{page_size}` exceeds the limit `{max_page_size}`"
+            )
+            .into())
+        }
+    }
+
     query(
         after,
         before,
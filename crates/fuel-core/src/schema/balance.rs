@@ -4,13 +4,15 @@ use crate::{
         Config,
     },
     query::BalanceQueryData,
-    schema::scalars::{
-        Address,
-        AssetId,
-        U64,
+    schema::{
+        scalars::{
+            Address,
+            AssetId,
+            U64,
+        },
+        FullNodeGuard,
     },
 };
-use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -50,6 +52,7 @@ pub struct BalanceQuery;
 
 #[Object]
 impl BalanceQuery {
+    #[graphql(guard = "FullNodeGuard")]
     async fn balance(
         &self,
         ctx: &Context<'_>,
@@ -65,10 +68,10 @@ impl BalanceQuery {
         Ok(balance)
     }
 
-    // TODO: We can't paginate over `AssetId` because it is not unique.
-    //  It should be replaced with `UtxoId`.
-    //  This API should be migrated to the indexer for better support and
-    //  discontinued within fuel-core.
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(first, last, child_complexity)",
+        guard = "FullNodeGuard"
+    )]
     async fn balances(
         &self,
         ctx: &Context<'_>,
@@ -79,22 +82,26 @@ impl BalanceQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<AssetId, Balance, EmptyFields, EmptyFields>>
     {
-        if before.is_some() || after.is_some() {
-            return Err(anyhow!("pagination is not yet supported").into())
-        }
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |_, direction| {
-            let owner = filter.owner.into();
-            let base_asset_id = *ctx
-                .data_unchecked::<Config>()
-                .consensus_parameters
-                .base_asset_id();
-            Ok(query
-                .balances(owner, direction, base_asset_id)
-                .map(|result| {
-                    result.map(|balance| (balance.asset_id.into(), balance.into()))
-                }))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let owner = filter.owner.into();
+                let base_asset_id = *ctx
+                    .data_unchecked::<Config>()
+                    .consensus_parameters
+                    .base_asset_id();
+                Ok(query
+                    .balances(owner, (*start).map(Into::into), direction, base_asset_id)
+                    .map(|result| {
+                        result.map(|balance| (balance.asset_id.into(), balance.into()))
+                    }))
+            },
+        )
         .await
     }
 }
@@ -5,8 +5,19 @@ use super::scalars::{
 use crate::{
     database::Database,
     fuel_core_graphql_api::{
-        api_service::ConsensusModule,
+        api_service::{
+            ConsensusModule,
+            LightClient,
+        },
+        auth::{
+            Role,
+            RoleGuard,
+        },
         database::ReadView,
+        limits_extension::{
+            FetchBudget,
+            MAX_FETCHED_BLOCKS_PER_REQUEST,
+        },
         ports::DatabaseBlocks,
         Config as GraphQLConfig,
         IntoApiResult,
@@ -37,6 +48,7 @@ use async_graphql::{
     SimpleObject,
     Union,
 };
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -50,6 +62,7 @@ use fuel_core_types::{
         block::CompressedBlock,
         header::BlockHeader,
     },
+    fuel_tx::UniqueIdentifier,
     fuel_types,
     fuel_types::BlockHeight,
 };
@@ -109,6 +122,31 @@ impl Block {
         &self,
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Vec<Transaction>> {
+        // The light client doesn't store transactions and fetches them from peers.
+        if self.0.is_header_only() {
+            let light_client: &LightClient = ctx.data().map_err(|_| {
+                anyhow!("The node doesn't have transactions of the block")
+            })?;
+            let budget: &FetchBudget = ctx.data_unchecked();
+            if !budget.take() {
+                graphql_metrics().graphql_request_rejected("light_client_fetches");
+                return Err(anyhow!(
+                    "The request can fetch transactions of at most \
+                    {MAX_FETCHED_BLOCKS_PER_REQUEST} blocks from peers"
+                )
+                .into())
+            }
+            let chain_id = ctx
+                .data_unchecked::<GraphQLConfig>()
+                .consensus_parameters
+                .chain_id;
+            let transactions = light_client.transactions(self.0.header()).await?;
+            return Ok(transactions
+                .into_iter()
+                .map(|tx| Transaction::from_tx(tx.id(&chain_id), tx))
+                .collect())
+        }
+
         let query: &ReadView = ctx.data_unchecked();
         self.0
             .transactions()
@@ -216,6 +254,9 @@ impl BlockQuery {
             .into_api_result()
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(first, last, child_complexity)"
+    )]
     async fn blocks(
         &self,
         ctx: &Context<'_>,
@@ -225,9 +266,14 @@ impl BlockQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<U32, Block, EmptyFields, EmptyFields>> {
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(query, start.map(Into::into), direction))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| Ok(blocks_query(query, start.map(Into::into), direction)),
+        )
         .await
     }
 }
@@ -249,6 +295,9 @@ impl HeaderQuery {
             .map(|b| b.0.header().clone().into()))
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(first, last, child_complexity)"
+    )]
     async fn headers(
         &self,
         ctx: &Context<'_>,
@@ -258,9 +307,14 @@ impl HeaderQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<U32, Header, EmptyFields, EmptyFields>> {
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(query, start.map(Into::into), direction))
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| Ok(blocks_query(query, start.map(Into::into), direction)),
+        )
         .await
     }
 }
@@ -290,6 +344,7 @@ impl BlockMutation {
     /// `start_timestamp`. If the block production in the [`crate::service::Config`] is
     /// `Trigger::Interval { block_time }`, produces blocks with `block_time ` intervals between
     /// them. The `start_timestamp` is the timestamp in seconds.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
     async fn produce_blocks(
         &self,
         ctx: &Context<'_>,
@@ -12,13 +12,16 @@ use crate::{
         asset_query::AssetSpendTarget,
         CoinQueryData,
     },
-    schema::scalars::{
-        Address,
-        AssetId,
-        Nonce,
-        UtxoId,
-        U32,
-        U64,
+    schema::{
+        scalars::{
+            Address,
+            AssetId,
+            Nonce,
+            UtxoId,
+            U32,
+            U64,
+        },
+        FullNodeGuard,
     },
 };
 use async_graphql::{
@@ -147,6 +150,7 @@ pub struct CoinQuery;
 #[async_graphql::Object]
 impl CoinQuery {
     /// Gets the coin by `utxo_id`.
+    #[graphql(guard = "FullNodeGuard")]
     async fn coin(
         &self,
         ctx: &Context<'_>,
@@ -157,6 +161,10 @@ impl CoinQuery {
     }
 
     /// Gets all unspent coins of some `owner` maybe filtered with by `asset_id` per page.
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(first, last, child_complexity)",
+        guard = "FullNodeGuard"
+    )]
     async fn coins(
         &self,
         ctx: &Context<'_>,
@@ -167,24 +175,32 @@ impl CoinQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let owner: fuel_tx::Address = filter.owner.into();
-            let coins = query
-                .owned_coins(&owner, (*start).map(Into::into), direction)
-                .filter_map(|result| {
-                    if let (Ok(coin), Some(filter_asset_id)) = (&result, &filter.asset_id)
-                    {
-                        if coin.asset_id != filter_asset_id.0 {
-                            return None
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let owner: fuel_tx::Address = filter.owner.into();
+                let coins = query
+                    .owned_coins(&owner, (*start).map(Into::into), direction)
+                    .filter_map(|result| {
+                        if let (Ok(coin), Some(filter_asset_id)) =
+                            (&result, &filter.asset_id)
+                        {
+                            if coin.asset_id != filter_asset_id.0 {
+                                return None
+                            }
                         }
-                    }
 
-                    Some(result)
-                })
-                .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
+                        Some(result)
+                    })
+                    .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
 
-            Ok(coins)
-        })
+                Ok(coins)
+            },
+        )
         .await
     }
 
@@ -198,6 +214,7 @@ impl CoinQuery {
     ///     The list of spendable coins per asset from the query. The length of the result is
     ///     the same as the length of `query_per_asset`. The ordering of assets and `query_per_asset`
     ///     is the same.
+    #[graphql(guard = "FullNodeGuard")]
     async fn coins_to_spend(
         &self,
         ctx: &Context<'_>,
@@ -4,12 +4,17 @@ use crate::{
         IntoApiResult,
     },
     query::ContractQueryData,
-    schema::scalars::{
-        AssetId,
-        ContractId,
-        HexString,
-        Salt,
-        U64,
+    schema::{
+        scalars::{
+            AssetId,
+            Bytes32,
+            ContractId,
+            HexString,
+            Salt,
+            U32,
+            U64,
+        },
+        FullNodeGuard,
     },
 };
 use async_graphql::{
@@ -22,6 +27,7 @@ use async_graphql::{
     Object,
 };
 use fuel_core_types::{
+    entities,
     fuel_types,
     services::graphql_api,
 };
@@ -62,6 +68,7 @@ pub struct ContractQuery;
 
 #[Object]
 impl ContractQuery {
+    #[graphql(guard = "FullNodeGuard")]
     async fn contract(
         &self,
         ctx: &Context<'_>,
@@ -70,6 +77,144 @@ impl ContractQuery {
         let query: &ReadView = ctx.data_unchecked();
         query.contract_id(id.0).into_api_result()
     }
+
+    #[graphql(guard = "FullNodeGuard")]
+    async fn contract_storage_slot(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        #[graphql(desc = "The key of the storage slot")] key: Bytes32,
+    ) -> async_graphql::Result<Option<ContractStorageSlot>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .contract_storage_slot(contract.0, key.0)
+            .into_api_result()
+    }
+
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(first, last, child_complexity)",
+        guard = "FullNodeGuard"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn contract_storage_slots(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        #[graphql(desc = "Returns only the slots with keys starting with the prefix")]
+        prefix: Option<HexString>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<Bytes32, ContractStorageSlot, EmptyFields, EmptyFields>,
+    > {
+        let query: &ReadView = ctx.data_unchecked();
+        let prefix = prefix.map(|prefix| prefix.0).unwrap_or_default();
+        if prefix.len() > fuel_types::Bytes32::LEN {
+            return Err(anyhow::anyhow!(
+                "The prefix can't be longer than {} bytes",
+                fuel_types::Bytes32::LEN
+            )
+            .into())
+        }
+
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let slots = query
+                    .contract_storage_slots(
+                        contract.0,
+                        prefix,
+                        (*start).map(Into::into),
+                        direction,
+                    )
+                    .map(|slot| {
+                        let slot = slot?;
+                        Ok((slot.key.into(), slot.into()))
+                    });
+
+                Ok(slots)
+            },
+        )
+        .await
+    }
+}
+
+pub struct ContractStorageSlot(graphql_api::ContractStorageSlot);
+
+#[Object]
+impl ContractStorageSlot {
+    async fn contract(&self) -> ContractId {
+        self.0.contract.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.0.key.into()
+    }
+
+    async fn value(&self) -> Bytes32 {
+        self.0.value.into()
+    }
+
+    /// The inclusion proof of the slot against the state root of the contract.
+    async fn proof(&self, ctx: &Context<'_>) -> async_graphql::Result<SparseMerkleProof> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .contract_storage_slot_proof(self.0.contract, self.0.key)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
+
+impl From<graphql_api::ContractStorageSlot> for ContractStorageSlot {
+    fn from(slot: graphql_api::ContractStorageSlot) -> Self {
+        ContractStorageSlot(slot)
+    }
+}
+
+pub struct SparseMerkleProof(pub(crate) entities::contract::SparseMerkleProof);
+
+#[Object]
+impl SparseMerkleProof {
+    /// The root of the sparse merkle tree.
+    async fn root(&self) -> Bytes32 {
+        self.0.root.into()
+    }
+
+    /// The siblings on the path from the leaf to the root.
+    async fn path(&self) -> Vec<SparseMerkleProofNode> {
+        self.0
+            .path
+            .iter()
+            .cloned()
+            .map(SparseMerkleProofNode)
+            .collect()
+    }
+}
+
+impl From<entities::contract::SparseMerkleProof> for SparseMerkleProof {
+    fn from(proof: entities::contract::SparseMerkleProof) -> Self {
+        SparseMerkleProof(proof)
+    }
+}
+
+pub struct SparseMerkleProofNode(entities::contract::SparseMerkleProofNode);
+
+#[Object]
+impl SparseMerkleProofNode {
+    /// The height of the parent node that joins the path and the sibling.
+    async fn height(&self) -> U32 {
+        self.0.height.into()
+    }
+
+    async fn sibling(&self) -> Bytes32 {
+        self.0.sibling.into()
+    }
 }
 
 pub struct ContractBalance(graphql_api::ContractBalance);
@@ -87,6 +232,18 @@ impl ContractBalance {
     async fn asset_id(&self) -> AssetId {
         self.0.asset_id.into()
     }
+
+    /// The inclusion proof of the balance against the assets root of the contract.
+    /// Returns `null` if the contract doesn't hold the asset.
+    async fn proof(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<SparseMerkleProof>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .contract_balance_proof(self.0.owner, self.0.asset_id)
+            .into_api_result()
+    }
 }
 
 #[derive(InputObject)]
@@ -100,6 +257,7 @@ pub struct ContractBalanceQuery;
 
 #[Object]
 impl ContractBalanceQuery {
+    #[graphql(guard = "FullNodeGuard")]
     async fn contract_balance(
         &self,
         ctx: &Context<'_>,
@@ -124,6 +282,10 @@ impl ContractBalanceQuery {
             })
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(first, last, child_complexity)",
+        guard = "FullNodeGuard"
+    )]
     async fn contract_balances(
         &self,
         ctx: &Context<'_>,
@@ -137,22 +299,29 @@ impl ContractBalanceQuery {
     > {
         let query: &ReadView = ctx.data_unchecked();
 
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let balances = query
-                .contract_balances(
-                    filter.contract.into(),
-                    (*start).map(Into::into),
-                    direction,
-                )
-                .map(move |balance| {
-                    let balance = balance?;
-                    let asset_id = balance.asset_id;
-
-                    Ok((asset_id.into(), balance.into()))
-                });
-
-            Ok(balances)
-        })
+        crate::schema::query_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                let balances = query
+                    .contract_balances(
+                        filter.contract.into(),
+                        (*start).map(Into::into),
+                        direction,
+                    )
+                    .map(move |balance| {
+                        let balance = balance?;
+                        let asset_id = balance.asset_id;
+
+                        Ok((asset_id.into(), balance.into()))
+                    });
+
+                Ok(balances)
+            },
+        )
         .await
     }
 }
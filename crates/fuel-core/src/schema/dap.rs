@@ -4,6 +4,10 @@ use crate::{
         transaction::DatabaseTransaction,
         Database,
     },
+    fuel_core_graphql_api::auth::{
+        Role,
+        RoleGuard,
+    },
     schema::scalars::{
         U32,
         U64,
@@ -213,7 +217,7 @@ pub fn init<Q, M, S>(
         .data(Config { debug_enabled })
 }
 
-fn require_debug(ctx: &Context<'_>) -> async_graphql::Result<()> {
+pub(crate) fn require_debug(ctx: &Context<'_>) -> async_graphql::Result<()> {
     let config = ctx.data_unchecked::<Config>();
 
     if config.debug_enabled {
@@ -226,6 +230,7 @@ fn require_debug(ctx: &Context<'_>) -> async_graphql::Result<()> {
 #[Object]
 impl DapQuery {
     /// Read register value by index.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
     async fn register(
         &self,
         ctx: &Context<'_>,
@@ -242,6 +247,7 @@ impl DapQuery {
     }
 
     /// Read read a range of memory bytes.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
     async fn memory(
         &self,
         ctx: &Context<'_>,
@@ -265,6 +271,7 @@ impl DapMutation {
     /// A new VM instance is spawned for each session.
     /// The session is run in a separate database transaction,
     /// on top of the most recent node state.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
     async fn start_session(&self, ctx: &Context<'_>) -> async_graphql::Result<ID> {
         require_debug(ctx)?;
         trace!("Initializing new interpreter");
@@ -283,6 +290,7 @@ impl DapMutation {
     }
 
     /// End debugger session.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
     async fn end_session(
         &self,
         ctx: &Context<'_>,
@@ -297,6 +305,7 @@ impl DapMutation {
     }
 
     /// Reset the VM instance to the initial state.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
     async fn reset(&self, ctx: &Context<'_>, id: ID) -> async_graphql::Result<bool> {
         require_debug(ctx)?;
         let db = ctx.data_unchecked::<Database>();
@@ -312,6 +321,7 @@ impl DapMutation {
     }
 
     /// Execute a single fuel-asm instruction.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
     async fn execute(
         &self,
         ctx: &Context<'_>,
@@ -334,6 +344,7 @@ impl DapMutation {
     }
 
     /// Set single-stepping mode for the VM instance.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
     async fn set_single_stepping(
         &self,
         ctx: &Context<'_>,
@@ -354,6 +365,7 @@ impl DapMutation {
     }
 
     /// Set a breakpoint for a VM instance.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
     async fn set_breakpoint(
         &self,
         ctx: &Context<'_>,
@@ -375,6 +387,7 @@ impl DapMutation {
 
     /// Run a single transaction in given session until it
     /// hits a breakpoint or completes.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
     async fn start_tx(
         &self,
         ctx: &Context<'_>,
@@ -449,6 +462,7 @@ impl DapMutation {
 
     /// Resume execution of the VM instance after a breakpoint.
     /// Runs until the next breakpoint or until the transaction completes.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
     async fn continue_tx(
         &self,
         ctx: &Context<'_>,
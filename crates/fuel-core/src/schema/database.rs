@@ -0,0 +1,103 @@
+use crate::{
+    combined_database::CombinedDatabase,
+    fuel_core_graphql_api::{
+        auth::{
+            Role,
+            RoleGuard,
+        },
+        Config as GraphQLConfig,
+    },
+    schema::scalars::{
+        U32,
+        U64,
+    },
+};
+use anyhow::anyhow;
+use async_graphql::{
+    Context,
+    Object,
+};
+use std::path::PathBuf;
+
+#[derive(Default)]
+pub struct DatabaseMutation;
+
+#[Object]
+impl DatabaseMutation {
+    /// Creates a backup of the node's databases in the empty `path` directory
+    /// relative to the backup root of the node. The node continues to process
+    /// blocks during the backup.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
+    async fn backup_database(
+        &self,
+        ctx: &Context<'_>,
+        path: String,
+    ) -> async_graphql::Result<DatabaseBackup> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+
+        let Some(root) = config.backup_root.clone() else {
+            return Err(anyhow!("The backup root of the node is not configured").into())
+        };
+
+        let database = ctx.data::<CombinedDatabase>()?.clone();
+        backup(database, root, path).await
+    }
+}
+
+#[cfg(feature = "rocksdb")]
+async fn backup(
+    database: CombinedDatabase,
+    root: PathBuf,
+    path: String,
+) -> async_graphql::Result<DatabaseBackup> {
+    let path = crate::combined_database::backup::resolve_backup_path(
+        &root,
+        std::path::Path::new(&path),
+    )?;
+    let backup = tokio::task::spawn_blocking(move || {
+        database.backup(&path).map(|metadata| DatabaseBackup {
+            path: path.display().to_string(),
+            block_height: metadata.block_height.into(),
+            da_block_height: metadata.da_block_height.map(|height| height.0.into()),
+        })
+    })
+    .await??;
+    Ok(backup)
+}
+
+#[cfg(not(feature = "rocksdb"))]
+async fn backup(
+    _: CombinedDatabase,
+    _: PathBuf,
+    _: String,
+) -> async_graphql::Result<DatabaseBackup> {
+    Err(anyhow!("Rocksdb must be enabled to create backups of the database").into())
+}
+
+pub struct DatabaseBackup {
+    path: String,
+    block_height: U32,
+    da_block_height: Option<U64>,
+}
+
+#[Object]
+impl DatabaseBackup {
+    /// The path to the backup on the node's filesystem.
+    async fn path(&self) -> &str {
+        self.path.as_str()
+    }
+
+    /// The height of the last block included in the backup.
+    async fn block_height(&self) -> U32 {
+        self.block_height
+    }
+
+    /// The height of the last DA block included in the backup.
+    async fn da_block_height(&self) -> Option<U64> {
+        self.da_block_height
+    }
+}
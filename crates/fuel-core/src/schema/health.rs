@@ -1,4 +1,19 @@
-use async_graphql::Object;
+use crate::{
+    fuel_core_graphql_api::health::{
+        self,
+        HealthCheck,
+    },
+    schema::scalars::{
+        U32,
+        U64,
+    },
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+};
+use std::sync::Arc;
 
 #[derive(Default)]
 pub struct HealthQuery;
@@ -9,4 +24,147 @@ impl HealthQuery {
     async fn health(&self) -> bool {
         true
     }
+
+    /// Returns the status of the node's services, database,
+    /// and synchronization with the network and the DA layer.
+    async fn node_status(&self, ctx: &Context<'_>) -> NodeStatus {
+        let health_check: &Arc<HealthCheck> = ctx.data_unchecked();
+        NodeStatus(health_check.status().await)
+    }
+}
+
+pub struct NodeStatus(health::NodeStatus);
+
+#[Object]
+impl NodeStatus {
+    /// `true` if none of the services has stopped.
+    async fn live(&self) -> bool {
+        self.0.live
+    }
+
+    /// `true` if the node is live, all services are started, the database is reachable,
+    /// and the node is in sync with the network and the DA layer.
+    async fn ready(&self) -> bool {
+        self.0.ready
+    }
+
+    async fn services(&self) -> Vec<ServiceStatus> {
+        self.0.services.iter().cloned().map(ServiceStatus).collect()
+    }
+
+    async fn database(&self) -> DatabaseStatus {
+        DatabaseStatus(self.0.database.clone())
+    }
+
+    async fn sync(&self) -> SyncStatus {
+        SyncStatus(self.0.sync.clone())
+    }
+
+    /// The status of the relayer. It is `null` if the relayer is disabled.
+    async fn relayer(&self) -> Option<RelayerStatus> {
+        self.0.relayer.clone().map(RelayerStatus)
+    }
+}
+
+pub struct ServiceStatus(health::ServiceStatus);
+
+#[Object]
+impl ServiceStatus {
+    async fn name(&self) -> &str {
+        self.0.name
+    }
+
+    async fn state(&self) -> ServiceState {
+        self.0.state.into()
+    }
+
+    /// The error that stopped the service, if any.
+    async fn error(&self) -> Option<&str> {
+        self.0.error.as_deref()
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ServiceState {
+    NotStarted,
+    Starting,
+    Started,
+    Stopping,
+    Stopped,
+    Failed,
+}
+
+impl From<health::ServiceState> for ServiceState {
+    fn from(state: health::ServiceState) -> Self {
+        match state {
+            health::ServiceState::NotStarted => ServiceState::NotStarted,
+            health::ServiceState::Starting => ServiceState::Starting,
+            health::ServiceState::Started => ServiceState::Started,
+            health::ServiceState::Stopping => ServiceState::Stopping,
+            health::ServiceState::Stopped => ServiceState::Stopped,
+            health::ServiceState::Failed => ServiceState::Failed,
+        }
+    }
+}
+
+pub struct DatabaseStatus(health::DatabaseStatus);
+
+#[Object]
+impl DatabaseStatus {
+    async fn reachable(&self) -> bool {
+        self.0.reachable
+    }
+
+    /// The error returned by the database, if it is unreachable.
+    async fn error(&self) -> Option<&str> {
+        self.0.error.as_deref()
+    }
+}
+
+pub struct SyncStatus(health::SyncStatus);
+
+#[Object]
+impl SyncStatus {
+    /// The height of the latest local block.
+    async fn local_height(&self) -> Option<U32> {
+        self.0.local_height.map(Into::into)
+    }
+
+    /// The highest block height advertised by the connected peers.
+    async fn best_peer_height(&self) -> Option<U32> {
+        self.0.best_peer_height.map(Into::into)
+    }
+
+    /// The number of blocks the node is behind the best peer.
+    async fn block_lag(&self) -> Option<U32> {
+        self.0.block_lag.map(Into::into)
+    }
+
+    async fn synced(&self) -> bool {
+        self.0.synced
+    }
+}
+
+pub struct RelayerStatus(health::RelayerStatus);
+
+#[Object]
+impl RelayerStatus {
+    /// The latest DA height processed by the relayer.
+    async fn local_da_height(&self) -> Option<U64> {
+        self.0.local_da_height.map(Into::into)
+    }
+
+    /// The latest finalized height of the DA layer.
+    async fn remote_da_height(&self) -> Option<U64> {
+        self.0.remote_da_height.map(Into::into)
+    }
+
+    /// The number of DA blocks the relayer is behind the DA layer.
+    async fn da_lag(&self) -> Option<U64> {
+        self.0.da_lag.map(Into::into)
+    }
+
+    async fn synced(&self) -> bool {
+        self.0.synced
+    }
 }
@@ -4,6 +4,7 @@ use super::{
         Address,
         Bytes32,
         HexString,
+        MessageId,
         Nonce,
         TransactionId,
         U64,
@@ -16,9 +17,12 @@ use crate::{
     },
     graphql_api::IntoApiResult,
     query::MessageQueryData,
-    schema::scalars::{
-        BlockId,
-        U32,
+    schema::{
+        scalars::{
+            BlockId,
+            U32,
+        },
+        FullNodeGuard,
     },
 };
 use anyhow::anyhow;
@@ -67,6 +71,7 @@ pub struct MessageQuery {}
 
 #[Object]
 impl MessageQuery {
+    #[graphql(guard = "FullNodeGuard")]
     async fn message(
         &self,
         ctx: &Context<'_>,
@@ -77,6 +82,10 @@ impl MessageQuery {
         query.message(&nonce).into_api_result()
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(first, last, child_complexity)",
+        guard = "FullNodeGuard"
+    )]
     async fn messages(
         &self,
         ctx: &Context<'_>,
@@ -89,6 +98,7 @@ impl MessageQuery {
     {
         let query: &ReadView = ctx.data_unchecked();
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -118,6 +128,7 @@ impl MessageQuery {
         .await
     }
 
+    #[graphql(guard = "FullNodeGuard")]
     async fn message_proof(
         &self,
         ctx: &Context<'_>,
@@ -148,6 +159,40 @@ impl MessageQuery {
         .map(MessageProof))
     }
 
+    /// Returns the proof that the transaction is included in the block
+    /// against the `transactionsRoot` of the block header.
+    #[graphql(guard = "FullNodeGuard")]
+    async fn transaction_proof(
+        &self,
+        ctx: &Context<'_>,
+        transaction_id: TransactionId,
+    ) -> async_graphql::Result<Option<TransactionProof>> {
+        let query: &ReadView = ctx.data_unchecked();
+        Ok(
+            crate::query::transaction_proof(query, transaction_id.into())?
+                .map(TransactionProof),
+        )
+    }
+
+    /// Returns the proof that the `MessageOut` receipt with the `nonce` was produced
+    /// by the transaction against the `messageReceiptRoot` of the block header.
+    #[graphql(guard = "FullNodeGuard")]
+    async fn message_receipt_proof(
+        &self,
+        ctx: &Context<'_>,
+        transaction_id: TransactionId,
+        nonce: Nonce,
+    ) -> async_graphql::Result<Option<MessageReceiptProof>> {
+        let query: &ReadView = ctx.data_unchecked();
+        Ok(crate::query::message_receipt_proof(
+            query,
+            transaction_id.into(),
+            nonce.into(),
+        )?
+        .map(MessageReceiptProof))
+    }
+
+    #[graphql(guard = "FullNodeGuard")]
     async fn message_status(
         &self,
         ctx: &Context<'_>,
@@ -176,6 +221,36 @@ impl MerkleProof {
     }
 }
 
+pub struct TransactionProof(pub(crate) entities::message::TransactionProof);
+
+#[Object]
+impl TransactionProof {
+    async fn transaction_proof(&self) -> MerkleProof {
+        self.0.transaction_proof.clone().into()
+    }
+
+    async fn block_header(&self) -> Header {
+        self.0.block_header.clone().into()
+    }
+}
+
+pub struct MessageReceiptProof(pub(crate) entities::message::MessageReceiptProof);
+
+#[Object]
+impl MessageReceiptProof {
+    async fn message_id(&self) -> MessageId {
+        self.0.message_id.into()
+    }
+
+    async fn message_proof(&self) -> MerkleProof {
+        self.0.message_proof.clone().into()
+    }
+
+    async fn block_header(&self) -> Header {
+        self.0.block_header.clone().into()
+    }
+}
+
 pub struct MessageProof(pub(crate) entities::message::MessageProof);
 
 #[Object]
@@ -1,13 +1,43 @@
-use super::scalars::{
-    U32,
-    U64,
+use super::{
+    dap::require_debug,
+    scalars::{
+        Tai64Timestamp,
+        U32,
+        U64,
+    },
 };
-use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::P2pService,
+        auth::{
+            Role,
+            RoleGuard,
+        },
+        Config as GraphQLConfig,
+    },
+    state::rocks_db_config::{
+        ColumnConfig,
+        RocksDbConfig,
+    },
+};
+use anyhow::anyhow;
 use async_graphql::{
     Context,
+    Enum,
     Object,
 };
-use std::time::UNIX_EPOCH;
+use fuel_core_types::{
+    services::p2p::{
+        self,
+        peer_reputation::PeerBan,
+        PeerId,
+    },
+    tai64::Tai64,
+};
+use std::{
+    str::FromStr,
+    time::UNIX_EPOCH,
+};
 
 pub struct NodeInfo {
     utxo_validation: bool,
@@ -16,6 +46,7 @@ pub struct NodeInfo {
     max_tx: U64,
     max_depth: U64,
     node_version: String,
+    database_config: Option<RocksDbConfig>,
 }
 
 #[Object]
@@ -44,13 +75,48 @@ impl NodeInfo {
         self.node_version.to_owned()
     }
 
+    /// The configuration of RocksDB used by the node.
+    /// It is `null` if the node doesn't use RocksDB.
+    async fn database_config(&self) -> Option<DatabaseConfig> {
+        self.database_config.clone().map(DatabaseConfig)
+    }
+
+    /// The connected peers and the banned peers, including disconnected ones.
     async fn peers(&self, _ctx: &Context<'_>) -> async_graphql::Result<Vec<PeerInfo>> {
         #[cfg(feature = "p2p")]
         {
             let p2p: &crate::fuel_core_graphql_api::api_service::P2pService =
                 _ctx.data_unchecked();
-            let peer_info = p2p.all_peer_info().await?;
-            let peers = peer_info.into_iter().map(PeerInfo).collect();
+            let mut bans: std::collections::BTreeMap<PeerId, (f64, PeerBan)> = p2p
+                .peer_reputations()
+                .await?
+                .into_iter()
+                .filter_map(|(id, reputation)| {
+                    reputation.ban.map(|ban| (id, (reputation.score, ban)))
+                })
+                .collect();
+            let mut peers: Vec<_> = p2p
+                .all_peer_info()
+                .await?
+                .into_iter()
+                .map(|info| {
+                    let ban = bans.remove(&info.id).map(|(_, ban)| ban);
+                    PeerInfo { info, ban }
+                })
+                .collect();
+            peers.extend(bans.into_iter().map(|(id, (app_score, ban))| PeerInfo {
+                info: p2p::PeerInfo {
+                    id,
+                    peer_addresses: Default::default(),
+                    client_version: None,
+                    heartbeat_data: p2p::HeartbeatData {
+                        block_height: None,
+                        last_heartbeat: UNIX_EPOCH,
+                    },
+                    app_score,
+                },
+                ban: Some(ban),
+            }));
             Ok(peers)
         }
         #[cfg(not(feature = "p2p"))]
@@ -60,6 +126,16 @@ impl NodeInfo {
             ))
         }
     }
+
+    /// The reachability of the node from the p2p network.
+    /// It is `null` if the P2P service is not running.
+    async fn reachability(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<NetworkReachability>> {
+        let p2p: &P2pService = ctx.data_unchecked();
+        Ok(p2p.reachability().await?.map(NetworkReachability))
+    }
 }
 
 #[derive(Default)]
@@ -79,32 +155,121 @@ impl NodeQuery {
             max_tx: (config.max_tx as u64).into(),
             max_depth: (config.max_depth as u64).into(),
             node_version: VERSION.to_owned(),
+            database_config: config.database_config.clone(),
         })
     }
 }
 
-struct PeerInfo(fuel_core_types::services::p2p::PeerInfo);
+#[derive(Default)]
+pub struct NodeMutation;
+
+#[Object]
+impl NodeMutation {
+    /// Bans the peer and closes connections with it. The ban survives restarts of the node.
+    /// The ban is permanent if `duration_secs` is not specified.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
+    async fn ban_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+        reason: String,
+        duration_secs: Option<U64>,
+    ) -> async_graphql::Result<bool> {
+        require_debug(ctx)?;
+        let peer_id = parse_peer_id(&peer_id)?;
+        let expires_at = duration_secs
+            .map(|duration| Tai64(Tai64::now().0.saturating_add(duration.into())));
+        let p2p: &P2pService = ctx.data_unchecked();
+        p2p.ban_peer(peer_id, PeerBan { reason, expires_at })
+            .await?;
+        Ok(true)
+    }
+
+    /// Removes the ban of the peer and resets its reputation.
+    /// Returns `false` if the peer was not banned.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
+    async fn unban_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+    ) -> async_graphql::Result<bool> {
+        require_debug(ctx)?;
+        let peer_id = parse_peer_id(&peer_id)?;
+        let p2p: &P2pService = ctx.data_unchecked();
+        Ok(p2p.unban_peer(peer_id).await?)
+    }
+
+    /// Overrides the application score of the peer if `app_score` is specified.
+    /// Returns the current application score of the peer,
+    /// or `null` if the reputation of the peer is not tracked.
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
+    async fn peer_reputation(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+        app_score: Option<f64>,
+    ) -> async_graphql::Result<Option<f64>> {
+        require_debug(ctx)?;
+        let peer_id = parse_peer_id(&peer_id)?;
+        let p2p: &P2pService = ctx.data_unchecked();
+        if let Some(app_score) = app_score {
+            p2p.set_peer_app_score(peer_id.clone(), app_score).await?;
+        }
+        let score = p2p
+            .peer_reputations()
+            .await?
+            .into_iter()
+            .find(|(id, _)| *id == peer_id)
+            .map(|(_, reputation)| reputation.score);
+        Ok(score)
+    }
+}
+
+fn parse_peer_id(peer_id: &str) -> async_graphql::Result<PeerId> {
+    PeerId::from_str(peer_id)
+        .map_err(|e| anyhow!("Invalid peer id `{peer_id}`: {e}").into())
+}
+
+struct PeerBanInfo(PeerBan);
+
+#[Object]
+impl PeerBanInfo {
+    /// The reason of the ban
+    async fn reason(&self) -> &str {
+        self.0.reason.as_str()
+    }
+
+    /// The time when the ban expires, `null` if the ban is permanent
+    async fn expires_at(&self) -> Option<Tai64Timestamp> {
+        self.0.expires_at.map(Tai64Timestamp)
+    }
+}
+
+struct PeerInfo {
+    info: fuel_core_types::services::p2p::PeerInfo,
+    ban: Option<PeerBan>,
+}
 
 #[Object]
 impl PeerInfo {
     /// The libp2p peer id
     async fn id(&self) -> String {
-        self.0.id.to_string()
+        self.info.id.to_string()
     }
 
     /// The advertised multi-addrs that can be used to connect to this peer
     async fn addresses(&self) -> Vec<String> {
-        self.0.peer_addresses.iter().cloned().collect()
+        self.info.peer_addresses.iter().cloned().collect()
     }
 
     /// The self-reported version of the client the peer is using
     async fn client_version(&self) -> Option<String> {
-        self.0.client_version.clone()
+        self.info.client_version.clone()
     }
 
     /// The last reported height of the peer
     async fn block_height(&self) -> Option<U32> {
-        self.0
+        self.info
             .heartbeat_data
             .block_height
             .map(|height| (*height).into())
@@ -112,7 +277,7 @@ impl PeerInfo {
 
     /// The last heartbeat from this peer in unix epoch time ms
     async fn last_heartbeat_ms(&self) -> U64 {
-        let time = self.0.heartbeat_data.last_heartbeat;
+        let time = self.info.heartbeat_data.last_heartbeat;
         let time = time
             .duration_since(UNIX_EPOCH)
             .unwrap_or_default()
@@ -122,6 +287,134 @@ impl PeerInfo {
 
     /// The internal fuel p2p reputation of this peer
     async fn app_score(&self) -> f64 {
-        self.0.app_score
+        self.info.app_score
+    }
+
+    /// The ban of the peer, `null` if the peer is not banned.
+    /// Banned peers are disconnected, so their addresses and heartbeat are unknown.
+    async fn ban(&self) -> Option<PeerBanInfo> {
+        self.ban.clone().map(PeerBanInfo)
+    }
+}
+
+struct NetworkReachability(fuel_core_types::services::p2p::NetworkReachability);
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum ReachabilityStatus {
+    Unknown,
+    Public,
+    Private,
+}
+
+#[Object]
+impl NetworkReachability {
+    /// The status detected by other peers dialing the node
+    async fn status(&self) -> ReachabilityStatus {
+        match self.0.status {
+            p2p::ReachabilityStatus::Unknown => ReachabilityStatus::Unknown,
+            p2p::ReachabilityStatus::Public => ReachabilityStatus::Public,
+            p2p::ReachabilityStatus::Private => ReachabilityStatus::Private,
+        }
+    }
+
+    /// The confirmed external multi-addrs of the node,
+    /// including UPnP mappings and circuits through relays
+    async fn external_addresses(&self) -> Vec<String> {
+        self.0.external_addresses.clone()
+    }
+}
+
+struct DatabaseConfig(RocksDbConfig);
+
+#[Object]
+impl DatabaseConfig {
+    /// The default compression of columns
+    async fn compression(&self) -> String {
+        self.0.compression.to_string()
+    }
+
+    /// The default block size of columns in bytes
+    async fn block_size(&self) -> U64 {
+        (self.0.block_size as u64).into()
+    }
+
+    /// The default number of bits per key of the bloom filter
+    async fn bloom_filter_bits(&self) -> Option<f64> {
+        self.0.bloom_filter_bits
+    }
+
+    /// The percentage of the cache capacity used by the block cache
+    async fn block_cache_percent(&self) -> U32 {
+        u32::from(self.0.block_cache_percent).into()
+    }
+
+    /// The percentage of the cache capacity used by the row cache
+    async fn row_cache_percent(&self) -> U32 {
+        u32::from(self.0.row_cache_percent).into()
+    }
+
+    /// The maximum total size of the write-ahead log files in bytes
+    async fn max_total_wal_size(&self) -> U64 {
+        self.0.max_total_wal_size.into()
+    }
+
+    /// The limit of the background I/O in bytes per second
+    async fn rate_limit_bytes_per_sec(&self) -> Option<U64> {
+        self.0.rate_limit_bytes_per_sec.map(Into::into)
+    }
+
+    /// The default compaction style of columns
+    async fn compaction_style(&self) -> String {
+        self.0.compaction_style.to_string()
+    }
+
+    /// The options overridden for specific columns
+    async fn columns(&self) -> Vec<DatabaseColumnConfig> {
+        self.0
+            .columns
+            .iter()
+            .flat_map(|(database, columns)| {
+                columns.iter().map(|(column, config)| DatabaseColumnConfig {
+                    database: database.clone(),
+                    column: column.clone(),
+                    config: config.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+struct DatabaseColumnConfig {
+    database: String,
+    column: String,
+    config: ColumnConfig,
+}
+
+#[Object]
+impl DatabaseColumnConfig {
+    /// The name of the database
+    async fn database(&self) -> &str {
+        self.database.as_str()
+    }
+
+    /// The name of the column
+    async fn column(&self) -> &str {
+        self.column.as_str()
+    }
+
+    async fn compression(&self) -> Option<String> {
+        self.config.compression.map(|c| c.to_string())
+    }
+
+    async fn block_size(&self) -> Option<U64> {
+        self.config.block_size.map(|size| (size as u64).into())
+    }
+
+    async fn bloom_filter_bits(&self) -> Option<f64> {
+        self.config.bloom_filter_bits
+    }
+
+    async fn compaction_style(&self) -> Option<String> {
+        self.config.compaction_style.map(|c| c.to_string())
     }
 }
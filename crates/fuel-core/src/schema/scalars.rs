@@ -82,6 +82,7 @@ macro_rules! number_scalar {
 number_scalar!(U64, u64, "U64");
 number_scalar!(U32, u32, "U32");
 number_scalar!(U8, u8, "U8");
+number_scalar!(I64, i64, "I64");
 
 impl From<BlockHeight> for U32 {
     fn from(h: BlockHeight) -> Self {
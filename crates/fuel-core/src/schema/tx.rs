@@ -4,6 +4,10 @@ use crate::{
             BlockProducer,
             TxPool,
         },
+        auth::{
+            Role,
+            RoleGuard,
+        },
         database::ReadView,
         ports::OffChainDatabase,
         Config,
@@ -12,18 +16,22 @@ use crate::{
     query::{
         transaction_status_change,
         BlockQueryData,
+        SimpleBlockData,
         SimpleTransactionData,
         TransactionQueryData,
     },
     schema::{
         scalars::{
             Address,
+            AssetId,
             HexString,
             SortedTxCursor,
+            Tai64Timestamp,
             TransactionId,
             TxPointer,
         },
         tx::types::TransactionStatus,
+        FullNodeGuard,
     },
 };
 use async_graphql::{
@@ -32,6 +40,8 @@ use async_graphql::{
         EmptyFields,
     },
     Context,
+    Enum,
+    InputObject,
     Object,
     Subscription,
 };
@@ -46,6 +56,7 @@ use fuel_core_txpool::{
 };
 use fuel_core_types::{
     fuel_tx::{
+        self,
         Cacheable,
         Transaction as FuelTx,
         UniqueIdentifier,
@@ -65,6 +76,7 @@ use futures::{
 use itertools::Itertools;
 use std::{
     iter,
+    ops::Range,
     sync::Arc,
 };
 use tokio_stream::StreamExt;
@@ -78,11 +90,182 @@ pub mod output;
 pub mod receipt;
 pub mod types;
 
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum TransactionDirection {
+    /// The owner spent at least one input of the transaction.
+    Sent,
+    /// The owner only received outputs of the transaction.
+    Received,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum TransactionOutcome {
+    Success,
+    Failure,
+}
+
+/// The maximum number of the owner's transactions scanned by one filtered
+/// `transactionsByOwner` query. The query fails when the page is not filled
+/// within the limit, so the client should narrow the time range.
+const MAX_SCANNED_TRANSACTIONS: usize = 10_000;
+
+/// The error for the transaction of the `owner` indexed before the flows
+/// of the assets were tracked.
+fn missing_flows(
+    owner: &fuel_types::Address,
+    tx_id: &fuel_types::Bytes32,
+) -> StorageError {
+    StorageError::Other(anyhow::anyhow!(
+        "The asset flows of the transaction {tx_id} are not indexed for {owner}. \
+        The transaction was indexed by an older version of the node"
+    ))
+}
+
+#[derive(InputObject)]
+struct TransactionsByOwnerFilter {
+    /// Returns only transactions sent or received by the owner.
+    direction: Option<TransactionDirection>,
+    /// Returns only transactions that spent or received the asset of the owner.
+    asset_id: Option<AssetId>,
+    /// Returns only transactions included in blocks produced at or after the time.
+    from_time: Option<Tai64Timestamp>,
+    /// Returns only transactions included in blocks produced at or before the time.
+    to_time: Option<Tai64Timestamp>,
+    /// Returns only transactions with the execution outcome.
+    status: Option<TransactionOutcome>,
+}
+
+impl TransactionsByOwnerFilter {
+    /// Returns the range of the heights of the blocks produced within the time range.
+    /// The time of the blocks doesn't decrease with the height, so the bounds are found
+    /// by the binary search.
+    fn heights(&self, query: &ReadView) -> StorageResult<Range<u64>> {
+        let all = 0..u64::from(u32::MAX).saturating_add(1);
+        if self.from_time.is_none() && self.to_time.is_none() {
+            return Ok(all)
+        }
+        let first = match query
+            .compressed_blocks(None, IterDirection::Forward)
+            .next()
+            .transpose()?
+        {
+            Some(block) => u64::from(u32::from(*block.header().height())),
+            None => return Ok(0..0),
+        };
+        let end = u64::from(u32::from(query.latest_block_height()?)).saturating_add(1);
+        let time = |height: u64| {
+            let height = u32::try_from(height).expect("The height is within the chain");
+            query
+                .block(&height.into())
+                .map(|block| block.header().time())
+        };
+
+        let start = match self.from_time {
+            Some(from) => {
+                partition_point(first..end, |height| Ok(time(height)? < from.0))?
+            }
+            None => first,
+        };
+        let end = match self.to_time {
+            Some(to) => partition_point(first..end, |height| Ok(time(height)? <= to.0))?,
+            None => end,
+        };
+        Ok(start..end)
+    }
+
+    fn matches(
+        &self,
+        query: &ReadView,
+        owner: &fuel_types::Address,
+        tx_id: &fuel_types::Bytes32,
+    ) -> StorageResult<bool> {
+        if self.direction.is_some() || self.asset_id.is_some() {
+            let flows = query
+                .owned_transaction_flows(owner, tx_id)?
+                .ok_or_else(|| missing_flows(owner, tx_id))?;
+            let direction = if flows.is_sender {
+                TransactionDirection::Sent
+            } else {
+                TransactionDirection::Received
+            };
+            if self.direction.is_some_and(|expected| expected != direction) {
+                return Ok(false)
+            }
+            if let Some(asset_id) = &self.asset_id {
+                if !flows.assets.iter().any(|flow| flow.asset_id == asset_id.0) {
+                    return Ok(false)
+                }
+            }
+        }
+
+        // The time range is applied to the index by `heights`.
+        if let Some(expected) = self.status {
+            let outcome = match query.status(tx_id)? {
+                txpool::TransactionStatus::Success { .. } => TransactionOutcome::Success,
+                txpool::TransactionStatus::Failed { .. } => TransactionOutcome::Failure,
+                txpool::TransactionStatus::Submitted { .. }
+                | txpool::TransactionStatus::SqueezedOut { .. } => return Ok(false),
+            };
+            if expected != outcome {
+                return Ok(false)
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Returns the first height in the `range` for which the `predicate` is `false`,
+/// or the end of the `range`. The `predicate` must be `true` only for a prefix of the `range`.
+fn partition_point(
+    range: Range<u64>,
+    predicate: impl Fn(u64) -> StorageResult<bool>,
+) -> StorageResult<u64> {
+    let Range {
+        start: mut low,
+        end: mut high,
+    } = range;
+    while low < high {
+        let middle = low.saturating_add(high.saturating_sub(low) / 2);
+        if predicate(middle)? {
+            low = middle.saturating_add(1);
+        } else {
+            high = middle;
+        }
+    }
+    Ok(low)
+}
+
+/// Moves the `start` of the iteration over the owner's transactions into the `heights`.
+fn seek(
+    start: Option<fuel_tx::TxPointer>,
+    heights: &Range<u64>,
+    direction: IterDirection,
+) -> Option<fuel_tx::TxPointer> {
+    let pointer = |height: u64, tx_index| {
+        u32::try_from(height)
+            .ok()
+            .map(|height| fuel_tx::TxPointer::new(height.into(), tx_index))
+    };
+    let bound = match direction {
+        IterDirection::Forward => pointer(heights.start, 0),
+        IterDirection::Reverse => pointer(heights.end.saturating_sub(1), u16::MAX),
+    };
+    match (start, bound) {
+        (Some(start), Some(bound)) => match direction {
+            IterDirection::Forward => Some(start.max(bound)),
+            IterDirection::Reverse => Some(start.min(bound)),
+        },
+        (start, bound) => start.or(bound),
+    }
+}
+
 #[derive(Default)]
 pub struct TxQuery;
 
 #[Object]
 impl TxQuery {
+    #[graphql(guard = "FullNodeGuard")]
     async fn transaction(
         &self,
         ctx: &Context<'_>,
@@ -102,6 +285,10 @@ impl TxQuery {
         }
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(first, last, child_complexity)",
+        guard = "FullNodeGuard"
+    )]
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -114,6 +301,7 @@ impl TxQuery {
     > {
         let query: &ReadView = ctx.data_unchecked();
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
@@ -163,10 +351,16 @@ impl TxQuery {
         .await
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(first, last, child_complexity)",
+        guard = "FullNodeGuard"
+    )]
+    #[allow(clippy::too_many_arguments)]
     async fn transactions_by_owner(
         &self,
         ctx: &Context<'_>,
         owner: Address,
+        filter: Option<TransactionsByOwnerFilter>,
         first: Option<i32>,
         after: Option<String>,
         last: Option<i32>,
@@ -174,25 +368,57 @@ impl TxQuery {
     ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
     {
         let query: &ReadView = ctx.data_unchecked();
-        let config = ctx.data_unchecked::<Config>();
         let owner = fuel_types::Address::from(owner);
+        let filter = filter.as_ref();
+
+        let heights = match filter {
+            Some(filter) => filter.heights(query)?,
+            None => 0..u64::from(u32::MAX).saturating_add(1),
+        };
 
         crate::schema::query_pagination(
+            ctx,
             after,
             before,
             first,
             last,
             |start: &Option<TxPointer>, direction| {
-                let start = (*start).map(Into::into);
-                let txs =
-                    query
-                        .owned_transactions(owner, start, direction)
-                        .map(|result| {
-                            result.map(|(cursor, tx)| {
-                                let tx_id = tx.id(&config.consensus_parameters.chain_id);
-                                (cursor.into(), Transaction::from_tx(tx_id, tx))
-                            })
-                        });
+                let start = seek((*start).map(Into::into), &heights, direction);
+                let txs = query
+                    .owned_transactions_ids(owner, start, direction)
+                    .take_while(move |result| match result {
+                        Ok((pointer, _)) => heights
+                            .contains(&u64::from(u32::from(pointer.block_height()))),
+                        Err(_) => true,
+                    })
+                    .enumerate()
+                    .filter_map(move |(scanned, result)| {
+                        let Some(filter) = filter else {
+                            return Some(result)
+                        };
+                        if scanned >= MAX_SCANNED_TRANSACTIONS {
+                            return Some(Err(StorageError::Other(anyhow::anyhow!(
+                                "Scanned {MAX_SCANNED_TRANSACTIONS} transactions without \
+                                filling the page, narrow the time range"
+                            ))))
+                        }
+                        match &result {
+                            Ok((_, tx_id)) => {
+                                match filter.matches(query, &owner, tx_id) {
+                                    Ok(true) => Some(result),
+                                    Ok(false) => None,
+                                    Err(err) => Some(Err(err)),
+                                }
+                            }
+                            Err(_) => Some(result),
+                        }
+                    })
+                    .map(|result| {
+                        result.and_then(|(cursor, tx_id)| {
+                            let tx = query.transaction(&tx_id)?;
+                            Ok((cursor.into(), Transaction::from_tx(tx_id, tx)))
+                        })
+                    });
                 Ok(txs)
             },
         )
@@ -237,6 +463,7 @@ pub struct TxMutation;
 #[Object]
 impl TxMutation {
     /// Execute a dry-run of multiple transactions using a fork of current state, no changes are committed.
+    #[graphql(guard = "FullNodeGuard")]
     async fn dry_run(
         &self,
         ctx: &Context<'_>,
@@ -271,6 +498,7 @@ impl TxMutation {
     /// Submits transaction to the `TxPool`.
     ///
     /// Returns submitted transaction if the transaction is included in the `TxPool` without problems.
+    #[graphql(guard = "RoleGuard::new(Role::Submitter).and(FullNodeGuard)")]
     async fn submit(
         &self,
         ctx: &Context<'_>,
@@ -334,6 +562,7 @@ impl TxStatusSubscription {
     }
 
     /// Submits transaction to the `TxPool` and await either confirmation or failure.
+    #[graphql(guard = "RoleGuard::new(Role::Submitter).and(FullNodeGuard)")]
     async fn submit_and_await<'a>(
         &self,
         ctx: &Context<'a>,
@@ -372,3 +601,60 @@ impl TxStatusSubscription {
             .take(1))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    fn pointer(height: u32, tx_index: u16) -> fuel_tx::TxPointer {
+        fuel_tx::TxPointer::new(height.into(), tx_index)
+    }
+
+    #[test]
+    fn partition_point__finds_first_height_outside_of_prefix() {
+        let times = [1, 3, 3, 5, 8];
+        let time = |height: u64| times[usize::try_from(height).unwrap()];
+
+        let after_3 = partition_point(0..5, |height| Ok(time(height) <= 3)).unwrap();
+        let from_4 = partition_point(0..5, |height| Ok(time(height) < 4)).unwrap();
+        let none = partition_point(0..5, |height| Ok(time(height) < 1)).unwrap();
+        let all = partition_point(2..5, |height| Ok(time(height) < 10)).unwrap();
+
+        assert_eq!(after_3, 3);
+        assert_eq!(from_4, 3);
+        assert_eq!(none, 0);
+        assert_eq!(all, 5);
+    }
+
+    #[test]
+    fn seek__moves_start_into_heights() {
+        let heights = 10..20;
+
+        assert_eq!(
+            seek(None, &heights, IterDirection::Forward),
+            Some(pointer(10, 0))
+        );
+        assert_eq!(
+            seek(Some(pointer(5, 3)), &heights, IterDirection::Forward),
+            Some(pointer(10, 0))
+        );
+        assert_eq!(
+            seek(Some(pointer(15, 3)), &heights, IterDirection::Forward),
+            Some(pointer(15, 3))
+        );
+        assert_eq!(
+            seek(None, &heights, IterDirection::Reverse),
+            Some(pointer(19, u16::MAX))
+        );
+        assert_eq!(
+            seek(Some(pointer(25, 0)), &heights, IterDirection::Reverse),
+            Some(pointer(19, u16::MAX))
+        );
+        assert_eq!(
+            seek(Some(pointer(15, 3)), &heights, IterDirection::Reverse),
+            Some(pointer(15, 3))
+        );
+    }
+}
@@ -7,7 +7,11 @@ use crate::{
     fuel_core_graphql_api::{
         api_service::TxPool,
         database::ReadView,
-        ports::DatabaseBlocks,
+        ports::{
+            DatabaseBlocks,
+            OffChainDatabase,
+        },
+        storage::transactions::AssetFlow,
         Config,
         IntoApiResult,
     },
@@ -19,6 +23,7 @@ use crate::{
         block::Block,
         contract::Contract,
         scalars::{
+            Address,
             AssetId,
             Bytes32,
             HexString,
@@ -26,6 +31,7 @@ use crate::{
             Tai64Timestamp,
             TransactionId,
             TxPointer,
+            I64,
             U32,
             U64,
         },
@@ -71,6 +77,7 @@ use fuel_core_types::{
         Executable,
         TxId,
     },
+    fuel_types,
     fuel_types::canonical::Serialize,
     fuel_vm::ProgramState as VmProgramState,
     services::{
@@ -331,12 +338,59 @@ impl Policies {
     }
 }
 
+/// The net change of the owner's balance of the asset caused by the transaction.
+pub struct BalanceChange(AssetFlow);
+
+#[Object]
+impl BalanceChange {
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    /// The received amount minus the spent amount, saturated to the `I64` range.
+    async fn amount(&self) -> I64 {
+        let delta = self.0.delta();
+        let amount = i64::try_from(delta).unwrap_or(if delta.is_negative() {
+            i64::MIN
+        } else {
+            i64::MAX
+        });
+        amount.into()
+    }
+}
+
 pub struct Transaction(pub(crate) fuel_tx::Transaction, pub(crate) fuel_tx::TxId);
 
 impl Transaction {
     pub fn from_tx(id: fuel_tx::TxId, tx: fuel_tx::Transaction) -> Self {
         Self(tx, id)
     }
+
+    /// Returns `true` if the `owner` spent or received coins in the transaction.
+    fn involves(&self, owner: &fuel_types::Address) -> bool {
+        let (inputs, outputs) = match &self.0 {
+            fuel_tx::Transaction::Script(script) => (script.inputs(), script.outputs()),
+            fuel_tx::Transaction::Create(create) => (create.inputs(), create.outputs()),
+            fuel_tx::Transaction::Mint(_) => return false,
+        };
+        inputs.iter().any(|input| {
+            input.input_owner() == Some(owner)
+                || ((input.is_message_coin_signed() || input.is_message_coin_predicate())
+                    && input.recipient() == Some(owner))
+        }) || outputs.iter().any(|output| output.to() == Some(owner))
+    }
+
+    /// Returns `true` if the transaction is included in a block.
+    fn is_included(&self, query: &ReadView) -> Result<bool, StorageError> {
+        match query.status(&self.1) {
+            Ok(status) => Ok(matches!(
+                status,
+                TxStatus::Success { .. } | TxStatus::Failed { .. }
+            )),
+            Err(StorageError::NotFound(_, _)) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
 }
 
 #[Object]
@@ -526,6 +580,24 @@ impl Transaction {
         get_tx_status(id, query, txpool).map_err(Into::into)
     }
 
+    /// The changes of the `owner`'s balances per asset caused by the transaction.
+    /// Empty if the transaction is not included in a block or doesn't involve the `owner`.
+    async fn balance_changes(
+        &self,
+        ctx: &Context<'_>,
+        owner: Address,
+    ) -> async_graphql::Result<Vec<BalanceChange>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let flows = match query.owned_transaction_flows(&owner.0, &self.1)? {
+            Some(flows) => flows,
+            None if self.involves(&owner.0) && self.is_included(query)? => {
+                return Err(super::missing_flows(&owner.0, &self.1).into())
+            }
+            None => Default::default(),
+        };
+        Ok(flows.assets.into_iter().map(BalanceChange).collect())
+    }
+
     async fn script(&self) -> Option<HexString> {
         match &self.0 {
             fuel_tx::Transaction::Script(script) => {
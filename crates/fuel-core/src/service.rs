@@ -38,6 +38,8 @@ pub mod config;
 pub mod genesis;
 pub mod metrics;
 mod query;
+#[cfg(all(feature = "p2p", feature = "rocksdb"))]
+pub mod snapshot;
 pub mod sub_services;
 
 #[derive(Clone)]
@@ -116,12 +118,39 @@ impl FuelService {
                     CombinedDatabase::open(
                         &config.database_path,
                         config.max_database_cache_size,
+                        &config.rocksdb,
                     )?
                 }
             }
+            #[cfg(feature = "redb")]
+            DbType::Redb => {
+                if config.database_path.as_os_str().is_empty() {
+                    warn!(
+                        "No redb path configured, initializing database with a tmp directory"
+                    );
+                    CombinedDatabase::new(
+                        Database::redb(),
+                        Database::redb(),
+                        Database::redb(),
+                    )
+                } else {
+                    tracing::info!("Opening redb database {:?}", config.database_path);
+                    CombinedDatabase::open_redb(&config.database_path)?
+                }
+            }
             DbType::InMemory => CombinedDatabase::in_memory(),
             #[cfg(not(feature = "rocksdb"))]
-            _ => CombinedDatabase::in_memory(),
+            DbType::RocksDb => {
+                return Err(anyhow::anyhow!(
+                    "The node is built without the `rocksdb` feature required by the `rocks-db` database type"
+                ))
+            }
+            #[cfg(not(feature = "redb"))]
+            DbType::Redb => {
+                return Err(anyhow::anyhow!(
+                    "The node is built without the `redb` feature required by the `redb` database type"
+                ))
+            }
         };
 
         Self::from_combined_database(combined_database, config).await
@@ -223,7 +252,13 @@ impl Task {
             .and_then(|state| state.height)
             .unwrap_or_default();
         let da_block_height = 0u64.into();
+        database
+            .migrate_off_chain(config.chain_conf.consensus_parameters.base_asset_id())?;
         database.init(&block_height, &da_block_height)?;
+        #[cfg(feature = "p2p")]
+        database.init_light_client(config.light_client.is_some())?;
+        #[cfg(not(feature = "p2p"))]
+        database.init_light_client(false)?;
 
         // initialize sub services
         tracing::info!("Initializing sub services");
@@ -394,4 +429,17 @@ mod tests {
             assert_eq!(service.borrow_and_update().clone(), State::Stopped);
         }
     }
+
+    #[cfg(not(feature = "redb"))]
+    #[tokio::test]
+    async fn new_node_fails_for_database_type_without_feature() {
+        let config = Config {
+            database_type: crate::service::DbType::Redb,
+            ..Config::local_node()
+        };
+
+        let result = crate::service::FuelService::new_node(config).await;
+
+        assert!(result.is_err());
+    }
 }
@@ -10,7 +10,10 @@ use fuel_core_consensus_module::{
     RelayerConsensusConfig,
 };
 use fuel_core_executor::executor::Executor;
-use fuel_core_services::stream::BoxStream;
+use fuel_core_services::{
+    stream::BoxStream,
+    StateWatcher,
+};
 use fuel_core_txpool::service::SharedState as TxPoolSharedState;
 #[cfg(feature = "p2p")]
 use fuel_core_types::services::p2p::peer_reputation::AppScore;
@@ -142,6 +145,16 @@ impl BlockImporterAdapter {
     }
 }
 
+/// Provides the state of the node's components to the health checks.
+pub struct NodeStatusAdapter {
+    /// The name and the state watcher of each service of the node.
+    pub services: Vec<(&'static str, StateWatcher)>,
+    pub database: Database,
+    pub p2p: P2PAdapter,
+    #[cfg(feature = "relayer")]
+    pub relayer: Option<fuel_core_relayer::SharedState<Database<Relayer>>>,
+}
+
 #[cfg(feature = "p2p")]
 #[derive(Clone)]
 pub struct P2PAdapter {
@@ -157,6 +170,68 @@ pub struct PeerReportConfig {
     pub bad_block_header: AppScore,
     pub missing_transactions: AppScore,
     pub invalid_transactions: AppScore,
+    pub missing_snapshot_chunks: AppScore,
+    pub invalid_snapshot_data: AppScore,
+}
+
+/// Imports the snapshot received by the snap-sync into the databases.
+#[cfg(feature = "p2p")]
+#[derive(Clone)]
+pub struct SnapshotImporterAdapter {
+    database: crate::combined_database::CombinedDatabase,
+}
+
+#[cfg(feature = "p2p")]
+impl SnapshotImporterAdapter {
+    pub fn new(database: crate::combined_database::CombinedDatabase) -> Self {
+        Self { database }
+    }
+}
+
+/// Imports headers received by the light client after their verification.
+#[cfg(feature = "p2p")]
+#[derive(Clone)]
+pub struct HeaderImporterAdapter {
+    database: Database,
+    importer: BlockImporterAdapter,
+    verifier: VerifierAdapter,
+}
+
+#[cfg(feature = "p2p")]
+impl HeaderImporterAdapter {
+    pub fn new(
+        database: Database,
+        importer: BlockImporterAdapter,
+        verifier: VerifierAdapter,
+    ) -> Self {
+        Self {
+            database,
+            importer,
+            verifier,
+        }
+    }
+}
+
+/// The database of the p2p service. Reputations of peers are local to the node,
+/// so they are stored in the off-chain database.
+#[cfg(feature = "p2p")]
+#[derive(Clone)]
+pub struct P2PDatabaseAdapter {
+    on_chain: Database,
+    off_chain: Database<crate::database::database_description::off_chain::OffChain>,
+}
+
+#[cfg(feature = "p2p")]
+impl P2PDatabaseAdapter {
+    pub fn new(
+        on_chain: Database,
+        off_chain: Database<crate::database::database_description::off_chain::OffChain>,
+    ) -> Self {
+        Self {
+            on_chain,
+            off_chain,
+        }
+    }
 }
 
 #[cfg(not(feature = "p2p"))]
@@ -174,6 +249,14 @@ impl P2PAdapter {
             peer_report_config,
         }
     }
+
+    /// Returns the adapter that doesn't interact with the network.
+    pub fn without_network(&self) -> Self {
+        Self {
+            service: None,
+            peer_report_config: self.peer_report_config.clone(),
+        }
+    }
 }
 
 #[cfg(not(feature = "p2p"))]
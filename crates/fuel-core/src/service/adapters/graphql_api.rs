@@ -1,15 +1,20 @@
 use super::{
     BlockImporterAdapter,
     BlockProducerAdapter,
+    NodeStatusAdapter,
 };
 use crate::{
     database::Database,
-    fuel_core_graphql_api::ports::{
-        worker,
-        BlockProducerPort,
-        DatabaseMessageProof,
-        P2pPort,
-        TxPoolPort,
+    fuel_core_graphql_api::{
+        health::RelayerHeights,
+        ports::{
+            worker,
+            BlockProducerPort,
+            DatabaseMessageProof,
+            NodeStatusPort,
+            P2pPort,
+            TxPoolPort,
+        },
     },
     service::adapters::{
         P2PAdapter,
@@ -17,7 +22,10 @@ use crate::{
     },
 };
 use async_trait::async_trait;
-use fuel_core_services::stream::BoxStream;
+use fuel_core_services::{
+    stream::BoxStream,
+    State,
+};
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::{
     service::TxStatusMessage,
@@ -30,7 +38,16 @@ use fuel_core_types::{
     services::{
         block_importer::SharedImportResult,
         executor::TransactionExecutionStatus,
-        p2p::PeerInfo,
+        p2p::{
+            peer_reputation::{
+                AppScore,
+                PeerBan,
+                PeerReputation,
+            },
+            NetworkReachability,
+            PeerId,
+            PeerInfo,
+        },
         txpool::InsertionResult,
     },
     tai64::Tai64,
@@ -132,6 +149,142 @@ impl P2pPort for P2PAdapter {
             Ok(vec![])
         }
     }
+
+    async fn peer_reputations(&self) -> anyhow::Result<Vec<(PeerId, PeerReputation)>> {
+        #[cfg(feature = "p2p")]
+        {
+            if let Some(service) = &self.service {
+                let reputations = service.get_peer_reputations().await?;
+                Ok(reputations
+                    .into_iter()
+                    .map(|(peer_id, reputation)| {
+                        (PeerId::from(peer_id.to_bytes()), reputation)
+                    })
+                    .collect())
+            } else {
+                Ok(vec![])
+            }
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            Ok(vec![])
+        }
+    }
+
+    async fn reachability(&self) -> anyhow::Result<Option<NetworkReachability>> {
+        #[cfg(feature = "p2p")]
+        {
+            if let Some(service) = &self.service {
+                Ok(Some(service.get_reachability().await?))
+            } else {
+                Ok(None)
+            }
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            Ok(None)
+        }
+    }
+
+    async fn set_peer_app_score(
+        &self,
+        peer_id: PeerId,
+        score: AppScore,
+    ) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            self.p2p_service()?.set_peer_app_score(peer_id, score).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = (peer_id, score);
+            Err(p2p_disabled())
+        }
+    }
+
+    async fn ban_peer(&self, peer_id: PeerId, ban: PeerBan) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            self.p2p_service()?.ban_peer(peer_id, ban).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = (peer_id, ban);
+            Err(p2p_disabled())
+        }
+    }
+
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<bool> {
+        #[cfg(feature = "p2p")]
+        {
+            self.p2p_service()?.unban_peer(peer_id).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(p2p_disabled())
+        }
+    }
+}
+
+#[cfg(feature = "p2p")]
+impl P2PAdapter {
+    fn p2p_service(&self) -> anyhow::Result<&fuel_core_p2p::service::SharedState> {
+        self.service.as_ref().ok_or_else(p2p_disabled)
+    }
+}
+
+fn p2p_disabled() -> anyhow::Error {
+    anyhow::anyhow!("The P2P service is disabled")
+}
+
+#[cfg(feature = "p2p")]
+#[async_trait::async_trait]
+impl crate::fuel_core_graphql_api::ports::LightClientPort
+    for fuel_core_sync::light_sync::LightClient<P2PAdapter>
+{
+    async fn transactions(
+        &self,
+        header: &fuel_core_types::blockchain::header::BlockHeader,
+    ) -> anyhow::Result<Vec<Transaction>> {
+        fuel_core_sync::light_sync::LightClient::transactions(self, header).await
+    }
+}
+
+#[async_trait::async_trait]
+impl NodeStatusPort for NodeStatusAdapter {
+    fn service_states(&self) -> Vec<(&'static str, State)> {
+        self.services
+            .iter()
+            .map(|(name, watcher)| (*name, watcher.borrow().clone()))
+            .collect()
+    }
+
+    fn latest_height(&self) -> anyhow::Result<BlockHeight> {
+        Ok(self.database.latest_height()?)
+    }
+
+    async fn best_peer_height(&self) -> anyhow::Result<Option<BlockHeight>> {
+        let peers = self.p2p.all_peer_info().await?;
+        Ok(peers
+            .into_iter()
+            .filter_map(|peer| peer.heartbeat_data.block_height)
+            .max())
+    }
+
+    fn relayer_heights(&self) -> Option<RelayerHeights> {
+        #[cfg(feature = "relayer")]
+        {
+            self.relayer.as_ref().map(|relayer| RelayerHeights {
+                local: relayer.get_finalized_da_height().ok(),
+                remote: relayer.get_remote_finalized_da_height(),
+            })
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            None
+        }
+    }
 }
 
 impl worker::BlockImporter for BlockImporterAdapter {
@@ -8,7 +8,20 @@ use crate::{
             worker,
             OffChainDatabase,
         },
-        storage::transactions::OwnedTransactionIndexCursor,
+        storage::{
+            balances::{
+                CoinBalances,
+                CoinBalancesKey,
+                MessageBalances,
+                TotalBalanceAmount,
+            },
+            transactions::{
+                OwnedTransactionFlows,
+                OwnedTransactionFlowsKey,
+                OwnedTransactionIndexCursor,
+                TransactionFlows,
+            },
+        },
     },
 };
 use fuel_core_storage::{
@@ -20,11 +33,13 @@ use fuel_core_storage::{
     not_found,
     Error as StorageError,
     Result as StorageResult,
+    StorageAsRef,
 };
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
     fuel_tx::{
         Address,
+        AssetId,
         Bytes32,
         TxPointer,
         UtxoId,
@@ -79,6 +94,50 @@ impl OffChainDatabase for Database<OffChain> {
             .map(|result| result.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn owned_transaction_flows(
+        &self,
+        owner: &Address,
+        tx_id: &TxId,
+    ) -> StorageResult<Option<TransactionFlows>> {
+        let flows = self
+            .storage::<OwnedTransactionFlows>()
+            .get(&OwnedTransactionFlowsKey::new(owner, tx_id))?
+            .map(|flows| flows.into_owned());
+        Ok(flows)
+    }
+
+    fn coin_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount> {
+        let balance = self
+            .storage::<CoinBalances>()
+            .get(&CoinBalancesKey::new(owner, asset_id))?
+            .map(|balance| balance.into_owned())
+            .unwrap_or_default();
+        Ok(balance)
+    }
+
+    fn message_balance(&self, owner: &Address) -> StorageResult<TotalBalanceAmount> {
+        let balance = self
+            .storage::<MessageBalances>()
+            .get(owner)?
+            .map(|balance| balance.into_owned())
+            .unwrap_or_default();
+        Ok(balance)
+    }
+
+    fn coin_balances(
+        &self,
+        owner: &Address,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, TotalBalanceAmount)>> {
+        self.coin_balances(owner, start_asset, Some(direction))
+            .into_boxed()
+    }
 }
 
 impl worker::OffChainDatabase for Database<OffChain> {
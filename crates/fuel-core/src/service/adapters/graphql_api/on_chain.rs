@@ -29,13 +29,20 @@ use fuel_core_types::{
             DaBlockHeight,
         },
     },
-    entities::message::Message,
+    entities::{
+        contract::SparseMerkleProof,
+        message::Message,
+    },
     fuel_tx::AssetId,
     fuel_types::{
         BlockHeight,
+        Bytes32,
         Nonce,
     },
-    services::graphql_api::ContractBalance,
+    services::graphql_api::{
+        ContractBalance,
+        ContractStorageSlot,
+    },
 };
 
 impl DatabaseBlocks for Database {
@@ -100,6 +107,40 @@ impl DatabaseContracts for Database {
             })
             .into_boxed()
     }
+
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        prefix: Vec<u8>,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractStorageSlot>> {
+        self.contract_storage_slots(contract, prefix, start_key, Some(direction))
+            .map(move |result| {
+                result.map(|(key, value)| ContractStorageSlot {
+                    contract,
+                    key,
+                    value,
+                })
+            })
+            .into_boxed()
+    }
+
+    fn contract_storage_slot_proof(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> StorageResult<Option<SparseMerkleProof>> {
+        self.contract_state_proof(contract, key)
+    }
+
+    fn contract_balance_proof(
+        &self,
+        contract: &ContractId,
+        asset_id: &AssetId,
+    ) -> StorageResult<Option<SparseMerkleProof>> {
+        self.contract_balance_proof(contract, asset_id)
+    }
 }
 
 impl DatabaseChain for Database {
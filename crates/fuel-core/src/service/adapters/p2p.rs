@@ -1,38 +1,116 @@
-use super::BlockImporterAdapter;
-use crate::database::Database;
+use super::{
+    BlockImporterAdapter,
+    P2PDatabaseAdapter,
+    TxPoolAdapter,
+};
+#[cfg(feature = "rocksdb")]
+use fuel_core_p2p::ports::SnapshotProvider;
 use fuel_core_p2p::ports::{
     BlockHeightImporter,
     P2pDb,
+    TxPool,
 };
 use fuel_core_services::stream::BoxStream;
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    transactional::AtomicView,
+    Result as StorageResult,
+};
+#[cfg(feature = "rocksdb")]
+use fuel_core_types::services::p2p::snapshot::{
+    SnapshotChunk,
+    SnapshotManifest,
+};
 use fuel_core_types::{
     blockchain::{
         consensus::Genesis,
+        SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
-    services::p2p::Transactions,
+    services::p2p::{
+        peer_reputation::PeerReputation,
+        PeerId,
+        Transactions,
+    },
 };
 use std::ops::Range;
 
-impl P2pDb for Database {
+impl AtomicView for P2PDatabaseAdapter {
+    type View = Self;
+
+    type Height = BlockHeight;
+
+    fn latest_height(&self) -> BlockHeight {
+        self.on_chain.latest_height().unwrap_or_default()
+    }
+
+    fn view_at(&self, _: &BlockHeight) -> StorageResult<Self::View> {
+        // TODO: Unimplemented until of the https://github.com/FuelLabs/fuel-core/issues/451
+        Ok(self.latest_view())
+    }
+
+    fn latest_view(&self) -> Self::View {
+        self.clone()
+    }
+}
+
+impl P2pDb for P2PDatabaseAdapter {
     fn get_sealed_headers(
         &self,
         block_height_range: Range<u32>,
     ) -> StorageResult<Vec<SealedBlockHeader>> {
-        self.get_sealed_block_headers(block_height_range)
+        self.on_chain.get_sealed_block_headers(block_height_range)
     }
 
     fn get_transactions(
         &self,
         block_height_range: Range<u32>,
     ) -> StorageResult<Option<Vec<Transactions>>> {
-        self.get_transactions_on_blocks(block_height_range)
+        self.on_chain.get_transactions_on_blocks(block_height_range)
+    }
+
+    fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> StorageResult<Vec<SealedBlock>> {
+        self.on_chain.get_sealed_blocks(block_height_range)
     }
 
     fn get_genesis(&self) -> StorageResult<Genesis> {
-        self.get_genesis()
+        self.on_chain.get_genesis()
+    }
+
+    fn get_peer_reputations(&self) -> StorageResult<Vec<(PeerId, PeerReputation)>> {
+        self.off_chain.get_peer_reputations()
+    }
+
+    fn update_peer_reputations(
+        &self,
+        updated: Vec<(PeerId, PeerReputation)>,
+        removed: Vec<PeerId>,
+    ) -> StorageResult<()> {
+        self.off_chain
+            .clone()
+            .update_peer_reputations(updated, removed)
+    }
+}
+
+impl TxPool for TxPoolAdapter {
+    fn get_transactions_by_id(&self, tx_ids: Vec<TxId>) -> Vec<Transaction> {
+        self.service
+            .find(tx_ids)
+            .into_iter()
+            .flatten()
+            .map(|info| info.tx().as_ref().into())
+            .collect()
+    }
+
+    fn get_tx_ids(&self, max_txs: usize) -> Vec<TxId> {
+        self.service.tx_ids(max_txs)
     }
 }
 
@@ -42,10 +120,33 @@ impl BlockHeightImporter for BlockImporterAdapter {
             wrappers::BroadcastStream,
             StreamExt,
         };
+        // The light client imports blocks without transactions. It doesn't advertise
+        // their heights, because it can't serve their transactions to peers.
         Box::pin(
             BroadcastStream::new(self.block_importer.subscribe())
                 .filter_map(|result| result.ok())
+                .filter(|result| !result.sealed_block.entity.is_header_only())
                 .map(|result| *result.sealed_block.entity.header().height()),
         )
     }
 }
+
+#[cfg(feature = "rocksdb")]
+impl SnapshotProvider for crate::service::snapshot::SnapshotStore {
+    fn latest_manifest(&self) -> StorageResult<Option<SnapshotManifest>> {
+        Ok(self.latest().map(|snapshot| snapshot.manifest().clone()))
+    }
+
+    fn get_chunks(
+        &self,
+        height: BlockHeight,
+        range: Range<u32>,
+    ) -> StorageResult<Option<Vec<SnapshotChunk>>> {
+        match self.latest() {
+            Some(snapshot) if snapshot.manifest().height() == height => {
+                snapshot.chunks(range)
+            }
+            _ => Ok(None),
+        }
+    }
+}
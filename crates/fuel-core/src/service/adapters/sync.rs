@@ -1,31 +1,59 @@
 use super::{
     BlockImporterAdapter,
     ConsensusAdapter,
+    HeaderImporterAdapter,
     P2PAdapter,
+    SnapshotImporterAdapter,
+};
+use crate::database::{
+    database_description::{
+        off_chain::OffChain,
+        DatabaseDescription,
+        DatabaseMetadata,
+    },
+    metadata::MetadataTable,
 };
 use fuel_core_poa::ports::RelayerPort;
 use fuel_core_services::stream::BoxStream;
+use fuel_core_storage::{
+    transactional::Transactional,
+    StorageAsMut,
+};
 use fuel_core_sync::ports::{
     BlockImporterPort,
     ConsensusPort,
+    HeaderImporterPort,
     PeerReportReason,
     PeerToPeerPort,
+    SnapshotImporterPort,
 };
 use fuel_core_types::{
     blockchain::{
+        block::Block,
         primitives::DaBlockHeight,
         SealedBlock,
         SealedBlockHeader,
     },
     fuel_types::BlockHeight,
-    services::p2p::{
-        peer_reputation::{
-            AppScore,
-            PeerReport,
+    services::{
+        block_importer::{
+            ImportResult,
+            UncommittedResult as UncommittedImportResult,
+        },
+        p2p::{
+            peer_reputation::{
+                AppScore,
+                PeerReport,
+            },
+            snapshot::{
+                SnapshotChunk,
+                SnapshotChunksRequest,
+                SnapshotManifest,
+            },
+            PeerId,
+            SourcePeer,
+            Transactions,
         },
-        PeerId,
-        SourcePeer,
-        Transactions,
     },
 };
 use std::ops::Range;
@@ -65,6 +93,25 @@ impl PeerToPeerPort for P2PAdapter {
         }
     }
 
+    async fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlock>>>> {
+        let result = if let Some(service) = &self.service {
+            service.get_sealed_blocks(block_height_range).await
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        };
+        match result {
+            Ok((peer_id, blocks)) => {
+                let peer_id: PeerId = peer_id.into();
+                let blocks = peer_id.bind(blocks);
+                Ok(blocks)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     async fn get_transactions(
         &self,
         range: SourcePeer<Range<u32>>,
@@ -82,6 +129,46 @@ impl PeerToPeerPort for P2PAdapter {
         }
     }
 
+    async fn get_peer_ids(&self) -> anyhow::Result<Vec<PeerId>> {
+        if let Some(service) = &self.service {
+            let peer_ids = service.get_peer_ids().await?;
+            Ok(peer_ids
+                .into_iter()
+                .map(|peer_id| peer_id.to_bytes().into())
+                .collect())
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        }
+    }
+
+    async fn get_snapshot_manifest(
+        &self,
+        peer: PeerId,
+    ) -> anyhow::Result<Option<SnapshotManifest>> {
+        if let Some(service) = &self.service {
+            service.get_snapshot_manifest_from_peer(peer.into()).await
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        }
+    }
+
+    async fn get_snapshot_chunks(
+        &self,
+        request: SourcePeer<SnapshotChunksRequest>,
+    ) -> anyhow::Result<Option<Vec<SnapshotChunk>>> {
+        let SourcePeer {
+            peer_id,
+            data: request,
+        } = request;
+        if let Some(service) = &self.service {
+            service
+                .get_snapshot_chunks_from_peer(peer_id.into(), request)
+                .await
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        }
+    }
+
     fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()> {
         if let Some(service) = &self.service {
             let service_name = "Sync";
@@ -110,6 +197,12 @@ impl P2PAdapter {
             PeerReportReason::InvalidTransactions => {
                 self.peer_report_config.invalid_transactions
             }
+            PeerReportReason::MissingSnapshotChunks => {
+                self.peer_report_config.missing_snapshot_chunks
+            }
+            PeerReportReason::InvalidSnapshotData => {
+                self.peer_report_config.invalid_snapshot_data
+            }
         };
         P2PAdapterPeerReport { score }
     }
@@ -157,3 +250,65 @@ impl ConsensusPort for ConsensusAdapter {
         .await?
     }
 }
+
+impl SnapshotImporterPort for SnapshotImporterAdapter {
+    fn prepare(&self) -> anyhow::Result<()> {
+        self.database.on_chain().clear_snapshot_columns()?;
+        Ok(())
+    }
+
+    fn import_chunk(&self, chunk: SnapshotChunk) -> anyhow::Result<()> {
+        self.database.on_chain().import_snapshot_chunk(chunk)?;
+        Ok(())
+    }
+
+    fn finalize(&self, manifest: &SnapshotManifest) -> anyhow::Result<()> {
+        let mut on_chain = self.database.on_chain().clone();
+        on_chain.finalize_snapshot(manifest)?;
+
+        let report = self.database.clone().rebuild_off_chain_indexes()?;
+        tracing::info!(
+            "Rebuilt {} off-chain index entries after the snapshot import",
+            report.repaired
+        );
+        let mut off_chain = self.database.off_chain().clone();
+        off_chain
+            .storage_as_mut::<MetadataTable<OffChain>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V1 {
+                    version: OffChain::version(),
+                    height: manifest.height(),
+                },
+            )?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl HeaderImporterPort for HeaderImporterAdapter {
+    async fn commit_header(&self, header: SealedBlockHeader) -> anyhow::Result<()> {
+        // Checks the `prev_root` against the root of the previous headers
+        // in the `FuelBlockMerkleData` and the monotonicity of fields.
+        self.verifier
+            .block_verifier
+            .verify_header_fields(&header.consensus, &header.entity)?;
+
+        // The block without transactions is imported as any other block, so the importer
+        // checks its height and notifies the off-chain worker, `TxPool` and subscribers.
+        let SealedBlockHeader {
+            entity: header,
+            consensus,
+        } = header;
+        let block = SealedBlock {
+            entity: Block::header_only(header),
+            consensus,
+        };
+        let result = UncommittedImportResult::new(
+            ImportResult::new_from_network(block, vec![], vec![]),
+            Transactional::transaction(&self.database),
+        );
+        self.importer.block_importer.commit_result(result).await?;
+        Ok(())
+    }
+}
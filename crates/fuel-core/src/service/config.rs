@@ -1,3 +1,4 @@
+use crate::state::rocks_db_config::RocksDbConfig;
 use clap::ValueEnum;
 use fuel_core_chain_config::{
     default_consensus_dev_key,
@@ -30,6 +31,15 @@ use fuel_core_p2p::config::{
 #[cfg(feature = "relayer")]
 use fuel_core_relayer::Config as RelayerConfig;
 
+pub use crate::fuel_core_graphql_api::{
+    auth::{
+        AuthConfig,
+        Role,
+    },
+    health::ReadinessConfig,
+    QueryLimits,
+    RateLimit,
+};
 pub use fuel_core_consensus_module::RelayerConsensusConfig;
 pub use fuel_core_importer;
 pub use fuel_core_poa::Trigger;
@@ -41,6 +51,8 @@ pub struct Config {
     pub max_database_cache_size: usize,
     pub database_path: PathBuf,
     pub database_type: DbType,
+    /// The configuration of RocksDB, used if the `database_type` is `DbType::RocksDb`.
+    pub rocksdb: RocksDbConfig,
     pub chain_conf: ChainConfig,
     /// When `true`:
     /// - Enables manual block production.
@@ -60,6 +72,17 @@ pub struct Config {
     pub p2p: Option<P2PConfig<NotInitialized>>,
     #[cfg(feature = "p2p")]
     pub sync: fuel_core_sync::Config,
+    /// Enables the snap-sync of the state before the block import
+    /// if the node starts with an empty database.
+    #[cfg(feature = "p2p")]
+    pub snap_sync: Option<fuel_core_sync::snap_sync::Config>,
+    /// Runs the node as the light client that syncs only block headers
+    /// and fetches transactions from full peers on demand.
+    #[cfg(feature = "p2p")]
+    pub light_client: Option<fuel_core_sync::light_sync::Config>,
+    /// The number of blocks between snapshots served to peers.
+    /// Snapshots are not served if `None`.
+    pub snapshot_interval: Option<u32>,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
     pub name: String,
     pub relayer_consensus_config: fuel_core_consensus_module::RelayerConsensusConfig,
@@ -69,6 +92,28 @@ pub struct Config {
     pub time_until_synced: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// The thresholds used by the readiness health check.
+    pub readiness: ReadinessConfig,
+    /// The limits of the GraphQL queries.
+    pub query_limits: QueryLimits,
+    /// The rate limit of the GraphQL requests per client.
+    /// Requests are not limited if `None`.
+    pub api_rate_limit: Option<RateLimit>,
+    /// The credentials of the clients of the GraphQL API.
+    /// If both `api_auth` and `admin_addr` are `None`, all clients have the admin role.
+    pub api_auth: Option<AuthConfig>,
+    /// The address of the GraphQL API listener that grants the admin role to all clients.
+    pub admin_addr: Option<SocketAddr>,
+    /// The origin allowed to make cross-origin requests to the public GraphQL API.
+    pub api_cors_origin: String,
+    /// Enables the JSON-RPC API next to the GraphQL API.
+    pub json_rpc: bool,
+    /// The number of parsed GraphQL persisted queries cached by the node.
+    /// The persisted queries are disabled if it is `0`.
+    pub persisted_queries_cache_size: usize,
+    /// The directory where backups of the running node are created.
+    /// The backups via the GraphQL API are disabled if `None`.
+    pub backup_root: Option<PathBuf>,
 }
 
 impl Config {
@@ -88,6 +133,7 @@ impl Config {
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
+            rocksdb: Default::default(),
             debug: true,
             chain_conf: chain_conf.clone(),
             block_production: Trigger::Instant,
@@ -108,12 +154,26 @@ impl Config {
             p2p: Some(P2PConfig::<NotInitialized>::default("test_network")),
             #[cfg(feature = "p2p")]
             sync: fuel_core_sync::Config::default(),
+            #[cfg(feature = "p2p")]
+            snap_sync: None,
+            #[cfg(feature = "p2p")]
+            light_client: None,
+            snapshot_interval: None,
             consensus_key: Some(Secret::new(default_consensus_dev_key().into())),
             name: String::default(),
             relayer_consensus_config: Default::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             query_log_threshold_time: Duration::from_secs(2),
+            readiness: Default::default(),
+            query_limits: Default::default(),
+            api_rate_limit: None,
+            api_auth: None,
+            admin_addr: None,
+            api_cors_origin: "*".to_string(),
+            json_rpc: false,
+            persisted_queries_cache_size: 1000,
+            backup_root: None,
         }
     }
 
@@ -169,4 +229,5 @@ pub struct VMConfig {
 pub enum DbType {
     InMemory,
     RocksDb,
+    Redb,
 }
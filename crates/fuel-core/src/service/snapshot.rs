@@ -0,0 +1,171 @@
+//! The service that periodically takes snapshots of the on-chain database
+//! and serves them to peers performing the snap-sync.
+
+use crate::{
+    database::{
+        snapshot::Snapshot,
+        Database,
+    },
+    fuel_core_graphql_api::ports::worker::BlockImporter,
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    SharedMutex,
+    StateWatcher,
+};
+use fuel_core_types::services::block_importer::SharedImportResult;
+use futures::StreamExt;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+};
+
+/// The latest snapshot available for peers.
+#[derive(Clone)]
+pub struct SnapshotStore(SharedMutex<Option<Arc<Snapshot>>>);
+
+impl Default for SnapshotStore {
+    fn default() -> Self {
+        Self(SharedMutex::new(None))
+    }
+}
+
+impl SnapshotStore {
+    /// Returns the latest snapshot.
+    pub fn latest(&self) -> Option<Arc<Snapshot>> {
+        self.0.apply(|snapshot| snapshot.clone())
+    }
+
+    fn replace(&self, snapshot: Snapshot) {
+        // The previous snapshot is removed when the last reader drops it.
+        self.0.apply(|latest| *latest = Some(Arc::new(snapshot)));
+    }
+}
+
+pub struct Task {
+    block_importer: BoxStream<SharedImportResult>,
+    database: Database,
+    /// The directory for checkpoints of the database.
+    directory: PathBuf,
+    /// The number of blocks between snapshots.
+    interval: u32,
+    store: SnapshotStore,
+    counter: u64,
+}
+
+impl Task {
+    async fn take_snapshot(&mut self) -> anyhow::Result<()> {
+        let path = self.directory.join(self.counter.to_string());
+        self.counter = self.counter.wrapping_add(1);
+        let database = self.database.clone();
+
+        let snapshot = tokio::task::spawn_blocking(move || {
+            if path.exists() {
+                std::fs::remove_dir_all(&path)?;
+            }
+            database.checkpoint(&path)?;
+            let checkpoint = Database::open(&path, None)?.with_drop({
+                let path = path.clone();
+                Box::new(move || {
+                    let _ = std::fs::remove_dir_all(path);
+                })
+            });
+            Snapshot::new(checkpoint).map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        tracing::info!(
+            "The snapshot at height {} with root {} and {} chunks is available",
+            snapshot.manifest().height(),
+            snapshot.manifest().root,
+            snapshot.manifest().chunks_count
+        );
+        self.store.replace(snapshot);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "SnapshotService";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        // Removes checkpoints left after the previous run.
+        if self.directory.exists() {
+            std::fs::remove_dir_all(&self.directory)?;
+        }
+        std::fs::create_dir_all(&self.directory)?;
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.block_importer.next() => {
+                if let Some(result) = result {
+                    let height = *result.sealed_block.entity.header().height();
+                    let is_snapshot_height = *height > 0
+                        && height.checked_rem(self.interval) == Some(0);
+                    if is_snapshot_height {
+                        if let Err(err) = self.take_snapshot().await {
+                            tracing::error!("Failed to take the snapshot at {height}: {err:?}");
+                        }
+                    }
+                    should_continue = true
+                } else {
+                    should_continue = false
+                }
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service<I>(
+    block_importer: I,
+    database: Database,
+    directory: PathBuf,
+    interval: u32,
+    store: SnapshotStore,
+) -> ServiceRunner<Task>
+where
+    I: BlockImporter,
+{
+    let block_importer = block_importer.block_events();
+    ServiceRunner::new(Task {
+        block_importer,
+        database,
+        directory,
+        interval,
+        store,
+        counter: 0,
+    })
+}
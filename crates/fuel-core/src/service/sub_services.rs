@@ -12,17 +12,25 @@ use crate::{
             BlockProducerAdapter,
             ExecutorAdapter,
             MaybeRelayerAdapter,
+            NodeStatusAdapter,
             PoAAdapter,
             TxPoolAdapter,
             VerifierAdapter,
         },
         genesis::create_genesis_block,
         Config,
+        DbType,
         SharedState,
         SubServices,
     },
 };
 use fuel_core_poa::Trigger;
+use fuel_core_services::{
+    RunnableService,
+    Service,
+    ServiceRunner,
+    StateWatcher,
+};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -34,7 +42,8 @@ use fuel_core_types::blockchain::primitives::DaBlockHeight;
 pub type PoAService =
     fuel_core_poa::Service<TxPoolAdapter, BlockProducerAdapter, BlockImporterAdapter>;
 #[cfg(feature = "p2p")]
-pub type P2PService = fuel_core_p2p::service::Service<Database>;
+pub type P2PService =
+    fuel_core_p2p::service::Service<super::adapters::P2PDatabaseAdapter>;
 pub type TxPoolService = fuel_core_txpool::Service<P2PAdapter, Database>;
 pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
     Database,
@@ -100,16 +109,43 @@ pub fn init_sub_services(
         ),
     };
 
+    #[cfg(all(feature = "p2p", feature = "rocksdb"))]
+    let snapshot_service = config
+        .snapshot_interval
+        .filter(|_| config.p2p.is_some())
+        .and_then(|interval| {
+            if config.database_type != DbType::RocksDb {
+                tracing::warn!("Snapshots are served only with the RocksDB database");
+                return None
+            }
+            let store = crate::service::snapshot::SnapshotStore::default();
+            let service = crate::service::snapshot::new_service(
+                importer_adapter.clone(),
+                database.on_chain().clone(),
+                config.database_path.join("snapshots"),
+                interval,
+                store.clone(),
+            );
+            Some((service, store))
+        });
+
+    // The light client doesn't have the state to validate transactions,
+    // so it doesn't receive them from the network.
     #[cfg(feature = "p2p")]
-    let mut network = config.p2p.clone().map(|p2p_config| {
-        fuel_core_p2p::service::new_service(
-            config.chain_conf.consensus_parameters.chain_id,
-            p2p_config,
-            database.on_chain().clone(),
-            importer_adapter.clone(),
-        )
+    let p2p_config = config.p2p.clone().map(|mut p2p_config| {
+        if config.light_client.is_some() {
+            p2p_config.subscribe_to_transactions = false;
+        }
+        p2p_config
     });
 
+    // The shared state of the p2p is built before the service itself,
+    // because the txpool uses it, while the p2p serves transactions from the txpool.
+    #[cfg(feature = "p2p")]
+    let p2p_externals = p2p_config
+        .as_ref()
+        .map(fuel_core_p2p::service::build_shared_state);
+
     #[cfg(feature = "p2p")]
     let p2p_adapter = {
         use crate::service::adapters::PeerReportConfig;
@@ -122,9 +158,11 @@ pub fn init_sub_services(
             bad_block_header: -100.,
             missing_transactions: -100.,
             invalid_transactions: -100.,
+            missing_snapshot_chunks: -100.,
+            invalid_snapshot_data: -100.,
         };
         P2PAdapter::new(
-            network.as_ref().map(|network| network.shared.clone()),
+            p2p_externals.as_ref().map(|(shared, _)| shared.clone()),
             peer_report_config,
         )
     };
@@ -132,15 +170,50 @@ pub fn init_sub_services(
     #[cfg(not(feature = "p2p"))]
     let p2p_adapter = P2PAdapter::new();
 
+    // The `TxPool` of the light client neither syncs with peers nor gossips transactions.
+    #[cfg(feature = "p2p")]
+    let txpool_p2p_adapter = if config.light_client.is_some() {
+        p2p_adapter.without_network()
+    } else {
+        p2p_adapter.clone()
+    };
+    #[cfg(not(feature = "p2p"))]
+    let txpool_p2p_adapter = p2p_adapter.clone();
+
     let txpool = fuel_core_txpool::new_service(
         config.txpool.clone(),
         database.on_chain().clone(),
         importer_adapter.clone(),
-        p2p_adapter.clone(),
+        txpool_p2p_adapter,
         last_height,
     );
     let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
 
+    #[cfg(feature = "p2p")]
+    let mut network = p2p_config.zip(p2p_externals).map(
+        |(p2p_config, (shared_state, request_receiver))| {
+            #[cfg(feature = "rocksdb")]
+            let snapshot_provider = snapshot_service.as_ref().map(|(_, store)| {
+                Arc::new(store.clone()) as Arc<dyn fuel_core_p2p::ports::SnapshotProvider>
+            });
+            #[cfg(not(feature = "rocksdb"))]
+            let snapshot_provider = None;
+            fuel_core_p2p::service::new_service(
+                config.chain_conf.consensus_parameters.chain_id,
+                p2p_config,
+                shared_state,
+                request_receiver,
+                crate::service::adapters::P2PDatabaseAdapter::new(
+                    database.on_chain().clone(),
+                    database.off_chain().clone(),
+                ),
+                snapshot_provider,
+                Arc::new(tx_pool_adapter.clone()),
+                importer_adapter.clone(),
+            )
+        },
+    );
+
     let block_producer = fuel_core_producer::Producer {
         config: config.block_producer.clone(),
         view_provider: database.on_chain().clone(),
@@ -159,6 +232,13 @@ pub fn init_sub_services(
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
 
+    // The light client doesn't have the state to produce blocks.
+    #[cfg(feature = "p2p")]
+    if production_enabled && config.light_client.is_some() {
+        production_enabled = false;
+        tracing::info!("Disabled block production because the node is a light client");
+    }
+
     let poa = (production_enabled).then(|| {
         fuel_core_poa::new_service(
             &last_block_header,
@@ -172,17 +252,56 @@ pub fn init_sub_services(
     let poa_adapter = PoAAdapter::new(poa.as_ref().map(|service| service.shared.clone()));
 
     #[cfg(feature = "p2p")]
-    let sync = fuel_core_sync::service::new_service(
-        last_height,
-        p2p_adapter.clone(),
-        importer_adapter.clone(),
-        super::adapters::ConsensusAdapter::new(
+    let mut light_client = None;
+    #[cfg(feature = "p2p")]
+    let (sync, sync_state) = {
+        let consensus_adapter = super::adapters::ConsensusAdapter::new(
             verifier.clone(),
             config.relayer_consensus_config.clone(),
             relayer_adapter,
-        ),
-        config.sync,
-    )?;
+        );
+        // The snapshot replaces the state, so it is imported only by the node
+        // that doesn't have blocks after the genesis.
+        let is_at_genesis =
+            last_height == *create_genesis_block(config).header().height();
+        match (config.light_client, config.snap_sync) {
+            (Some(light_client_config), _) => {
+                let sync = fuel_core_sync::service::new_light_service(
+                    last_height,
+                    p2p_adapter.clone(),
+                    consensus_adapter,
+                    Arc::new(super::adapters::HeaderImporterAdapter::new(
+                        database.on_chain().clone(),
+                        importer_adapter.clone(),
+                        verifier.clone(),
+                    )),
+                    light_client_config,
+                )?;
+                light_client = Some(sync.shared.clone());
+                boxed_service(sync)
+            }
+            (None, Some(snap_sync_config)) if is_at_genesis => {
+                boxed_service(fuel_core_sync::service::new_service_with_snap_sync(
+                    last_height,
+                    p2p_adapter.clone(),
+                    importer_adapter.clone(),
+                    consensus_adapter,
+                    Arc::new(super::adapters::SnapshotImporterAdapter::new(
+                        database.clone(),
+                    )),
+                    snap_sync_config,
+                    config.sync,
+                )?)
+            }
+            _ => boxed_service(fuel_core_sync::service::new_service(
+                last_height,
+                p2p_adapter.clone(),
+                importer_adapter.clone(),
+                consensus_adapter,
+                config.sync,
+            )?),
+        }
+    };
 
     // TODO: Figure out on how to move it into `fuel-core-graphql-api`.
     let schema = crate::schema::dap::init(
@@ -190,13 +309,33 @@ pub fn init_sub_services(
         config.chain_conf.consensus_parameters.clone(),
         config.debug,
     )
-    .data(database.on_chain().clone());
+    .data(database.on_chain().clone())
+    .data(database.clone());
+    #[cfg(feature = "p2p")]
+    let schema = match light_client {
+        Some(light_client) => {
+            let light_client: fuel_core_graphql_api::api_service::LightClient =
+                Arc::new(light_client);
+            schema.data(light_client)
+        }
+        None => schema,
+    };
 
     let graphql_worker = fuel_core_graphql_api::worker_service::new_service(
         importer_adapter.clone(),
         database.off_chain().clone(),
+        *config.chain_conf.consensus_parameters.base_asset_id(),
     );
 
+    // The light client doesn't have the state to serve the JSON-RPC methods.
+    #[allow(unused_mut)]
+    let mut json_rpc = config.json_rpc;
+    #[cfg(feature = "p2p")]
+    if json_rpc && config.light_client.is_some() {
+        json_rpc = false;
+        tracing::info!("Disabled the JSON-RPC API because the node is a light client");
+    }
+
     let graphql_config = GraphQLConfig {
         addr: config.addr,
         utxo_validation: config.utxo_validation,
@@ -208,17 +347,62 @@ pub fn init_sub_services(
         chain_name: config.chain_conf.chain_name.clone(),
         consensus_parameters: config.chain_conf.consensus_parameters.clone(),
         consensus_key: config.consensus_key.clone(),
+        database_config: (cfg!(feature = "rocksdb")
+            && config.database_type == DbType::RocksDb)
+            .then(|| config.rocksdb.clone()),
+        query_limits: config.query_limits,
+        rate_limit: config.api_rate_limit,
+        auth: config.api_auth.clone(),
+        admin_addr: config.admin_addr,
+        cors_origin: config.api_cors_origin.clone(),
+        json_rpc,
+        persisted_queries_cache_size: config.persisted_queries_cache_size,
+        backup_root: config.backup_root.clone(),
     };
 
+    // The GraphQL service reports the state of all other services.
+    let mut service_states = vec![state_of(&txpool)];
+    if let Some(poa) = &poa {
+        service_states.push(state_of(poa));
+    }
+    #[cfg(feature = "relayer")]
+    if let Some(relayer) = &relayer_service {
+        service_states.push(state_of(relayer));
+    }
+    #[cfg(feature = "p2p")]
+    {
+        if let Some(network) = &network {
+            service_states.push(state_of(network));
+            service_states.push(sync_state);
+        }
+        #[cfg(feature = "rocksdb")]
+        if let Some((snapshot_service, _)) = &snapshot_service {
+            service_states.push(state_of(snapshot_service));
+        }
+    }
+    service_states.push(state_of(&graphql_worker));
+
+    let health_check = fuel_core_graphql_api::health::HealthCheck::new(
+        Box::new(NodeStatusAdapter {
+            services: service_states,
+            database: database.on_chain().clone(),
+            p2p: p2p_adapter.clone(),
+            #[cfg(feature = "relayer")]
+            relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
+        }),
+        config.readiness,
+    );
+
     let graph_ql = fuel_core_graphql_api::api_service::new_service(
         graphql_config,
         schema,
         database.on_chain().clone(),
         database.off_chain().clone(),
-        Box::new(tx_pool_adapter),
-        Box::new(producer_adapter),
+        Arc::new(tx_pool_adapter),
+        Arc::new(producer_adapter),
         Box::new(poa_adapter.clone()),
         Box::new(p2p_adapter),
+        health_check,
         config.query_log_threshold_time,
         config.api_request_timeout,
     )?;
@@ -257,7 +441,11 @@ pub fn init_sub_services(
     {
         if let Some(network) = network.take() {
             services.push(Box::new(network));
-            services.push(Box::new(sync));
+            services.push(sync);
+        }
+        #[cfg(feature = "rocksdb")]
+        if let Some((snapshot_service, _)) = snapshot_service {
+            services.push(Box::new(snapshot_service));
         }
     }
 
@@ -265,3 +453,25 @@ pub fn init_sub_services(
 
     Ok((services, shared))
 }
+
+/// Boxes the service and takes its state for the health checks.
+#[cfg(feature = "p2p")]
+fn boxed_service<S>(
+    service: ServiceRunner<S>,
+) -> (
+    Box<dyn Service + Send + Sync + 'static>,
+    (&'static str, StateWatcher),
+)
+where
+    S: RunnableService + 'static,
+{
+    let state = state_of(&service);
+    (Box::new(service), state)
+}
+
+fn state_of<S>(service: &ServiceRunner<S>) -> (&'static str, StateWatcher)
+where
+    S: RunnableService + 'static,
+{
+    (S::NAME, service.state_watcher())
+}
@@ -20,12 +20,18 @@ use fuel_core_storage::{
 };
 use std::{
     fmt::Debug,
+    path::Path,
     sync::Arc,
 };
 
 pub mod in_memory;
+#[cfg(feature = "redb")]
+pub mod redb_store;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
+pub mod rocks_db_config;
+#[cfg(test)]
+pub(crate) mod storage_tests;
 
 type DataSourceInner<Column> = Arc<dyn TransactableStorage<Column = Column>>;
 
@@ -54,6 +60,17 @@ where
     }
 }
 
+#[cfg(feature = "redb")]
+impl<Description> From<Arc<redb_store::RedbStore<Description>>>
+    for DataSource<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn from(inner: Arc<redb_store::RedbStore<Description>>) -> Self {
+        Self(inner)
+    }
+}
+
 impl<Description> From<Arc<MemoryStore<Description>>> for DataSource<Description>
 where
     Description: DatabaseDescription,
@@ -87,4 +104,10 @@ pub trait TransactableStorage:
     IteratorableStore + BatchOperations + Debug + Send + Sync
 {
     fn flush(&self) -> DatabaseResult<()>;
+
+    /// Creates a consistent point-in-time copy of the storage inside of the `path` directory.
+    /// The storage is not blocked during the checkpoint and continues to accept writes.
+    fn checkpoint(&self, _path: &Path) -> DatabaseResult<()> {
+        Err(anyhow::anyhow!("The storage doesn't support checkpoints").into())
+    }
 }
@@ -173,6 +173,25 @@ where
         self.take(key, column).map(|_| ())
     }
 
+    fn compare_and_swap(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        expected: Option<&[u8]>,
+        new: Option<Value>,
+    ) -> StorageResult<Result<(), Option<Value>>> {
+        let mut lock = self.inner[column.as_usize()].lock().expect("poisoned");
+        let current = lock.get(key);
+        if current.map(|value| value.as_slice()) != expected {
+            return Ok(Err(current.cloned()))
+        }
+        match new {
+            Some(value) => lock.insert(key.to_vec(), value),
+            None => lock.remove(key),
+        };
+        Ok(Ok(()))
+    }
+
     fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
         Ok(self.inner[column.as_usize()]
             .lock()
@@ -292,4 +311,28 @@ mod tests {
 
         assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
+
+    #[test]
+    fn compare_and_swap_is_atomic() {
+        let db = MemoryStore::<OnChain>::default();
+        crate::state::storage_tests::compare_and_swap_is_atomic(&db);
+    }
+
+    #[test]
+    fn compare_and_swap_returns_current_value() {
+        let db = MemoryStore::<OnChain>::default();
+        crate::state::storage_tests::compare_and_swap_returns_current_value(&db);
+    }
+
+    #[test]
+    fn take_is_atomic() {
+        let db = MemoryStore::<OnChain>::default();
+        crate::state::storage_tests::take_is_atomic(&db);
+    }
+
+    #[test]
+    fn replace_is_atomic() {
+        let db = MemoryStore::<OnChain>::default();
+        crate::state::storage_tests::replace_is_atomic(&db);
+    }
 }
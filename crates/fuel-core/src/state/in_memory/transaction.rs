@@ -88,6 +88,21 @@ where
 
         self.data_source.batch_write(&mut iter)
     }
+
+    /// Returns the value visible inside of the transaction. The caller holds
+    /// the lock of `changes`, so the value can't be modified concurrently.
+    fn get_with_changes(
+        &self,
+        changes: &HashMap<Vec<u8>, WriteOperation>,
+        key: &[u8],
+        column: Description::Column,
+    ) -> StorageResult<Option<Value>> {
+        if changes.contains_key(key) {
+            self.view_layer.get(key, column)
+        } else {
+            self.data_source.get(key, column)
+        }
+    }
 }
 
 impl<Description> KeyValueStore for MemoryTransactionView<Description>
@@ -102,18 +117,13 @@ where
         column: Self::Column,
         value: Value,
     ) -> StorageResult<Option<Value>> {
-        let key_vec = key.to_vec();
-        let contained_key = self.changes[column.as_usize()]
+        let mut changes = self.changes[column.as_usize()]
             .lock()
-            .expect("poisoned lock")
-            .insert(key_vec, WriteOperation::Insert(value.clone()))
-            .is_some();
-        let res = self.view_layer.replace(key, column, value);
-        if contained_key {
-            res
-        } else {
-            self.data_source.get(key, column)
-        }
+            .expect("poisoned lock");
+        let previous = self.get_with_changes(&changes, key, column)?;
+        changes.insert(key.to_vec(), WriteOperation::Insert(value.clone()));
+        self.view_layer.put(key, column, value)?;
+        Ok(previous)
     }
 
     fn write(
@@ -122,39 +132,58 @@ where
         column: Self::Column,
         buf: &[u8],
     ) -> StorageResult<usize> {
-        let k = key.to_vec();
-        self.changes[column.as_usize()]
+        let mut changes = self.changes[column.as_usize()]
             .lock()
-            .expect("poisoned lock")
-            .insert(k, WriteOperation::Insert(Arc::new(buf.to_vec())));
+            .expect("poisoned lock");
+        changes.insert(key.to_vec(), WriteOperation::Insert(Arc::new(buf.to_vec())));
         self.view_layer.write(key, column, buf)
     }
 
     fn take(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
-        let k = key.to_vec();
-        let contained_key = {
-            let mut lock = self.changes[column.as_usize()]
-                .lock()
-                .expect("poisoned lock");
-            lock.insert(k, WriteOperation::Remove).is_some()
-        };
-        let res = self.view_layer.take(key, column);
-        if contained_key {
-            res
-        } else {
-            self.data_source.get(key, column)
-        }
+        let mut changes = self.changes[column.as_usize()]
+            .lock()
+            .expect("poisoned lock");
+        let previous = self.get_with_changes(&changes, key, column)?;
+        changes.insert(key.to_vec(), WriteOperation::Remove);
+        self.view_layer.delete(key, column)?;
+        Ok(previous)
     }
 
     fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
-        let k = key.to_vec();
-        self.changes[column.as_usize()]
+        let mut changes = self.changes[column.as_usize()]
             .lock()
-            .expect("poisoned lock")
-            .insert(k, WriteOperation::Remove);
+            .expect("poisoned lock");
+        changes.insert(key.to_vec(), WriteOperation::Remove);
         self.view_layer.delete(key, column)
     }
 
+    fn compare_and_swap(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        expected: Option<&[u8]>,
+        new: Option<Value>,
+    ) -> StorageResult<Result<(), Option<Value>>> {
+        let mut changes = self.changes[column.as_usize()]
+            .lock()
+            .expect("poisoned lock");
+        let current = self.get_with_changes(&changes, key, column)?;
+        if current.as_deref().map(Vec::as_slice) != expected {
+            return Ok(Err(current))
+        }
+        match new {
+            Some(value) => {
+                changes.insert(key.to_vec(), WriteOperation::Insert(value.clone()));
+                self.view_layer.put(key, column, value)?;
+            }
+            None => {
+                changes.insert(key.to_vec(), WriteOperation::Remove);
+                self.view_layer.delete(key, column)?;
+            }
+        }
+        Ok(Ok(()))
+    }
+
     fn size_of_value(
         &self,
         key: &[u8],
@@ -705,4 +734,28 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    fn compare_and_swap_is_atomic() {
+        let db = MemoryTransactionView::new(Arc::new(MemoryStore::default()));
+        crate::state::storage_tests::compare_and_swap_is_atomic(&db);
+    }
+
+    #[test]
+    fn compare_and_swap_returns_current_value() {
+        let db = MemoryTransactionView::new(Arc::new(MemoryStore::default()));
+        crate::state::storage_tests::compare_and_swap_returns_current_value(&db);
+    }
+
+    #[test]
+    fn take_is_atomic() {
+        let db = MemoryTransactionView::new(Arc::new(MemoryStore::default()));
+        crate::state::storage_tests::take_is_atomic(&db);
+    }
+
+    #[test]
+    fn replace_is_atomic() {
+        let db = MemoryTransactionView::new(Arc::new(MemoryStore::default()));
+        crate::state::storage_tests::replace_is_atomic(&db);
+    }
 }
@@ -0,0 +1,513 @@
+use crate::{
+    database::{
+        database_description::DatabaseDescription,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        BatchOperations,
+        IterDirection,
+        TransactableStorage,
+    },
+};
+use fuel_core_metrics::core_metrics::database_metrics;
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IteratorableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyValueStore,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    Result as StorageResult,
+};
+use redb::{
+    ReadableTable,
+    TableDefinition,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+    ops::Bound,
+    path::Path,
+    sync::Arc,
+};
+
+type RedbItem<'a> = Result<
+    (
+        redb::AccessGuard<'a, &'static [u8]>,
+        redb::AccessGuard<'a, &'static [u8]>,
+    ),
+    redb::StorageError,
+>;
+
+/// The name of the file with the data inside of the database directory.
+const DATA_FILE: &str = "data.redb";
+
+/// The storage backed by the embedded pure-Rust [`redb`] engine.
+///
+/// Each column is stored in a separate table. Write transactions of `redb`
+/// are serialized, so each write operation is atomic.
+pub struct RedbStore<Description> {
+    db: redb::Database,
+    _marker: core::marker::PhantomData<Description>,
+}
+
+impl<Description> Debug for RedbStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedbStore")
+            .field("name", &Description::name())
+            .finish()
+    }
+}
+
+impl<Description> RedbStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// Opens the database inside of the `path` directory, the same as
+    /// `RocksDb::default_open` does, creating it if necessary.
+    pub fn default_open<P: AsRef<Path>>(path: P) -> DatabaseResult<Self> {
+        let path = path.as_ref().join(Description::name());
+        std::fs::create_dir_all(&path).map_err(|e| {
+            anyhow::anyhow!("Unable to create the directory {}: {}", path.display(), e)
+        })?;
+        Self::open(path.join(DATA_FILE))
+    }
+
+    /// Opens the database from the `file`, creating it if necessary.
+    pub fn open<P: AsRef<Path>>(file: P) -> DatabaseResult<Self> {
+        let db = redb::Database::create(file.as_ref())
+            .map_err(|e| anyhow::anyhow!("Unable to open the database: {}", e))?;
+
+        // All tables are created in advance to not handle their absence during reading.
+        let tx = db.begin_write().map_err(into_database_error)?;
+        for column in enum_iterator::all::<Description::Column>() {
+            tx.open_table(Self::table(&Self::col_name(column)))
+                .map_err(into_database_error)?;
+        }
+        tx.commit().map_err(into_database_error)?;
+
+        Ok(Self {
+            db,
+            _marker: Default::default(),
+        })
+    }
+
+    fn col_name(column: Description::Column) -> String {
+        format!("col-{}", column.as_usize())
+    }
+
+    fn table(name: &str) -> TableDefinition<'_, &'static [u8], &'static [u8]> {
+        TableDefinition::new(name)
+    }
+
+    /// Applies the `f` to the table of the `column` inside of the write transaction
+    /// and commits it.
+    fn write_with<R>(
+        &self,
+        column: Description::Column,
+        f: impl FnOnce(
+            &mut redb::Table<&'static [u8], &'static [u8]>,
+        ) -> Result<R, redb::Error>,
+    ) -> StorageResult<R> {
+        let name = Self::col_name(column);
+        let tx = self.db.begin_write().map_err(into_database_error)?;
+        let result = {
+            let mut table = tx
+                .open_table(Self::table(&name))
+                .map_err(into_database_error)?;
+            f(&mut table).map_err(into_database_error)?
+        };
+        tx.commit().map_err(into_database_error)?;
+        database_metrics().write_meter.inc();
+        Ok(result)
+    }
+
+    fn read_table(
+        &self,
+        column: Description::Column,
+    ) -> StorageResult<redb::ReadOnlyTable<&'static [u8], &'static [u8]>> {
+        let name = Self::col_name(column);
+        let tx = self.db.begin_read().map_err(into_database_error)?;
+        let table = tx
+            .open_table(Self::table(&name))
+            .map_err(into_database_error)?;
+        Ok(table)
+    }
+
+    /// Returns the lazy iterator over the range of the `column` defined by
+    /// the `prefix`, `start` and `direction`. The iterator holds the read transaction,
+    /// so it sees the snapshot of the table at the moment of creation.
+    fn iter_range(
+        &self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> StorageResult<BoxedIter<'static, KVItem>> {
+        fn clone(item: RedbItem) -> KVItem {
+            let (key, value) = item.map_err(into_database_error)?;
+            Ok((key.value().to_vec(), Arc::new(value.value().to_vec())))
+        }
+
+        let prefix_end = prefix.and_then(prefix_end);
+        let lower = match (direction, start, prefix) {
+            (IterDirection::Forward, Some(start), _) => Bound::Included(start),
+            (_, _, Some(prefix)) => Bound::Included(prefix),
+            _ => Bound::Unbounded,
+        };
+        let upper = match (direction, start, prefix_end.as_deref()) {
+            (IterDirection::Reverse, Some(start), _) => Bound::Included(start),
+            (_, _, Some(prefix_end)) => Bound::Excluded(prefix_end),
+            _ => Bound::Unbounded,
+        };
+
+        let range = self
+            .read_table(column)?
+            .range::<&[u8]>((lower, upper))
+            .map_err(into_database_error)?;
+
+        let iter = match direction {
+            IterDirection::Forward => range.map(clone).into_boxed(),
+            IterDirection::Reverse => range.rev().map(clone).into_boxed(),
+        };
+        Ok(iter)
+    }
+}
+
+/// Returns the smallest key that is greater than all keys with the `prefix`.
+/// The `None` means that all keys after the `prefix` have it.
+fn prefix_end(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut end = prefix.to_vec();
+    while let Some(byte) = end.pop() {
+        if let Some(byte) = byte.checked_add(1) {
+            end.push(byte);
+            return Some(end)
+        }
+    }
+    None
+}
+
+fn into_database_error<E>(e: E) -> DatabaseError
+where
+    E: Into<redb::Error>,
+{
+    DatabaseError::Other(anyhow::anyhow!(e.into()))
+}
+
+impl<Description> KeyValueStore for RedbStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        value: Value,
+    ) -> StorageResult<Option<Value>> {
+        database_metrics().bytes_written.observe(value.len() as f64);
+        self.write_with(column, |table| {
+            let prev = table.insert(key, value.as_slice())?;
+            Ok(prev.map(|prev| Arc::new(prev.value().to_vec())))
+        })
+    }
+
+    fn write(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &[u8],
+    ) -> StorageResult<usize> {
+        database_metrics().bytes_written.observe(buf.len() as f64);
+        self.write_with(column, |table| {
+            table.insert(key, buf)?;
+            Ok(buf.len())
+        })
+    }
+
+    fn take(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.write_with(column, |table| {
+            let prev = table.remove(key)?;
+            Ok(prev.map(|prev| Arc::new(prev.value().to_vec())))
+        })
+    }
+
+    fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
+        self.take(key, column).map(|_| ())
+    }
+
+    fn compare_and_swap(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        expected: Option<&[u8]>,
+        new: Option<Value>,
+    ) -> StorageResult<Result<(), Option<Value>>> {
+        self.write_with(column, |table| {
+            let current = table.get(key)?.map(|value| value.value().to_vec());
+            if current.as_deref() != expected {
+                return Ok(Err(current.map(Arc::new)))
+            }
+            match new {
+                Some(value) => {
+                    table.insert(key, value.as_slice())?;
+                }
+                None => {
+                    table.remove(key)?;
+                }
+            }
+            Ok(Ok(()))
+        })
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        database_metrics().read_meter.inc();
+
+        let value = self
+            .read_table(column)?
+            .get(key)
+            .map_err(into_database_error)?
+            .map(|value| value.value().to_vec());
+
+        if let Some(value) = &value {
+            database_metrics().bytes_read.observe(value.len() as f64);
+        }
+
+        Ok(value.map(Arc::new))
+    }
+}
+
+impl<Description> IteratorableStore for RedbStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_all(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        // If the `start` doesn't have the same `prefix`, return nothing,
+        // the same as `RocksDb` does.
+        if let (Some(prefix), Some(start)) = (prefix, start) {
+            if !start.starts_with(prefix) {
+                return core::iter::empty().into_boxed()
+            }
+        }
+
+        match self.iter_range(column, prefix, start, direction) {
+            Ok(iter) => iter,
+            Err(e) => core::iter::once(Err(e)).into_boxed(),
+        }
+    }
+}
+
+impl<Description> BatchOperations for RedbStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Self::Column, WriteOperation)>,
+    ) -> StorageResult<()> {
+        let tx = self.db.begin_write().map_err(into_database_error)?;
+        let mut bytes_written = 0usize;
+
+        for (key, column, op) in entries {
+            let name = Self::col_name(column);
+            let mut table = tx
+                .open_table(Self::table(&name))
+                .map_err(into_database_error)?;
+            match op {
+                WriteOperation::Insert(value) => {
+                    bytes_written = bytes_written.saturating_add(value.len());
+                    table
+                        .insert(key.as_slice(), value.as_slice())
+                        .map_err(into_database_error)?;
+                }
+                WriteOperation::Remove => {
+                    table.remove(key.as_slice()).map_err(into_database_error)?;
+                }
+            }
+        }
+
+        tx.commit().map_err(into_database_error)?;
+
+        database_metrics().write_meter.inc();
+        database_metrics()
+            .bytes_written
+            .observe(bytes_written as f64);
+
+        Ok(())
+    }
+}
+
+impl<Description> TransactableStorage for RedbStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn flush(&self) -> DatabaseResult<()> {
+        // Each transaction is durable after the commit.
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::database_description::on_chain::OnChain;
+    use fuel_core_storage::column::Column;
+    use tempfile::TempDir;
+
+    fn create_db() -> (RedbStore<OnChain>, TempDir) {
+        let tmp_dir = TempDir::new().unwrap();
+        (RedbStore::default_open(tmp_dir.path()).unwrap(), tmp_dir)
+    }
+
+    #[test]
+    fn can_reopen_with_data() {
+        let key = vec![0xA, 0xB, 0xC];
+        let expected = Arc::new(vec![1, 2, 3]);
+
+        let (db, tmp) = create_db();
+        db.put(&key, Column::Metadata, expected.clone()).unwrap();
+        drop(db);
+
+        let db = RedbStore::<OnChain>::default_open(tmp.path()).unwrap();
+        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected)
+    }
+
+    #[test]
+    fn can_put_and_read() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::can_put_and_read(&db);
+    }
+
+    #[test]
+    fn put_returns_previous_value() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::put_returns_previous_value(&db);
+    }
+
+    #[test]
+    fn delete_and_get() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::delete_and_get(&db);
+    }
+
+    #[test]
+    fn key_exists() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::key_exists(&db);
+    }
+
+    #[test]
+    fn batch_write_inserts() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::batch_write_inserts(&db);
+    }
+
+    #[test]
+    fn batch_write_removes() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::batch_write_removes(&db);
+    }
+
+    #[test]
+    fn iter_all_respects_prefix_start_and_direction() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::iter_all_respects_prefix_start_and_direction(&db);
+    }
+
+    #[test]
+    fn iter_all_stops_at_the_end_of_prefix_with_max_byte() {
+        let (db, _tmp) = create_db();
+        for key in [vec![1, 0xFF], vec![1, 0xFF, 0], vec![2, 0]] {
+            db.put(&key, Column::Metadata, Arc::new(vec![])).unwrap();
+        }
+
+        let keys = |direction| {
+            db.iter_all(Column::Metadata, Some(&[1, 0xFF]), None, direction)
+                .map(|item| item.unwrap().0)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            keys(IterDirection::Forward),
+            vec![vec![1, 0xFF], vec![1, 0xFF, 0]]
+        );
+        assert_eq!(
+            keys(IterDirection::Reverse),
+            vec![vec![1, 0xFF, 0], vec![1, 0xFF]]
+        );
+    }
+
+    #[test]
+    fn iter_all_reads_the_snapshot_lazily() {
+        let (db, _tmp) = create_db();
+        db.put(&[1], Column::Metadata, Arc::new(vec![])).unwrap();
+
+        let mut iter = db.iter_all(Column::Metadata, None, None, IterDirection::Forward);
+        db.put(&[2], Column::Metadata, Arc::new(vec![])).unwrap();
+
+        assert_eq!(iter.next().unwrap().unwrap().0, vec![1]);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn can_use_unit_value() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::can_use_key_and_value(&db, vec![0x00], vec![]);
+    }
+
+    #[test]
+    fn can_use_unit_key() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::can_use_key_and_value(&db, vec![], vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn can_use_unit_key_and_value() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::can_use_key_and_value(&db, vec![], vec![]);
+    }
+
+    #[test]
+    fn compare_and_swap_is_atomic() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::compare_and_swap_is_atomic(&db);
+    }
+
+    #[test]
+    fn compare_and_swap_returns_current_value() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::compare_and_swap_returns_current_value(&db);
+    }
+
+    #[test]
+    fn take_is_atomic() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::take_is_atomic(&db);
+    }
+
+    #[test]
+    fn replace_is_atomic() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::replace_is_atomic(&db);
+    }
+}
@@ -6,6 +6,11 @@ use crate::{
         Result as DatabaseResult,
     },
     state::{
+        rocks_db_config::{
+            CompactionStyle,
+            Compression,
+            RocksDbConfig,
+        },
         BatchOperations,
         IterDirection,
         TransactableStorage,
@@ -29,10 +34,12 @@ use fuel_core_storage::{
 };
 use rand::RngCore;
 use rocksdb::{
+    checkpoint::Checkpoint,
     BlockBasedOptions,
     BoundColumnFamily,
     Cache,
     ColumnFamilyDescriptor,
+    DBCompactionStyle,
     DBCompressionType,
     DBWithThreadMode,
     IteratorMode,
@@ -44,18 +51,69 @@ use rocksdb::{
 };
 use std::{
     cmp,
+    collections::BTreeSet,
     env,
     fmt::Debug,
+    hash::{
+        Hash,
+        Hasher,
+    },
     iter,
     path::{
         Path,
         PathBuf,
     },
-    sync::Arc,
+    sync::{
+        Arc,
+        Mutex,
+        MutexGuard,
+    },
 };
 
 type DB = DBWithThreadMode<MultiThreaded>;
 
+/// The number of stripes in the [`KeyLocks`].
+const KEY_LOCK_STRIPES: usize = 256;
+
+/// Striped locks over the keys of the database.
+///
+/// RocksDB doesn't support atomic read-modify-write operations without transactions.
+/// All writes lock the stripe of the key, making `replace`, `take`, and
+/// `compare_and_swap` atomic relative to other writes.
+#[derive(Debug)]
+struct KeyLocks(Vec<Mutex<()>>);
+
+impl KeyLocks {
+    fn new() -> Self {
+        Self((0..KEY_LOCK_STRIPES).map(|_| Mutex::new(())).collect())
+    }
+
+    fn stripe(column: u32, key: &[u8]) -> usize {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        column.hash(&mut hasher);
+        key.hash(&mut hasher);
+        let stripe = hasher
+            .finish()
+            .checked_rem(KEY_LOCK_STRIPES as u64)
+            .expect("`KEY_LOCK_STRIPES` is not zero");
+        usize::try_from(stripe).expect("The stripe is less than `KEY_LOCK_STRIPES`")
+    }
+
+    fn lock(&self, column: u32, key: &[u8]) -> MutexGuard<()> {
+        self.0[Self::stripe(column, key)]
+            .lock()
+            .expect("poisoned lock")
+    }
+
+    /// Locks stripes in the ascending order to avoid deadlocks.
+    fn lock_many(&self, stripes: BTreeSet<usize>) -> Vec<MutexGuard<()>> {
+        stripes
+            .into_iter()
+            .map(|stripe| self.0[stripe].lock().expect("poisoned lock"))
+            .collect()
+    }
+}
+
 /// Reimplementation of `tempdir::TempDir` that allows creating a new
 /// instance without actually creating a new directory on the filesystem.
 /// This is needed since rocksdb requires empty directory for checkpoints.
@@ -94,6 +152,7 @@ impl Drop for ShallowTempDir {
 #[derive(Debug)]
 pub struct RocksDb<Description> {
     db: DB,
+    locks: KeyLocks,
     _marker: core::marker::PhantomData<Description>,
 }
 
@@ -104,11 +163,20 @@ where
     pub fn default_open<P: AsRef<Path>>(
         path: P,
         capacity: Option<usize>,
+    ) -> DatabaseResult<Self> {
+        Self::default_open_with_config(path, capacity, &RocksDbConfig::default())
+    }
+
+    pub fn default_open_with_config<P: AsRef<Path>>(
+        path: P,
+        capacity: Option<usize>,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<Self> {
         Self::open(
             path,
             enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
             capacity,
+            config,
         )
     }
 
@@ -116,46 +184,47 @@ where
         path: P,
         columns: Vec<Description::Column>,
         capacity: Option<usize>,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<Self> {
+        config.validate().map_err(DatabaseError::Other)?;
+
         let path = path.as_ref().join(Description::name());
-        let mut block_opts = BlockBasedOptions::default();
-        // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
-        block_opts.set_format_version(5);
 
-        if let Some(capacity) = capacity {
-            // Set cache size 1/3 of the capacity as recommended by
-            // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
-            let block_cache_size = capacity / 3;
-            let cache = Cache::new_lru_cache(block_cache_size);
-            block_opts.set_block_cache(&cache);
-            // "index and filter blocks will be stored in block cache, together with all other data blocks."
-            // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
-            block_opts.set_cache_index_and_filter_blocks(true);
-            // Don't evict L0 filter/index blocks from the cache
-            block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
-        } else {
-            block_opts.disable_cache();
-        }
-        block_opts.set_bloom_filter(10.0, true);
+        // Set cache size 1/3 of the capacity as recommended by
+        // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
+        // by default, but the split is configurable.
+        let block_cache = capacity
+            .map(|capacity| Cache::new_lru_cache(config.block_cache_size(capacity)));
+
+        let cf_opts = |column: Description::Column| {
+            Self::cf_opts(column, config, block_cache.as_ref())
+        };
 
-        let cf_descriptors = columns.clone().into_iter().map(|i| {
-            ColumnFamilyDescriptor::new(Self::col_name(i), Self::cf_opts(i, &block_opts))
-        });
+        let cf_descriptors = columns
+            .clone()
+            .into_iter()
+            .map(|i| ColumnFamilyDescriptor::new(Self::col_name(i), cf_opts(i)));
 
         let mut opts = Options::default();
         opts.create_if_missing(true);
-        opts.set_compression_type(DBCompressionType::Lz4);
-        // TODO: Make it customizable https://github.com/FuelLabs/fuel-core/issues/1666
-        opts.set_max_total_wal_size(64 * 1024 * 1024);
+        opts.set_compression_type(config.compression.into());
+        opts.set_compaction_style(config.compaction_style.into());
+        opts.set_max_total_wal_size(config.max_total_wal_size);
+        if let Some(rate_limit) = config.rate_limit_bytes_per_sec {
+            let rate_limit =
+                i64::try_from(rate_limit).expect("The rate limit is validated above");
+            // The default refill period and fairness recommended by RocksDB.
+            opts.set_ratelimiter(rate_limit, 100_000, 10);
+        }
         let cpu_number =
             i32::try_from(num_cpus::get()).expect("The number of CPU can't exceed `i32`");
         opts.increase_parallelism(cmp::max(1, cpu_number / 2));
         if let Some(capacity) = capacity {
-            // Set cache size 1/3 of the capacity. Another 1/3 is
-            // used by block cache and the last 1 / 3 remains for other purposes:
+            // Another part of the capacity is used by row cache and
+            // the rest remains for other purposes:
             //
             // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
-            let row_cache_size = capacity / 3;
+            let row_cache_size = config.row_cache_size(capacity);
             let cache = Cache::new_lru_cache(row_cache_size);
             opts.set_row_cache(&cache);
         }
@@ -166,7 +235,7 @@ where
                 match DB::open_cf(&opts, &path, &[] as &[&str]) {
                     Ok(db) => {
                         for i in columns {
-                            let opts = Self::cf_opts(i, &block_opts);
+                            let opts = cf_opts(i);
                             db.create_cf(Self::col_name(i), &opts)
                                 .map_err(|e| DatabaseError::Other(e.into()))?;
                         }
@@ -178,10 +247,7 @@ where
                             .map_err(|e| DatabaseError::Other(e.into()))?;
 
                         let cf_descriptors = columns.clone().into_iter().map(|i| {
-                            ColumnFamilyDescriptor::new(
-                                Self::col_name(i),
-                                Self::cf_opts(i, &block_opts),
-                            )
+                            ColumnFamilyDescriptor::new(Self::col_name(i), cf_opts(i))
                         });
                         DB::open_cf_descriptors(&opts, &path, cf_descriptors)
                     }
@@ -192,6 +258,7 @@ where
         .map_err(|e| DatabaseError::Other(e.into()))?;
         let rocks_db = RocksDb {
             db,
+            locks: KeyLocks::new(),
             _marker: Default::default(),
         };
         Ok(rocks_db)
@@ -207,11 +274,37 @@ where
         format!("col-{}", column.as_usize())
     }
 
-    fn cf_opts(column: Description::Column, block_opts: &BlockBasedOptions) -> Options {
+    fn cf_opts(
+        column: Description::Column,
+        config: &RocksDbConfig,
+        block_cache: Option<&Cache>,
+    ) -> Options {
+        let column_options = config.column_options::<Description>(&column);
+
+        let mut block_opts = BlockBasedOptions::default();
+        // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
+        block_opts.set_format_version(5);
+        block_opts.set_block_size(column_options.block_size);
+
+        if let Some(cache) = block_cache {
+            block_opts.set_block_cache(cache);
+            // "index and filter blocks will be stored in block cache, together with all other data blocks."
+            // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
+            block_opts.set_cache_index_and_filter_blocks(true);
+            // Don't evict L0 filter/index blocks from the cache
+            block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
+        } else {
+            block_opts.disable_cache();
+        }
+        if let Some(bits) = column_options.bloom_filter_bits {
+            block_opts.set_bloom_filter(bits, true);
+        }
+
         let mut opts = Options::default();
         opts.create_if_missing(true);
-        opts.set_compression_type(DBCompressionType::Lz4);
-        opts.set_block_based_table_factory(block_opts);
+        opts.set_compression_type(column_options.compression.into());
+        opts.set_compaction_style(column_options.compaction_style.into());
+        opts.set_block_based_table_factory(&block_opts);
 
         // All double-keys should be configured here
         if let Some(size) = Description::prefix(&column) {
@@ -235,10 +328,11 @@ where
     ) -> impl Iterator<Item = KVItem> + '_ {
         let maybe_next_item = next_prefix(prefix.to_vec())
             .and_then(|next_prefix| {
+                // The next element can have any prefix after the `next_prefix`.
                 self.iter_all(
                     column,
-                    Some(next_prefix.as_slice()),
                     None,
+                    Some(next_prefix.as_slice()),
                     IterDirection::Forward,
                 )
                 .next()
@@ -278,6 +372,35 @@ where
         }
     }
 
+    /// Writes the value without locking the key. The caller should hold the lock.
+    fn write_unlocked(
+        &self,
+        key: &[u8],
+        column: Description::Column,
+        buf: &[u8],
+    ) -> StorageResult<usize> {
+        let r = buf.len();
+        self.db
+            .put_cf(&self.cf(column), key, buf)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+
+        database_metrics().write_meter.inc();
+        database_metrics().bytes_written.observe(r as f64);
+
+        Ok(r)
+    }
+
+    /// Deletes the value without locking the key. The caller should hold the lock.
+    fn delete_unlocked(
+        &self,
+        key: &[u8],
+        column: Description::Column,
+    ) -> StorageResult<()> {
+        self.db
+            .delete_cf(&self.cf(column), key)
+            .map_err(|e| DatabaseError::Other(e.into()).into())
+    }
+
     fn _iter_all(
         &self,
         column: Description::Column,
@@ -309,27 +432,59 @@ where
 {
     type Column = Description::Column;
 
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        value: Value,
+    ) -> StorageResult<Option<Value>> {
+        let _lock = self.locks.lock(column.id(), key);
+        let previous = self.get(key, column)?;
+        self.write_unlocked(key, column, value.as_slice())?;
+        Ok(previous)
+    }
+
     fn write(
         &self,
         key: &[u8],
         column: Self::Column,
         buf: &[u8],
     ) -> StorageResult<usize> {
-        let r = buf.len();
-        self.db
-            .put_cf(&self.cf(column), key, buf)
-            .map_err(|e| DatabaseError::Other(e.into()))?;
-
-        database_metrics().write_meter.inc();
-        database_metrics().bytes_written.observe(r as f64);
+        let _lock = self.locks.lock(column.id(), key);
+        self.write_unlocked(key, column, buf)
+    }
 
-        Ok(r)
+    fn take(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        let _lock = self.locks.lock(column.id(), key);
+        let previous = self.get(key, column)?;
+        self.delete_unlocked(key, column)?;
+        Ok(previous)
     }
 
     fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
-        self.db
-            .delete_cf(&self.cf(column), key)
-            .map_err(|e| DatabaseError::Other(e.into()).into())
+        let _lock = self.locks.lock(column.id(), key);
+        self.delete_unlocked(key, column)
+    }
+
+    fn compare_and_swap(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        expected: Option<&[u8]>,
+        new: Option<Value>,
+    ) -> StorageResult<Result<(), Option<Value>>> {
+        let _lock = self.locks.lock(column.id(), key);
+        let current = self.get(key, column)?;
+        if current.as_deref().map(Vec::as_slice) != expected {
+            return Ok(Err(current))
+        }
+        match new {
+            Some(value) => {
+                self.write_unlocked(key, column, value.as_slice())?;
+            }
+            None => self.delete_unlocked(key, column)?,
+        }
+        Ok(Ok(()))
     }
 
     fn size_of_value(
@@ -468,6 +623,13 @@ where
         &self,
         entries: &mut dyn Iterator<Item = (Vec<u8>, Self::Column, WriteOperation)>,
     ) -> StorageResult<()> {
+        let entries: Vec<_> = entries.collect();
+        let stripes = entries
+            .iter()
+            .map(|(key, column, _)| KeyLocks::stripe(column.id(), key))
+            .collect();
+        let _locks = self.locks.lock_many(stripes);
+
         let mut batch = WriteBatch::default();
 
         for (key, column, op) in entries {
@@ -505,6 +667,42 @@ where
             .map_err(|e| anyhow::anyhow!("Unable to flush SST files: {}", e))?;
         Ok(())
     }
+
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        // Each database has its own sub-directory, the same as in `default_open`.
+        // It allows opening the checkpoint as a regular database.
+        let path = path.join(Description::name());
+        Checkpoint::new(&self.db)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(&path))
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Unable to create a checkpoint at {}: {}",
+                    path.display(),
+                    e
+                )
+            })?;
+        Ok(())
+    }
+}
+
+impl From<Compression> for DBCompressionType {
+    fn from(compression: Compression) -> Self {
+        match compression {
+            Compression::None => DBCompressionType::None,
+            Compression::Lz4 => DBCompressionType::Lz4,
+            Compression::Lz4hc => DBCompressionType::Lz4hc,
+            Compression::Zstd => DBCompressionType::Zstd,
+        }
+    }
+}
+
+impl From<CompactionStyle> for DBCompactionStyle {
+    fn from(style: CompactionStyle) -> Self {
+        match style {
+            CompactionStyle::Level => DBCompactionStyle::Level,
+            CompactionStyle::Universal => DBCompactionStyle::Universal,
+        }
+    }
 }
 
 /// The `None` means overflow, so there is not following prefix.
@@ -533,6 +731,56 @@ mod tests {
         )
     }
 
+    #[test]
+    fn can_open_with_custom_config() {
+        let tmp_dir = TempDir::new().unwrap();
+        let mut config = RocksDbConfig {
+            compression: Compression::Zstd,
+            bloom_filter_bits: None,
+            max_total_wal_size: 1024 * 1024,
+            rate_limit_bytes_per_sec: Some(1024 * 1024),
+            compaction_style: CompactionStyle::Universal,
+            ..Default::default()
+        };
+        config.columns.insert(
+            "on_chain".to_string(),
+            [(
+                "Metadata".to_string(),
+                crate::state::rocks_db_config::ColumnConfig {
+                    compression: Some(Compression::None),
+                    block_size: Some(16 * 1024),
+                    ..Default::default()
+                },
+            )]
+            .into_iter()
+            .collect(),
+        );
+        let db = RocksDb::<OnChain>::default_open_with_config(
+            tmp_dir.path(),
+            Some(1024 * 1024),
+            &config,
+        )
+        .unwrap();
+
+        let key = vec![0xA, 0xB, 0xC];
+        let expected = Arc::new(vec![1, 2, 3]);
+        db.put(&key, Column::Metadata, expected.clone()).unwrap();
+        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected)
+    }
+
+    #[test]
+    fn open_fails_with_invalid_config() {
+        let tmp_dir = TempDir::new().unwrap();
+        let config = RocksDbConfig {
+            block_size: 0,
+            ..Default::default()
+        };
+
+        let result =
+            RocksDb::<OnChain>::default_open_with_config(tmp_dir.path(), None, &config);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn can_put_and_read() {
         let key = vec![0xA, 0xB, 0xC];
@@ -682,4 +930,75 @@ mod tests {
 
         assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
+
+    #[test]
+    fn passes_shared_storage_tests() {
+        use crate::state::storage_tests;
+
+        storage_tests::can_put_and_read(&create_db().0);
+        storage_tests::put_returns_previous_value(&create_db().0);
+        storage_tests::delete_and_get(&create_db().0);
+        storage_tests::key_exists(&create_db().0);
+        storage_tests::batch_write_inserts(&create_db().0);
+        storage_tests::batch_write_removes(&create_db().0);
+        storage_tests::can_use_key_and_value(&create_db().0, vec![0x00], vec![]);
+        storage_tests::can_use_key_and_value(&create_db().0, vec![], vec![1, 2, 3]);
+        storage_tests::can_use_key_and_value(&create_db().0, vec![], vec![]);
+    }
+
+    #[test]
+    fn iter_all_respects_prefix_start_and_direction() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::iter_all_respects_prefix_start_and_direction(&db);
+    }
+
+    #[test]
+    fn reverse_prefix_iter_stops_before_the_next_prefix() {
+        let (db, _tmp) = create_db();
+        let key = |prefix: u8, suffix: u8| {
+            let mut key = vec![prefix; 32];
+            key.push(suffix);
+            key
+        };
+        for key in [key(0, 1), key(1, 0), key(1, 1), key(2, 0)] {
+            db.put(&key, Column::ContractsState, Arc::new(vec![]))
+                .unwrap();
+        }
+
+        let keys = db
+            .iter_all(
+                Column::ContractsState,
+                Some(&[1; 32]),
+                None,
+                IterDirection::Reverse,
+            )
+            .map(|item| item.unwrap().0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(keys, vec![key(1, 1), key(1, 0)]);
+    }
+
+    #[test]
+    fn compare_and_swap_is_atomic() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::compare_and_swap_is_atomic(&db);
+    }
+
+    #[test]
+    fn compare_and_swap_returns_current_value() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::compare_and_swap_returns_current_value(&db);
+    }
+
+    #[test]
+    fn take_is_atomic() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::take_is_atomic(&db);
+    }
+
+    #[test]
+    fn replace_is_atomic() {
+        let (db, _tmp) = create_db();
+        crate::state::storage_tests::replace_is_atomic(&db);
+    }
 }
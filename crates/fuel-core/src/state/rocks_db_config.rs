@@ -0,0 +1,414 @@
+//! The configuration of the RocksDB instances used by the node.
+//!
+//! The configuration is shared by all databases(on-chain, off-chain, relayer),
+//! while the `columns` section allows overriding the options for the specific
+//! column of the specific database.
+
+use crate::database::database_description::{
+    off_chain::OffChain,
+    on_chain::OnChain,
+    relayer::Relayer,
+    DatabaseDescription,
+};
+use anyhow::anyhow;
+use clap::ValueEnum;
+use fuel_core_storage::kv_store::StorageColumn;
+use std::{
+    collections::BTreeMap,
+    path::Path,
+};
+use strum_macros::{
+    Display,
+    EnumString,
+};
+
+/// The default maximum total size of the write-ahead log files.
+pub const DEFAULT_MAX_TOTAL_WAL_SIZE: u64 = 64 * 1024 * 1024;
+/// The default size of the block in the block-based table.
+pub const DEFAULT_BLOCK_SIZE: usize = 4 * 1024;
+/// The default number of bits per key used by the bloom filter.
+pub const DEFAULT_BLOOM_FILTER_BITS: f64 = 10.0;
+/// The default percentage of the cache capacity used by the block cache.
+pub const DEFAULT_BLOCK_CACHE_PERCENT: u8 = 33;
+/// The default percentage of the cache capacity used by the row cache.
+pub const DEFAULT_ROW_CACHE_PERCENT: u8 = 33;
+
+/// The compression algorithm applied to the SST files.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Display,
+    Eq,
+    PartialEq,
+    EnumString,
+    ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[strum(serialize_all = "kebab_case")]
+#[serde(rename_all = "kebab-case")]
+pub enum Compression {
+    None,
+    #[default]
+    Lz4,
+    Lz4hc,
+    Zstd,
+}
+
+/// The compaction style used by RocksDB. The FIFO compaction is not supported,
+/// because it drops the oldest data once the size limit is reached.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Display,
+    Eq,
+    PartialEq,
+    EnumString,
+    ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[strum(serialize_all = "kebab_case")]
+#[serde(rename_all = "kebab-case")]
+pub enum CompactionStyle {
+    #[default]
+    Level,
+    Universal,
+}
+
+/// The options that can be overridden for the specific column.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ColumnConfig {
+    pub compression: Option<Compression>,
+    pub block_size: Option<usize>,
+    /// The `0` disables the bloom filter of the column.
+    pub bloom_filter_bits: Option<f64>,
+    pub compaction_style: Option<CompactionStyle>,
+}
+
+/// The resolved options of the column after applying overrides.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnOptions {
+    pub compression: Compression,
+    pub block_size: usize,
+    /// The `None` means that bloom filter is disabled.
+    pub bloom_filter_bits: Option<f64>,
+    pub compaction_style: CompactionStyle,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RocksDbConfig {
+    /// The default compression of all columns.
+    pub compression: Compression,
+    /// The default size of the block of all columns.
+    pub block_size: usize,
+    /// The default number of bits per key of the bloom filter.
+    /// The `None` or `0` disables the bloom filter.
+    pub bloom_filter_bits: Option<f64>,
+    /// The percentage of the database cache capacity used by the block cache.
+    pub block_cache_percent: u8,
+    /// The percentage of the database cache capacity used by the row cache.
+    pub row_cache_percent: u8,
+    /// The maximum total size of the write-ahead log files in bytes.
+    pub max_total_wal_size: u64,
+    /// The limit of the background I/O(flushes and compactions) in bytes per second.
+    /// The `None` means no limit.
+    pub rate_limit_bytes_per_sec: Option<u64>,
+    /// The default compaction style of all columns.
+    pub compaction_style: CompactionStyle,
+    /// Overrides for specific columns, grouped by the name of the database
+    /// (`on_chain`, `off_chain`, `relayer`) and the name of the column.
+    pub columns: BTreeMap<String, BTreeMap<String, ColumnConfig>>,
+}
+
+impl Default for RocksDbConfig {
+    fn default() -> Self {
+        Self {
+            compression: Compression::default(),
+            block_size: DEFAULT_BLOCK_SIZE,
+            bloom_filter_bits: Some(DEFAULT_BLOOM_FILTER_BITS),
+            block_cache_percent: DEFAULT_BLOCK_CACHE_PERCENT,
+            row_cache_percent: DEFAULT_ROW_CACHE_PERCENT,
+            max_total_wal_size: DEFAULT_MAX_TOTAL_WAL_SIZE,
+            rate_limit_bytes_per_sec: None,
+            compaction_style: CompactionStyle::default(),
+            columns: BTreeMap::new(),
+        }
+    }
+}
+
+impl RocksDbConfig {
+    /// Loads the configuration from the TOML file.
+    pub fn load_from_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            anyhow!("Unable to read the RocksDB config {}: {e}", path.display())
+        })?;
+        toml::from_str(&content).map_err(|e| {
+            anyhow!("Unable to parse the RocksDB config {}: {e}", path.display())
+        })
+    }
+
+    /// Checks that the configuration is consistent and all overridden columns exist.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        validate_column_config(
+            "default",
+            &ColumnConfig {
+                compression: Some(self.compression),
+                block_size: Some(self.block_size),
+                bloom_filter_bits: self.bloom_filter_bits,
+                compaction_style: Some(self.compaction_style),
+            },
+        )?;
+
+        let cache_percent = u16::from(self.block_cache_percent)
+            .saturating_add(u16::from(self.row_cache_percent));
+        if cache_percent > 100 {
+            return Err(anyhow!(
+                "The block cache({}%) and row cache({}%) exceed the database cache capacity",
+                self.block_cache_percent,
+                self.row_cache_percent
+            ))
+        }
+
+        if self.max_total_wal_size == 0 {
+            return Err(anyhow!(
+                "The `max-total-wal-size` should be greater than zero"
+            ))
+        }
+
+        if let Some(rate_limit) = self.rate_limit_bytes_per_sec {
+            if rate_limit == 0 || i64::try_from(rate_limit).is_err() {
+                return Err(anyhow!(
+                    "The `rate-limit-bytes-per-sec` should be in the range `1..=i64::MAX`"
+                ))
+            }
+        }
+
+        for (database, columns) in &self.columns {
+            let known_columns = match database.as_str() {
+                name if name == OnChain::name() => column_names::<OnChain>(),
+                name if name == OffChain::name() => column_names::<OffChain>(),
+                name if name == Relayer::name() => column_names::<Relayer>(),
+                _ => {
+                    return Err(anyhow!(
+                        "Unknown database `{database}` in the RocksDB config"
+                    ))
+                }
+            };
+
+            for (column, config) in columns {
+                if !known_columns.contains(&column.as_str()) {
+                    return Err(anyhow!(
+                        "Unknown column `{column}` of the `{database}` database in the RocksDB config"
+                    ))
+                }
+                validate_column_config(&format!("{database}.{column}"), config)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the options for the `column` of the database with `Description`.
+    pub fn column_options<Description>(
+        &self,
+        column: &Description::Column,
+    ) -> ColumnOptions
+    where
+        Description: DatabaseDescription,
+    {
+        let overrides = self
+            .columns
+            .get(Description::name())
+            .and_then(|columns| columns.get(column.name()))
+            .cloned()
+            .unwrap_or_default();
+
+        ColumnOptions {
+            compression: overrides.compression.unwrap_or(self.compression),
+            block_size: overrides.block_size.unwrap_or(self.block_size),
+            bloom_filter_bits: overrides
+                .bloom_filter_bits
+                .or(self.bloom_filter_bits)
+                .filter(|bits| *bits != 0.0),
+            compaction_style: overrides.compaction_style.unwrap_or(self.compaction_style),
+        }
+    }
+
+    /// Returns the size of the block cache for the database `capacity`.
+    pub fn block_cache_size(&self, capacity: usize) -> usize {
+        percent_of(capacity, self.block_cache_percent)
+    }
+
+    /// Returns the size of the row cache for the database `capacity`.
+    pub fn row_cache_size(&self, capacity: usize) -> usize {
+        percent_of(capacity, self.row_cache_percent)
+    }
+}
+
+fn validate_column_config(name: &str, config: &ColumnConfig) -> anyhow::Result<()> {
+    if let Some(block_size) = config.block_size {
+        if block_size == 0 {
+            return Err(anyhow!(
+                "The `block-size` of `{name}` should be greater than zero"
+            ))
+        }
+    }
+
+    if let Some(bits) = config.bloom_filter_bits {
+        if !(0.0..=100.0).contains(&bits) {
+            return Err(anyhow!(
+                "The `bloom-filter-bits` of `{name}` should be in the range `[0, 100]`"
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+fn column_names<Description>() -> Vec<&'static str>
+where
+    Description: DatabaseDescription,
+{
+    enum_iterator::all::<Description::Column>()
+        .map(|column| column.name())
+        .collect()
+}
+
+fn percent_of(capacity: usize, percent: u8) -> usize {
+    (capacity / 100).saturating_mul(usize::from(percent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_is_valid() {
+        RocksDbConfig::default()
+            .validate()
+            .expect("The default config should be valid");
+    }
+
+    #[test]
+    fn parses_toml_with_column_overrides() {
+        let config: RocksDbConfig = toml::from_str(
+            r#"
+            compression = "zstd"
+            bloom-filter-bits = 12.5
+            max-total-wal-size = 1048576
+            compaction-style = "universal"
+
+            [columns.on_chain.ContractsState]
+            compression = "none"
+            block-size = 16384
+            "#,
+        )
+        .unwrap();
+
+        config.validate().unwrap();
+        let state = config.column_options::<OnChain>(
+            &fuel_core_storage::column::Column::ContractsState,
+        );
+        assert_eq!(
+            state,
+            ColumnOptions {
+                compression: Compression::None,
+                block_size: 16384,
+                bloom_filter_bits: Some(12.5),
+                compaction_style: CompactionStyle::Universal,
+            }
+        );
+        let coins =
+            config.column_options::<OnChain>(&fuel_core_storage::column::Column::Coins);
+        assert_eq!(coins.compression, Compression::Zstd);
+        assert_eq!(coins.block_size, DEFAULT_BLOCK_SIZE);
+    }
+
+    #[test]
+    fn unknown_column_is_rejected() {
+        let config: RocksDbConfig = toml::from_str(
+            r#"
+            [columns.on_chain.UnknownColumn]
+            compression = "none"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn unknown_database_is_rejected() {
+        let config: RocksDbConfig = toml::from_str(
+            r#"
+            [columns.archive.Coins]
+            compression = "none"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn cache_split_exceeding_capacity_is_rejected() {
+        let config = RocksDbConfig {
+            block_cache_percent: 60,
+            row_cache_percent: 50,
+            ..Default::default()
+        };
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn invalid_bloom_filter_bits_are_rejected() {
+        let config = RocksDbConfig {
+            bloom_filter_bits: Some(-1.0),
+            ..Default::default()
+        };
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn column_override_disables_bloom_filter() {
+        let config: RocksDbConfig = toml::from_str(
+            r#"
+            bloom-filter-bits = 12.5
+
+            [columns.on_chain.Coins]
+            bloom-filter-bits = 0
+            "#,
+        )
+        .unwrap();
+
+        config.validate().unwrap();
+        let coins =
+            config.column_options::<OnChain>(&fuel_core_storage::column::Column::Coins);
+        assert_eq!(coins.bloom_filter_bits, None);
+        let messages = config
+            .column_options::<OnChain>(&fuel_core_storage::column::Column::Messages);
+        assert_eq!(messages.bloom_filter_bits, Some(12.5));
+    }
+
+    #[test]
+    fn fifo_compaction_is_rejected() {
+        let config = toml::from_str::<RocksDbConfig>(
+            r#"
+            [columns.on_chain.Coins]
+            compaction-style = "fifo"
+            "#,
+        );
+
+        assert!(config.is_err());
+    }
+}
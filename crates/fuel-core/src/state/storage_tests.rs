@@ -0,0 +1,356 @@
+//! Tests shared by all storages. Each storage runs them against its own instance,
+//! so all backends behave the same way.
+
+use crate::state::TransactableStorage;
+use fuel_core_storage::{
+    column::Column,
+    iter::IterDirection,
+    kv_store::{
+        KeyValueStore,
+        WriteOperation,
+    },
+};
+use std::{
+    collections::BTreeSet,
+    sync::Arc,
+};
+
+const THREADS: u64 = 8;
+const ITERATIONS: u64 = 50;
+
+fn decode(value: &[u8]) -> u64 {
+    u64::from_be_bytes(value.try_into().expect("The counter is 8 bytes"))
+}
+
+pub fn can_put_and_read<S>(store: &S)
+where
+    S: KeyValueStore<Column = Column>,
+{
+    let key = vec![0xA, 0xB, 0xC];
+    let expected = Arc::new(vec![1, 2, 3]);
+    store.put(&key, Column::Metadata, expected.clone()).unwrap();
+
+    assert_eq!(
+        store.get(&key, Column::Metadata).unwrap().unwrap(),
+        expected
+    )
+}
+
+pub fn put_returns_previous_value<S>(store: &S)
+where
+    S: KeyValueStore<Column = Column>,
+{
+    let key = vec![0xA, 0xB, 0xC];
+    let expected = Arc::new(vec![1, 2, 3]);
+    store.put(&key, Column::Metadata, expected.clone()).unwrap();
+    let prev = store
+        .replace(&key, Column::Metadata, Arc::new(vec![2, 4, 6]))
+        .unwrap();
+
+    assert_eq!(prev, Some(expected));
+}
+
+pub fn delete_and_get<S>(store: &S)
+where
+    S: KeyValueStore<Column = Column>,
+{
+    let key = vec![0xA, 0xB, 0xC];
+    let expected = Arc::new(vec![1, 2, 3]);
+    store.put(&key, Column::Metadata, expected.clone()).unwrap();
+    assert_eq!(
+        store.get(&key, Column::Metadata).unwrap().unwrap(),
+        expected
+    );
+
+    store.delete(&key, Column::Metadata).unwrap();
+    assert_eq!(store.get(&key, Column::Metadata).unwrap(), None);
+}
+
+pub fn key_exists<S>(store: &S)
+where
+    S: KeyValueStore<Column = Column>,
+{
+    let key = vec![0xA, 0xB, 0xC];
+    let expected = Arc::new(vec![1, 2, 3]);
+    store.put(&key, Column::Metadata, expected).unwrap();
+    assert!(store.exists(&key, Column::Metadata).unwrap());
+}
+
+pub fn batch_write_inserts<S>(store: &S)
+where
+    S: TransactableStorage<Column = Column>,
+{
+    let key = vec![0xA, 0xB, 0xC];
+    let value = Arc::new(vec![1, 2, 3]);
+    let ops = vec![(
+        key.clone(),
+        Column::Metadata,
+        WriteOperation::Insert(value.clone()),
+    )];
+
+    store.batch_write(&mut ops.into_iter()).unwrap();
+    assert_eq!(store.get(&key, Column::Metadata).unwrap().unwrap(), value)
+}
+
+pub fn batch_write_removes<S>(store: &S)
+where
+    S: TransactableStorage<Column = Column>,
+{
+    let key = vec![0xA, 0xB, 0xC];
+    let value = Arc::new(vec![1, 2, 3]);
+    store.put(&key, Column::Metadata, value).unwrap();
+
+    let ops = vec![(key.clone(), Column::Metadata, WriteOperation::Remove)];
+    store.batch_write(&mut ops.into_iter()).unwrap();
+
+    assert_eq!(store.get(&key, Column::Metadata).unwrap(), None);
+}
+
+/// Verifies all operations with the `key` and `value`, used for empty keys and values.
+pub fn can_use_key_and_value<S>(store: &S, key: Vec<u8>, expected: Vec<u8>)
+where
+    S: TransactableStorage<Column = Column>,
+{
+    let expected = Arc::new(expected);
+    store.put(&key, Column::Metadata, expected.clone()).unwrap();
+
+    assert_eq!(
+        store.get(&key, Column::Metadata).unwrap().unwrap(),
+        expected
+    );
+
+    assert!(store.exists(&key, Column::Metadata).unwrap());
+
+    assert_eq!(
+        store
+            .iter_all(Column::Metadata, None, None, IterDirection::Forward)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()[0],
+        (key.clone(), expected.clone())
+    );
+
+    assert_eq!(
+        store.take(&key, Column::Metadata).unwrap().unwrap(),
+        expected
+    );
+
+    assert!(!store.exists(&key, Column::Metadata).unwrap());
+}
+
+/// Iterates over the column with 32 bytes prefixes, because RocksDB supports
+/// the prefix iteration only for columns with the prefix extractor.
+pub fn iter_all_respects_prefix_start_and_direction<S>(store: &S)
+where
+    S: TransactableStorage<Column = Column>,
+{
+    const COLUMN: Column = Column::ContractsState;
+    let key = |prefix: u8, suffix: u8| {
+        let mut key = vec![prefix; 32];
+        key.push(suffix);
+        key
+    };
+    let keys = vec![key(0, 1), key(1, 0), key(1, 1), key(1, 2), key(2, 0)];
+    for key in &keys {
+        store.put(key, COLUMN, Arc::new(key.clone())).unwrap();
+    }
+
+    let iter = |prefix: Option<&[u8]>, start: Option<&[u8]>, direction| {
+        store
+            .iter_all(COLUMN, prefix, start, direction)
+            .map(|item| item.unwrap().0)
+            .collect::<Vec<_>>()
+    };
+    let prefix = [1; 32];
+
+    assert_eq!(iter(None, None, IterDirection::Forward), keys);
+    assert_eq!(
+        iter(None, None, IterDirection::Reverse),
+        keys.iter().rev().cloned().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        iter(Some(&prefix), None, IterDirection::Forward),
+        vec![key(1, 0), key(1, 1), key(1, 2)]
+    );
+    assert_eq!(
+        iter(Some(&prefix), None, IterDirection::Reverse),
+        vec![key(1, 2), key(1, 1), key(1, 0)]
+    );
+    assert_eq!(
+        iter(None, Some(&key(1, 1)), IterDirection::Forward),
+        vec![key(1, 1), key(1, 2), key(2, 0)]
+    );
+    assert_eq!(
+        iter(None, Some(&key(1, 1)), IterDirection::Reverse),
+        vec![key(1, 1), key(1, 0), key(0, 1)]
+    );
+    assert_eq!(
+        iter(Some(&prefix), Some(&key(1, 1)), IterDirection::Forward),
+        vec![key(1, 1), key(1, 2)]
+    );
+    assert_eq!(
+        iter(Some(&prefix), Some(&key(1, 1)), IterDirection::Reverse),
+        vec![key(1, 1), key(1, 0)]
+    );
+    assert!(iter(Some(&prefix), Some(&key(2, 0)), IterDirection::Forward).is_empty());
+}
+
+/// Increments the counter from many threads via `compare_and_swap`
+/// and verifies that no increment was lost.
+pub fn compare_and_swap_is_atomic<S>(store: &S)
+where
+    S: KeyValueStore<Column = Column> + Sync,
+{
+    let key = b"counter".as_slice();
+
+    std::thread::scope(|scope| {
+        for _ in 0..THREADS {
+            scope.spawn(|| {
+                for _ in 0..ITERATIONS {
+                    loop {
+                        let current = store.get(key, Column::Metadata).unwrap();
+                        let next = current
+                            .as_ref()
+                            .map_or(0, |value| decode(value))
+                            .checked_add(1)
+                            .unwrap();
+                        let result = store
+                            .compare_and_swap(
+                                key,
+                                Column::Metadata,
+                                current.as_deref().map(Vec::as_slice),
+                                Some(Arc::new(next.to_be_bytes().to_vec())),
+                            )
+                            .unwrap();
+                        if result.is_ok() {
+                            break
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    let value = store.get(key, Column::Metadata).unwrap().unwrap();
+    assert_eq!(decode(&value), THREADS.checked_mul(ITERATIONS).unwrap());
+}
+
+/// Verifies that `compare_and_swap` returns the current value on mismatch
+/// and can remove the value.
+pub fn compare_and_swap_returns_current_value<S>(store: &S)
+where
+    S: KeyValueStore<Column = Column>,
+{
+    let key = b"key".as_slice();
+    let value = Arc::new(vec![1, 2, 3]);
+
+    let result = store
+        .compare_and_swap(key, Column::Metadata, Some(&[1]), Some(value.clone()))
+        .unwrap();
+    assert_eq!(result, Err(None));
+
+    let result = store
+        .compare_and_swap(key, Column::Metadata, None, Some(value.clone()))
+        .unwrap();
+    assert_eq!(result, Ok(()));
+
+    let result = store
+        .compare_and_swap(key, Column::Metadata, None, None)
+        .unwrap();
+    assert_eq!(result, Err(Some(value.clone())));
+
+    let result = store
+        .compare_and_swap(key, Column::Metadata, Some(value.as_slice()), None)
+        .unwrap();
+    assert_eq!(result, Ok(()));
+    assert_eq!(store.get(key, Column::Metadata).unwrap(), None);
+}
+
+/// Takes the same values from many threads and verifies that each value
+/// is returned only once.
+pub fn take_is_atomic<S>(store: &S)
+where
+    S: KeyValueStore<Column = Column> + Sync,
+{
+    let keys: Vec<_> = (0..ITERATIONS).map(u64::to_be_bytes).collect();
+    for key in &keys {
+        store
+            .put(key, Column::Metadata, Arc::new(key.to_vec()))
+            .unwrap();
+    }
+
+    let taken: Vec<Vec<u8>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                scope.spawn(|| {
+                    keys.iter()
+                        .filter_map(|key| store.take(key, Column::Metadata).unwrap())
+                        .map(|value| value.as_ref().clone())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    let expected: Vec<_> = keys.iter().map(|key| key.to_vec()).collect();
+    let mut taken = taken;
+    taken.sort();
+    assert_eq!(taken, expected);
+}
+
+/// Replaces the value from many threads and verifies that each written value
+/// is returned as the previous value only once.
+pub fn replace_is_atomic<S>(store: &S)
+where
+    S: KeyValueStore<Column = Column> + Sync,
+{
+    let key = b"key".as_slice();
+    store
+        .put(
+            key,
+            Column::Metadata,
+            Arc::new(u64::MAX.to_be_bytes().to_vec()),
+        )
+        .unwrap();
+
+    let previous: Vec<u64> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|thread| {
+                scope.spawn(move || {
+                    (0..ITERATIONS)
+                        .map(|i| {
+                            let value = thread
+                                .checked_mul(ITERATIONS)
+                                .and_then(|v| v.checked_add(i))
+                                .unwrap();
+                            let previous = store
+                                .replace(
+                                    key,
+                                    Column::Metadata,
+                                    Arc::new(value.to_be_bytes().to_vec()),
+                                )
+                                .unwrap()
+                                .unwrap();
+                            decode(&previous)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    let last = decode(&store.get(key, Column::Metadata).unwrap().unwrap());
+    let observed: BTreeSet<_> = previous.iter().copied().chain([last]).collect();
+    let expected: BTreeSet<_> = (0..THREADS.checked_mul(ITERATIONS).unwrap())
+        .chain([u64::MAX])
+        .collect();
+    assert_eq!(previous.len(), expected.len().checked_sub(1).unwrap());
+    assert_eq!(observed, expected);
+}
@@ -2,6 +2,7 @@ use crate::timing_buckets;
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::{
+        counter::Counter,
         family::Family,
         gauge::Gauge,
         histogram::Histogram,
@@ -16,11 +17,25 @@ pub struct Label {
     path: String,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct RejectionLabel {
+    // the reason why the request was rejected
+    reason: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ParseLabel {
+    // whether the parsed query was taken from the persisted queries cache
+    persisted: String,
+}
+
 pub struct GraphqlMetrics {
     pub registry: Registry,
     // using gauges in case blocks are rolled back for any reason
     pub total_txs_count: Gauge,
     requests: Family<Label, Histogram>,
+    rejected_requests: Family<RejectionLabel, Counter>,
+    parse_durations: Family<ParseLabel, Histogram>,
 }
 
 impl GraphqlMetrics {
@@ -32,6 +47,23 @@ impl GraphqlMetrics {
         });
         registry.register("graphql_request_duration_seconds", "", requests.clone());
 
+        let rejected_requests = Family::<RejectionLabel, Counter>::default();
+        registry.register(
+            "graphql_rejected_requests",
+            "the number of requests rejected because of the API limits",
+            rejected_requests.clone(),
+        );
+
+        let parse_durations =
+            Family::<ParseLabel, Histogram>::new_with_constructor(|| {
+                Histogram::new(timing_buckets().iter().cloned())
+            });
+        registry.register(
+            "graphql_query_parse_duration_seconds",
+            "the duration of the query parsing, persisted queries are parsed only once",
+            parse_durations.clone(),
+        );
+
         registry.register(
             "importer_tx_count",
             "the total amount of transactions that have been imported on chain",
@@ -42,6 +74,8 @@ impl GraphqlMetrics {
             registry,
             total_txs_count: tx_count_gauge,
             requests,
+            rejected_requests,
+            parse_durations,
         }
     }
 
@@ -51,6 +85,22 @@ impl GraphqlMetrics {
         });
         histogram.observe(time);
     }
+
+    pub fn graphql_parse_observe(&self, persisted: bool, time: f64) {
+        self.parse_durations
+            .get_or_create(&ParseLabel {
+                persisted: persisted.to_string(),
+            })
+            .observe(time);
+    }
+
+    pub fn graphql_request_rejected(&self, reason: &str) {
+        self.rejected_requests
+            .get_or_create(&RejectionLabel {
+                reason: reason.to_string(),
+            })
+            .inc();
+    }
 }
 
 static GRAPHQL_METRICS: OnceLock<GraphqlMetrics> = OnceLock::new();
@@ -10,6 +10,9 @@ pub struct P2PMetrics {
     // For descriptions of each Counter, see the `new` function where each Counter/Histogram is initialized
     pub peer_metrics: Registry,
     pub unique_peers: Counter,
+    pub full_tx_gossip_bytes: Counter,
+    pub tx_announcement_gossip_bytes: Counter,
+    pub announced_tx_fetch_bytes: Counter,
 }
 
 impl P2PMetrics {
@@ -17,11 +20,17 @@ impl P2PMetrics {
         let peer_metrics = Registry::default();
 
         let unique_peers = Counter::default();
+        let full_tx_gossip_bytes = Counter::default();
+        let tx_announcement_gossip_bytes = Counter::default();
+        let announced_tx_fetch_bytes = Counter::default();
 
         let mut metrics = P2PMetrics {
             gossip_sub_registry: OnceBox::new(),
             peer_metrics,
             unique_peers,
+            full_tx_gossip_bytes,
+            tx_announcement_gossip_bytes,
+            announced_tx_fetch_bytes,
         };
 
         metrics.peer_metrics.register(
@@ -30,6 +39,24 @@ impl P2PMetrics {
             metrics.unique_peers.clone(),
         );
 
+        metrics.peer_metrics.register(
+            "Full_Tx_Gossip_Bytes",
+            "The number of bytes of whole transactions published and received via gossip",
+            metrics.full_tx_gossip_bytes.clone(),
+        );
+
+        metrics.peer_metrics.register(
+            "Tx_Announcement_Gossip_Bytes",
+            "The number of bytes of transaction announcements published and received via gossip",
+            metrics.tx_announcement_gossip_bytes.clone(),
+        );
+
+        metrics.peer_metrics.register(
+            "Announced_Tx_Fetch_Bytes",
+            "The number of bytes of transactions fetched from peers after their announcements",
+            metrics.announced_tx_fetch_bytes.clone(),
+        );
+
         metrics
     }
 }
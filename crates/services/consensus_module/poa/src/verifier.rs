@@ -35,7 +35,24 @@ pub fn verify_block_fields<D: Database>(
     database: &D,
     block: &Block,
 ) -> anyhow::Result<()> {
-    let height = *block.header().height();
+    let header = block.header();
+    verify_header_fields(database, header)?;
+
+    ensure!(
+        header.validate_transactions(block.transactions()),
+        "The transactions don't match header."
+    );
+
+    Ok(())
+}
+
+/// Verifies that the header extends the previous block. Unlike [`verify_block_fields`],
+/// it doesn't check the transactions, so it is used by the light client.
+pub fn verify_header_fields<D: Database>(
+    database: &D,
+    header: &BlockHeader,
+) -> anyhow::Result<()> {
+    let height = *header.height();
     ensure!(
         height != 0u32.into(),
         "The PoA block can't have the zero height"
@@ -43,7 +60,6 @@ pub fn verify_block_fields<D: Database>(
 
     let prev_height = height.pred().expect("We checked the height above");
     let prev_root = database.block_header_merkle_root(&prev_height)?;
-    let header = block.header();
     ensure!(
         header.prev_root() == &prev_root,
         "Previous root of the next block should match the previous block root"
@@ -66,10 +82,5 @@ pub fn verify_block_fields<D: Database>(
         "The application hash mismatch."
     );
 
-    ensure!(
-        header.validate_transactions(block.transactions()),
-        "The transactions don't match header."
-    );
-
     Ok(())
 }
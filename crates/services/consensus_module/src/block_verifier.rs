@@ -72,6 +72,24 @@ where
         }
     }
 
+    /// Verifies fields of the header that doesn't belong to the genesis block
+    /// without its transactions.
+    ///
+    /// Return an error if the verification failed, otherwise `Ok(())`.
+    pub fn verify_header_fields(
+        &self,
+        consensus: &Consensus,
+        header: &BlockHeader,
+    ) -> anyhow::Result<()> {
+        match consensus {
+            Consensus::PoA(_) => {
+                let view = self.view_provider.latest_view();
+                fuel_core_poa::verifier::verify_header_fields(&view, header)
+            }
+            _ => Err(anyhow::anyhow!("Unsupported consensus: {:?}", consensus)),
+        }
+    }
+
     /// Verifies the consensus of the block header.
     pub fn verify_consensus(&self, header: &SealedBlockHeader) -> bool {
         let SealedBlockHeader {
@@ -19,6 +19,8 @@ use crate::{
 use fuel_core_types::fuel_types::BlockHeight;
 use libp2p::{
     allow_block_list,
+    autonat,
+    dcutr,
     gossipsub::{
         self,
         MessageAcceptance,
@@ -26,13 +28,18 @@ use libp2p::{
         PublishError,
     },
     identify,
+    relay,
     request_response::{
         self,
         OutboundRequestId,
         ProtocolSupport,
         ResponseChannel,
     },
-    swarm::NetworkBehaviour,
+    swarm::{
+        behaviour::toggle::Toggle,
+        NetworkBehaviour,
+    },
+    upnp,
     Multiaddr,
     PeerId,
 };
@@ -62,10 +69,29 @@ pub struct FuelBehaviour {
 
     /// RequestResponse protocol
     request_response: request_response::Behaviour<PostcardCodec>,
+
+    /// Detects whether the node is reachable by other peers
+    autonat: autonat::Behaviour,
+
+    /// Maps the ports of the node on the gateway, if enabled
+    upnp: Toggle<upnp::tokio::Behaviour>,
+
+    /// Reserves circuits through the relays for the node behind the NAT
+    relay_client: relay::client::Behaviour,
+
+    /// Relays connections for the peers behind the NAT, if enabled
+    relay_server: Toggle<relay::Behaviour>,
+
+    /// Upgrades relayed connections to direct ones with hole punching
+    dcutr: dcutr::Behaviour,
 }
 
 impl FuelBehaviour {
-    pub(crate) fn new(p2p_config: &Config, codec: PostcardCodec) -> Self {
+    pub(crate) fn new(
+        p2p_config: &Config,
+        codec: PostcardCodec,
+        relay_client: relay::client::Behaviour,
+    ) -> Self {
         let local_public_key = p2p_config.keypair.public();
         let local_peer_id = PeerId::from_public_key(&local_public_key);
 
@@ -78,6 +104,7 @@ impl FuelBehaviour {
                 .max_peers_connected(p2p_config.max_peers_connected as usize)
                 .with_bootstrap_nodes(p2p_config.bootstrap_nodes.clone())
                 .with_reserved_nodes(p2p_config.reserved_nodes.clone())
+                .with_private_nodes(p2p_config.private_nodes.clone())
                 .enable_reserved_nodes_only_mode(p2p_config.reserved_nodes_only_mode);
 
             if let Some(random_walk) = p2p_config.random_walk {
@@ -112,8 +139,10 @@ impl FuelBehaviour {
             BlockHeight::default(),
         );
 
-        let req_res_protocol =
-            core::iter::once((codec.get_req_res_protocol(), ProtocolSupport::Full));
+        let req_res_protocol = codec
+            .get_req_res_protocols()
+            .into_iter()
+            .map(|protocol| (protocol, ProtocolSupport::Full));
 
         let req_res_config = request_response::Config::default();
         req_res_config
@@ -126,6 +155,25 @@ impl FuelBehaviour {
             req_res_config,
         );
 
+        let autonat = autonat::Behaviour::new(
+            local_peer_id,
+            autonat::Config {
+                only_global_ips: !p2p_config.allow_private_addresses,
+                ..Default::default()
+            },
+        );
+
+        let upnp =
+            Toggle::from(p2p_config.enable_upnp.then(upnp::tokio::Behaviour::default));
+
+        let relay_server = Toggle::from(
+            p2p_config
+                .enable_relay_server
+                .then(|| relay::Behaviour::new(local_peer_id, Default::default())),
+        );
+
+        let dcutr = dcutr::Behaviour::new(local_peer_id);
+
         Self {
             discovery: discovery_config.finish(),
             gossipsub,
@@ -134,9 +182,18 @@ impl FuelBehaviour {
             blocked_peer: Default::default(),
             identify,
             heartbeat,
+            autonat,
+            upnp,
+            relay_client,
+            relay_server,
+            dcutr,
         }
     }
 
+    pub fn nat_status(&self) -> autonat::NatStatus {
+        self.autonat.nat_status()
+    }
+
     pub fn add_addresses_to_discovery(
         &mut self,
         peer_id: &PeerId,
@@ -213,4 +270,8 @@ impl FuelBehaviour {
     pub fn block_peer(&mut self, peer_id: PeerId) {
         self.blocked_peer.block_peer(peer_id)
     }
+
+    pub fn unblock_peer(&mut self, peer_id: PeerId) {
+        self.blocked_peer.unblock_peer(peer_id)
+    }
 }
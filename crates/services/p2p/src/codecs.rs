@@ -39,7 +39,7 @@ pub trait NetworkCodec:
     + Send
     + 'static
 {
-    /// Returns RequestResponse's Protocol
+    /// Returns RequestResponse's Protocols in the order of preference
     /// Needed for initialization of RequestResponse Behaviour
-    fn get_req_res_protocol(&self) -> <Self as request_response::Codec>::Protocol;
+    fn get_req_res_protocols(&self) -> Vec<<Self as request_response::Codec>::Protocol>;
 }
@@ -10,8 +10,10 @@ use crate::{
     },
     request_response::messages::{
         RequestMessage,
+        RequestResponseProtocol,
         ResponseMessage,
-        REQUEST_RESPONSE_PROTOCOL_ID,
+        UnsupportedByProtocol,
+        V1ResponseMessage,
     },
 };
 use async_trait::async_trait;
@@ -39,12 +41,34 @@ fn serialize<D: Serialize>(data: &D) -> Result<Vec<u8>, io::Error> {
         .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
 }
 
+/// Returns the number of bytes that `data` occupies when encoded by the [`PostcardCodec`].
+pub(crate) fn serialized_size<D: Serialize>(data: &D) -> usize {
+    postcard::serialize_with_flavor(data, postcard::ser_flavors::Size::default())
+        .expect("Measuring the size of the encoded data is infallible")
+}
+
+fn check_request_protocol(
+    request: &RequestMessage,
+    protocol: &RequestResponseProtocol,
+) -> Result<(), io::Error> {
+    if request.min_protocol() > *protocol {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            UnsupportedByProtocol(*protocol),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PostcardCodec {
     /// Used for `max_size` parameter when reading Response Message
     /// Necessary in order to avoid DoS attacks
     /// Currently the size mostly depends on the max size of the Block
     max_response_size: usize,
+    /// Supported versions of the request-response protocol
+    protocols: Vec<RequestResponseProtocol>,
 }
 
 impl PostcardCodec {
@@ -56,8 +80,17 @@ impl PostcardCodec {
 
         Self {
             max_response_size: max_block_size,
+            protocols: RequestResponseProtocol::ALL.to_vec(),
         }
     }
+
+    /// Limits the supported versions of the request-response protocol,
+    /// to simulate nodes that are not upgraded yet.
+    #[cfg(test)]
+    pub fn with_protocols(mut self, protocols: Vec<RequestResponseProtocol>) -> Self {
+        self.protocols = protocols;
+        self
+    }
 }
 
 /// Since Postcard does not support async reads or writes out of the box
@@ -69,13 +102,13 @@ impl PostcardCodec {
 /// run into a timeout waiting for the response.
 #[async_trait]
 impl request_response::Codec for PostcardCodec {
-    type Protocol = MessageExchangePostcardProtocol;
+    type Protocol = RequestResponseProtocol;
     type Request = RequestMessage;
     type Response = ResponseMessage;
 
     async fn read_request<T>(
         &mut self,
-        _: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
     ) -> io::Result<Self::Request>
     where
@@ -86,12 +119,14 @@ impl request_response::Codec for PostcardCodec {
             .take(self.max_response_size as u64)
             .read_to_end(&mut response)
             .await?;
-        deserialize(&response)
+        let request = deserialize(&response)?;
+        check_request_protocol(&request, protocol)?;
+        Ok(request)
     }
 
     async fn read_response<T>(
         &mut self,
-        _: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
     ) -> io::Result<Self::Response>
     where
@@ -103,18 +138,24 @@ impl request_response::Codec for PostcardCodec {
             .read_to_end(&mut response)
             .await?;
 
-        deserialize(&response)
+        match protocol {
+            RequestResponseProtocol::V1 => {
+                deserialize::<V1ResponseMessage>(&response).map(Into::into)
+            }
+            RequestResponseProtocol::V2 => deserialize(&response),
+        }
     }
 
     async fn write_request<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
         req: Self::Request,
     ) -> io::Result<()>
     where
         T: futures::AsyncWrite + Unpin + Send,
     {
+        check_request_protocol(&req, protocol)?;
         let encoded_data = serialize(&req)?;
         socket.write_all(&encoded_data).await?;
         Ok(())
@@ -122,14 +163,21 @@ impl request_response::Codec for PostcardCodec {
 
     async fn write_response<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
         res: Self::Response,
     ) -> io::Result<()>
     where
         T: futures::AsyncWrite + Unpin + Send,
     {
-        let encoded_data = serialize(&res)?;
+        let encoded_data = match protocol {
+            RequestResponseProtocol::V1 => {
+                let res = V1ResponseMessage::try_from(res)
+                    .map_err(|e| io::Error::new(io::ErrorKind::Unsupported, e))?;
+                serialize(&res)?
+            }
+            RequestResponseProtocol::V2 => serialize(&res)?,
+        };
         socket.write_all(&encoded_data).await?;
         Ok(())
     }
@@ -142,6 +190,9 @@ impl GossipsubCodec for PostcardCodec {
     fn encode(&self, data: Self::RequestMessage) -> Result<Vec<u8>, io::Error> {
         let encoded_data = match data {
             GossipsubBroadcastRequest::NewTx(tx) => postcard::to_stdvec(&*tx),
+            GossipsubBroadcastRequest::NewTxAnnouncement(tx_id) => {
+                postcard::to_stdvec(&tx_id)
+            }
         };
 
         encoded_data.map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
@@ -154,6 +205,9 @@ impl GossipsubCodec for PostcardCodec {
     ) -> Result<Self::ResponseMessage, io::Error> {
         let decoded_response = match gossipsub_tag {
             GossipTopicTag::NewTx => GossipsubMessage::NewTx(deserialize(encoded_data)?),
+            GossipTopicTag::NewTxAnnouncement => {
+                GossipsubMessage::NewTxAnnouncement(deserialize(encoded_data)?)
+            }
         };
 
         Ok(decoded_response)
@@ -161,24 +215,19 @@ impl GossipsubCodec for PostcardCodec {
 }
 
 impl NetworkCodec for PostcardCodec {
-    fn get_req_res_protocol(&self) -> <Self as request_response::Codec>::Protocol {
-        MessageExchangePostcardProtocol {}
-    }
-}
-
-#[derive(Default, Debug, Clone)]
-pub struct MessageExchangePostcardProtocol;
-
-impl AsRef<str> for MessageExchangePostcardProtocol {
-    fn as_ref(&self) -> &str {
-        REQUEST_RESPONSE_PROTOCOL_ID
+    fn get_req_res_protocols(&self) -> Vec<<Self as request_response::Codec>::Protocol> {
+        self.protocols.clone()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::request_response::messages::MAX_REQUEST_SIZE;
+    use crate::request_response::messages::{
+        ResponseMessageErrorCode,
+        MAX_REQUEST_SIZE,
+    };
+    use request_response::Codec;
 
     #[test]
     fn test_request_size_fits() {
@@ -186,4 +235,114 @@ mod tests {
         let m = RequestMessage::Transactions(arbitrary_range);
         assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
     }
+
+    async fn write_and_read_response(
+        protocol: RequestResponseProtocol,
+        response: ResponseMessage,
+    ) -> ResponseMessage {
+        let mut codec = PostcardCodec::new(1024);
+        let mut buffer = Vec::new();
+        codec
+            .write_response(&protocol, &mut buffer, response)
+            .await
+            .unwrap();
+        codec
+            .read_response(&protocol, &mut futures::io::Cursor::new(buffer))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn v2_response_keeps_error_code() {
+        let response = ResponseMessage::SealedHeaders(Err(
+            ResponseMessageErrorCode::RequestedRangeTooLarge,
+        ));
+
+        let response =
+            write_and_read_response(RequestResponseProtocol::V2, response).await;
+
+        assert!(matches!(
+            response,
+            ResponseMessage::SealedHeaders(Err(
+                ResponseMessageErrorCode::RequestedRangeTooLarge
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn v1_response_loses_error_code() {
+        let response = ResponseMessage::SealedHeaders(Err(
+            ResponseMessageErrorCode::RequestedRangeTooLarge,
+        ));
+
+        let response =
+            write_and_read_response(RequestResponseProtocol::V1, response).await;
+
+        assert!(matches!(
+            response,
+            ResponseMessage::SealedHeaders(Err(
+                ResponseMessageErrorCode::ProtocolV1EmptyResponse
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn v1_response_is_compatible_with_previous_encoding() {
+        let response = ResponseMessage::Transactions(Ok(vec![]));
+        let mut buffer = Vec::new();
+
+        PostcardCodec::new(1024)
+            .write_response(&RequestResponseProtocol::V1, &mut buffer, response)
+            .await
+            .unwrap();
+
+        // The response of the `V1` protocol before the versioning was introduced
+        let previous_encoding =
+            postcard::to_stdvec(&V1ResponseMessage::Transactions(Some(vec![]))).unwrap();
+        assert_eq!(buffer, previous_encoding);
+    }
+
+    #[tokio::test]
+    async fn v1_doesnt_support_requests_added_in_v2() {
+        let mut codec = PostcardCodec::new(1024);
+        let request = RequestMessage::SealedBlocks(0..2);
+
+        let result = codec
+            .write_request(&RequestResponseProtocol::V1, &mut Vec::new(), request)
+            .await;
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::Unsupported);
+    }
+
+    #[tokio::test]
+    async fn v1_rejects_inbound_requests_added_in_v2() {
+        let mut codec = PostcardCodec::new(1024);
+        let request = RequestMessage::TransactionsById(vec![Default::default()]);
+        let mut buffer = Vec::new();
+        codec
+            .write_request(&RequestResponseProtocol::V2, &mut buffer, request)
+            .await
+            .unwrap();
+
+        let result = codec
+            .read_request(
+                &RequestResponseProtocol::V1,
+                &mut futures::io::Cursor::new(buffer),
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn serialized_size_matches_the_encoding() {
+        let response = ResponseMessage::TransactionsById(Ok(vec![
+            fuel_core_types::fuel_tx::Transaction::default_test_tx(),
+        ]));
+
+        assert_eq!(
+            serialized_size(&response),
+            serialize(&response).unwrap().len()
+        );
+    }
 }
@@ -1,10 +1,15 @@
 use crate::{
+    codecs::postcard::serialized_size,
     gossipsub::config::default_gossipsub_config,
     heartbeat,
     peer_manager::ConnectionState,
+    request_response::rate_limit::RequestRateLimits,
     TryPeerId,
 };
-use fuel_core_types::blockchain::consensus::Genesis;
+use fuel_core_types::{
+    blockchain::consensus::Genesis,
+    fuel_tx::TxId,
+};
 
 use libp2p::{
     core::{
@@ -17,6 +22,7 @@ use libp2p::{
         Keypair,
     },
     noise,
+    relay,
     tcp::{
         self,
         tokio,
@@ -42,14 +48,19 @@ use std::{
 
 use self::{
     connection_tracker::ConnectionTracker,
-    fuel_authenticated::FuelAuthenticated,
+    fuel_authenticated::{
+        Approver,
+        FuelAuthenticated,
+    },
     fuel_upgrade::Checksum,
     guarded_node::GuardedNode,
+    quic_authenticated::build_quic_transport,
 };
 mod connection_tracker;
 mod fuel_authenticated;
 pub(crate) mod fuel_upgrade;
 mod guarded_node;
+mod quic_authenticated;
 
 const REQ_RES_TIMEOUT: Duration = Duration::from_secs(20);
 
@@ -62,10 +73,26 @@ pub const MAX_RESPONSE_SIZE: usize = 18 * 1024 * 1024;
 /// Maximum number of headers per request.
 pub const MAX_HEADERS_PER_REQUEST: u32 = 100;
 
+/// Maximum number of snapshot chunks per request.
+pub const MAX_SNAPSHOT_CHUNKS_PER_REQUEST: u32 = 16;
+
 /// Adds a timeout to the setup and protocol upgrade process for all
 /// inbound and outbound connections established through the transport.
 const TRANSPORT_TIMEOUT: Duration = Duration::from_secs(20);
 
+/// Defines how the node gossips new transactions to the network.
+/// Nodes receive and handle both kinds of gossip regardless of the mode,
+/// so the mode only affects transactions published by this node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TxGossipMode {
+    /// The whole transaction is sent to every peer in the mesh.
+    #[default]
+    Full,
+    /// Only the id of the transaction is sent, and peers that miss the transaction
+    /// request it via the request-response protocol.
+    Announcement,
+}
+
 #[derive(Clone, Debug)]
 pub struct Config<State = Initialized> {
     /// The keypair used for for handshake during communication with other p2p nodes.
@@ -86,9 +113,15 @@ pub struct Config<State = Initialized> {
     /// The TCP port that Swarm listens on
     pub tcp_port: u16,
 
+    /// The UDP port that Swarm listens on for QUIC connections in addition to the TCP port.
+    /// QUIC is disabled if `None`.
+    pub quic_port: Option<u16>,
+
     /// Max Size of a Block in bytes
     pub max_block_size: usize,
     pub max_headers_per_request: u32,
+    /// Limits of the inbound requests from a single peer.
+    pub request_rate_limits: RequestRateLimits,
 
     // `DiscoveryBehaviour` related fields
     pub bootstrap_nodes: Vec<Multiaddr>,
@@ -103,6 +136,23 @@ pub struct Config<State = Initialized> {
     /// Should the node only accept connection requests from the Reserved Nodes
     pub reserved_nodes_only_mode: bool,
 
+    // 'Sentry Node' mode
+    /// Reserved nodes that are hidden behind this node, like a block producer running
+    /// in the `reserved_nodes_only_mode`. They are never advertised to other peers,
+    /// while this node relays gossip and serves sync requests for them.
+    pub private_nodes: Vec<Multiaddr>,
+
+    // NAT traversal
+    /// Should the node map its ports on the gateway with UPnP
+    pub enable_upnp: bool,
+    /// Should the node relay connections for the peers behind the NAT.
+    /// The relay should know its external address, either the `public_address` or detected by the AutoNAT.
+    pub enable_relay_server: bool,
+    /// Relays that the node reserves circuits through, so it is dialable behind the NAT.
+    /// The relayed connections are upgraded to direct ones with hole punching.
+    /// They should contain PeerId within their `Multiaddr`.
+    pub relay_nodes: Vec<Multiaddr>,
+
     // `PeerManager` fields
     /// Max number of unique peers connected
     /// This number should be at least number of `mesh_n` from `Gossipsub` configuration.
@@ -136,6 +186,13 @@ pub struct Config<State = Initialized> {
     /// Max time since a given peer has sent a heartbeat before getting reputation penalty
     pub heartbeat_max_time_since_last: Duration,
 
+    /// How the node gossips the new transactions to the network.
+    pub tx_gossip_mode: TxGossipMode,
+
+    /// Subscribes to the gossip of new transactions and their announcements.
+    /// The node that can't validate transactions, like the light client, disables it.
+    pub subscribe_to_transactions: bool,
+
     /// Enables prometheus metrics for this fuel-service
     pub metrics: bool,
 
@@ -157,6 +214,9 @@ impl Config<NotInitialized> {
     pub fn init(self, genesis: Genesis) -> anyhow::Result<Config<Initialized>> {
         use fuel_core_chain_config::GenesisCommitment;
 
+        self.validate_sentry_mode()?;
+        self.validate_relay_nodes()?;
+
         Ok(Config {
             keypair: self.keypair,
             network_name: self.network_name,
@@ -164,8 +224,10 @@ impl Config<NotInitialized> {
             address: self.address,
             public_address: self.public_address,
             tcp_port: self.tcp_port,
+            quic_port: self.quic_port,
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
+            request_rate_limits: self.request_rate_limits,
             bootstrap_nodes: self.bootstrap_nodes,
             enable_mdns: self.enable_mdns,
             max_peers_connected: self.max_peers_connected,
@@ -175,6 +237,10 @@ impl Config<NotInitialized> {
             connection_idle_timeout: self.connection_idle_timeout,
             reserved_nodes: self.reserved_nodes,
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
+            private_nodes: self.private_nodes,
+            enable_upnp: self.enable_upnp,
+            enable_relay_server: self.enable_relay_server,
+            relay_nodes: self.relay_nodes,
             identify_interval: self.identify_interval,
             info_interval: self.info_interval,
             gossipsub_config: self.gossipsub_config,
@@ -184,12 +250,81 @@ impl Config<NotInitialized> {
             heartbeat_check_interval: self.heartbeat_check_interval,
             heartbeat_max_avg_interval: self.heartbeat_max_time_since_last,
             heartbeat_max_time_since_last: self.heartbeat_max_time_since_last,
+            tx_gossip_mode: self.tx_gossip_mode,
+            subscribe_to_transactions: self.subscribe_to_transactions,
             metrics: self.metrics,
             state: Initialized(()),
         })
     }
 }
 
+impl<State> Config<State> {
+    /// Checks that the private nodes are hidden behind this node correctly.
+    fn validate_sentry_mode(&self) -> anyhow::Result<()> {
+        if self.private_nodes.is_empty() {
+            return Ok(())
+        }
+
+        if self.reserved_nodes_only_mode {
+            anyhow::bail!(
+                "The sentry node with private nodes can't run in the reserved nodes only mode"
+            );
+        }
+
+        let reserved_peers = self
+            .reserved_nodes
+            .iter()
+            .filter_map(|node| node.try_to_peer_id())
+            .collect::<HashSet<_>>();
+        let bootstrap_peers = self
+            .bootstrap_nodes
+            .iter()
+            .filter_map(|node| node.try_to_peer_id())
+            .collect::<HashSet<_>>();
+        for node in &self.private_nodes {
+            let Some(peer_id) = node.try_to_peer_id() else {
+                anyhow::bail!("The private node {node} doesn't specify the peer id");
+            };
+            if !reserved_peers.contains(&peer_id) {
+                anyhow::bail!("The private node {node} should also be a reserved node");
+            }
+            if bootstrap_peers.contains(&peer_id) {
+                anyhow::bail!("The private node {node} can't be a bootstrap node");
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_relay_nodes(&self) -> anyhow::Result<()> {
+        if self.relay_nodes.is_empty() {
+            return Ok(())
+        }
+
+        if self.reserved_nodes_only_mode {
+            anyhow::bail!(
+                "The relay nodes can't be used in the reserved nodes only mode"
+            );
+        }
+
+        for node in &self.relay_nodes {
+            if node.try_to_peer_id().is_none() {
+                anyhow::bail!("The relay node {node} doesn't specify the peer id");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The maximum number of transactions that can be requested by id at once.
+    /// The request is limited by the `max_block_size`, like any other message.
+    pub fn max_txs_by_id_per_request(&self) -> usize {
+        self.max_block_size
+            .checked_div(serialized_size(&TxId::zeroed()))
+            .expect("The encoded `TxId` is not empty")
+    }
+}
+
 /// Takes secret key bytes generated outside of libp2p.
 /// And converts it into libp2p's `Keypair::Secp256k1`.
 pub fn convert_to_libp2p_keypair(
@@ -212,8 +347,10 @@ impl Config<NotInitialized> {
             address: IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0])),
             public_address: None,
             tcp_port: 0,
+            quic_port: None,
             max_block_size: MAX_RESPONSE_SIZE,
             max_headers_per_request: MAX_HEADERS_PER_REQUEST,
+            request_rate_limits: RequestRateLimits::new(MAX_RESPONSE_SIZE),
             bootstrap_nodes: vec![],
             enable_mdns: false,
             max_peers_connected: 50,
@@ -223,6 +360,10 @@ impl Config<NotInitialized> {
             connection_idle_timeout: Some(Duration::from_secs(120)),
             reserved_nodes: vec![],
             reserved_nodes_only_mode: false,
+            private_nodes: vec![],
+            enable_upnp: false,
+            enable_relay_server: false,
+            relay_nodes: vec![],
             gossipsub_config: default_gossipsub_config(),
             heartbeat_config: heartbeat::Config::default(),
             set_request_timeout: REQ_RES_TIMEOUT,
@@ -232,6 +373,8 @@ impl Config<NotInitialized> {
             heartbeat_max_time_since_last: Duration::from_secs(40),
             info_interval: Some(Duration::from_secs(3)),
             identify_interval: Some(Duration::from_secs(5)),
+            tx_gossip_mode: TxGossipMode::Full,
+            subscribe_to_transactions: true,
             metrics: false,
             state: NotInitialized,
         }
@@ -248,11 +391,13 @@ impl Config<Initialized> {
 }
 
 /// Transport for libp2p communication:
-/// TCP/IP, Websocket
+/// TCP/IP, Websocket, circuits through relays
 /// Noise as encryption layer
 /// mplex or yamux for multiplexing
+/// QUIC with built-in encryption and multiplexing, if `quic_port` is set
 pub(crate) fn build_transport_function(
     p2p_config: &Config,
+    relay_transport: relay::client::Transport,
 ) -> (
     impl FnOnce(&Keypair) -> Boxed<(PeerId, StreamMuxerBox)> + '_,
     Arc<RwLock<ConnectionState>>,
@@ -260,70 +405,87 @@ pub(crate) fn build_transport_function(
     let connection_state = ConnectionState::new();
     let kept_connection_state = connection_state.clone();
     let transport_function = move |keypair: &Keypair| {
-        let transport = {
-            let generate_tcp_transport = || {
-                tokio::Transport::new(tcp::Config::new().port_reuse(true).nodelay(true))
-            };
-
-            let tcp = generate_tcp_transport();
-
-            let ws_tcp = libp2p::websocket::WsConfig::new(generate_tcp_transport())
-                .or_transport(tcp);
-
-            libp2p::dns::tokio::Transport::system(ws_tcp).unwrap()
-        }
-        .upgrade(libp2p::core::upgrade::Version::V1Lazy);
-
-        let noise_authenticated =
-            noise::Config::new(keypair).expect("Noise key generation failed");
-
-        let multiplex_config = {
-            let mplex_config = MplexConfig::default();
-
-            let mut yamux_config = yamux::Config::default();
-            // TODO: remove deprecated method call https://github.com/FuelLabs/fuel-core/issues/1592
-            #[allow(deprecated)]
-            yamux_config.set_max_buffer_size(MAX_RESPONSE_SIZE);
-            libp2p::core::upgrade::SelectUpgrade::new(yamux_config, mplex_config)
-        };
-
         if p2p_config.reserved_nodes_only_mode {
             let guarded_node = GuardedNode::new(&p2p_config.reserved_nodes);
 
-            let fuel_authenticated = FuelAuthenticated::new(
-                noise_authenticated,
-                guarded_node,
-                p2p_config.checksum,
-            );
-
-            transport
-                .authenticate(fuel_authenticated)
-                .multiplex(multiplex_config)
-                .timeout(TRANSPORT_TIMEOUT)
-                .boxed()
+            build_transport(keypair, p2p_config, relay_transport, guarded_node)
         } else {
             let connection_tracker = ConnectionTracker::new(
                 &p2p_config.reserved_nodes,
                 connection_state.clone(),
             );
 
-            let fuel_authenticated = FuelAuthenticated::new(
-                noise_authenticated,
-                connection_tracker,
-                p2p_config.checksum,
-            );
-
-            transport
-                .authenticate(fuel_authenticated)
-                .multiplex(multiplex_config)
-                .timeout(TRANSPORT_TIMEOUT)
-                .boxed()
+            build_transport(keypair, p2p_config, relay_transport, connection_tracker)
         }
     };
 
     (transport_function, kept_connection_state)
 }
 
+/// Builds the TCP (and WebSocket) transport with relayed circuits and, if enabled, the QUIC transport.
+/// The `approver` decides whether the connection with the remote peer is allowed.
+fn build_transport<A>(
+    keypair: &Keypair,
+    p2p_config: &Config,
+    relay_transport: relay::client::Transport,
+    approver: A,
+) -> Boxed<(PeerId, StreamMuxerBox)>
+where
+    A: Approver + Clone + Send + Sync + 'static,
+{
+    let transport = {
+        let generate_tcp_transport =
+            || tokio::Transport::new(tcp::Config::new().port_reuse(true).nodelay(true));
+
+        let tcp = generate_tcp_transport();
+
+        let ws_tcp =
+            libp2p::websocket::WsConfig::new(generate_tcp_transport()).or_transport(tcp);
+
+        let dns_ws_tcp = libp2p::dns::tokio::Transport::system(ws_tcp).unwrap();
+
+        // Circuits are authenticated and multiplexed like direct TCP connections
+        relay_transport.or_transport(dns_ws_tcp)
+    }
+    .upgrade(libp2p::core::upgrade::Version::V1Lazy);
+
+    let noise_authenticated =
+        noise::Config::new(keypair).expect("Noise key generation failed");
+
+    let multiplex_config = {
+        let mplex_config = MplexConfig::default();
+
+        let mut yamux_config = yamux::Config::default();
+        // TODO: remove deprecated method call https://github.com/FuelLabs/fuel-core/issues/1592
+        #[allow(deprecated)]
+        yamux_config.set_max_buffer_size(MAX_RESPONSE_SIZE);
+        libp2p::core::upgrade::SelectUpgrade::new(yamux_config, mplex_config)
+    };
+
+    let fuel_authenticated = FuelAuthenticated::new(
+        noise_authenticated,
+        approver.clone(),
+        p2p_config.checksum,
+    );
+
+    let tcp_transport = transport
+        .authenticate(fuel_authenticated)
+        .multiplex(multiplex_config)
+        .timeout(TRANSPORT_TIMEOUT)
+        .boxed();
+
+    if p2p_config.quic_port.is_none() {
+        return tcp_transport
+    }
+
+    // The QUIC transport goes first to be used for QUIC addresses,
+    // other addresses fall through to the TCP transport.
+    build_quic_transport(keypair, approver, p2p_config.checksum)
+        .or_transport(tcp_transport)
+        .map(|either, _| either.into_inner())
+        .boxed()
+}
+
 fn peer_ids_set_from(multiaddr: &[Multiaddr]) -> HashSet<PeerId> {
     multiaddr
         .iter()
@@ -332,3 +494,71 @@ fn peer_ids_set_from(multiaddr: &[Multiaddr]) -> HashSet<PeerId> {
         .map(|address| address.try_to_peer_id().unwrap())
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    fn node_address(peer_id: PeerId) -> Multiaddr {
+        format!("/ip4/127.0.0.1/tcp/4001/p2p/{peer_id}")
+            .parse()
+            .unwrap()
+    }
+
+    fn sentry_config(private_node: Multiaddr) -> Config<NotInitialized> {
+        let mut config = Config::default("validate_sentry_mode");
+        config.reserved_nodes = vec![private_node.clone()];
+        config.private_nodes = vec![private_node];
+        config
+    }
+
+    #[test]
+    fn validate_sentry_mode__accepts_config_without_private_nodes() {
+        let mut config = Config::default("validate_sentry_mode");
+        config.reserved_nodes_only_mode = true;
+
+        assert!(config.validate_sentry_mode().is_ok());
+    }
+
+    #[test]
+    fn validate_sentry_mode__accepts_private_node_that_is_reserved() {
+        let config = sentry_config(node_address(PeerId::random()));
+
+        assert!(config.validate_sentry_mode().is_ok());
+    }
+
+    #[test]
+    fn validate_sentry_mode__rejects_reserved_nodes_only_mode() {
+        let mut config = sentry_config(node_address(PeerId::random()));
+        config.reserved_nodes_only_mode = true;
+
+        assert!(config.validate_sentry_mode().is_err());
+    }
+
+    #[test]
+    fn validate_sentry_mode__rejects_private_node_without_peer_id() {
+        let private_node: Multiaddr = "/ip4/127.0.0.1/tcp/4001".parse().unwrap();
+        let config = sentry_config(private_node);
+
+        assert!(config.validate_sentry_mode().is_err());
+    }
+
+    #[test]
+    fn validate_sentry_mode__rejects_private_node_that_is_not_reserved() {
+        let mut config = sentry_config(node_address(PeerId::random()));
+        config.reserved_nodes = vec![node_address(PeerId::random())];
+
+        assert!(config.validate_sentry_mode().is_err());
+    }
+
+    #[test]
+    fn validate_sentry_mode__rejects_private_node_that_is_bootstrap_node() {
+        let private_node = node_address(PeerId::random());
+        let mut config = sentry_config(private_node.clone());
+        config.bootstrap_nodes = vec![private_node];
+
+        assert!(config.validate_sentry_mode().is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use super::{
+    fuel_authenticated::Approver,
+    fuel_upgrade::Checksum,
+    MAX_RESPONSE_SIZE,
+    TRANSPORT_TIMEOUT,
+};
+use futures::{
+    future,
+    AsyncReadExt,
+    AsyncWriteExt,
+};
+use libp2p::{
+    core::{
+        muxing::{
+            StreamMuxerBox,
+            StreamMuxerExt,
+        },
+        transport::{
+            timeout::TransportTimeout,
+            Boxed,
+        },
+        ConnectedPoint,
+    },
+    identity::Keypair,
+    quic,
+    PeerId,
+    Transport,
+};
+use std::io;
+
+/// Builds the QUIC transport.
+///
+/// QUIC connections are encrypted and authenticated by TLS, so the noise upgrade
+/// with the [`Checksum`] in the protocol name can't be used. Instead, both sides
+/// exchange the checksum over the first stream of the connection and close it if
+/// the checksum of the remote node is different.
+pub(crate) fn build_quic_transport<A>(
+    keypair: &Keypair,
+    approver: A,
+    checksum: Checksum,
+) -> Boxed<(PeerId, StreamMuxerBox)>
+where
+    A: Approver + Clone + Send + Sync + 'static,
+{
+    let mut config = quic::Config::new(keypair);
+    config.max_stream_data = u32::try_from(MAX_RESPONSE_SIZE).unwrap_or(u32::MAX);
+    config.max_connection_data =
+        u32::try_from(MAX_RESPONSE_SIZE.saturating_mul(2)).unwrap_or(u32::MAX);
+
+    let transport = quic::tokio::Transport::new(config)
+        .and_then(move |(peer_id, connection), endpoint| {
+            authenticate(peer_id, connection, endpoint, approver.clone(), checksum)
+        })
+        .map(|(peer_id, connection), _| (peer_id, StreamMuxerBox::new(connection)));
+
+    TransportTimeout::new(transport, TRANSPORT_TIMEOUT).boxed()
+}
+
+async fn authenticate<A: Approver>(
+    peer_id: PeerId,
+    mut connection: quic::Connection,
+    endpoint: ConnectedPoint,
+    approver: A,
+    checksum: Checksum,
+) -> io::Result<(PeerId, quic::Connection)> {
+    if !approver.allow_peer(&peer_id) {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("The connection with {peer_id} is not allowed"),
+        ))
+    }
+
+    // The dialer opens the stream and writes first, because the listener
+    // doesn't see the stream until some data is sent over it.
+    let mut stream = if endpoint.is_dialer() {
+        future::poll_fn(|cx| connection.poll_outbound_unpin(cx)).await
+    } else {
+        future::poll_fn(|cx| connection.poll_inbound_unpin(cx)).await
+    }
+    .map_err(io::Error::other)?;
+
+    stream.write_all(checksum.as_ref()).await?;
+    stream.flush().await?;
+
+    let mut remote_checksum = [0u8; 32];
+    stream.read_exact(&mut remote_checksum).await?;
+    if remote_checksum != checksum.as_ref() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("The checksum of {peer_id} is different"),
+        ))
+    }
+    stream.close().await?;
+
+    Ok((peer_id, connection))
+}
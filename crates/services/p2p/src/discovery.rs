@@ -7,6 +7,7 @@ use libp2p::{
         store::MemoryStore,
     },
     mdns,
+    multiaddr::Protocol,
     swarm::{
         derive_prelude::{
             ConnectionClosed,
@@ -50,6 +51,9 @@ pub struct Behaviour {
     /// Track the connected peers
     connected_peers: HashSet<PeerId>,
 
+    /// Peers that are never added to the routing table, so they are not advertised to other peers
+    private_peers: HashSet<PeerId>,
+
     /// For discovery on local network, optionally available
     mdns: MdnsWrapper,
 
@@ -69,8 +73,11 @@ pub struct Behaviour {
 
 impl Behaviour {
     /// Adds a known listen address of a peer participating in the DHT to the routing table.
+    /// Addresses of private peers are ignored.
     pub fn add_address(&mut self, peer_id: &PeerId, address: Multiaddr) {
-        self.kademlia.add_address(peer_id, address);
+        if !self.private_peers.contains(peer_id) {
+            self.kademlia.add_address(peer_id, address);
+        }
     }
 }
 
@@ -115,6 +122,9 @@ impl NetworkBehaviour for Behaviour {
             effective_role,
         )?;
         kademlia_addrs.extend(mdns_addrs);
+        // Addresses are dialed in order, so QUIC addresses go first to be preferred.
+        // The stable sort keeps the order of the rest.
+        kademlia_addrs.sort_by_key(|address| !is_quic_address(address));
         Ok(kademlia_addrs)
     }
 
@@ -198,15 +208,25 @@ impl NetworkBehaviour for Behaviour {
         }
 
         // poll sub-behaviors
-        if let Poll::Ready(kad_action) = self.kademlia.poll(cx) {
-            return Poll::Ready(kad_action)
-        };
+        while let Poll::Ready(kad_action) = self.kademlia.poll(cx) {
+            match kad_action {
+                // Kademlia adds connected peers to the routing table on its own,
+                // so private peers are removed right after that
+                ToSwarm::GenerateEvent(kad::Event::RoutingUpdated { peer, .. })
+                    if self.private_peers.contains(&peer) =>
+                {
+                    trace!("Removing private peer {:?} from the routing table", peer);
+                    self.kademlia.remove_peer(&peer);
+                }
+                kad_action => return Poll::Ready(kad_action),
+            }
+        }
 
         while let Poll::Ready(mdns_event) = self.mdns.poll(cx) {
             match mdns_event {
                 ToSwarm::GenerateEvent(mdns::Event::Discovered(list)) => {
                     for (peer_id, multiaddr) in list {
-                        self.kademlia.add_address(&peer_id, multiaddr);
+                        self.add_address(&peer_id, multiaddr);
                     }
                 }
                 ToSwarm::CloseConnection {
@@ -225,6 +245,12 @@ impl NetworkBehaviour for Behaviour {
     }
 }
 
+fn is_quic_address(address: &Multiaddr) -> bool {
+    address
+        .iter()
+        .any(|protocol| matches!(protocol, Protocol::QuicV1))
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -293,6 +319,88 @@ mod tests {
         (swarm, listen_addr, peer_id)
     }
 
+    fn routing_table_peers(swarm: &mut Swarm<Behaviour>) -> HashSet<PeerId> {
+        swarm
+            .behaviour_mut()
+            .kademlia
+            .kbuckets()
+            .flat_map(|bucket| {
+                bucket
+                    .iter()
+                    .map(|entry| *entry.node.key.preimage())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn private_nodes_are_not_added_to_routing_table() {
+        let mut private_swarm = Swarm::new_ephemeral(build_behavior_fn(vec![]));
+        let (private_addr, _) = private_swarm.listen().with_memory_addr_external().await;
+        let private_peer_id = *private_swarm.local_peer_id();
+        let private_addr = private_addr.with(Protocol::P2p(private_peer_id));
+
+        let mut public_swarm = Swarm::new_ephemeral(build_behavior_fn(vec![]));
+        let (public_addr, _) = public_swarm.listen().with_memory_addr_external().await;
+        let public_peer_id = *public_swarm.local_peer_id();
+
+        let mut sentry_swarm = Swarm::new_ephemeral(|keypair| {
+            let mut config =
+                Config::new(keypair.public().to_peer_id(), "test_network".into());
+            config
+                .max_peers_connected(MAX_PEERS)
+                .with_reserved_nodes(vec![private_addr.clone()])
+                .with_private_nodes(vec![private_addr.clone()]);
+            config.finish()
+        });
+        let (sentry_addr, _) = sentry_swarm.listen().await;
+        let sentry_peer_id = *sentry_swarm.local_peer_id();
+
+        sentry_swarm.connect(&mut private_swarm).await;
+        sentry_swarm.connect(&mut public_swarm).await;
+
+        // the private node queries the sentry, and the sentry queries the public node,
+        // so both peers are confirmed to support the Kademlia protocol
+        private_swarm
+            .behaviour_mut()
+            .add_address(&sentry_peer_id, sentry_addr);
+        private_swarm
+            .behaviour_mut()
+            .kademlia
+            .get_closest_peers(PeerId::random());
+        sentry_swarm
+            .behaviour_mut()
+            .add_address(&private_peer_id, private_addr);
+        sentry_swarm
+            .behaviour_mut()
+            .add_address(&public_peer_id, public_addr);
+        sentry_swarm
+            .behaviour_mut()
+            .kademlia
+            .get_closest_peers(PeerId::random());
+        tokio::spawn(private_swarm.loop_on_next());
+        tokio::spawn(public_swarm.loop_on_next());
+
+        let routing_table = tokio::time::timeout(Duration::from_secs(10), async {
+            let mut private_node_queried = false;
+            loop {
+                let routing_table = routing_table_peers(&mut sentry_swarm);
+                if private_node_queried && routing_table.contains(&public_peer_id) {
+                    break routing_table
+                }
+                if let Some(SwarmEvent::Behaviour(Event::InboundRequest { .. })) =
+                    sentry_swarm.next().await
+                {
+                    private_node_queried = true;
+                }
+            }
+        })
+        .await
+        .expect("The sentry should be queried and discover the public node");
+
+        assert!(!routing_table.contains(&private_peer_id));
+    }
+
     // builds 25 discovery swarms,
     // initially, only connects first_swarm to the rest of the swarms
     // after that each swarm uses kademlia to discover other swarms
@@ -27,6 +27,7 @@ pub struct Config {
     bootstrap_nodes: Vec<Multiaddr>,
     reserved_nodes: Vec<Multiaddr>,
     reserved_nodes_only_mode: bool,
+    private_nodes: Vec<Multiaddr>,
     random_walk: Option<Duration>,
     with_mdns: bool,
     network_name: String,
@@ -41,6 +42,7 @@ impl Config {
             bootstrap_nodes: vec![],
             reserved_nodes: vec![],
             reserved_nodes_only_mode: false,
+            private_nodes: vec![],
             random_walk: None,
             max_peers_connected: std::usize::MAX,
             with_mdns: false,
@@ -87,6 +89,15 @@ impl Config {
         self
     }
 
+    // List of private nodes that are never advertised to other peers
+    pub fn with_private_nodes<I>(&mut self, private_nodes: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Multiaddr>,
+    {
+        self.private_nodes.extend(private_nodes);
+        self
+    }
+
     pub fn enable_mdns(&mut self, value: bool) -> &mut Self {
         self.with_mdns = value;
         self
@@ -105,6 +116,7 @@ impl Config {
             max_peers_connected,
             reserved_nodes,
             reserved_nodes_only_mode,
+            private_nodes,
             ..
         } = self;
 
@@ -126,6 +138,12 @@ impl Config {
             .filter_map(|node| node.try_to_peer_id().map(|peer_id| (peer_id, node)))
             .collect::<Vec<_>>();
 
+        // private nodes need to have their peer_id defined in the Multiaddr
+        let private_peers = private_nodes
+            .iter()
+            .filter_map(|node| node.try_to_peer_id())
+            .collect::<HashSet<_>>();
+
         // reserved nodes need to have their peer_id defined in the Multiaddr
         let reserved_nodes = reserved_nodes
             .into_iter()
@@ -139,8 +157,11 @@ impl Config {
             }
         }
 
+        // private nodes are dialed as reserved nodes, but never added to the routing table
         for (peer_id, address) in &reserved_nodes {
-            kademlia.add_address(peer_id, address.clone());
+            if !private_peers.contains(peer_id) {
+                kademlia.add_address(peer_id, address.clone());
+            }
         }
 
         if let Err(e) = kademlia.bootstrap() {
@@ -169,6 +190,7 @@ impl Config {
 
         Behaviour {
             connected_peers: HashSet::new(),
+            private_peers,
             kademlia,
             next_kad_random_walk,
             duration_to_next_kad: Duration::from_secs(1),
@@ -24,6 +24,7 @@ use std::time::Duration;
 
 use super::topics::{
     GossipTopic,
+    NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC,
     NEW_TX_GOSSIP_TOPIC,
 };
 
@@ -48,6 +49,9 @@ const MESH_SIZE: usize = 8;
 // The weight applied to the score for delivering new transactions.
 const NEW_TX_GOSSIP_WEIGHT: f64 = 0.05;
 
+// The weight applied to the score for delivering announcements of new transactions.
+const NEW_TX_ANNOUNCEMENT_GOSSIP_WEIGHT: f64 = 0.05;
+
 // The threshold for a peer's score to be considered for greylisting.
 // If a peer's score falls below this value, they will be greylisted.
 // Greylisting is a lighter form of banning, where the peer's messages might be ignored or given lower priority,
@@ -226,7 +230,14 @@ fn initialize_gossipsub(gossipsub: &mut gossipsub::Behaviour, p2p_config: &Confi
         .with_peer_score(peer_score_params, peer_score_thresholds)
         .expect("gossipsub initialized with peer score");
 
-    let topics = vec![(NEW_TX_GOSSIP_TOPIC, NEW_TX_GOSSIP_WEIGHT)];
+    let mut topics = vec![];
+    if p2p_config.subscribe_to_transactions {
+        topics.push((NEW_TX_GOSSIP_TOPIC, NEW_TX_GOSSIP_WEIGHT));
+        topics.push((
+            NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC,
+            NEW_TX_ANNOUNCEMENT_GOSSIP_WEIGHT,
+        ));
+    }
 
     // subscribe to gossipsub topics with the network name suffix
     for (topic, weight) in topics {
@@ -241,3 +252,19 @@ fn initialize_gossipsub(gossipsub: &mut gossipsub::Behaviour, p2p_config: &Confi
             .expect("Subscription to Topic: {topic} successful");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    #[test]
+    fn build_gossipsub_behaviour__subscribes_to_transactions_only_if_enabled() {
+        let mut p2p_config = Config::default_initialized("test_network");
+        assert_eq!(build_gossipsub_behaviour(&p2p_config).topics().count(), 2);
+
+        p2p_config.subscribe_to_transactions = false;
+        assert_eq!(build_gossipsub_behaviour(&p2p_config).topics().count(), 0);
+    }
+}
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    TxId,
+};
 
 use serde::{
     Deserialize,
@@ -12,6 +15,7 @@ use serde::{
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum GossipTopicTag {
     NewTx,
+    NewTxAnnouncement,
 }
 
 /// Takes `Arc<T>` and wraps it in a matching GossipsubBroadcastRequest
@@ -20,9 +24,12 @@ pub enum GossipTopicTag {
 #[derive(Debug, Clone)]
 pub enum GossipsubBroadcastRequest {
     NewTx(Arc<Transaction>),
+    /// Announces the id of the new transaction, that peers can request if they miss it.
+    NewTxAnnouncement(TxId),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum GossipsubMessage {
     NewTx(Transaction),
+    NewTxAnnouncement(TxId),
 }
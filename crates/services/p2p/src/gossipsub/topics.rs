@@ -11,6 +11,7 @@ use super::messages::{
 
 pub type GossipTopic = Sha256Topic;
 pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
+pub const NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC: &str = "new_tx_announcement";
 
 /// Holds used Gossipsub Topics
 /// Each field contains TopicHash and GossipTopic itself
@@ -18,14 +19,21 @@ pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
 #[derive(Debug)]
 pub struct GossipsubTopics {
     new_tx_topic: (TopicHash, GossipTopic),
+    new_tx_announcement_topic: (TopicHash, GossipTopic),
 }
 
 impl GossipsubTopics {
     pub fn new(network_name: &str) -> Self {
         let new_tx_topic = Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+        let new_tx_announcement_topic =
+            Topic::new(format!("{NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC}/{network_name}"));
 
         Self {
             new_tx_topic: (new_tx_topic.hash(), new_tx_topic),
+            new_tx_announcement_topic: (
+                new_tx_announcement_topic.hash(),
+                new_tx_announcement_topic,
+            ),
         }
     }
 
@@ -34,10 +42,16 @@ impl GossipsubTopics {
         &self,
         incoming_topic: &TopicHash,
     ) -> Option<GossipTopicTag> {
-        let GossipsubTopics { new_tx_topic } = &self;
+        let GossipsubTopics {
+            new_tx_topic,
+            new_tx_announcement_topic,
+        } = &self;
 
         match incoming_topic {
             hash if hash == &new_tx_topic.0 => Some(GossipTopicTag::NewTx),
+            hash if hash == &new_tx_announcement_topic.0 => {
+                Some(GossipTopicTag::NewTxAnnouncement)
+            }
             _ => None,
         }
     }
@@ -50,6 +64,9 @@ impl GossipsubTopics {
     ) -> GossipTopic {
         match outgoing_request {
             GossipsubBroadcastRequest::NewTx(_) => self.new_tx_topic.1.clone(),
+            GossipsubBroadcastRequest::NewTxAnnouncement(_) => {
+                self.new_tx_announcement_topic.1.clone()
+            }
         }
     }
 }
@@ -66,17 +83,27 @@ mod tests {
         let network_name = "fuel_test_network";
         let new_tx_topic: GossipTopic =
             Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+        let new_tx_announcement_topic: GossipTopic =
+            Topic::new(format!("{NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC}/{network_name}"));
 
         let gossipsub_topics = GossipsubTopics::new(network_name);
 
         // Test matching Topic Hashes
         assert_eq!(gossipsub_topics.new_tx_topic.0, new_tx_topic.hash());
+        assert_eq!(
+            gossipsub_topics.new_tx_announcement_topic.0,
+            new_tx_announcement_topic.hash()
+        );
 
         // Test given a TopicHash that `get_gossipsub_tag()` returns matching `GossipTopicTag`
         assert_eq!(
             gossipsub_topics.get_gossipsub_tag(&new_tx_topic.hash()),
             Some(GossipTopicTag::NewTx)
         );
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&new_tx_announcement_topic.hash()),
+            Some(GossipTopicTag::NewTxAnnouncement)
+        );
 
         // Test given a `GossipsubBroadcastRequest` that `get_gossipsub_topic()` returns matching `Topic`
         let broadcast_req =
@@ -85,5 +112,11 @@ mod tests {
             gossipsub_topics.get_gossipsub_topic(&broadcast_req).hash(),
             new_tx_topic.hash()
         );
+        let broadcast_req =
+            GossipsubBroadcastRequest::NewTxAnnouncement(Default::default());
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_topic(&broadcast_req).hash(),
+            new_tx_announcement_topic.hash()
+        );
     }
 }
@@ -4,7 +4,10 @@ use crate::{
         FuelBehaviourEvent,
     },
     codecs::{
-        postcard::PostcardCodec,
+        postcard::{
+            serialized_size,
+            PostcardCodec,
+        },
         GossipsubCodec,
     },
     config::{
@@ -13,6 +16,7 @@ use crate::{
     },
     gossipsub::{
         messages::{
+            GossipTopicTag,
             GossipsubBroadcastRequest,
             GossipsubMessage as FuelGossipsubMessage,
         },
@@ -24,23 +28,40 @@ use crate::{
         Punisher,
     },
     peer_report::PeerReportEvent,
-    request_response::messages::{
-        RequestError,
-        RequestMessage,
-        ResponseError,
-        ResponseMessage,
-        ResponseSendError,
-        ResponseSender,
+    request_response::{
+        messages::{
+            RequestError,
+            RequestMessage,
+            ResponseError,
+            ResponseMessage,
+            ResponseMessageErrorCode,
+            ResponseSendError,
+            ResponseSender,
+        },
+        rate_limit::RequestRateLimiter,
     },
     TryPeerId,
 };
 use fuel_core_metrics::p2p_metrics::p2p_metrics;
 use fuel_core_types::{
     fuel_types::BlockHeight,
-    services::p2p::peer_reputation::AppScore,
+    services::p2p::{
+        peer_reputation::{
+            AppScore,
+            PeerBan,
+            PeerReport,
+            PeerReputation,
+        },
+        NetworkReachability,
+        ReachabilityStatus,
+    },
+    tai64::Tai64,
 };
 use futures::prelude::*;
 use libp2p::{
+    autonat,
+    core::transport::ListenerId,
+    dcutr,
     gossipsub::{
         self,
         MessageAcceptance,
@@ -50,6 +71,7 @@ use libp2p::{
     },
     identify,
     multiaddr::Protocol,
+    relay,
     request_response::{
         self,
         InboundRequestId,
@@ -57,6 +79,7 @@ use libp2p::{
         ResponseChannel,
     },
     swarm::SwarmEvent,
+    upnp,
     Multiaddr,
     PeerId,
     Swarm,
@@ -64,12 +87,19 @@ use libp2p::{
 };
 use rand::seq::IteratorRandom;
 use std::{
-    collections::HashMap,
-    time::Duration,
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
 };
 use tokio::sync::broadcast;
 use tracing::{
     debug,
+    info,
     warn,
 };
 
@@ -80,6 +110,23 @@ impl Punisher for Swarm<FuelBehaviour> {
     fn ban_peer(&mut self, peer_id: PeerId) {
         self.behaviour_mut().block_peer(peer_id)
     }
+
+    fn unban_peer(&mut self, peer_id: PeerId) {
+        self.behaviour_mut().unblock_peer(peer_id)
+    }
+}
+
+/// Logs the reason why the peer didn't provide the requested data.
+/// The requesters only distinguish between the absence and presence of the data.
+fn log_error_code<T>(
+    peer: &PeerId,
+    response: Result<T, ResponseMessageErrorCode>,
+) -> Option<T> {
+    response
+        .map_err(
+            |code| debug!(target: "fuel-p2p", "{peer} didn't provide the data: {code}"),
+        )
+        .ok()
 }
 
 /// Listens to the events on the p2p network
@@ -94,6 +141,12 @@ pub struct FuelP2PService {
     /// The TCP port that Swarm listens on
     tcp_port: u16,
 
+    /// The UDP port that Swarm listens on for QUIC connections
+    quic_port: Option<u16>,
+
+    /// Relays that the node listens through
+    relay_nodes: Vec<Multiaddr>,
+
     /// Swarm handler for FuelBehaviour
     swarm: Swarm<FuelBehaviour>,
 
@@ -109,7 +162,11 @@ pub struct FuelP2PService {
     /// Whenever we're done processing the request, it's removed from this table,
     /// and the channel is used to send the result to libp2p, which will forward it
     /// to the peer that requested it.
-    inbound_requests_table: HashMap<InboundRequestId, ResponseChannel<ResponseMessage>>,
+    inbound_requests_table:
+        HashMap<InboundRequestId, (PeerId, ResponseChannel<ResponseMessage>)>,
+
+    /// Limits the requests served to each peer.
+    request_rate_limiter: RequestRateLimiter,
 
     /// NetworkCodec used as `<GossipsubCodec>` for encoding and decoding of Gossipsub messages    
     network_codec: PostcardCodec,
@@ -173,8 +230,11 @@ impl FuelP2PService {
         let network_metadata = NetworkMetadata { gossipsub_data };
 
         // configure and build P2P Service
-        let (transport_function, connection_state) = build_transport_function(&config);
-        let behaviour = FuelBehaviour::new(&config, codec.clone());
+        let local_peer_id = PeerId::from_public_key(&config.keypair.public());
+        let (relay_transport, relay_client) = relay::client::new(local_peer_id);
+        let (transport_function, connection_state) =
+            build_transport_function(&config, relay_transport);
+        let behaviour = FuelBehaviour::new(&config, codec.clone(), relay_client);
 
         let mut swarm = SwarmBuilder::with_existing_identity(config.keypair.clone())
             .with_tokio()
@@ -191,8 +251,6 @@ impl FuelP2PService {
             })
             .build();
 
-        let local_peer_id = swarm.local_peer_id().to_owned();
-
         let metrics = config.metrics;
 
         if let Some(public_address) = config.public_address.clone() {
@@ -209,10 +267,13 @@ impl FuelP2PService {
             local_peer_id,
             local_address: config.address,
             tcp_port: config.tcp_port,
+            quic_port: config.quic_port,
+            relay_nodes: config.relay_nodes.clone(),
             swarm,
             network_codec: codec,
             outbound_requests_table: HashMap::default(),
             inbound_requests_table: HashMap::default(),
+            request_rate_limiter: RequestRateLimiter::new(config.request_rate_limits),
             network_metadata,
             metrics,
             peer_manager: PeerManager::new(
@@ -225,35 +286,59 @@ impl FuelP2PService {
     }
 
     pub async fn start(&mut self) -> anyhow::Result<()> {
-        // set up node's address to listen on
-        let listen_multiaddr = {
+        // set up node's addresses to listen on
+        let mut listen_multiaddrs = vec![{
             let mut m = Multiaddr::from(self.local_address);
             m.push(Protocol::Tcp(self.tcp_port));
             m
-        };
+        }];
+        if let Some(quic_port) = self.quic_port {
+            let mut m = Multiaddr::from(self.local_address);
+            m.push(Protocol::Udp(quic_port));
+            m.push(Protocol::QuicV1);
+            listen_multiaddrs.push(m);
+        }
         let peer_id = self.local_peer_id;
 
-        tracing::info!(
-            "The p2p service starts on the `{listen_multiaddr}` with `{peer_id}`"
-        );
+        let mut listeners = HashSet::new();
+        for listen_multiaddr in listen_multiaddrs {
+            tracing::info!(
+                "The p2p service starts on the `{listen_multiaddr}` with `{peer_id}`"
+            );
 
-        // start listening at the given address
-        self.swarm.listen_on(listen_multiaddr)?;
+            // start listening at the given address
+            listeners.insert(self.swarm.listen_on(listen_multiaddr)?);
+        }
 
         // Wait for listener addresses.
-        tokio::time::timeout(Duration::from_secs(5), self.await_listeners_address())
-            .await
-            .map_err(|_| {
-                anyhow::anyhow!("P2PService should get a new address within 5 seconds")
-            })?;
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            self.await_listeners_address(listeners),
+        )
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!("P2PService should get a new address within 5 seconds")
+        })?;
+
+        // The circuit addresses appear only after the relays accept the reservations,
+        // so they are not awaited.
+        for relay_node in self.relay_nodes.clone() {
+            let circuit_multiaddr = relay_node.with(Protocol::P2pCircuit);
+            tracing::info!(
+                "The p2p service listens through the relay `{circuit_multiaddr}`"
+            );
+            self.swarm.listen_on(circuit_multiaddr)?;
+        }
         Ok(())
     }
 
-    async fn await_listeners_address(&mut self) {
-        loop {
-            if let SwarmEvent::NewListenAddr { .. } = self.swarm.select_next_some().await
+    /// Waits until each of the `listeners` gets at least one address.
+    async fn await_listeners_address(&mut self, mut listeners: HashSet<ListenerId>) {
+        while !listeners.is_empty() {
+            if let SwarmEvent::NewListenAddr { listener_id, .. } =
+                self.swarm.select_next_some().await
             {
-                break;
+                listeners.remove(&listener_id);
             }
         }
     }
@@ -264,9 +349,12 @@ impl FuelP2PService {
         self.swarm
             .listeners()
             .map(|addr| {
-                format!("{addr}/p2p/{local_peer}")
-                    .parse()
-                    .expect("The format is always valid")
+                // Addresses of circuits through relays already end with the local peer id
+                if matches!(addr.iter().last(), Some(Protocol::P2p(_))) {
+                    addr.clone()
+                } else {
+                    addr.clone().with(Protocol::P2p(local_peer))
+                }
             })
             .collect()
     }
@@ -284,16 +372,36 @@ impl FuelP2PService {
             .gossipsub_data
             .topics
             .get_gossipsub_topic(&message);
+        let tag = match &message {
+            GossipsubBroadcastRequest::NewTx(_) => GossipTopicTag::NewTx,
+            GossipsubBroadcastRequest::NewTxAnnouncement(_) => {
+                GossipTopicTag::NewTxAnnouncement
+            }
+        };
 
         match self.network_codec.encode(message) {
-            Ok(encoded_data) => self
-                .swarm
-                .behaviour_mut()
-                .publish_message(topic, encoded_data),
+            Ok(encoded_data) => {
+                self.record_gossip_bytes(tag, encoded_data.len());
+                self.swarm
+                    .behaviour_mut()
+                    .publish_message(topic, encoded_data)
+            }
             Err(e) => Err(PublishError::TransformFailed(e)),
         }
     }
 
+    fn record_gossip_bytes(&self, tag: GossipTopicTag, bytes: usize) {
+        if self.metrics {
+            let counter = match tag {
+                GossipTopicTag::NewTx => &p2p_metrics().full_tx_gossip_bytes,
+                GossipTopicTag::NewTxAnnouncement => {
+                    &p2p_metrics().tx_announcement_gossip_bytes
+                }
+            };
+            counter.inc_by(bytes as u64);
+        }
+    }
+
     /// Sends RequestMessage to a peer
     /// If the peer is not defined it will pick one at random
     /// Only returns error if no peers are connected
@@ -334,11 +442,18 @@ impl FuelP2PService {
         request_id: InboundRequestId,
         message: ResponseMessage,
     ) -> Result<(), ResponseSendError> {
-        let Some(channel) = self.inbound_requests_table.remove(&request_id) else {
+        let Some((peer_id, channel)) = self.inbound_requests_table.remove(&request_id)
+        else {
             debug!("ResponseChannel for {:?} does not exist!", request_id);
             return Err(ResponseSendError::ResponseChannelDoesNotExist);
         };
 
+        self.request_rate_limiter.record_response(
+            peer_id,
+            serialized_size(&message),
+            Instant::now(),
+        );
+
         if self
             .swarm
             .behaviour_mut()
@@ -407,6 +522,31 @@ impl FuelP2PService {
         );
     }
 
+    /// Overrides the application score of the peer
+    pub fn set_app_score(&mut self, peer_id: PeerId, app_score: AppScore) {
+        self.peer_manager
+            .set_app_score(peer_id, app_score, &mut self.swarm);
+    }
+
+    /// Bans the peer and closes all connections with it
+    pub fn ban_peer(&mut self, peer_id: PeerId, ban: PeerBan) {
+        self.peer_manager.ban_peer(peer_id, ban, &mut self.swarm);
+    }
+
+    /// Returns `false` if the peer was not banned
+    pub fn unban_peer(&mut self, peer_id: PeerId) -> bool {
+        self.peer_manager.unban_peer(peer_id, &mut self.swarm)
+    }
+
+    /// Restores reputations of peers, stored during the previous run of the node
+    pub fn restore_reputations(
+        &mut self,
+        reputations: impl IntoIterator<Item = (PeerId, PeerReputation)>,
+    ) {
+        self.peer_manager
+            .restore_reputations(reputations, Tai64::now(), &mut self.swarm);
+    }
+
     #[tracing::instrument(skip_all,
         level = "debug",
         fields(
@@ -446,6 +586,25 @@ impl FuelP2PService {
         &self.peer_manager
     }
 
+    /// Returns the reachability of the node detected by the AutoNAT
+    /// and the external addresses confirmed so far.
+    pub fn reachability(&self) -> NetworkReachability {
+        let status = match self.swarm.behaviour().nat_status() {
+            autonat::NatStatus::Public(_) => ReachabilityStatus::Public,
+            autonat::NatStatus::Private => ReachabilityStatus::Private,
+            autonat::NatStatus::Unknown => ReachabilityStatus::Unknown,
+        };
+        let external_addresses = self
+            .swarm
+            .external_addresses()
+            .map(|address| address.to_string())
+            .collect();
+        NetworkReachability {
+            status,
+            external_addresses,
+        }
+    }
+
     fn handle_behaviour_event(
         &mut self,
         event: FuelBehaviourEvent,
@@ -458,6 +617,12 @@ impl FuelP2PService {
             }
             FuelBehaviourEvent::Identify(event) => self.handle_identify_event(event),
             FuelBehaviourEvent::Heartbeat(event) => self.handle_heartbeat_event(event),
+            FuelBehaviourEvent::Autonat(event) => self.handle_autonat_event(event),
+            FuelBehaviourEvent::Upnp(event) => self.handle_upnp_event(event),
+            FuelBehaviourEvent::RelayClient(event) => {
+                self.handle_relay_client_event(event)
+            }
+            FuelBehaviourEvent::Dcutr(event) => self.handle_dcutr_event(event),
             _ => None,
         }
     }
@@ -478,6 +643,7 @@ impl FuelP2PService {
                 .topics
                 .get_gossipsub_tag(&message.topic)
             {
+                self.record_gossip_bytes(correct_topic, message.data.len());
                 match self.network_codec.decode(&message.data, correct_topic) {
                     Ok(decoded_message) => {
                         return Some(FuelP2PEvent::GossipsubMessage {
@@ -510,7 +676,9 @@ impl FuelP2PService {
     ) -> Option<FuelP2PEvent> {
         match event {
             PeerReportEvent::PerformDecay => {
-                self.peer_manager.batch_update_score_with_decay()
+                self.peer_manager.batch_update_score_with_decay();
+                self.peer_manager
+                    .remove_expired_bans(Tai64::now(), &mut self.swarm);
             }
             PeerReportEvent::CheckReservedNodesHealth => {
                 let disconnected_peers: Vec<_> = self
@@ -539,6 +707,7 @@ impl FuelP2PService {
                 }
             }
             PeerReportEvent::PeerDisconnected { peer_id } => {
+                self.request_rate_limiter.remove_idle_peers(Instant::now());
                 if self.peer_manager.handle_peer_disconnect(peer_id) {
                     let _ = self.swarm.dial(peer_id);
                 }
@@ -559,7 +728,21 @@ impl FuelP2PService {
                     channel,
                     request_id,
                 } => {
-                    self.inbound_requests_table.insert(request_id, channel);
+                    // Reserved peers are trusted, so their requests are not limited.
+                    if !self.peer_manager.is_reserved(&peer) {
+                        if let Err(report) = self
+                            .request_rate_limiter
+                            .check_request(peer, Instant::now())
+                        {
+                            debug!(target: "fuel-p2p", "Dropping the request {:?} from the peer {:?}: {:?}", request_id, peer, report);
+                            // Dropping the channel notifies the peer that the request is not served.
+                            self.report_peer(peer, report.get_score_from_report(), "p2p");
+                            return None
+                        }
+                    }
+
+                    self.inbound_requests_table
+                        .insert(request_id, (peer, channel));
 
                     return Some(FuelP2PEvent::InboundRequestMessage {
                         request_id,
@@ -579,7 +762,7 @@ impl FuelP2PService {
                     let send_ok = match channel {
                         ResponseSender::SealedHeaders(c) => match response {
                             ResponseMessage::SealedHeaders(v) => {
-                                c.send((peer, Ok(v))).is_ok()
+                                c.send((peer, Ok(log_error_code(&peer, v)))).is_ok()
                             }
                             _ => {
                                 warn!(
@@ -591,6 +774,19 @@ impl FuelP2PService {
                         },
                         ResponseSender::Transactions(c) => match response {
                             ResponseMessage::Transactions(v) => {
+                                c.send((peer, Ok(log_error_code(&peer, v)))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
+                            }
+                        },
+                        ResponseSender::SnapshotManifest(c) => match response {
+                            ResponseMessage::SnapshotManifest(v) => {
+                                let v = log_error_code(&peer, v).map(|v| *v);
                                 c.send((peer, Ok(v))).is_ok()
                             }
                             _ => {
@@ -601,6 +797,54 @@ impl FuelP2PService {
                                 c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
                             }
                         },
+                        ResponseSender::SnapshotChunks(c) => match response {
+                            ResponseMessage::SnapshotChunks(v) => {
+                                c.send((peer, Ok(log_error_code(&peer, v)))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
+                            }
+                        },
+                        ResponseSender::TransactionsById(c) => match response {
+                            ResponseMessage::TransactionsById(v) => {
+                                c.send((peer, Ok(log_error_code(&peer, v)))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
+                            }
+                        },
+                        ResponseSender::SealedBlocks(c) => match response {
+                            ResponseMessage::SealedBlocks(v) => {
+                                c.send((peer, Ok(log_error_code(&peer, v)))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
+                            }
+                        },
+                        ResponseSender::PooledTransactionIds(c) => match response {
+                            ResponseMessage::PooledTransactionIds(v) => {
+                                c.send((peer, Ok(log_error_code(&peer, v)))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
+                            }
+                        },
                     };
 
                     if !send_ok {
@@ -633,6 +877,21 @@ impl FuelP2PService {
                         ResponseSender::Transactions(c) => {
                             let _ = c.send((peer, Err(ResponseError::P2P(error))));
                         }
+                        ResponseSender::SnapshotManifest(c) => {
+                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
+                        }
+                        ResponseSender::SnapshotChunks(c) => {
+                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
+                        }
+                        ResponseSender::TransactionsById(c) => {
+                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
+                        }
+                        ResponseSender::SealedBlocks(c) => {
+                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
+                        }
+                        ResponseSender::PooledTransactionIds(c) => {
+                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
+                        }
                     };
                 }
             }
@@ -696,6 +955,64 @@ impl FuelP2PService {
             block_height: latest_block_height,
         })
     }
+
+    fn handle_autonat_event(&mut self, event: autonat::Event) -> Option<FuelP2PEvent> {
+        if let autonat::Event::StatusChanged { old, new } = event {
+            info!(target: "fuel-p2p", "The reachability of the node changed from {:?} to {:?}", old, new);
+        }
+        None
+    }
+
+    fn handle_upnp_event(&mut self, event: upnp::Event) -> Option<FuelP2PEvent> {
+        match event {
+            upnp::Event::NewExternalAddr(address) => {
+                info!(target: "fuel-p2p", "The gateway mapped the external address {}", address);
+            }
+            upnp::Event::ExpiredExternalAddr(address) => {
+                debug!(target: "fuel-p2p", "The mapping of the external address {} expired", address);
+            }
+            upnp::Event::GatewayNotFound => {
+                warn!(target: "fuel-p2p", "The UPnP gateway is not found");
+            }
+            upnp::Event::NonRoutableGateway => {
+                warn!(target: "fuel-p2p", "The UPnP gateway is not exposed to the public network");
+            }
+        }
+        None
+    }
+
+    fn handle_relay_client_event(
+        &mut self,
+        event: relay::client::Event,
+    ) -> Option<FuelP2PEvent> {
+        if let relay::client::Event::ReservationReqAccepted {
+            relay_peer_id,
+            renewal: false,
+            ..
+        } = event
+        {
+            info!(target: "fuel-p2p", "The relay {:?} accepted the reservation", relay_peer_id);
+        } else {
+            debug!(target: "fuel-p2p", "Relay client event: {:?}", event);
+        }
+        None
+    }
+
+    fn handle_dcutr_event(&mut self, event: dcutr::Event) -> Option<FuelP2PEvent> {
+        let dcutr::Event {
+            remote_peer_id,
+            result,
+        } = event;
+        match result {
+            Ok(_) => {
+                debug!(target: "fuel-p2p", "Established the direct connection with {:?}", remote_peer_id);
+            }
+            Err(e) => {
+                debug!(target: "fuel-p2p", "Failed to establish the direct connection with {:?}: {}", remote_peer_id, e);
+            }
+        }
+        None
+    }
 }
 
 #[allow(clippy::cast_possible_truncation)]
@@ -715,33 +1032,48 @@ mod tests {
             },
             topics::{
                 GossipTopic,
+                NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC,
                 NEW_TX_GOSSIP_TOPIC,
             },
         },
         p2p_service::FuelP2PEvent,
         peer_manager::PeerInfo,
-        request_response::messages::{
-            RequestMessage,
-            ResponseError,
-            ResponseMessage,
-            ResponseSender,
+        request_response::{
+            messages::{
+                RequestMessage,
+                RequestResponseProtocol,
+                ResponseError,
+                ResponseMessage,
+                ResponseSender,
+            },
+            rate_limit::RequestRateLimits,
         },
         service::to_message_acceptance,
     };
     use fuel_core_types::{
         blockchain::{
+            block::Block,
             consensus::{
                 poa::PoAConsensus,
                 Consensus,
             },
             header::BlockHeader,
+            SealedBlock,
             SealedBlockHeader,
         },
         fuel_tx::{
             Transaction,
             TransactionBuilder,
+            UniqueIdentifier,
         },
         services::p2p::{
+            peer_reputation::DEFAULT_APP_SCORE,
+            snapshot::{
+                snapshot_root,
+                SnapshotChunk,
+                SnapshotChunksRequest,
+                SnapshotManifest,
+            },
             GossipsubMessageAcceptance,
             Transactions,
         },
@@ -753,6 +1085,7 @@ mod tests {
     use libp2p::{
         gossipsub::Topic,
         identity::Keypair,
+        multiaddr::Protocol,
         swarm::{
             ListenError,
             SwarmEvent,
@@ -778,14 +1111,22 @@ mod tests {
     type P2PService = FuelP2PService;
 
     /// helper function for building FuelP2PService
-    async fn build_service_from_config(mut p2p_config: Config) -> P2PService {
+    async fn build_service_from_config(p2p_config: Config) -> P2PService {
+        build_service_with_protocols(p2p_config, RequestResponseProtocol::ALL.to_vec())
+            .await
+    }
+
+    async fn build_service_with_protocols(
+        mut p2p_config: Config,
+        protocols: Vec<RequestResponseProtocol>,
+    ) -> P2PService {
         p2p_config.keypair = Keypair::generate_secp256k1(); // change keypair for each Node
         let max_block_size = p2p_config.max_block_size;
         let (sender, _) =
             broadcast::channel(p2p_config.reserved_nodes.len().saturating_add(1));
 
-        let mut service =
-            FuelP2PService::new(sender, p2p_config, PostcardCodec::new(max_block_size));
+        let codec = PostcardCodec::new(max_block_size).with_protocols(protocols);
+        let mut service = FuelP2PService::new(sender, p2p_config, codec);
         service.start().await.unwrap();
         service
     }
@@ -826,6 +1167,68 @@ mod tests {
         build_service_from_config(Config::default_initialized("p2p_service_works")).await;
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_connect_through_relay() {
+        let p2p_config = Config::default_initialized("nodes_connect_through_relay");
+
+        let mut relay_node = {
+            let mut p2p_config = p2p_config.clone();
+            p2p_config.enable_relay_server = true;
+            let mut relay_node = build_service_from_config(p2p_config).await;
+            // The relay only accepts reservations if it knows its external addresses
+            for address in relay_node.multiaddrs() {
+                relay_node.swarm.add_external_address(address);
+            }
+            relay_node
+        };
+
+        let mut relayed_node = {
+            let mut p2p_config = p2p_config.clone();
+            p2p_config.relay_nodes = relay_node.multiaddrs();
+            build_service_from_config(p2p_config).await
+        };
+        let relayed_node_peer_id = relayed_node.local_peer_id;
+        let mut dialing_node = build_service_from_config(p2p_config).await;
+
+        tokio::time::timeout(Duration::from_secs(20), async {
+            // The relayed node listens on the circuit once the relay accepts the reservation
+            let circuit_address = loop {
+                let circuit_address =
+                    relayed_node.multiaddrs().into_iter().find(|address| {
+                        address
+                            .iter()
+                            .any(|protocol| matches!(protocol, Protocol::P2pCircuit))
+                    });
+                if let Some(circuit_address) = circuit_address {
+                    break circuit_address
+                }
+                tokio::select! {
+                    _ = relay_node.next_event() => {}
+                    _ = relayed_node.next_event() => {}
+                }
+            };
+
+            dialing_node.swarm.dial(circuit_address).unwrap();
+
+            loop {
+                tokio::select! {
+                    event = dialing_node.next_event() => {
+                        if let Some(FuelP2PEvent::PeerConnected(peer_id)) = event {
+                            if peer_id == relayed_node_peer_id {
+                                break
+                            }
+                        }
+                    }
+                    _ = relay_node.next_event() => {}
+                    _ = relayed_node.next_event() => {}
+                }
+            }
+        })
+        .await
+        .expect("The nodes should connect through the relay within 20 seconds");
+    }
+
     // Single sentry node connects to multiple reserved nodes and `max_peers_allowed` amount of non-reserved nodes.
     // It also tries to dial extra non-reserved nodes to establish the connection.
     // A single reserved node is not started immediately with the rest of the nodes.
@@ -1149,6 +1552,111 @@ mod tests {
         }
     }
 
+    fn is_quic(address: &Multiaddr) -> bool {
+        address
+            .iter()
+            .any(|protocol| matches!(protocol, Protocol::QuicV1))
+    }
+
+    async fn await_connection(node_a: &mut P2PService, node_b: &mut P2PService) {
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(peer_id)) = node_b_event {
+                        if peer_id == node_a.local_peer_id {
+                            break
+                        }
+                    }
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+            };
+        }
+    }
+
+    // Simulates 2 p2p nodes that listen on both TCP and QUIC
+    // Node B knows only the QUIC address of Node A and connects over it
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_connected_via_quic() {
+        let mut p2p_config = Config::default_initialized("nodes_connected_via_quic");
+        p2p_config.quic_port = Some(0);
+
+        // Node A
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+        let quic_addresses: Vec<_> =
+            node_a.multiaddrs().into_iter().filter(is_quic).collect();
+        assert!(!quic_addresses.is_empty());
+
+        // Node B
+        p2p_config.bootstrap_nodes = quic_addresses;
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        await_connection(&mut node_a, &mut node_b).await;
+    }
+
+    // Simulates the dual-stack Node A and the TCP-only Node B
+    // Node B can't dial QUIC addresses of Node A, so it connects over TCP
+    #[tokio::test]
+    #[instrument]
+    async fn tcp_only_node_connects_to_dual_stack_node() {
+        let mut p2p_config =
+            Config::default_initialized("tcp_only_node_connects_to_dual_stack_node");
+
+        // Node A
+        let mut node_a_config = p2p_config.clone();
+        node_a_config.quic_port = Some(0);
+        let mut node_a = build_service_from_config(node_a_config).await;
+        let mut addresses = node_a.multiaddrs();
+        addresses.sort_by_key(|address| !is_quic(address));
+        assert!(addresses.iter().any(is_quic));
+
+        // Node B
+        p2p_config.bootstrap_nodes = addresses;
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        await_connection(&mut node_a, &mut node_b).await;
+    }
+
+    // Simulates 2 p2p nodes with different checksums that try to connect over QUIC
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_cannot_connect_via_quic_due_to_different_checksum() {
+        let mut p2p_config = Config::default_initialized(
+            "nodes_cannot_connect_via_quic_due_to_different_checksum",
+        );
+        p2p_config.quic_port = Some(0);
+
+        // Node A
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // different checksum
+        p2p_config.checksum = [1u8; 32].into();
+        p2p_config.bootstrap_nodes =
+            node_a.multiaddrs().into_iter().filter(is_quic).collect();
+        // Node B
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.swarm.select_next_some() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                    if let SwarmEvent::IncomingConnectionError { .. } = node_a_event {
+                        break
+                    }
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(_)) = node_b_event {
+                        panic!("Node B should not connect to Node A!")
+                    }
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+            };
+        }
+    }
+
     // Simulates 3 p2p nodes, Node B & Node C are bootstrapped with Node A
     // Using Identify Protocol Node C should be able to identify and connect to Node B
     #[tokio::test]
@@ -1246,6 +1754,18 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_tx_announcement_with_accept() {
+        gossipsub_broadcast(
+            GossipsubBroadcastRequest::NewTxAnnouncement(
+                Transaction::default_test_tx().id(&Default::default()),
+            ),
+            GossipsubMessageAcceptance::Accept,
+        )
+        .await;
+    }
+
     #[tokio::test]
     #[instrument]
     async fn gossipsub_broadcast_tx_with_reject() {
@@ -1386,6 +1906,9 @@ mod tests {
         let selected_topic: GossipTopic = {
             let topic = match broadcast_request {
                 GossipsubBroadcastRequest::NewTx(_) => NEW_TX_GOSSIP_TOPIC,
+                GossipsubBroadcastRequest::NewTxAnnouncement(_) => {
+                    NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC
+                }
             };
 
             Topic::new(format!("{}/{}", topic, p2p_config.network_name))
@@ -1447,6 +1970,12 @@ mod tests {
                                     panic!("Wrong GossipsubMessage")
                                 }
                             }
+                            GossipsubMessage::NewTxAnnouncement(tx_id) => {
+                                if tx_id != &Transaction::default_test_tx().id(&Default::default()) {
+                                    tracing::error!("Wrong p2p message {:?}", message);
+                                    panic!("Wrong GossipsubMessage")
+                                }
+                            }
                         }
 
                         // Node B received the correct message
@@ -1501,6 +2030,48 @@ mod tests {
         blocks
     }
 
+    fn arbitrary_blocks_for_range(range: Range<u32>) -> Vec<SealedBlock> {
+        arbitrary_headers_for_range(range)
+            .into_iter()
+            .map(|header| {
+                let mut block = Block::default();
+                *block.header_mut() = header.entity;
+                SealedBlock {
+                    entity: block,
+                    consensus: header.consensus,
+                }
+            })
+            .collect()
+    }
+
+    fn arbitrary_snapshot_chunk() -> SnapshotChunk {
+        SnapshotChunk {
+            index: 0,
+            column: 5,
+            entries: vec![(vec![1; 34], vec![2; 100])],
+            proof: vec![],
+        }
+    }
+
+    fn arbitrary_snapshot_manifest() -> SnapshotManifest {
+        let chunk = arbitrary_snapshot_chunk();
+        SnapshotManifest {
+            header: arbitrary_headers_for_range(7..8).remove(0),
+            root: snapshot_root(&[chunk.hash()]),
+            chunks_count: 1,
+        }
+    }
+
+    fn arbitrary_transactions() -> Vec<Transaction> {
+        (0..3u64)
+            .map(|i| {
+                TransactionBuilder::script(vec![], vec![])
+                    .script_gas_limit(i)
+                    .finalize_as_transaction()
+            })
+            .collect()
+    }
+
     // Metadata gets skipped during serialization, so this is the fuzzy way to compare blocks
     fn eq_except_metadata(a: &SealedBlockHeader, b: &SealedBlockHeader) -> bool {
         a.entity.application() == b.entity.application()
@@ -1572,6 +2143,92 @@ mod tests {
                                             }
                                         });
                                     }
+                                    RequestMessage::SnapshotManifest => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::SnapshotManifest(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Some(manifest)))) = response_message {
+                                                let check = manifest.root == arbitrary_snapshot_manifest().root;
+                                                let _ = tx_test_end.send(check).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
+                                    RequestMessage::SnapshotChunks(_) => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::SnapshotChunks(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Some(chunks)))) = response_message {
+                                                let manifest = arbitrary_snapshot_manifest();
+                                                let check = chunks.len() == 1 && chunks[0].verify(&manifest);
+                                                let _ = tx_test_end.send(check).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
+                                    RequestMessage::TransactionsById(tx_ids) => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::TransactionsById(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Some(transactions)))) = response_message {
+                                                let ids = transactions.iter().map(|tx| tx.id(&Default::default())).collect::<Vec<_>>();
+                                                let _ = tx_test_end.send(ids == tx_ids).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
+                                    RequestMessage::SealedBlocks(range) => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::SealedBlocks(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Some(blocks)))) = response_message {
+                                                let heights = blocks.iter().map(|block| **block.entity.header().height()).collect::<Vec<_>>();
+                                                let _ = tx_test_end.send(heights == range.collect::<Vec<_>>()).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
+                                    RequestMessage::PooledTransactionIds => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::PooledTransactionIds(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Some(tx_ids)))) = response_message {
+                                                let expected = arbitrary_transactions().iter().map(|tx| tx.id(&Default::default())).collect::<Vec<_>>();
+                                                let _ = tx_test_end.send(tx_ids == expected).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
                                 }
                             }
                         }
@@ -1586,12 +2243,30 @@ mod tests {
                             RequestMessage::SealedHeaders(range) => {
                                 let sealed_headers: Vec<_> = arbitrary_headers_for_range(range.clone());
 
-                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::SealedHeaders(Some(sealed_headers)));
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::SealedHeaders(Ok(sealed_headers)));
                             }
                             RequestMessage::Transactions(_) => {
                                 let txs = (0..5).map(|_| Transaction::default_test_tx()).collect();
                                 let transactions = vec![Transactions(txs)];
-                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::Transactions(Some(transactions)));
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::Transactions(Ok(transactions)));
+                            }
+                            RequestMessage::SnapshotManifest => {
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::SnapshotManifest(Ok(Box::new(arbitrary_snapshot_manifest()))));
+                            }
+                            RequestMessage::SnapshotChunks(_) => {
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::SnapshotChunks(Ok(vec![arbitrary_snapshot_chunk()])));
+                            }
+                            RequestMessage::TransactionsById(_) => {
+                                let txs = arbitrary_transactions();
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::TransactionsById(Ok(txs)));
+                            }
+                            RequestMessage::SealedBlocks(range) => {
+                                let blocks = arbitrary_blocks_for_range(range.clone());
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::SealedBlocks(Ok(blocks)));
+                            }
+                            RequestMessage::PooledTransactionIds => {
+                                let tx_ids = arbitrary_transactions().iter().map(|tx| tx.id(&Default::default())).collect();
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::PooledTransactionIds(Ok(tx_ids)));
                             }
                         }
                     }
@@ -1616,6 +2291,45 @@ mod tests {
         request_response_works_with(RequestMessage::SealedHeaders(arbitrary_range)).await
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_transactions_by_id() {
+        let tx_ids = arbitrary_transactions()
+            .iter()
+            .map(|tx| tx.id(&Default::default()))
+            .collect();
+        request_response_works_with(RequestMessage::TransactionsById(tx_ids)).await
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_sealed_blocks() {
+        let arbitrary_range = 2..6;
+        request_response_works_with(RequestMessage::SealedBlocks(arbitrary_range)).await
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_pooled_transaction_ids() {
+        request_response_works_with(RequestMessage::PooledTransactionIds).await
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_snapshot_manifest() {
+        request_response_works_with(RequestMessage::SnapshotManifest).await
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_snapshot_chunks() {
+        let request = SnapshotChunksRequest {
+            height: 7,
+            range: 0..1,
+        };
+        request_response_works_with(RequestMessage::SnapshotChunks(request)).await
+    }
+
     /// We send a request for transactions, but it's responded by only headers
     #[tokio::test]
     #[instrument]
@@ -1684,7 +2398,7 @@ mod tests {
                     // 2. Node B receives the RequestMessage from Node A initiated by the NetworkOrchestrator
                     if let Some(FuelP2PEvent::InboundRequestMessage{ request_id, request_message: _ }) = &node_b_event {
                         let sealed_headers: Vec<_> = arbitrary_headers_for_range(1..3);
-                        let _ = node_b.send_response_msg(*request_id, ResponseMessage::SealedHeaders(Some(sealed_headers)));
+                        let _ = node_b.send_response_msg(*request_id, ResponseMessage::SealedHeaders(Ok(sealed_headers)));
                     }
 
                     tracing::info!("Node B Event: {:?}", node_b_event);
@@ -1693,6 +2407,142 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_over_the_rate_limit_is_dropped_and_peer_is_penalized() {
+        let mut p2p_config = Config::default_initialized(
+            "request_over_the_rate_limit_is_dropped_and_peer_is_penalized",
+        );
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // Node B serves only one request without refilling
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        p2p_config.request_rate_limits = RequestRateLimits {
+            requests_per_sec: 0,
+            request_burst: 1,
+            ..p2p_config.request_rate_limits
+        };
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        let (first_sender, mut first_receiver) = oneshot::channel();
+        let (second_sender, mut second_receiver) = oneshot::channel();
+        let mut senders = Some((first_sender, second_sender));
+        let mut first_response = None;
+        let mut second_response = None;
+        while first_response.is_none() || second_response.is_none() {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    if let Some(FuelP2PEvent::PeerInfoUpdated { peer_id, .. }) = node_a_event {
+                        if let Some((first_sender, second_sender)) = senders.take() {
+                            node_a.send_request_msg(Some(peer_id), RequestMessage::SealedHeaders(0..2), ResponseSender::SealedHeaders(first_sender)).unwrap();
+                            node_a.send_request_msg(Some(peer_id), RequestMessage::SealedHeaders(0..2), ResponseSender::SealedHeaders(second_sender)).unwrap();
+                        }
+                    }
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::InboundRequestMessage { request_id, .. }) = node_b_event {
+                        let sealed_headers = arbitrary_headers_for_range(0..2);
+                        let _ = node_b.send_response_msg(request_id, ResponseMessage::SealedHeaders(Ok(sealed_headers)));
+                    }
+                },
+                response = &mut first_receiver, if first_response.is_none() => {
+                    first_response = Some(response.unwrap().1);
+                },
+                response = &mut second_receiver, if second_response.is_none() => {
+                    second_response = Some(response.unwrap().1);
+                },
+            }
+        }
+
+        assert!(matches!(first_response, Some(Ok(Some(_)))));
+        assert!(matches!(second_response, Some(Err(ResponseError::P2P(_)))));
+        let score = node_b
+            .peer_manager
+            .get_peer_info(&node_a.local_peer_id)
+            .unwrap()
+            .score;
+        assert!(score < DEFAULT_APP_SCORE);
+    }
+
+    /// Requests sealed headers from the node supporting `responder_protocols`
+    /// by the node supporting `requester_protocols`.
+    async fn request_sealed_headers_with_protocols(
+        requester_protocols: Vec<RequestResponseProtocol>,
+        responder_protocols: Vec<RequestResponseProtocol>,
+    ) -> Result<Option<Vec<SealedBlockHeader>>, ResponseError> {
+        let mut p2p_config =
+            Config::default_initialized("request_sealed_headers_with_protocols");
+        let mut node_a =
+            build_service_with_protocols(p2p_config.clone(), requester_protocols).await;
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        let mut node_b =
+            build_service_with_protocols(p2p_config, responder_protocols).await;
+
+        let (tx_orchestrator, mut rx_orchestrator) = oneshot::channel();
+        let mut tx_orchestrator = Some(tx_orchestrator);
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    if let Some(FuelP2PEvent::PeerInfoUpdated { peer_id, .. }) = node_a_event {
+                        if let Some(tx_orchestrator) = tx_orchestrator.take() {
+                            let request_msg = RequestMessage::SealedHeaders(0..2);
+                            let channel = ResponseSender::SealedHeaders(tx_orchestrator);
+                            node_a.send_request_msg(Some(peer_id), request_msg, channel).unwrap();
+                        }
+                    }
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::InboundRequestMessage { request_id, .. }) = node_b_event {
+                        let sealed_headers = arbitrary_headers_for_range(0..2);
+                        let _ = node_b.send_response_msg(request_id, ResponseMessage::SealedHeaders(Ok(sealed_headers)));
+                    }
+                },
+                response = &mut rx_orchestrator => {
+                    let (_, response) = response.unwrap();
+                    return response
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn not_upgraded_node_requests_from_upgraded_node() {
+        let response = request_sealed_headers_with_protocols(
+            vec![RequestResponseProtocol::V1],
+            RequestResponseProtocol::ALL.to_vec(),
+        )
+        .await;
+
+        let sealed_headers = response.unwrap().unwrap();
+        assert_eq!(sealed_headers.len(), 2);
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn upgraded_node_requests_from_not_upgraded_node() {
+        let response = request_sealed_headers_with_protocols(
+            RequestResponseProtocol::ALL.to_vec(),
+            vec![RequestResponseProtocol::V1],
+        )
+        .await;
+
+        let sealed_headers = response.unwrap().unwrap();
+        assert_eq!(sealed_headers.len(), 2);
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_without_common_protocol_cannot_exchange_messages() {
+        let response = request_sealed_headers_with_protocols(
+            vec![RequestResponseProtocol::V2],
+            vec![RequestResponseProtocol::V1],
+        )
+        .await;
+
+        assert!(matches!(response, Err(ResponseError::P2P(_))));
+    }
+
     #[tokio::test]
     #[instrument]
     async fn req_res_outbound_timeout_works() {
@@ -2,11 +2,14 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::p2p::peer_reputation::{
         AppScore,
+        PeerBan,
+        PeerReputation,
         DECAY_APP_SCORE,
         DEFAULT_APP_SCORE,
         MAX_APP_SCORE,
         MIN_APP_SCORE,
     },
+    tai64::Tai64,
 };
 use libp2p::{
     Multiaddr,
@@ -38,6 +41,13 @@ pub mod heartbeat_data;
 /// At this point we better just ban the peer
 const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
 
+/// The duration in seconds of bans caused by a low score of the peer
+const SCORE_BAN_DURATION_SECS: u64 = 24 * 60 * 60;
+
+/// Reputations of disconnected peers that decayed closer than this
+/// to the default score are forgotten
+const FORGET_APP_SCORE_THRESHOLD: AppScore = 1.0;
+
 // Info about a single Peer that we're connected to
 #[derive(Debug, Clone)]
 pub struct PeerInfo {
@@ -68,6 +78,9 @@ pub struct PeerManager {
     connection_state: Arc<RwLock<ConnectionState>>,
     max_non_reserved_peers: usize,
     reserved_peers_updates: tokio::sync::broadcast::Sender<usize>,
+    /// Scores of non-reserved peers that are not connected at the moment
+    disconnected_peers_scores: HashMap<PeerId, AppScore>,
+    banned_peers: HashMap<PeerId, PeerBan>,
 }
 
 impl PeerManager {
@@ -85,6 +98,8 @@ impl PeerManager {
             connection_state,
             max_non_reserved_peers,
             reserved_peers_updates,
+            disconnected_peers_scores: HashMap::new(),
+            banned_peers: HashMap::new(),
         }
     }
 
@@ -97,7 +112,7 @@ impl PeerManager {
     }
 
     pub fn handle_gossip_score_update<T: Punisher>(
-        &self,
+        &mut self,
         peer_id: PeerId,
         gossip_score: f64,
         punisher: &mut T,
@@ -105,7 +120,8 @@ impl PeerManager {
         if gossip_score < self.score_config.min_gossip_score_allowed
             && !self.reserved_peers.contains(&peer_id)
         {
-            punisher.ban_peer(peer_id);
+            let reason = format!("The gossipsub score {gossip_score} is too low");
+            self.ban_peer_for_low_score(peer_id, reason, punisher);
         }
     }
 
@@ -153,6 +169,13 @@ impl PeerManager {
         for peer_info in self.non_reserved_connected_peers.values_mut() {
             peer_info.score *= DECAY_APP_SCORE;
         }
+
+        let banned_peers = &self.banned_peers;
+        self.disconnected_peers_scores.retain(|peer_id, score| {
+            *score *= DECAY_APP_SCORE;
+            banned_peers.contains_key(peer_id)
+                || (*score - DEFAULT_APP_SCORE).abs() >= FORGET_APP_SCORE_THRESHOLD
+        });
     }
 
     pub fn update_app_score<T: Punisher>(
@@ -170,13 +193,171 @@ impl PeerManager {
             info!(target: "fuel-p2p", "{reporting_service} updated {peer_id} with new score {score}");
 
             if new_score < self.score_config.min_app_score_allowed {
-                punisher.ban_peer(peer_id);
+                let reason = format!("The reputation {new_score} is too low");
+                self.ban_peer_for_low_score(peer_id, reason, punisher);
             }
         } else {
             log_missing_peer(&peer_id);
         }
     }
 
+    /// Overrides the application score of the peer, even if it is not connected.
+    /// The score of reserved peers is not tracked.
+    pub fn set_app_score<T: Punisher>(
+        &mut self,
+        peer_id: PeerId,
+        score: AppScore,
+        punisher: &mut T,
+    ) {
+        if self.reserved_peers.contains(&peer_id) {
+            return
+        }
+
+        let score = self.score_config.max_app_score.min(score);
+        if let Some(peer) = self.non_reserved_connected_peers.get_mut(&peer_id) {
+            peer.score = score;
+        } else {
+            self.disconnected_peers_scores.insert(peer_id, score);
+        }
+
+        if score < self.score_config.min_app_score_allowed {
+            let reason = format!("The reputation {score} is too low");
+            self.ban_peer_for_low_score(peer_id, reason, punisher);
+        }
+    }
+
+    /// Returns the application score of the connected or previously known peer.
+    pub fn get_app_score(&self, peer_id: &PeerId) -> Option<AppScore> {
+        self.get_peer_info(peer_id)
+            .map(|info| info.score)
+            .or_else(|| self.disconnected_peers_scores.get(peer_id).copied())
+    }
+
+    /// Bans the peer until the ban expires or the peer is unbanned.
+    pub fn ban_peer<T: Punisher>(
+        &mut self,
+        peer_id: PeerId,
+        ban: PeerBan,
+        punisher: &mut T,
+    ) {
+        info!(target: "fuel-p2p", "Banning {peer_id}: {}", ban.reason);
+        self.banned_peers.insert(peer_id, ban);
+        punisher.ban_peer(peer_id);
+    }
+
+    /// Removes the ban of the peer and resets its score to the default one,
+    /// so the peer is not banned again right away.
+    /// Returns `false` if the peer was not banned.
+    pub fn unban_peer<T: Punisher>(&mut self, peer_id: PeerId, punisher: &mut T) -> bool {
+        if self.banned_peers.remove(&peer_id).is_none() {
+            return false
+        }
+
+        info!(target: "fuel-p2p", "Unbanning {peer_id}");
+        if let Some(peer) = self.non_reserved_connected_peers.get_mut(&peer_id) {
+            peer.score = DEFAULT_APP_SCORE;
+        }
+        self.disconnected_peers_scores.remove(&peer_id);
+        punisher.unban_peer(peer_id);
+        true
+    }
+
+    /// Unbans peers with bans expired at the `now` time.
+    pub fn remove_expired_bans<T: Punisher>(&mut self, now: Tai64, punisher: &mut T) {
+        let expired: Vec<_> = self
+            .banned_peers
+            .iter()
+            .filter(|(_, ban)| ban.is_expired(now))
+            .map(|(peer_id, _)| *peer_id)
+            .collect();
+
+        for peer_id in expired {
+            self.unban_peer(peer_id, punisher);
+        }
+    }
+
+    pub fn is_banned(&self, peer_id: &PeerId) -> bool {
+        self.banned_peers.contains_key(peer_id)
+    }
+
+    /// Restores reputations of peers from the previous run of the node.
+    pub fn restore_reputations<T: Punisher>(
+        &mut self,
+        reputations: impl IntoIterator<Item = (PeerId, PeerReputation)>,
+        now: Tai64,
+        punisher: &mut T,
+    ) {
+        for (peer_id, reputation) in reputations {
+            if self.reserved_peers.contains(&peer_id) {
+                continue
+            }
+
+            match reputation.ban {
+                Some(ban) if !ban.is_expired(now) => {
+                    self.disconnected_peers_scores
+                        .insert(peer_id, reputation.score);
+                    self.banned_peers.insert(peer_id, ban);
+                    punisher.ban_peer(peer_id);
+                }
+                // The ban is expired, so the peer starts from scratch
+                Some(_) => {}
+                None => {
+                    self.disconnected_peers_scores
+                        .insert(peer_id, reputation.score);
+                }
+            }
+        }
+    }
+
+    /// Returns reputations of all known non-reserved peers.
+    pub fn reputations(&self) -> Vec<(PeerId, PeerReputation)> {
+        let scores = self
+            .non_reserved_connected_peers
+            .iter()
+            .map(|(peer_id, info)| (*peer_id, info.score))
+            .chain(
+                self.disconnected_peers_scores
+                    .iter()
+                    .map(|(peer_id, score)| (*peer_id, *score)),
+            );
+        let mut reputations: HashMap<PeerId, PeerReputation> = scores
+            .map(|(peer_id, score)| {
+                let ban = self.banned_peers.get(&peer_id).cloned();
+                (peer_id, PeerReputation { score, ban })
+            })
+            .collect();
+
+        // Peers can be banned without having any score
+        for (peer_id, ban) in &self.banned_peers {
+            reputations
+                .entry(*peer_id)
+                .or_insert_with(|| PeerReputation {
+                    score: DEFAULT_APP_SCORE,
+                    ban: Some(ban.clone()),
+                });
+        }
+
+        reputations.into_iter().collect()
+    }
+
+    fn ban_peer_for_low_score<T: Punisher>(
+        &mut self,
+        peer_id: PeerId,
+        reason: String,
+        punisher: &mut T,
+    ) {
+        if self.banned_peers.contains_key(&peer_id) {
+            return
+        }
+
+        let now = Tai64::now();
+        let ban = PeerBan {
+            reason,
+            expires_at: Some(Tai64(now.0.saturating_add(SCORE_BAN_DURATION_SECS))),
+        };
+        self.ban_peer(peer_id, ban, punisher);
+    }
+
     pub fn total_peers_connected(&self) -> usize {
         self.reserved_connected_peers
             .len()
@@ -219,9 +400,14 @@ impl PeerManager {
             let all_slots_taken = self.max_non_reserved_peers
                 == self.non_reserved_connected_peers.len().saturating_add(1);
 
-            if self.non_reserved_connected_peers.remove(&peer_id).is_some()
-                && all_slots_taken
-            {
+            let peer_info = self.non_reserved_connected_peers.remove(&peer_id);
+            if let Some(peer_info) = &peer_info {
+                // remember the reputation in the case if the peer reconnects
+                self.disconnected_peers_scores
+                    .insert(peer_id, peer_info.score);
+            }
+
+            if peer_info.is_some() && all_slots_taken {
                 // since all the slots were full prior to this disconnect
                 // let's allow new peer non-reserved peers connections
                 if let Ok(mut connection_state) = self.connection_state.write() {
@@ -260,6 +446,10 @@ impl PeerManager {
 
         // if the connected Peer is not from the reserved peers
         if !self.reserved_peers.contains(peer_id) {
+            if self.banned_peers.contains_key(peer_id) {
+                return true
+            }
+
             let non_reserved_peers_connected = self.non_reserved_connected_peers.len();
             // check if all the slots are already taken
             if non_reserved_peers_connected >= self.max_non_reserved_peers {
@@ -276,8 +466,12 @@ impl PeerManager {
                 }
             }
 
+            let mut peer_info = PeerInfo::new(HEARTBEAT_AVG_WINDOW);
+            if let Some(score) = self.disconnected_peers_scores.remove(peer_id) {
+                peer_info.score = score;
+            }
             self.non_reserved_connected_peers
-                .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
+                .insert(*peer_id, peer_info);
         } else {
             self.reserved_connected_peers
                 .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
@@ -398,6 +592,8 @@ impl ScoreConfig {
 
 pub trait Punisher {
     fn ban_peer(&mut self, peer_id: PeerId);
+
+    fn unban_peer(&mut self, peer_id: PeerId);
 }
 
 #[cfg(test)]
@@ -499,4 +695,141 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    #[derive(Default)]
+    struct FakePunisher {
+        banned: HashSet<PeerId>,
+    }
+
+    impl Punisher for FakePunisher {
+        fn ban_peer(&mut self, peer_id: PeerId) {
+            self.banned.insert(peer_id);
+        }
+
+        fn unban_peer(&mut self, peer_id: PeerId) {
+            self.banned.remove(&peer_id);
+        }
+    }
+
+    #[test]
+    fn peer_with_low_score_is_banned_and_not_allowed_to_reconnect() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+
+        peer_manager.update_app_score(
+            peer_id,
+            MIN_APP_SCORE - 1.0,
+            "test",
+            &mut punisher,
+        );
+
+        assert!(punisher.banned.contains(&peer_id));
+        assert!(peer_manager.is_banned(&peer_id));
+        peer_manager.handle_peer_disconnect(peer_id);
+        let should_disconnect = peer_manager.handle_initial_connection(&peer_id);
+        assert!(should_disconnect);
+    }
+
+    #[test]
+    fn reputation_is_kept_after_reconnect() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+        peer_manager.update_app_score(peer_id, -10.0, "test", &mut punisher);
+
+        peer_manager.handle_peer_disconnect(peer_id);
+        peer_manager.handle_initial_connection(&peer_id);
+
+        assert_eq!(peer_manager.get_app_score(&peer_id), Some(-10.0));
+    }
+
+    #[test]
+    fn restored_reputations_are_applied() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let now = Tai64::now();
+        let banned_peer = PeerId::random();
+        let expired_ban_peer = PeerId::random();
+        let scored_peer = PeerId::random();
+        let reputations = vec![
+            (
+                banned_peer,
+                PeerReputation {
+                    score: MIN_APP_SCORE,
+                    ban: Some(PeerBan {
+                        reason: "test".to_string(),
+                        expires_at: None,
+                    }),
+                },
+            ),
+            (
+                expired_ban_peer,
+                PeerReputation {
+                    score: MIN_APP_SCORE,
+                    ban: Some(PeerBan {
+                        reason: "test".to_string(),
+                        expires_at: Some(now),
+                    }),
+                },
+            ),
+            (
+                scored_peer,
+                PeerReputation {
+                    score: 20.0,
+                    ban: None,
+                },
+            ),
+        ];
+
+        peer_manager.restore_reputations(reputations, now, &mut punisher);
+
+        assert_eq!(punisher.banned, HashSet::from([banned_peer]));
+        assert!(peer_manager.is_banned(&banned_peer));
+        assert!(!peer_manager.is_banned(&expired_ban_peer));
+        assert_eq!(peer_manager.get_app_score(&expired_ban_peer), None);
+        assert_eq!(peer_manager.get_app_score(&scored_peer), Some(20.0));
+        assert_eq!(peer_manager.reputations().len(), 2);
+    }
+
+    #[test]
+    fn unbanned_peer_starts_with_default_score() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.set_app_score(peer_id, MIN_APP_SCORE - 1.0, &mut punisher);
+        assert!(peer_manager.is_banned(&peer_id));
+
+        assert!(peer_manager.unban_peer(peer_id, &mut punisher));
+
+        assert!(punisher.banned.is_empty());
+        assert!(!peer_manager.handle_initial_connection(&peer_id));
+        assert_eq!(
+            peer_manager.get_app_score(&peer_id),
+            Some(DEFAULT_APP_SCORE)
+        );
+    }
+
+    #[test]
+    fn expired_bans_are_removed() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let now = Tai64::now();
+        let expired_peer = PeerId::random();
+        let permanent_peer = PeerId::random();
+        let ban = |expires_at| PeerBan {
+            reason: "test".to_string(),
+            expires_at,
+        };
+        peer_manager.ban_peer(expired_peer, ban(Some(now)), &mut punisher);
+        peer_manager.ban_peer(permanent_peer, ban(None), &mut punisher);
+
+        peer_manager.remove_expired_bans(now, &mut punisher);
+
+        assert!(!peer_manager.is_banned(&expired_peer));
+        assert!(peer_manager.is_banned(&permanent_peer));
+        assert_eq!(punisher.banned, HashSet::from([permanent_peer]));
+    }
 }
@@ -3,10 +3,23 @@ use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::{
         consensus::Genesis,
+        SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
-    services::p2p::Transactions,
+    services::p2p::{
+        peer_reputation::PeerReputation,
+        snapshot::{
+            SnapshotChunk,
+            SnapshotManifest,
+        },
+        PeerId,
+        Transactions,
+    },
 };
 use std::ops::Range;
 
@@ -21,7 +34,47 @@ pub trait P2pDb: Send + Sync {
         block_height_range: Range<u32>,
     ) -> StorageResult<Option<Vec<Transactions>>>;
 
+    fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> StorageResult<Vec<SealedBlock>>;
+
     fn get_genesis(&self) -> StorageResult<Genesis>;
+
+    /// Returns the reputations of peers stored by `update_peer_reputations`.
+    fn get_peer_reputations(&self) -> StorageResult<Vec<(PeerId, PeerReputation)>>;
+
+    /// Stores the `updated` reputations of peers and removes the reputations
+    /// of the `removed` peers.
+    fn update_peer_reputations(
+        &self,
+        updated: Vec<(PeerId, PeerReputation)>,
+        removed: Vec<PeerId>,
+    ) -> StorageResult<()>;
+}
+
+/// Provides snapshots of the state to peers performing the snap-sync.
+pub trait SnapshotProvider: Send + Sync {
+    /// Returns the manifest of the latest available snapshot.
+    fn latest_manifest(&self) -> StorageResult<Option<SnapshotManifest>>;
+
+    /// Returns chunks of the snapshot at the `height`, or `None` if the snapshot
+    /// at this height is not available anymore.
+    fn get_chunks(
+        &self,
+        height: BlockHeight,
+        range: Range<u32>,
+    ) -> StorageResult<Option<Vec<SnapshotChunk>>>;
+}
+
+/// Provides transactions from the transaction pool to peers.
+pub trait TxPool: Send + Sync {
+    /// Returns the transactions with `tx_ids` that are present in the pool,
+    /// in the order of `tx_ids`.
+    fn get_transactions_by_id(&self, tx_ids: Vec<TxId>) -> Vec<Transaction>;
+
+    /// Returns the ids of at most `max_txs` transactions from the pool.
+    fn get_tx_ids(&self, max_txs: usize) -> Vec<TxId>;
 }
 
 pub trait BlockHeightImporter: Send + Sync {
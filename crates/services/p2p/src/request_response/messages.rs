@@ -1,6 +1,20 @@
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
-    services::p2p::Transactions,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    services::p2p::{
+        snapshot::{
+            SnapshotChunk,
+            SnapshotChunksRequest,
+            SnapshotManifest,
+        },
+        Transactions,
+    },
 };
 use libp2p::{
     request_response::OutboundFailure,
@@ -14,7 +28,35 @@ use std::ops::Range;
 use thiserror::Error;
 use tokio::sync::oneshot;
 
-pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.1";
+pub(crate) const V1_REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.1";
+pub(crate) const V2_REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.2";
+
+/// Versions of the request-response protocol.
+///
+/// The node supports all versions at the same time and advertises them via `identify`.
+/// The version is negotiated for each stream with the peer, preferring the newest one,
+/// so changes of messages can be rolled out without upgrading the whole network at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RequestResponseProtocol {
+    /// The responses don't explain why the data is missing.
+    V1,
+    /// The responses contain [`ResponseMessageErrorCode`] if the data is missing.
+    V2,
+}
+
+impl RequestResponseProtocol {
+    /// All supported versions, in the order of preference.
+    pub const ALL: [Self; 2] = [Self::V2, Self::V1];
+}
+
+impl AsRef<str> for RequestResponseProtocol {
+    fn as_ref(&self) -> &str {
+        match self {
+            RequestResponseProtocol::V1 => V1_REQUEST_RESPONSE_PROTOCOL_ID,
+            RequestResponseProtocol::V2 => V2_REQUEST_RESPONSE_PROTOCOL_ID,
+        }
+    }
+}
 
 /// Max Size in Bytes of the Request Message
 #[cfg(test)]
@@ -24,12 +66,108 @@ pub(crate) const MAX_REQUEST_SIZE: usize = core::mem::size_of::<RequestMessage>(
 pub enum RequestMessage {
     SealedHeaders(Range<u32>),
     Transactions(Range<u32>),
+    SnapshotManifest,
+    SnapshotChunks(SnapshotChunksRequest),
+    /// Transactions from the transaction pool of the peer.
+    TransactionsById(Vec<TxId>),
+    /// Full blocks, including headers, consensus seals and transactions.
+    SealedBlocks(Range<u32>),
+    /// Ids of the transactions from the transaction pool of the peer.
+    PooledTransactionIds,
 }
 
+impl RequestMessage {
+    /// The oldest version of the protocol that supports the request.
+    pub fn min_protocol(&self) -> RequestResponseProtocol {
+        match self {
+            RequestMessage::SealedHeaders(_)
+            | RequestMessage::Transactions(_)
+            | RequestMessage::SnapshotManifest
+            | RequestMessage::SnapshotChunks(_) => RequestResponseProtocol::V1,
+            RequestMessage::TransactionsById(_)
+            | RequestMessage::SealedBlocks(_)
+            | RequestMessage::PooledTransactionIds => RequestResponseProtocol::V2,
+        }
+    }
+}
+
+/// The message can't be sent via the negotiated version of the protocol.
+#[derive(Debug, Error)]
+#[error("The message is not supported by the protocol `{}`", .0.as_ref())]
+pub struct UnsupportedByProtocol(pub RequestResponseProtocol);
+
+/// The reason why the peer didn't respond with the requested data.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, Copy, Error)]
+pub enum ResponseMessageErrorCode {
+    /// The peer responded without data via the `V1` protocol, that doesn't support error codes.
+    #[error("The peer sent an empty response using the protocol `/fuel/req_res/0.0.1`")]
+    ProtocolV1EmptyResponse,
+    /// The requested range is bigger than the peer is ready to serve.
+    #[error("The requested range is too large")]
+    RequestedRangeTooLarge,
+    /// The peer doesn't have the requested data.
+    #[error("The requested data is not available")]
+    NotAvailable,
+    /// The peer failed to read the requested data.
+    #[error("The peer failed to process the request")]
+    InternalError,
+}
+
+/// The response message of the latest version of the protocol.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ResponseMessage {
+    SealedHeaders(Result<Vec<SealedBlockHeader>, ResponseMessageErrorCode>),
+    Transactions(Result<Vec<Transactions>, ResponseMessageErrorCode>),
+    SnapshotManifest(Result<Box<SnapshotManifest>, ResponseMessageErrorCode>),
+    SnapshotChunks(Result<Vec<SnapshotChunk>, ResponseMessageErrorCode>),
+    /// The requested transactions that were found, in the order of the request.
+    TransactionsById(Result<Vec<Transaction>, ResponseMessageErrorCode>),
+    /// The blocks from the start of the requested range.
+    SealedBlocks(Result<Vec<SealedBlock>, ResponseMessageErrorCode>),
+    /// The ids of the transactions in the transaction pool of the peer.
+    PooledTransactionIds(Result<Vec<TxId>, ResponseMessageErrorCode>),
+}
+
+/// The response message of the `V1` protocol.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum V1ResponseMessage {
     SealedHeaders(Option<Vec<SealedBlockHeader>>),
     Transactions(Option<Vec<Transactions>>),
+    SnapshotManifest(Option<Box<SnapshotManifest>>),
+    SnapshotChunks(Option<Vec<SnapshotChunk>>),
+}
+
+impl TryFrom<ResponseMessage> for V1ResponseMessage {
+    type Error = UnsupportedByProtocol;
+
+    fn try_from(response: ResponseMessage) -> Result<Self, Self::Error> {
+        match response {
+            ResponseMessage::SealedHeaders(v) => Ok(Self::SealedHeaders(v.ok())),
+            ResponseMessage::Transactions(v) => Ok(Self::Transactions(v.ok())),
+            ResponseMessage::SnapshotManifest(v) => Ok(Self::SnapshotManifest(v.ok())),
+            ResponseMessage::SnapshotChunks(v) => Ok(Self::SnapshotChunks(v.ok())),
+            ResponseMessage::TransactionsById(_)
+            | ResponseMessage::SealedBlocks(_)
+            | ResponseMessage::PooledTransactionIds(_) => {
+                Err(UnsupportedByProtocol(RequestResponseProtocol::V1))
+            }
+        }
+    }
+}
+
+impl From<V1ResponseMessage> for ResponseMessage {
+    fn from(response: V1ResponseMessage) -> Self {
+        fn convert<T>(v: Option<T>) -> Result<T, ResponseMessageErrorCode> {
+            v.ok_or(ResponseMessageErrorCode::ProtocolV1EmptyResponse)
+        }
+
+        match response {
+            V1ResponseMessage::SealedHeaders(v) => Self::SealedHeaders(convert(v)),
+            V1ResponseMessage::Transactions(v) => Self::Transactions(convert(v)),
+            V1ResponseMessage::SnapshotManifest(v) => Self::SnapshotManifest(convert(v)),
+            V1ResponseMessage::SnapshotChunks(v) => Self::SnapshotChunks(convert(v)),
+        }
+    }
 }
 
 pub type OnResponse<T> = oneshot::Sender<(PeerId, Result<T, ResponseError>)>;
@@ -38,6 +176,11 @@ pub type OnResponse<T> = oneshot::Sender<(PeerId, Result<T, ResponseError>)>;
 pub enum ResponseSender {
     SealedHeaders(OnResponse<Option<Vec<SealedBlockHeader>>>),
     Transactions(OnResponse<Option<Vec<Transactions>>>),
+    SnapshotManifest(OnResponse<Option<SnapshotManifest>>),
+    SnapshotChunks(OnResponse<Option<Vec<SnapshotChunk>>>),
+    TransactionsById(OnResponse<Option<Vec<Transaction>>>),
+    SealedBlocks(OnResponse<Option<Vec<SealedBlock>>>),
+    PooledTransactionIds(OnResponse<Option<Vec<TxId>>>),
 }
 
 #[derive(Debug, Error)]
@@ -0,0 +1,281 @@
+use fuel_core_types::services::p2p::peer_reputation::{
+    AppScore,
+    PeerReport,
+};
+use libp2p::PeerId;
+use std::{
+    collections::HashMap,
+    time::Instant,
+};
+
+/// Limits of the inbound requests served to a single peer.
+/// Each limit is a token bucket: it allows bursts up to its capacity
+/// and refills at the constant rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RequestRateLimits {
+    /// The average number of requests per second served to a peer.
+    pub requests_per_sec: u32,
+    /// The number of requests that a peer can make at once.
+    pub request_burst: u32,
+    /// The average number of response bytes per second sent to a peer.
+    pub response_bytes_per_sec: u64,
+    /// The number of response bytes that can be sent to a peer at once.
+    pub response_bytes_burst: u64,
+}
+
+impl RequestRateLimits {
+    pub fn new(max_response_size: usize) -> Self {
+        Self {
+            requests_per_sec: 50,
+            request_burst: 100,
+            response_bytes_per_sec: 10 * 1024 * 1024,
+            response_bytes_burst: u64::try_from(max_response_size)
+                .unwrap_or(u64::MAX)
+                .saturating_mul(2),
+        }
+    }
+}
+
+/// The peer exceeded the limits of the request-response protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestRateLimitReport {
+    /// The peer made more requests than allowed.
+    TooManyRequests,
+    /// The peer has already received more response bytes than allowed.
+    TooManyResponseBytes,
+}
+
+impl PeerReport for RequestRateLimitReport {
+    fn get_score_from_report(&self) -> AppScore {
+        match self {
+            RequestRateLimitReport::TooManyRequests => -5.,
+            RequestRateLimitReport::TooManyResponseBytes => -5.,
+        }
+    }
+}
+
+/// Tokens are refilled at `rate` per second up to the `capacity`.
+/// The bucket can go into debt, when the cost is known only after the fact.
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    capacity: f64,
+    rate: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: f64, rate: f64, now: Instant) -> Self {
+        Self {
+            capacity,
+            rate,
+            tokens: capacity,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = self.capacity.min(self.tokens + elapsed * self.rate);
+        self.last_refill = now;
+    }
+
+    /// Takes the `cost` if the bucket has enough tokens.
+    fn try_take(&mut self, cost: f64, now: Instant) -> bool {
+        self.refill(now);
+        if self.tokens >= cost {
+            self.tokens -= cost;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Takes the `cost` even if it puts the bucket into debt.
+    fn take(&mut self, cost: f64, now: Instant) {
+        self.refill(now);
+        self.tokens -= cost;
+    }
+
+    fn is_in_debt(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.tokens <= 0.
+    }
+
+    /// The full bucket is the same as the new one.
+    fn is_full(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.tokens >= self.capacity
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PeerBuckets {
+    requests: TokenBucket,
+    response_bytes: TokenBucket,
+}
+
+/// Tracks the requests and the responses of each peer against the [`RequestRateLimits`].
+#[derive(Debug)]
+pub(crate) struct RequestRateLimiter {
+    limits: RequestRateLimits,
+    peers: HashMap<PeerId, PeerBuckets>,
+}
+
+impl RequestRateLimiter {
+    pub fn new(limits: RequestRateLimits) -> Self {
+        Self {
+            limits,
+            peers: HashMap::new(),
+        }
+    }
+
+    fn buckets(&mut self, peer_id: PeerId, now: Instant) -> &mut PeerBuckets {
+        let limits = &self.limits;
+        self.peers.entry(peer_id).or_insert_with(|| PeerBuckets {
+            requests: TokenBucket::new(
+                f64::from(limits.request_burst),
+                f64::from(limits.requests_per_sec),
+                now,
+            ),
+            response_bytes: TokenBucket::new(
+                limits.response_bytes_burst as f64,
+                limits.response_bytes_per_sec as f64,
+                now,
+            ),
+        })
+    }
+
+    /// Checks whether the new request of the peer can be served.
+    /// The size of the response is accounted after it is sent, so the request is rejected
+    /// only if the peer already spent all its response bytes.
+    pub fn check_request(
+        &mut self,
+        peer_id: PeerId,
+        now: Instant,
+    ) -> Result<(), RequestRateLimitReport> {
+        let buckets = self.buckets(peer_id, now);
+        if !buckets.requests.try_take(1., now) {
+            return Err(RequestRateLimitReport::TooManyRequests)
+        }
+        if buckets.response_bytes.is_in_debt(now) {
+            return Err(RequestRateLimitReport::TooManyResponseBytes)
+        }
+        Ok(())
+    }
+
+    /// Accounts the size of the response sent to the peer.
+    pub fn record_response(&mut self, peer_id: PeerId, bytes: usize, now: Instant) {
+        self.buckets(peer_id, now)
+            .response_bytes
+            .take(bytes as f64, now);
+    }
+
+    /// Forgets the peers with fully refilled limits, since they are the same as
+    /// the limits of a new peer. The limits of other peers are kept even after
+    /// the disconnect, so the peer can't reset them by reconnecting.
+    pub fn remove_idle_peers(&mut self, now: Instant) {
+        self.peers.retain(|_, buckets| {
+            !(buckets.requests.is_full(now) && buckets.response_bytes.is_full(now))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use std::time::Duration;
+
+    fn limits() -> RequestRateLimits {
+        RequestRateLimits {
+            requests_per_sec: 1,
+            request_burst: 2,
+            response_bytes_per_sec: 100,
+            response_bytes_burst: 100,
+        }
+    }
+
+    #[test]
+    fn check_request__allows_burst_and_then_rejects() {
+        let mut limiter = RequestRateLimiter::new(limits());
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+
+        assert_eq!(limiter.check_request(peer_id, now), Ok(()));
+        assert_eq!(limiter.check_request(peer_id, now), Ok(()));
+        assert_eq!(
+            limiter.check_request(peer_id, now),
+            Err(RequestRateLimitReport::TooManyRequests)
+        );
+    }
+
+    #[test]
+    fn check_request__refills_over_time() {
+        let mut limiter = RequestRateLimiter::new(limits());
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+        let _ = limiter.check_request(peer_id, now);
+        let _ = limiter.check_request(peer_id, now);
+
+        let later = now + Duration::from_secs(1);
+
+        assert_eq!(limiter.check_request(peer_id, later), Ok(()));
+        assert_eq!(
+            limiter.check_request(peer_id, later),
+            Err(RequestRateLimitReport::TooManyRequests)
+        );
+    }
+
+    #[test]
+    fn check_request__rejects_peer_in_debt_for_response_bytes() {
+        let mut limiter = RequestRateLimiter::new(limits());
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+        assert_eq!(limiter.check_request(peer_id, now), Ok(()));
+
+        // The response is bigger than the whole burst.
+        limiter.record_response(peer_id, 150, now);
+
+        assert_eq!(
+            limiter.check_request(peer_id, now),
+            Err(RequestRateLimitReport::TooManyResponseBytes)
+        );
+        let debt_is_paid = now + Duration::from_secs(1);
+        assert_eq!(limiter.check_request(peer_id, debt_is_paid), Ok(()));
+    }
+
+    #[test]
+    fn remove_idle_peers__keeps_limits_of_peers_until_they_are_refilled() {
+        let mut limiter = RequestRateLimiter::new(limits());
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+        let _ = limiter.check_request(peer_id, now);
+        let _ = limiter.check_request(peer_id, now);
+
+        limiter.remove_idle_peers(now);
+
+        assert_eq!(
+            limiter.check_request(peer_id, now),
+            Err(RequestRateLimitReport::TooManyRequests)
+        );
+        let refilled = now + Duration::from_secs(2);
+        limiter.remove_idle_peers(refilled);
+        assert!(limiter.peers.is_empty());
+    }
+
+    #[test]
+    fn check_request__peers_are_limited_independently() {
+        let mut limiter = RequestRateLimiter::new(limits());
+        let peer_a = PeerId::random();
+        let peer_b = PeerId::random();
+        let now = Instant::now();
+        let _ = limiter.check_request(peer_a, now);
+        let _ = limiter.check_request(peer_a, now);
+
+        assert_eq!(limiter.check_request(peer_b, now), Ok(()));
+    }
+}
@@ -1,8 +1,13 @@
 use crate::{
-    codecs::postcard::PostcardCodec,
+    codecs::postcard::{
+        serialized_size,
+        PostcardCodec,
+    },
     config::{
         Config,
         NotInitialized,
+        TxGossipMode,
+        MAX_SNAPSHOT_CHUNKS_PER_REQUEST,
     },
     gossipsub::messages::{
         GossipsubBroadcastRequest,
@@ -16,15 +21,19 @@ use crate::{
     ports::{
         BlockHeightImporter,
         P2pDb,
+        SnapshotProvider,
+        TxPool,
     },
     request_response::messages::{
         OnResponse,
         RequestMessage,
         ResponseMessage,
+        ResponseMessageErrorCode,
         ResponseSender,
     },
 };
 use anyhow::anyhow;
+use fuel_core_metrics::p2p_metrics::p2p_metrics;
 use fuel_core_services::{
     stream::BoxStream,
     RunnableService,
@@ -34,9 +43,13 @@ use fuel_core_services::{
 };
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
     fuel_tx::{
         Transaction,
+        TxId,
         UniqueIdentifier,
     },
     fuel_types::{
@@ -46,12 +59,20 @@ use fuel_core_types::{
     services::p2p::{
         peer_reputation::{
             AppScore,
+            PeerBan,
             PeerReport,
+            PeerReputation,
+        },
+        snapshot::{
+            SnapshotChunk,
+            SnapshotChunksRequest,
+            SnapshotManifest,
         },
         BlockHeightHeartbeatData,
         GossipData,
         GossipsubMessageAcceptance,
         GossipsubMessageInfo,
+        NetworkReachability,
         PeerId as FuelPeerId,
         TransactionGossipData,
         Transactions,
@@ -59,6 +80,7 @@ use fuel_core_types::{
 };
 use futures::{
     future::BoxFuture,
+    stream::FuturesUnordered,
     StreamExt,
 };
 use libp2p::{
@@ -66,7 +88,9 @@ use libp2p::{
     request_response::InboundRequestId,
     PeerId,
 };
+use serde::Serialize;
 use std::{
+    collections::HashMap,
     fmt::Debug,
     ops::Range,
     sync::Arc,
@@ -86,7 +110,24 @@ use tracing::warn;
 
 pub type Service<V> = ServiceRunner<UninitializedTask<V, SharedState>>;
 
-enum TaskRequest {
+/// Reserved for the tags of the response and the length of its payload.
+const RESPONSE_ENVELOPE_SIZE: usize = 16;
+
+/// The maximum number of announced transactions fetched from peers at the same time.
+const MAX_ANNOUNCED_TX_FETCHES: usize = 1024;
+
+/// The interval between writes of the changed reputations of peers to the database.
+/// Bans and overrides of the score by the operator are written immediately.
+const REPUTATIONS_PERSIST_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The transaction fetched from the peer that announced it.
+struct AnnouncedTxFetch {
+    tx_id: TxId,
+    message_info: GossipsubMessageInfo,
+    transaction: Option<Transaction>,
+}
+
+pub enum TaskRequest {
     // Broadcast requests to p2p network
     BroadcastTransaction(Arc<Transaction>),
     // Request to get one-off data from p2p network
@@ -95,6 +136,29 @@ enum TaskRequest {
     GetAllPeerInfo {
         channel: oneshot::Sender<Vec<(PeerId, PeerInfo)>>,
     },
+    // Request to get reputations of all known peers
+    GetPeerReputations {
+        channel: oneshot::Sender<Vec<(PeerId, PeerReputation)>>,
+    },
+    // Request to get the reachability of the node from the network
+    GetReachability {
+        channel: oneshot::Sender<NetworkReachability>,
+    },
+    // Requests to manage reputations of peers by the operator of the node
+    SetPeerAppScore {
+        peer_id: PeerId,
+        score: AppScore,
+        channel: oneshot::Sender<()>,
+    },
+    BanPeer {
+        peer_id: PeerId,
+        ban: PeerBan,
+        channel: oneshot::Sender<()>,
+    },
+    UnbanPeer {
+        peer_id: PeerId,
+        channel: oneshot::Sender<bool>,
+    },
     GetSealedHeaders {
         block_height_range: Range<u32>,
         channel: OnResponse<Option<Vec<SealedBlockHeader>>>,
@@ -104,6 +168,28 @@ enum TaskRequest {
         from_peer: PeerId,
         channel: OnResponse<Option<Vec<Transactions>>>,
     },
+    GetSealedBlocks {
+        block_height_range: Range<u32>,
+        channel: OnResponse<Option<Vec<SealedBlock>>>,
+    },
+    GetTransactionsById {
+        tx_ids: Vec<TxId>,
+        from_peer: PeerId,
+        channel: OnResponse<Option<Vec<Transaction>>>,
+    },
+    GetPooledTransactionIds {
+        from_peer: PeerId,
+        channel: OnResponse<Option<Vec<TxId>>>,
+    },
+    GetSnapshotManifest {
+        from_peer: PeerId,
+        channel: OnResponse<Option<SnapshotManifest>>,
+    },
+    GetSnapshotChunks {
+        request: SnapshotChunksRequest,
+        from_peer: PeerId,
+        channel: OnResponse<Option<Vec<SnapshotChunk>>>,
+    },
     // Responds back to the p2p network
     RespondWithGossipsubMessageReport((GossipsubMessageInfo, GossipsubMessageAcceptance)),
     RespondWithPeerReport {
@@ -128,6 +214,21 @@ impl Debug for TaskRequest {
             TaskRequest::GetTransactions { .. } => {
                 write!(f, "TaskRequest::GetTransactions")
             }
+            TaskRequest::GetSealedBlocks { .. } => {
+                write!(f, "TaskRequest::GetSealedBlocks")
+            }
+            TaskRequest::GetTransactionsById { .. } => {
+                write!(f, "TaskRequest::GetTransactionsById")
+            }
+            TaskRequest::GetPooledTransactionIds { .. } => {
+                write!(f, "TaskRequest::GetPooledTransactionIds")
+            }
+            TaskRequest::GetSnapshotManifest { .. } => {
+                write!(f, "TaskRequest::GetSnapshotManifest")
+            }
+            TaskRequest::GetSnapshotChunks { .. } => {
+                write!(f, "TaskRequest::GetSnapshotChunks")
+            }
             TaskRequest::RespondWithGossipsubMessageReport(_) => {
                 write!(f, "TaskRequest::RespondWithGossipsubMessageReport")
             }
@@ -137,6 +238,21 @@ impl Debug for TaskRequest {
             TaskRequest::GetAllPeerInfo { .. } => {
                 write!(f, "TaskRequest::GetPeerInfo")
             }
+            TaskRequest::GetPeerReputations { .. } => {
+                write!(f, "TaskRequest::GetPeerReputations")
+            }
+            TaskRequest::GetReachability { .. } => {
+                write!(f, "TaskRequest::GetReachability")
+            }
+            TaskRequest::SetPeerAppScore { .. } => {
+                write!(f, "TaskRequest::SetPeerAppScore")
+            }
+            TaskRequest::BanPeer { .. } => {
+                write!(f, "TaskRequest::BanPeer")
+            }
+            TaskRequest::UnbanPeer { .. } => {
+                write!(f, "TaskRequest::UnbanPeer")
+            }
         }
     }
 }
@@ -186,6 +302,16 @@ pub trait TaskP2PService: Send {
     ) -> anyhow::Result<()>;
 
     fn update_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()>;
+
+    fn get_peer_reputations(&self) -> Vec<(PeerId, PeerReputation)>;
+
+    fn get_reachability(&self) -> NetworkReachability;
+
+    fn set_app_score(&mut self, peer_id: PeerId, score: AppScore) -> anyhow::Result<()>;
+
+    fn ban_peer(&mut self, peer_id: PeerId, ban: PeerBan) -> anyhow::Result<()>;
+
+    fn unban_peer(&mut self, peer_id: PeerId) -> anyhow::Result<bool>;
 }
 
 impl TaskP2PService for FuelP2PService {
@@ -255,6 +381,28 @@ impl TaskP2PService for FuelP2PService {
         self.update_block_height(height);
         Ok(())
     }
+
+    fn get_peer_reputations(&self) -> Vec<(PeerId, PeerReputation)> {
+        self.peer_manager().reputations()
+    }
+
+    fn get_reachability(&self) -> NetworkReachability {
+        self.reachability()
+    }
+
+    fn set_app_score(&mut self, peer_id: PeerId, score: AppScore) -> anyhow::Result<()> {
+        self.set_app_score(peer_id, score);
+        Ok(())
+    }
+
+    fn ban_peer(&mut self, peer_id: PeerId, ban: PeerBan) -> anyhow::Result<()> {
+        self.ban_peer(peer_id, ban);
+        Ok(())
+    }
+
+    fn unban_peer(&mut self, peer_id: PeerId) -> anyhow::Result<bool> {
+        Ok(self.unban_peer(peer_id))
+    }
 }
 
 pub trait Broadcast: Send {
@@ -271,6 +419,8 @@ pub trait Broadcast: Send {
     ) -> anyhow::Result<()>;
 
     fn tx_broadcast(&self, transaction: TransactionGossipData) -> anyhow::Result<()>;
+
+    fn new_peer_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()>;
 }
 
 impl Broadcast for SharedState {
@@ -295,12 +445,19 @@ impl Broadcast for SharedState {
         self.tx_broadcast.send(transaction)?;
         Ok(())
     }
+
+    fn new_peer_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()> {
+        self.new_peers_broadcast.send(peer_id)?;
+        Ok(())
+    }
 }
 
 /// Uninitialized task for the p2p that can be upgraded later into [`Task`].
 pub struct UninitializedTask<V, B> {
     chain_id: ChainId,
     view_provider: V,
+    snapshot_provider: Option<Arc<dyn SnapshotProvider>>,
+    tx_pool: Arc<dyn TxPool>,
     next_block_height: BoxStream<BlockHeight>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
@@ -314,17 +471,30 @@ pub struct Task<P, V, B> {
     chain_id: ChainId,
     p2p_service: P,
     view_provider: V,
+    /// Serves snapshots of the state for the snap-sync, if enabled.
+    snapshot_provider: Option<Arc<dyn SnapshotProvider>>,
+    /// Serves transactions from the pool requested by id.
+    tx_pool: Arc<dyn TxPool>,
     next_block_height: BoxStream<BlockHeight>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     broadcast: B,
+    max_block_size: usize,
     max_headers_per_request: u32,
+    max_txs_by_id_per_request: usize,
+    tx_gossip_mode: TxGossipMode,
+    /// Requests of the transactions announced by peers.
+    announced_tx_fetches: FuturesUnordered<BoxFuture<'static, AnnouncedTxFetch>>,
+    metrics: bool,
     // milliseconds wait time between peer heartbeat reputation checks
     heartbeat_check_interval: Duration,
     heartbeat_max_avg_interval: Duration,
     heartbeat_max_time_since_last: Duration,
     next_check_time: Instant,
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
+    /// The reputations of peers written to the database, used to write only changed ones.
+    persisted_reputations: HashMap<FuelPeerId, PeerReputation>,
+    next_reputations_persist_time: Instant,
 }
 
 #[derive(Clone)]
@@ -334,41 +504,48 @@ pub struct HeartbeatPeerReputationConfig {
 }
 
 impl<V> UninitializedTask<V, SharedState> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new<B: BlockHeightImporter>(
         chain_id: ChainId,
         config: Config<NotInitialized>,
+        shared_state: SharedState,
+        request_receiver: mpsc::Receiver<TaskRequest>,
         view_provider: V,
+        snapshot_provider: Option<Arc<dyn SnapshotProvider>>,
+        tx_pool: Arc<dyn TxPool>,
         block_importer: B,
     ) -> Self {
-        let (request_sender, request_receiver) = mpsc::channel(1024 * 10);
-        let (tx_broadcast, _) = broadcast::channel(1024 * 10);
-        let (block_height_broadcast, _) = broadcast::channel(1024 * 10);
-
-        let (reserved_peers_broadcast, _) = broadcast::channel::<usize>(
-            config
-                .reserved_nodes
-                .len()
-                .saturating_mul(2)
-                .saturating_add(1),
-        );
         let next_block_height = block_importer.next_block_height();
 
         Self {
             chain_id,
             view_provider,
+            snapshot_provider,
+            tx_pool,
             next_block_height,
             request_receiver,
-            broadcast: SharedState {
-                request_sender,
-                tx_broadcast,
-                reserved_peers_broadcast,
-                block_height_broadcast,
-            },
+            broadcast: shared_state,
             config,
         }
     }
 }
 
+/// Takes items from the start of the `items` while the response with them
+/// fits into the `max_response_size`.
+fn take_while_fits<T: Serialize>(items: Vec<T>, max_response_size: usize) -> Vec<T> {
+    let mut remaining = max_response_size.saturating_sub(RESPONSE_ENVELOPE_SIZE);
+    items
+        .into_iter()
+        .take_while(|item| match remaining.checked_sub(serialized_size(item)) {
+            Some(left) => {
+                remaining = left;
+                true
+            }
+            None => false,
+        })
+        .collect()
+}
+
 impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
     fn peer_heartbeat_reputation_checks(&self) -> anyhow::Result<()> {
         for (peer_id, peer_info) in self.p2p_service.get_all_peer_info() {
@@ -410,6 +587,140 @@ impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
             .report_peer(peer_id, app_score, reporting_service)?;
         Ok(())
     }
+
+    /// Requests the announced transaction from the peer, unless the transaction is already known.
+    fn handle_tx_announcement(
+        &mut self,
+        tx_id: TxId,
+        peer_id: PeerId,
+        message_id: Vec<u8>,
+    ) -> anyhow::Result<()> {
+        let message_info = GossipsubMessageInfo {
+            message_id,
+            peer_id: peer_id.to_bytes().into(),
+        };
+
+        if !self.tx_pool.get_transactions_by_id(vec![tx_id]).is_empty() {
+            // We can serve the transaction, so the announcement can be propagated further.
+            return self
+                .p2p_service
+                .report_message(message_info, GossipsubMessageAcceptance::Accept)
+        }
+
+        if self.announced_tx_fetches.len() >= MAX_ANNOUNCED_TX_FETCHES {
+            tracing::debug!(
+                "Too many announced transactions are fetched, ignoring {tx_id}"
+            );
+            return self
+                .p2p_service
+                .report_message(message_info, GossipsubMessageAcceptance::Ignore)
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        let request_msg = RequestMessage::TransactionsById(vec![tx_id]);
+        let channel = ResponseSender::TransactionsById(sender);
+        self.p2p_service
+            .send_request_msg(Some(peer_id), request_msg, channel)
+            .expect("We always a peer here, so send has a target");
+
+        self.announced_tx_fetches.push(Box::pin(async move {
+            let transaction = match receiver.await {
+                Ok((_, Ok(Some(transactions)))) => transactions.into_iter().next(),
+                _ => None,
+            };
+            AnnouncedTxFetch {
+                tx_id,
+                message_info,
+                transaction,
+            }
+        }));
+        Ok(())
+    }
+
+    /// Passes the fetched transaction to the subscribers as if it was gossiped in full,
+    /// so its validation result is reported for the announcement.
+    fn handle_announced_tx_fetch(
+        &mut self,
+        fetch: AnnouncedTxFetch,
+    ) -> anyhow::Result<()> {
+        let AnnouncedTxFetch {
+            tx_id,
+            message_info,
+            transaction,
+        } = fetch;
+
+        match transaction {
+            Some(transaction) if transaction.id(&self.chain_id) == tx_id => {
+                if self.metrics {
+                    p2p_metrics()
+                        .announced_tx_fetch_bytes
+                        .inc_by(serialized_size(&transaction) as u64);
+                }
+                let GossipsubMessageInfo {
+                    message_id,
+                    peer_id,
+                } = message_info;
+                let next_transaction = GossipData::new(transaction, peer_id, message_id);
+                let _ = self.broadcast.tx_broadcast(next_transaction);
+                Ok(())
+            }
+            Some(_) => {
+                tracing::debug!(
+                    "The peer responded with a wrong transaction for {tx_id}"
+                );
+                self.p2p_service
+                    .report_message(message_info, GossipsubMessageAcceptance::Reject)
+            }
+            None => self
+                .p2p_service
+                .report_message(message_info, GossipsubMessageAcceptance::Ignore),
+        }
+    }
+}
+
+impl<P, V, B> Task<P, V, B>
+where
+    P: TaskP2PService,
+    V: AtomicView,
+    V::View: P2pDb,
+{
+    fn persist_peer_reputations(&mut self) {
+        let reputations: HashMap<_, _> = self
+            .p2p_service
+            .get_peer_reputations()
+            .into_iter()
+            .map(|(peer_id, reputation)| {
+                (FuelPeerId::from(peer_id.to_bytes()), reputation)
+            })
+            .collect();
+        let updated: Vec<_> = reputations
+            .iter()
+            .filter(|(peer_id, reputation)| {
+                self.persisted_reputations.get(*peer_id) != Some(*reputation)
+            })
+            .map(|(peer_id, reputation)| (peer_id.clone(), reputation.clone()))
+            .collect();
+        let removed: Vec<_> = self
+            .persisted_reputations
+            .keys()
+            .filter(|peer_id| !reputations.contains_key(*peer_id))
+            .cloned()
+            .collect();
+        if updated.is_empty() && removed.is_empty() {
+            return
+        }
+
+        let result = self
+            .view_provider
+            .latest_view()
+            .update_peer_reputations(updated, removed);
+        match result {
+            Ok(()) => self.persisted_reputations = reputations,
+            Err(e) => {
+                tracing::error!("Failed to persist reputations of peers: {:?}", e)
+            }
+        }
+    }
 }
 
 fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
@@ -441,6 +752,8 @@ where
         let Self {
             chain_id,
             view_provider,
+            snapshot_provider,
+            tx_pool,
             next_block_height,
             request_receiver,
             broadcast,
@@ -450,9 +763,12 @@ where
         let view = view_provider.latest_view();
         let genesis = view.get_genesis()?;
         let config = config.init(genesis)?;
+        let max_txs_by_id_per_request = config.max_txs_by_id_per_request();
         let Config {
             max_block_size,
             max_headers_per_request,
+            tx_gossip_mode,
+            metrics,
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
@@ -471,26 +787,49 @@ where
             config,
             PostcardCodec::new(max_block_size),
         );
+        let persisted_reputations: HashMap<_, _> =
+            view.get_peer_reputations()?.into_iter().collect();
+        let reputations =
+            persisted_reputations
+                .iter()
+                .filter_map(|(peer_id, reputation)| {
+                    PeerId::from_bytes(peer_id.as_ref())
+                        .ok()
+                        .map(|peer_id| (peer_id, reputation.clone()))
+                });
+        p2p_service.restore_reputations(reputations);
         p2p_service.start().await?;
 
         let next_check_time =
             Instant::now().checked_add(heartbeat_check_interval).expect(
                 "The heartbeat check interval should be small enough to do frequently",
             );
+        let next_reputations_persist_time = Instant::now()
+            .checked_add(REPUTATIONS_PERSIST_INTERVAL)
+            .expect("The reputations persist interval is small");
 
         let task = Task {
             chain_id,
             p2p_service,
             view_provider,
+            snapshot_provider,
+            tx_pool,
             request_receiver,
             next_block_height,
             broadcast,
+            max_block_size,
             max_headers_per_request,
+            max_txs_by_id_per_request,
+            tx_gossip_mode,
+            announced_tx_fetches: FuturesUnordered::new(),
+            metrics,
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
             next_check_time,
             heartbeat_peer_reputation_config,
+            persisted_reputations,
+            next_reputations_persist_time,
         };
         Ok(task)
     }
@@ -521,7 +860,10 @@ where
                 match next_service_request {
                     Some(TaskRequest::BroadcastTransaction(transaction)) => {
                         let tx_id = transaction.id(&self.chain_id);
-                        let broadcast = GossipsubBroadcastRequest::NewTx(transaction);
+                        let broadcast = match self.tx_gossip_mode {
+                            TxGossipMode::Full => GossipsubBroadcastRequest::NewTx(transaction),
+                            TxGossipMode::Announcement => GossipsubBroadcastRequest::NewTxAnnouncement(tx_id),
+                        };
                         let result = self.p2p_service.publish_message(broadcast);
                         if let Err(e) = result {
                             tracing::error!("Got an error during transaction {} broadcasting {}", tx_id, e);
@@ -548,6 +890,38 @@ where
                         let request_msg = RequestMessage::Transactions(block_height_range);
                         self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always a peer here, so send has a target");
                     }
+                    Some(TaskRequest::GetSealedBlocks { block_height_range, channel }) => {
+                        let channel = ResponseSender::SealedBlocks(channel);
+                        let request_msg = RequestMessage::SealedBlocks(block_height_range.clone());
+
+                        // Note: this range has already been checked for
+                        // validity in `SharedState::get_sealed_blocks`.
+                        let height = BlockHeight::from(block_height_range.end.saturating_sub(1));
+                        let peer = self.p2p_service.get_peer_id_with_height(&height);
+                        if self.p2p_service.send_request_msg(peer, request_msg, channel).is_err() {
+                            tracing::warn!("No peers found for block at height {:?}", height);
+                        }
+                    }
+                    Some(TaskRequest::GetTransactionsById { tx_ids, from_peer, channel }) => {
+                        let channel = ResponseSender::TransactionsById(channel);
+                        let request_msg = RequestMessage::TransactionsById(tx_ids);
+                        self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always a peer here, so send has a target");
+                    }
+                    Some(TaskRequest::GetPooledTransactionIds { from_peer, channel }) => {
+                        let channel = ResponseSender::PooledTransactionIds(channel);
+                        let request_msg = RequestMessage::PooledTransactionIds;
+                        self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always a peer here, so send has a target");
+                    }
+                    Some(TaskRequest::GetSnapshotManifest { from_peer, channel }) => {
+                        let channel = ResponseSender::SnapshotManifest(channel);
+                        let request_msg = RequestMessage::SnapshotManifest;
+                        self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always a peer here, so send has a target");
+                    }
+                    Some(TaskRequest::GetSnapshotChunks { request, from_peer, channel }) => {
+                        let channel = ResponseSender::SnapshotChunks(channel);
+                        let request_msg = RequestMessage::SnapshotChunks(request);
+                        self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always a peer here, so send has a target");
+                    }
                     Some(TaskRequest::RespondWithGossipsubMessageReport((message, acceptance))) => {
                         // report_message(&mut self.p2p_service, message, acceptance);
                         self.p2p_service.report_message(message, acceptance)?;
@@ -562,6 +936,27 @@ where
                             .collect::<Vec<_>>();
                         let _ = channel.send(peers);
                     }
+                    Some(TaskRequest::GetPeerReputations { channel }) => {
+                        let _ = channel.send(self.p2p_service.get_peer_reputations());
+                    }
+                    Some(TaskRequest::GetReachability { channel }) => {
+                        let _ = channel.send(self.p2p_service.get_reachability());
+                    }
+                    Some(TaskRequest::SetPeerAppScore { peer_id, score, channel }) => {
+                        self.p2p_service.set_app_score(peer_id, score)?;
+                        self.persist_peer_reputations();
+                        let _ = channel.send(());
+                    }
+                    Some(TaskRequest::BanPeer { peer_id, ban, channel }) => {
+                        self.p2p_service.ban_peer(peer_id, ban)?;
+                        self.persist_peer_reputations();
+                        let _ = channel.send(());
+                    }
+                    Some(TaskRequest::UnbanPeer { peer_id, channel }) => {
+                        let unbanned = self.p2p_service.unban_peer(peer_id)?;
+                        self.persist_peer_reputations();
+                        let _ = channel.send(unbanned);
+                    }
                     None => {
                         unreachable!("The `Task` is holder of the `Sender`, so it should not be possible");
                     }
@@ -579,6 +974,10 @@ where
 
                         let _ = self.broadcast.block_height_broadcast(block_height_data);
                     }
+                    Some(FuelP2PEvent::PeerConnected(peer_id)) => {
+                        let peer_id: Vec<u8> = peer_id.into();
+                        let _ = self.broadcast.new_peer_broadcast(peer_id.into());
+                    }
                     Some(FuelP2PEvent::GossipsubMessage { message, message_id, peer_id,.. }) => {
                         let message_id = message_id.0;
 
@@ -587,6 +986,9 @@ where
                                 let next_transaction = GossipData::new(transaction, peer_id, message_id);
                                 let _ = self.broadcast.tx_broadcast(next_transaction);
                             },
+                            GossipsubMessage::NewTxAnnouncement(tx_id) => {
+                                self.handle_tx_announcement(tx_id, peer_id, message_id)?;
+                            },
                         }
                     },
                     Some(FuelP2PEvent::InboundRequestMessage { request_message, request_id }) => {
@@ -595,11 +997,12 @@ where
                                 let view = self.view_provider.latest_view();
                                 match view.get_transactions(range.clone()) {
                                     Ok(response) => {
+                                        let response = response.ok_or(ResponseMessageErrorCode::NotAvailable);
                                         let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::Transactions(response));
                                     },
                                     Err(e) => {
                                         tracing::error!("Failed to get transactions for range {:?}: {:?}", range, e);
-                                        let response = None;
+                                        let response = Err(ResponseMessageErrorCode::InternalError);
                                         let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::Transactions(response));
                                         return Err(e.into())
                                     }
@@ -609,30 +1012,93 @@ where
                                 let max_len = self.max_headers_per_request.try_into().expect("u32 should always fit into usize");
                                 if range.len() > max_len {
                                     tracing::error!("Requested range of sealed headers is too big. Requested length: {:?}, Max length: {:?}", range.len(), max_len);
-                                    // TODO: Return helpful error message to requester. https://github.com/FuelLabs/fuel-core/issues/1311
-                                    let response = None;
+                                    let response = Err(ResponseMessageErrorCode::RequestedRangeTooLarge);
                                     let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SealedHeaders(response));
                                 } else {
                                     let view = self.view_provider.latest_view();
                                     match view.get_sealed_headers(range.clone()) {
                                         Ok(headers) => {
-                                            let response = Some(headers);
+                                            let response = Ok(headers);
                                             let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SealedHeaders(response));
                                         },
                                         Err(e) => {
                                             tracing::error!("Failed to get sealed headers for range {:?}: {:?}", range, &e);
-                                            let response = None;
+                                            let response = Err(ResponseMessageErrorCode::InternalError);
                                             let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SealedHeaders(response));
                                             return Err(e.into())
                                         }
                                     }
                                 };
                             }
+                            RequestMessage::SnapshotManifest => {
+                                let response = match &self.snapshot_provider {
+                                    Some(provider) => match provider.latest_manifest() {
+                                        Ok(manifest) => manifest.map(Box::new).ok_or(ResponseMessageErrorCode::NotAvailable),
+                                        Err(e) => {
+                                            tracing::error!("Failed to get the snapshot manifest: {:?}", e);
+                                            Err(ResponseMessageErrorCode::InternalError)
+                                        }
+                                    },
+                                    None => Err(ResponseMessageErrorCode::NotAvailable),
+                                };
+                                let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SnapshotManifest(response));
+                            }
+                            RequestMessage::SnapshotChunks(request) => {
+                                let response = match &self.snapshot_provider {
+                                    Some(_) if request.range.end.saturating_sub(request.range.start) > MAX_SNAPSHOT_CHUNKS_PER_REQUEST => {
+                                        tracing::error!("Requested range of snapshot chunks is too big. Requested length: {:?}, Max length: {:?}", request.range.len(), MAX_SNAPSHOT_CHUNKS_PER_REQUEST);
+                                        Err(ResponseMessageErrorCode::RequestedRangeTooLarge)
+                                    }
+                                    Some(provider) => match provider.get_chunks(request.height.into(), request.range.clone()) {
+                                        Ok(chunks) => chunks.ok_or(ResponseMessageErrorCode::NotAvailable),
+                                        Err(e) => {
+                                            tracing::error!("Failed to get snapshot chunks {:?}: {:?}", request, e);
+                                            Err(ResponseMessageErrorCode::InternalError)
+                                        }
+                                    },
+                                    None => Err(ResponseMessageErrorCode::NotAvailable),
+                                };
+                                let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SnapshotChunks(response));
+                            }
+                            RequestMessage::TransactionsById(tx_ids) => {
+                                let response = if tx_ids.len() > self.max_txs_by_id_per_request {
+                                    tracing::error!("Requested too many transactions by id. Requested length: {:?}, Max length: {:?}", tx_ids.len(), self.max_txs_by_id_per_request);
+                                    Err(ResponseMessageErrorCode::RequestedRangeTooLarge)
+                                } else {
+                                    let transactions = self.tx_pool.get_transactions_by_id(tx_ids);
+                                    Ok(take_while_fits(transactions, self.max_block_size))
+                                };
+                                let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::TransactionsById(response));
+                            }
+                            RequestMessage::SealedBlocks(range) => {
+                                let max_len = self.max_headers_per_request.try_into().expect("u32 should always fit into usize");
+                                let response = if range.len() > max_len {
+                                    tracing::error!("Requested range of sealed blocks is too big. Requested length: {:?}, Max length: {:?}", range.len(), max_len);
+                                    Err(ResponseMessageErrorCode::RequestedRangeTooLarge)
+                                } else {
+                                    match self.view_provider.latest_view().get_sealed_blocks(range.clone()) {
+                                        Ok(blocks) => Ok(take_while_fits(blocks, self.max_block_size)),
+                                        Err(e) => {
+                                            tracing::error!("Failed to get sealed blocks for range {:?}: {:?}", range, e);
+                                            Err(ResponseMessageErrorCode::InternalError)
+                                        }
+                                    }
+                                };
+                                let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SealedBlocks(response));
+                            }
+                            RequestMessage::PooledTransactionIds => {
+                                let tx_ids = self.tx_pool.get_tx_ids(self.max_txs_by_id_per_request);
+                                let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::PooledTransactionIds(Ok(tx_ids)));
+                            }
                         }
                     },
                     _ => (),
                 }
             },
+            Some(fetch) = self.announced_tx_fetches.next(), if !self.announced_tx_fetches.is_empty() => {
+                should_continue = true;
+                self.handle_announced_tx_fetch(fetch)?;
+            },
             _  = tokio::time::sleep_until(self.next_check_time) => {
                 should_continue = true;
                 let res = self.peer_heartbeat_reputation_checks();
@@ -642,6 +1108,10 @@ where
                         tracing::error!("Failed to perform peer heartbeat reputation checks: {:?}", e);
                     }
                 }
+                if Instant::now() >= self.next_reputations_persist_time {
+                    self.persist_peer_reputations();
+                    self.next_reputations_persist_time += REPUTATIONS_PERSIST_INTERVAL;
+                }
                 self.next_check_time += self.heartbeat_check_interval;
             },
             latest_block_height = self.next_block_height.next() => {
@@ -658,9 +1128,10 @@ where
         Ok(should_continue)
     }
 
-    async fn shutdown(self) -> anyhow::Result<()> {
-        // Nothing to shut down because we don't have any temporary state that should be dumped,
-        // and we don't spawn any sub-tasks that we need to finish or await.
+    async fn shutdown(mut self) -> anyhow::Result<()> {
+        // The only temporary state that should be dumped is reputations of peers.
+        // We don't spawn any sub-tasks that we need to finish or await.
+        self.persist_peer_reputations();
 
         // `FuelP2PService` doesn't support graceful shutdown(with informing of connected peers).
         // https://github.com/libp2p/specs/blob/master/ROADMAP.md#%EF%B8%8F-polite-peering
@@ -680,6 +1151,10 @@ pub struct SharedState {
     request_sender: mpsc::Sender<TaskRequest>,
     /// Sender of p2p blopck height data
     block_height_broadcast: broadcast::Sender<BlockHeightHeartbeatData>,
+    /// Sender of the peers with the newly established connections.
+    new_peers_broadcast: broadcast::Sender<FuelPeerId>,
+    /// The maximum number of transactions requested by id at once.
+    max_txs_by_id_per_request: usize,
 }
 
 impl SharedState {
@@ -747,6 +1222,122 @@ impl SharedState {
         response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))
     }
 
+    pub async fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> anyhow::Result<(Vec<u8>, Option<Vec<SealedBlock>>)> {
+        let (sender, receiver) = oneshot::channel();
+
+        if block_height_range.is_empty() {
+            return Err(anyhow!(
+                "Cannot retrieve blocks for an empty range of block heights"
+            ))
+        }
+
+        self.request_sender
+            .send(TaskRequest::GetSealedBlocks {
+                block_height_range,
+                channel: sender,
+            })
+            .await?;
+
+        let (peer_id, response) = receiver.await.map_err(|e| anyhow!("{e}"))?;
+
+        let data = response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))?;
+        Ok((peer_id.to_bytes(), data))
+    }
+
+    /// Requests transactions from the pool of the peer. The response contains only
+    /// transactions known to the peer, so it may be shorter than `tx_ids`.
+    pub async fn get_transactions_by_id_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Option<Vec<Transaction>>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id)?;
+
+        if tx_ids.len() > self.max_txs_by_id_per_request {
+            return Err(anyhow!(
+                "Cannot request more than {} transactions at once",
+                self.max_txs_by_id_per_request
+            ))
+        }
+
+        let request = TaskRequest::GetTransactionsById {
+            tx_ids,
+            from_peer,
+            channel: sender,
+        };
+        self.request_sender.send(request).await?;
+
+        let (_, response) = receiver.await.map_err(|e| anyhow!("{e}"))?;
+        response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))
+    }
+
+    /// Requests the ids of the transactions from the transaction pool of the peer.
+    pub async fn get_pooled_transaction_ids_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+    ) -> anyhow::Result<Option<Vec<TxId>>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id)?;
+
+        let request = TaskRequest::GetPooledTransactionIds {
+            from_peer,
+            channel: sender,
+        };
+        self.request_sender.send(request).await?;
+
+        let (_, response) = receiver.await.map_err(|e| anyhow!("{e}"))?;
+        response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))
+    }
+
+    /// The maximum number of transactions that can be requested by id at once.
+    pub fn max_txs_by_id_per_request(&self) -> usize {
+        self.max_txs_by_id_per_request
+    }
+
+    pub async fn get_snapshot_manifest_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+    ) -> anyhow::Result<Option<SnapshotManifest>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id)?;
+
+        let request = TaskRequest::GetSnapshotManifest {
+            from_peer,
+            channel: sender,
+        };
+        self.request_sender.send(request).await?;
+
+        let (_, response) = receiver.await.map_err(|e| anyhow!("{e}"))?;
+        response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))
+    }
+
+    pub async fn get_snapshot_chunks_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+        request: SnapshotChunksRequest,
+    ) -> anyhow::Result<Option<Vec<SnapshotChunk>>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id)?;
+
+        if request.range.is_empty() {
+            return Err(anyhow!("Cannot retrieve an empty range of snapshot chunks"))
+        }
+
+        let request = TaskRequest::GetSnapshotChunks {
+            request,
+            from_peer,
+            channel: sender,
+        };
+        self.request_sender.send(request).await?;
+
+        let (_, response) = receiver.await.map_err(|e| anyhow!("{e}"))?;
+        response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))
+    }
+
     pub fn broadcast_transaction(
         &self,
         transaction: Arc<Transaction>,
@@ -776,6 +1367,81 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    pub async fn get_peer_reputations(
+        &self,
+    ) -> anyhow::Result<Vec<(PeerId, PeerReputation)>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetPeerReputations { channel: sender })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    pub async fn get_reachability(&self) -> anyhow::Result<NetworkReachability> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetReachability { channel: sender })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    pub async fn set_peer_app_score(
+        &self,
+        peer_id: FuelPeerId,
+        score: AppScore,
+    ) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+        let peer_id = PeerId::from_bytes(peer_id.as_ref())?;
+
+        self.request_sender
+            .send(TaskRequest::SetPeerAppScore {
+                peer_id,
+                score,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    pub async fn ban_peer(
+        &self,
+        peer_id: FuelPeerId,
+        ban: PeerBan,
+    ) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+        let peer_id = PeerId::from_bytes(peer_id.as_ref())?;
+
+        self.request_sender
+            .send(TaskRequest::BanPeer {
+                peer_id,
+                ban,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    /// Returns `false` if the peer was not banned.
+    pub async fn unban_peer(&self, peer_id: FuelPeerId) -> anyhow::Result<bool> {
+        let (sender, receiver) = oneshot::channel();
+        let peer_id = PeerId::from_bytes(peer_id.as_ref())?;
+
+        self.request_sender
+            .send(TaskRequest::UnbanPeer {
+                peer_id,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
     pub fn subscribe_tx(&self) -> broadcast::Receiver<TransactionGossipData> {
         self.tx_broadcast.subscribe()
     }
@@ -790,6 +1456,10 @@ impl SharedState {
         self.reserved_peers_broadcast.subscribe()
     }
 
+    pub fn subscribe_new_peers(&self) -> broadcast::Receiver<FuelPeerId> {
+        self.new_peers_broadcast.subscribe()
+    }
+
     pub fn report_peer<T: PeerReport>(
         &self,
         peer_id: FuelPeerId,
@@ -817,10 +1487,46 @@ impl SharedState {
     }
 }
 
+/// Builds the shared state of the service before the service itself,
+/// so other services that the p2p depends on can use it.
+pub fn build_shared_state(
+    config: &Config<NotInitialized>,
+) -> (SharedState, mpsc::Receiver<TaskRequest>) {
+    let (request_sender, request_receiver) = mpsc::channel(1024 * 10);
+    let (tx_broadcast, _) = broadcast::channel(1024 * 10);
+    let (block_height_broadcast, _) = broadcast::channel(1024 * 10);
+    let (new_peers_broadcast, _) = broadcast::channel(1024);
+
+    let (reserved_peers_broadcast, _) = broadcast::channel::<usize>(
+        config
+            .reserved_nodes
+            .len()
+            .saturating_mul(2)
+            .saturating_add(1),
+    );
+
+    (
+        SharedState {
+            request_sender,
+            tx_broadcast,
+            reserved_peers_broadcast,
+            block_height_broadcast,
+            new_peers_broadcast,
+            max_txs_by_id_per_request: config.max_txs_by_id_per_request(),
+        },
+        request_receiver,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn new_service<V, B>(
     chain_id: ChainId,
     p2p_config: Config<NotInitialized>,
+    shared_state: SharedState,
+    request_receiver: mpsc::Receiver<TaskRequest>,
     view_provider: V,
+    snapshot_provider: Option<Arc<dyn SnapshotProvider>>,
+    tx_pool: Arc<dyn TxPool>,
     block_importer: B,
 ) -> Service<V>
 where
@@ -828,8 +1534,16 @@ where
     V::View: P2pDb,
     B: BlockHeightImporter,
 {
-    let task =
-        UninitializedTask::new(chain_id, p2p_config, view_provider, block_importer);
+    let task = UninitializedTask::new(
+        chain_id,
+        p2p_config,
+        shared_state,
+        request_receiver,
+        view_provider,
+        snapshot_provider,
+        tx_pool,
+        block_importer,
+    );
     Service::new(task)
 }
 
@@ -879,7 +1593,9 @@ pub mod tests {
     use fuel_core_storage::Result as StorageResult;
     use fuel_core_types::{
         blockchain::consensus::Genesis,
+        fuel_tx::TransactionBuilder,
         fuel_types::BlockHeight,
+        services::p2p::peer_reputation::DEFAULT_APP_SCORE,
     };
     use futures::FutureExt;
     use std::{
@@ -887,8 +1603,12 @@ pub mod tests {
         time::SystemTime,
     };
 
-    #[derive(Clone, Debug)]
-    struct FakeDb;
+    #[derive(Clone, Debug, Default)]
+    struct FakeDb {
+        reputations: Arc<std::sync::Mutex<Vec<(FuelPeerId, PeerReputation)>>>,
+        /// The peers of the updated reputations, per write.
+        updates: Arc<std::sync::Mutex<Vec<Vec<FuelPeerId>>>>,
+    }
 
     impl AtomicView for FakeDb {
         type View = Self;
@@ -923,14 +1643,78 @@ pub mod tests {
             unimplemented!()
         }
 
+        fn get_sealed_blocks(
+            &self,
+            _block_height_range: Range<u32>,
+        ) -> StorageResult<Vec<SealedBlock>> {
+            unimplemented!()
+        }
+
         fn get_genesis(&self) -> StorageResult<Genesis> {
             Ok(Default::default())
         }
+
+        fn get_peer_reputations(
+            &self,
+        ) -> StorageResult<Vec<(FuelPeerId, PeerReputation)>> {
+            Ok(self.reputations.lock().unwrap().clone())
+        }
+
+        fn update_peer_reputations(
+            &self,
+            updated: Vec<(FuelPeerId, PeerReputation)>,
+            removed: Vec<FuelPeerId>,
+        ) -> StorageResult<()> {
+            let mut reputations = self.reputations.lock().unwrap();
+            reputations.retain(|(peer_id, _)| {
+                !removed.contains(peer_id)
+                    && !updated.iter().any(|(updated, _)| updated == peer_id)
+            });
+            self.updates
+                .lock()
+                .unwrap()
+                .push(updated.iter().map(|(peer_id, _)| peer_id.clone()).collect());
+            reputations.extend(updated);
+            Ok(())
+        }
     }
 
     #[derive(Clone, Debug)]
     struct FakeBlockImporter;
 
+    struct FakeTxPool;
+
+    impl TxPool for FakeTxPool {
+        fn get_transactions_by_id(&self, _tx_ids: Vec<TxId>) -> Vec<Transaction> {
+            vec![]
+        }
+
+        fn get_tx_ids(&self, _max_txs: usize) -> Vec<TxId> {
+            vec![]
+        }
+    }
+
+    fn new_service<V>(
+        config: Config<NotInitialized>,
+        view_provider: V,
+    ) -> super::Service<V>
+    where
+        V: AtomicView + 'static,
+        V::View: P2pDb,
+    {
+        let (shared_state, request_receiver) = build_shared_state(&config);
+        super::new_service(
+            ChainId::default(),
+            config,
+            shared_state,
+            request_receiver,
+            view_provider,
+            None,
+            Arc::new(FakeTxPool),
+            FakeBlockImporter,
+        )
+    }
+
     impl BlockHeightImporter for FakeBlockImporter {
         fn next_block_height(&self) -> BoxStream<BlockHeight> {
             Box::pin(fuel_core_services::stream::pending())
@@ -940,8 +1724,7 @@ pub mod tests {
     #[tokio::test]
     async fn start_and_stop_awaits_works() {
         let p2p_config = Config::<NotInitialized>::default("start_stop_works");
-        let service =
-            new_service(ChainId::default(), p2p_config, FakeDb, FakeBlockImporter);
+        let service = new_service(p2p_config, FakeDb::default());
 
         // Node with p2p service started
         assert!(service.start_and_await().await.unwrap().started());
@@ -949,8 +1732,93 @@ pub mod tests {
         assert!(service.stop_and_await().await.unwrap().stopped());
     }
 
+    #[tokio::test]
+    async fn peer_reputations_are_restored_after_restart() {
+        let db = FakeDb::default();
+        let banned_peer = PeerId::random();
+        let ban = PeerBan {
+            reason: "Misbehaving".to_string(),
+            expires_at: None,
+        };
+        let new_service = || {
+            new_service(
+                Config::<NotInitialized>::default("reputations_are_restored"),
+                db.clone(),
+            )
+        };
+
+        // Given
+        let service = new_service();
+        service.start_and_await().await.unwrap();
+        service
+            .shared
+            .ban_peer(FuelPeerId::from(banned_peer.to_bytes()), ban.clone())
+            .await
+            .unwrap();
+        service.stop_and_await().await.unwrap();
+
+        // When
+        let service = new_service();
+        service.start_and_await().await.unwrap();
+        let reputations = service.shared.get_peer_reputations().await.unwrap();
+        service.stop_and_await().await.unwrap();
+
+        // Then
+        assert_eq!(
+            reputations,
+            vec![(
+                banned_peer,
+                PeerReputation {
+                    score: DEFAULT_APP_SCORE,
+                    ban: Some(ban),
+                }
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn only_changed_peer_reputations_are_written() {
+        let db = FakeDb::default();
+        let service = new_service(
+            Config::<NotInitialized>::default("only_changed_reputations_are_written"),
+            db.clone(),
+        );
+        let first_peer = FuelPeerId::from(PeerId::random().to_bytes());
+        let second_peer = FuelPeerId::from(PeerId::random().to_bytes());
+        let ban = PeerBan {
+            reason: "Misbehaving".to_string(),
+            expires_at: None,
+        };
+
+        // Given
+        service.start_and_await().await.unwrap();
+        service
+            .shared
+            .ban_peer(first_peer.clone(), ban.clone())
+            .await
+            .unwrap();
+
+        // When
+        service
+            .shared
+            .ban_peer(second_peer.clone(), ban)
+            .await
+            .unwrap();
+        service.stop_and_await().await.unwrap();
+
+        // Then
+        assert_eq!(
+            *db.updates.lock().unwrap(),
+            vec![vec![first_peer], vec![second_peer]]
+        );
+    }
+
+    #[derive(Default)]
     struct FakeP2PService {
         peer_info: Vec<(PeerId, PeerInfo)>,
+        /// Served for requests of transactions by id.
+        transactions: Vec<Transaction>,
+        message_reports: Vec<GossipsubMessageAcceptance>,
     }
 
     impl TaskP2PService for FakeP2PService {
@@ -981,9 +1849,16 @@ pub mod tests {
             &mut self,
             _peer_id: Option<PeerId>,
             _request_msg: RequestMessage,
-            _on_response: ResponseSender,
+            on_response: ResponseSender,
         ) -> anyhow::Result<()> {
-            todo!()
+            match on_response {
+                ResponseSender::TransactionsById(channel) => {
+                    let response = Ok(Some(self.transactions.clone()));
+                    let _ = channel.send((PeerId::random(), response));
+                    Ok(())
+                }
+                _ => todo!(),
+            }
         }
 
         fn send_response_msg(
@@ -997,9 +1872,10 @@ pub mod tests {
         fn report_message(
             &mut self,
             _message: GossipsubMessageInfo,
-            _acceptance: GossipsubMessageAcceptance,
+            acceptance: GossipsubMessageAcceptance,
         ) -> anyhow::Result<()> {
-            todo!()
+            self.message_reports.push(acceptance);
+            Ok(())
         }
 
         fn report_peer(
@@ -1014,6 +1890,30 @@ pub mod tests {
         fn update_block_height(&mut self, _height: BlockHeight) -> anyhow::Result<()> {
             todo!()
         }
+
+        fn get_peer_reputations(&self) -> Vec<(PeerId, PeerReputation)> {
+            vec![]
+        }
+
+        fn get_reachability(&self) -> NetworkReachability {
+            Default::default()
+        }
+
+        fn set_app_score(
+            &mut self,
+            _peer_id: PeerId,
+            _score: AppScore,
+        ) -> anyhow::Result<()> {
+            todo!()
+        }
+
+        fn ban_peer(&mut self, _peer_id: PeerId, _ban: PeerBan) -> anyhow::Result<()> {
+            todo!()
+        }
+
+        fn unban_peer(&mut self, _peer_id: PeerId) -> anyhow::Result<bool> {
+            todo!()
+        }
     }
 
     #[derive(Clone)]
@@ -1052,13 +1952,35 @@ pub mod tests {
             todo!()
         }
 
+        fn get_sealed_blocks(
+            &self,
+            _block_height_range: Range<u32>,
+        ) -> StorageResult<Vec<SealedBlock>> {
+            todo!()
+        }
+
         fn get_genesis(&self) -> StorageResult<Genesis> {
             todo!()
         }
+
+        fn get_peer_reputations(
+            &self,
+        ) -> StorageResult<Vec<(FuelPeerId, PeerReputation)>> {
+            todo!()
+        }
+
+        fn update_peer_reputations(
+            &self,
+            _updated: Vec<(FuelPeerId, PeerReputation)>,
+            _removed: Vec<FuelPeerId>,
+        ) -> StorageResult<()> {
+            Ok(())
+        }
     }
 
     struct FakeBroadcast {
         pub peer_reports: mpsc::Sender<(FuelPeerId, AppScore, String)>,
+        pub transactions: mpsc::Sender<TransactionGossipData>,
     }
 
     impl Broadcast for FakeBroadcast {
@@ -1083,10 +2005,12 @@ pub mod tests {
             todo!()
         }
 
-        fn tx_broadcast(
-            &self,
-            _transaction: TransactionGossipData,
-        ) -> anyhow::Result<()> {
+        fn tx_broadcast(&self, transaction: TransactionGossipData) -> anyhow::Result<()> {
+            self.transactions.try_send(transaction)?;
+            Ok(())
+        }
+
+        fn new_peer_broadcast(&self, _peer_id: FuelPeerId) -> anyhow::Result<()> {
             todo!()
         }
     }
@@ -1114,12 +2038,16 @@ pub mod tests {
             score: 100.0,
         };
         let peer_info = vec![(peer_id, peer_info)];
-        let p2p_service = FakeP2PService { peer_info };
+        let p2p_service = FakeP2PService {
+            peer_info,
+            ..Default::default()
+        };
         let (_request_sender, request_receiver) = mpsc::channel(100);
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
         let broadcast = FakeBroadcast {
             peer_reports: report_sender,
+            transactions: mpsc::channel(1).0,
         };
 
         // Less than actual
@@ -1137,15 +2065,24 @@ pub mod tests {
             chain_id: Default::default(),
             p2p_service,
             view_provider: FakeDB,
+            snapshot_provider: None,
+            tx_pool: Arc::new(FakeTxPool),
             next_block_height: FakeBlockImporter.next_block_height(),
             request_receiver,
             broadcast,
+            max_block_size: 0,
             max_headers_per_request: 0,
+            max_txs_by_id_per_request: 0,
+            tx_gossip_mode: TxGossipMode::Full,
+            announced_tx_fetches: FuturesUnordered::new(),
+            metrics: false,
             heartbeat_check_interval: Duration::from_secs(0),
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            persisted_reputations: HashMap::new(),
+            next_reputations_persist_time: Instant::now(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1195,12 +2132,16 @@ pub mod tests {
             score: 100.0,
         };
         let peer_info = vec![(peer_id, peer_info)];
-        let p2p_service = FakeP2PService { peer_info };
+        let p2p_service = FakeP2PService {
+            peer_info,
+            ..Default::default()
+        };
         let (_request_sender, request_receiver) = mpsc::channel(100);
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
         let broadcast = FakeBroadcast {
             peer_reports: report_sender,
+            transactions: mpsc::channel(1).0,
         };
 
         // Greater than actual
@@ -1218,15 +2159,24 @@ pub mod tests {
             chain_id: Default::default(),
             p2p_service,
             view_provider: FakeDB,
+            snapshot_provider: None,
+            tx_pool: Arc::new(FakeTxPool),
             next_block_height: FakeBlockImporter.next_block_height(),
             request_receiver,
             broadcast,
+            max_block_size: 0,
             max_headers_per_request: 0,
+            max_txs_by_id_per_request: 0,
+            tx_gossip_mode: TxGossipMode::Full,
+            announced_tx_fetches: FuturesUnordered::new(),
+            metrics: false,
             heartbeat_check_interval: Duration::from_secs(0),
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            persisted_reputations: HashMap::new(),
+            next_reputations_persist_time: Instant::now(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1250,4 +2200,108 @@ pub mod tests {
         );
         assert_eq!(reporting_service, "p2p");
     }
+
+    #[test]
+    fn take_while_fits__stops_at_the_first_item_over_the_limit() {
+        let items = vec![vec![0u8; 10], vec![0u8; 10], vec![0u8; 1], vec![0u8; 1]];
+        // Each item is encoded with 1 byte of the length prefix
+        let max_response_size = RESPONSE_ENVELOPE_SIZE + 11 + 5;
+
+        let taken = take_while_fits(items.clone(), max_response_size);
+
+        assert_eq!(taken, items[..1].to_vec());
+    }
+
+    fn announcement_task(
+        p2p_service: FakeP2PService,
+        broadcast: FakeBroadcast,
+    ) -> Task<FakeP2PService, FakeDB, FakeBroadcast> {
+        Task {
+            chain_id: Default::default(),
+            p2p_service,
+            view_provider: FakeDB,
+            snapshot_provider: None,
+            tx_pool: Arc::new(FakeTxPool),
+            next_block_height: FakeBlockImporter.next_block_height(),
+            request_receiver: mpsc::channel(1).1,
+            broadcast,
+            max_block_size: 0,
+            max_headers_per_request: 0,
+            max_txs_by_id_per_request: 0,
+            tx_gossip_mode: TxGossipMode::Announcement,
+            announced_tx_fetches: FuturesUnordered::new(),
+            metrics: false,
+            heartbeat_check_interval: Duration::from_secs(0),
+            heartbeat_max_avg_interval: Duration::from_secs(0),
+            heartbeat_max_time_since_last: Duration::from_secs(0),
+            next_check_time: Instant::now(),
+            heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig {
+                old_heartbeat_penalty: 0.,
+                low_heartbeat_frequency_penalty: 0.,
+            },
+            persisted_reputations: HashMap::new(),
+            next_reputations_persist_time: Instant::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn tx_announcement__unknown_transaction_is_fetched_and_broadcast() {
+        // given
+        let tx = Transaction::default_test_tx();
+        let tx_id = tx.id(&Default::default());
+        let p2p_service = FakeP2PService {
+            transactions: vec![tx.clone()],
+            ..Default::default()
+        };
+        let (tx_sender, mut tx_receiver) = mpsc::channel(1);
+        let broadcast = FakeBroadcast {
+            peer_reports: mpsc::channel(1).0,
+            transactions: tx_sender,
+        };
+        let mut task = announcement_task(p2p_service, broadcast);
+        let message_id = vec![1, 2, 3];
+
+        // when
+        task.handle_tx_announcement(tx_id, PeerId::random(), message_id.clone())
+            .unwrap();
+        let fetch = task.announced_tx_fetches.next().await.unwrap();
+        task.handle_announced_tx_fetch(fetch).unwrap();
+
+        // then
+        let gossiped = tx_receiver.try_recv().unwrap();
+        assert_eq!(gossiped.data, Some(tx));
+        assert_eq!(gossiped.message_id, message_id);
+        assert!(task.p2p_service.message_reports.is_empty());
+    }
+
+    #[tokio::test]
+    async fn tx_announcement__wrong_transaction_is_rejected() {
+        // given
+        let tx_id = Transaction::default_test_tx().id(&Default::default());
+        let p2p_service = FakeP2PService {
+            transactions: vec![
+                TransactionBuilder::script(vec![1], vec![]).finalize_as_transaction()
+            ],
+            ..Default::default()
+        };
+        let (tx_sender, mut tx_receiver) = mpsc::channel(1);
+        let broadcast = FakeBroadcast {
+            peer_reports: mpsc::channel(1).0,
+            transactions: tx_sender,
+        };
+        let mut task = announcement_task(p2p_service, broadcast);
+
+        // when
+        task.handle_tx_announcement(tx_id, PeerId::random(), vec![1])
+            .unwrap();
+        let fetch = task.announced_tx_fetches.next().await.unwrap();
+        task.handle_announced_tx_fetch(fetch).unwrap();
+
+        // then
+        assert!(tx_receiver.try_recv().is_err());
+        assert_eq!(
+            task.p2p_service.message_reports,
+            vec![GossipsubMessageAcceptance::Reject]
+        );
+    }
 }
@@ -55,6 +55,8 @@ mod test;
 
 type Synced = watch::Receiver<Option<DaBlockHeight>>;
 type NotifySynced = watch::Sender<Option<DaBlockHeight>>;
+type RemoteHeight = watch::Receiver<Option<DaBlockHeight>>;
+type NotifyRemoteHeight = watch::Sender<Option<DaBlockHeight>>;
 
 /// The alias of runnable relayer service.
 pub type Service<D> = CustomizableService<Provider<Http>, D>;
@@ -65,6 +67,8 @@ type CustomizableService<P, D> = ServiceRunner<NotInitializedTask<P, D>>;
 pub struct SharedState<D> {
     /// Receives signals when the relayer reaches consistency with the DA layer.
     synced: Synced,
+    /// Receives the latest finalized height of the DA layer observed by the relayer.
+    remote_height: RemoteHeight,
     database: D,
 }
 
@@ -72,6 +76,8 @@ pub struct SharedState<D> {
 pub struct NotInitializedTask<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends the latest finalized height of the DA layer.
+    remote_height: NotifyRemoteHeight,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -84,6 +90,8 @@ pub struct NotInitializedTask<P, D> {
 pub struct Task<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends the latest finalized height of the DA layer.
+    remote_height: NotifyRemoteHeight,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -99,8 +107,10 @@ impl<P, D> NotInitializedTask<P, D> {
     /// Create a new relayer task.
     fn new(eth_node: P, database: D, config: Config) -> Self {
         let (synced, _) = watch::channel(None);
+        let (remote_height, _) = watch::channel(None);
         Self {
             synced,
+            remote_height,
             eth_node,
             database,
             config,
@@ -175,9 +185,11 @@ where
 
     fn shared_data(&self) -> Self::SharedData {
         let synced = self.synced.subscribe();
+        let remote_height = self.remote_height.subscribe();
 
         SharedState {
             synced,
+            remote_height,
             database: self.database.clone(),
         }
     }
@@ -190,12 +202,14 @@ where
         let shutdown = watcher.clone();
         let NotInitializedTask {
             synced,
+            remote_height,
             eth_node,
             database,
             config,
         } = self;
         let mut task = Task {
             synced,
+            remote_height,
             eth_node,
             database,
             config,
@@ -281,6 +295,12 @@ impl<D> SharedState<D> {
     {
         self.database.get_finalized_da_height().map_err(Into::into)
     }
+
+    /// Get the latest finalized height of the DA layer observed by the relayer.
+    /// Returns `None` if the relayer didn't receive it from the DA node yet.
+    pub fn get_remote_finalized_da_height(&self) -> Option<DaBlockHeight> {
+        *self.remote_height.borrow()
+    }
 }
 
 #[async_trait]
@@ -301,6 +321,7 @@ where
                     .and_then(|block| block.number)
                     .ok_or(anyhow::anyhow!("Block pending"))?
                     .as_u64();
+                self.remote_height.send_replace(Some(block_number.into()));
                 Ok(block_number)
             }
         }
@@ -21,6 +21,7 @@ use fuel_core_types::{
     },
 };
 use futures::{
+    future::Either,
     stream::StreamExt,
     FutureExt,
     Stream,
@@ -65,6 +66,10 @@ pub struct Config {
     pub block_stream_buffer_size: usize,
     /// The maximum number of headers to request in a single batch.
     pub header_batch_size: usize,
+    /// Request full blocks with a single request per batch, instead of
+    /// requesting headers and transactions separately.
+    /// Peers running the old version of the protocol don't serve full blocks.
+    pub fetch_full_blocks: bool,
 }
 
 impl Default for Config {
@@ -72,6 +77,7 @@ impl Default for Config {
         Self {
             block_stream_buffer_size: 10,
             header_batch_size: 100,
+            fetch_full_blocks: false,
         }
     }
 }
@@ -208,8 +214,22 @@ where
         let (shutdown_guard, mut shutdown_guard_recv) =
             tokio::sync::mpsc::channel::<()>(1);
 
-        let block_stream =
-            get_block_stream(range.clone(), params, p2p.clone(), consensus.clone());
+        let block_stream = if params.fetch_full_blocks {
+            Either::Left(
+                get_full_block_stream(
+                    range.clone(),
+                    params,
+                    p2p.clone(),
+                    consensus.clone(),
+                )
+                .map(Either::Left),
+            )
+        } else {
+            Either::Right(
+                get_block_stream(range.clone(), params, p2p.clone(), consensus.clone())
+                    .map(Either::Right),
+            )
+        };
         let result = block_stream
             .map(move |stream_block_batch| {
                 let shutdown_guard = shutdown_guard.clone();
@@ -352,6 +372,28 @@ fn get_block_stream<
         })
 }
 
+fn get_full_block_stream<
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+>(
+    range: RangeInclusive<u32>,
+    params: &Config,
+    p2p: Arc<P>,
+    consensus: Arc<C>,
+) -> impl Stream<Item = impl Future<Output = SealedBlockBatch>> {
+    let Config {
+        header_batch_size, ..
+    } = params;
+    let ranges = range_chunks(range, *header_batch_size);
+    futures::stream::iter(ranges).map(move |range| {
+        let consensus = consensus.clone();
+        let p2p = p2p.clone();
+        async move { get_sealed_blocks_batch(range, &p2p, &consensus).await }
+            .instrument(tracing::debug_span!("consensus_and_blocks"))
+            .in_current_span()
+    })
+}
+
 fn get_header_batch_stream<P: PeerToPeerPort + Send + Sync + 'static>(
     range: RangeInclusive<u32>,
     params: &Config,
@@ -498,6 +540,83 @@ where
     Batch::new(peer_id, range, headers)
 }
 
+/// Get full blocks of the range from a single peer and check them the same way
+/// as blocks assembled from headers and transactions.
+async fn get_sealed_blocks_batch<P, C>(
+    range: Range<u32>,
+    p2p: &Arc<P>,
+    consensus: &Arc<C>,
+) -> SealedBlockBatch
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    tracing::debug!(
+        "getting block range from {} to {} inclusive",
+        range.start,
+        range.end
+    );
+    let SourcePeer {
+        peer_id,
+        data: sealed_blocks,
+    } = p2p
+        .get_sealed_blocks(range.clone())
+        .await
+        .trace_err("Failed to get blocks")
+        .unwrap_or_default()
+        .map(|inner| inner.unwrap_or_default());
+
+    // The peer may send only the start of the range to fit into the response.
+    if sealed_blocks.is_empty() {
+        report_peer(p2p, peer_id.clone(), PeerReportReason::MissingBlockHeaders);
+        return Batch::new(peer_id, range, vec![])
+    }
+
+    let mut blocks = vec![];
+    for (sealed_block, expected_height) in sealed_blocks
+        .into_iter()
+        .zip(range.clone().map(BlockHeight::from))
+    {
+        let SealedBlock {
+            entity: block,
+            consensus: seal,
+        } = sealed_block;
+        let (header, transactions) = block.into_inner();
+        if *header.height() != expected_height {
+            report_peer(p2p, peer_id.clone(), PeerReportReason::MissingBlockHeaders);
+            break
+        }
+        let sealed_header = SealedBlockHeader {
+            entity: header,
+            consensus: seal,
+        };
+        if !check_sealed_header(&sealed_header, peer_id.clone(), p2p, consensus) {
+            break
+        }
+        let SealedBlockHeader {
+            entity: header,
+            consensus: seal,
+        } = sealed_header;
+        if let Some(block) = Block::try_from_executed(header, transactions) {
+            blocks.push(SealedBlock {
+                entity: block,
+                consensus: seal,
+            });
+        } else {
+            report_peer(p2p, peer_id.clone(), PeerReportReason::InvalidTransactions);
+            break
+        }
+    }
+
+    if let Some(last) = blocks.last() {
+        let _ = consensus
+            .await_da_height(&last.entity.header().da_height)
+            .await
+            .trace_err("Failed to wait for DA layer to sync");
+    }
+    Batch::new(peer_id, range, blocks)
+}
+
 fn report_peer<P>(p2p: &Arc<P>, peer_id: PeerId, reason: PeerReportReason)
 where
     P: PeerToPeerPort + Send + Sync + 'static,
@@ -24,6 +24,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        fetch_full_blocks: false,
     }
     => Count::default() ; "Empty sanity test"
 )]
@@ -36,6 +37,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        fetch_full_blocks: false,
     }
     => is less_or_equal_than Count{ headers: 1, consensus: 1, transactions: 1, executes: 1, blocks: 1 }
     ; "Single with slow headers"
@@ -49,6 +51,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow headers"
@@ -62,6 +65,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow transactions"
@@ -75,6 +79,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 with slow executes"
@@ -88,6 +93,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 size and max 10 requests"
@@ -8,11 +8,13 @@ mod pressure_peer_to_peer;
 
 use fuel_core_types::{
     blockchain::{
+        block::Block,
         consensus::{
             Consensus,
             Sealed,
         },
         header::BlockHeader,
+        SealedBlock,
         SealedBlockHeader,
     },
     fuel_types::BlockHeight,
@@ -55,3 +57,13 @@ pub fn empty_header<I: Into<BlockHeight>>(i: I) -> SealedBlockHeader {
         consensus,
     }
 }
+
+pub fn empty_block<I: Into<BlockHeight>>(i: I) -> SealedBlock {
+    let Sealed { entity, consensus } = empty_header(i);
+    let block = Block::try_from_executed(entity, vec![])
+        .expect("The transaction root matches empty transactions");
+    Sealed {
+        entity: block,
+        consensus,
+    }
+}
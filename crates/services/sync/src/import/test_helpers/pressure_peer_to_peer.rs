@@ -12,9 +12,17 @@ use crate::{
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
     fuel_types::BlockHeight,
     services::p2p::{
+        snapshot::{
+            SnapshotChunk,
+            SnapshotChunksRequest,
+            SnapshotManifest,
+        },
         PeerId,
         SourcePeer,
         Transactions,
@@ -50,6 +58,13 @@ impl PeerToPeerPort for PressurePeerToPeer {
         self.p2p.get_sealed_block_headers(block_height_range).await
     }
 
+    async fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlock>>>> {
+        self.p2p.get_sealed_blocks(block_height_range).await
+    }
+
     async fn get_transactions(
         &self,
         block_ids: SourcePeer<Range<u32>>,
@@ -63,6 +78,24 @@ impl PeerToPeerPort for PressurePeerToPeer {
         self.p2p.get_transactions(block_ids).await
     }
 
+    async fn get_peer_ids(&self) -> anyhow::Result<Vec<PeerId>> {
+        self.p2p.get_peer_ids().await
+    }
+
+    async fn get_snapshot_manifest(
+        &self,
+        peer: PeerId,
+    ) -> anyhow::Result<Option<SnapshotManifest>> {
+        self.p2p.get_snapshot_manifest(peer).await
+    }
+
+    async fn get_snapshot_chunks(
+        &self,
+        request: SourcePeer<SnapshotChunksRequest>,
+    ) -> anyhow::Result<Option<Vec<SnapshotChunk>>> {
+        self.p2p.get_snapshot_chunks(request).await
+    }
+
     fn report_peer(
         &self,
         _peer: PeerId,
@@ -3,6 +3,7 @@
 
 use crate::{
     import::test_helpers::{
+        empty_block,
         empty_header,
         random_peer,
     },
@@ -13,7 +14,10 @@ use crate::{
         PeerReportReason,
     },
 };
-use fuel_core_types::services::p2p::Transactions;
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    services::p2p::Transactions,
+};
 
 use super::*;
 
@@ -53,6 +57,7 @@ async fn test_import_0_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
     let mocks = Mocks {
         consensus_port,
@@ -99,6 +104,7 @@ async fn test_import_3_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
     let mocks = Mocks {
         consensus_port,
@@ -165,6 +171,7 @@ async fn test_import_0_to_499() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size,
+        fetch_full_blocks: false,
     };
     let mocks = Mocks {
         consensus_port,
@@ -217,6 +224,7 @@ async fn import__signature_fails_on_header_5_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -265,6 +273,7 @@ async fn import__signature_fails_on_header_4_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -296,6 +305,7 @@ async fn import__header_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -327,6 +337,7 @@ async fn import__header_response_incomplete() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -366,6 +377,7 @@ async fn import__header_5_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -398,6 +410,7 @@ async fn import__header_4_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -442,6 +455,7 @@ async fn import__transactions_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -496,6 +510,7 @@ async fn import__transactions_not_found_for_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -541,6 +556,7 @@ async fn import__transactions_not_found_for_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -568,6 +584,7 @@ async fn import__p2p_error() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -612,6 +629,7 @@ async fn import__p2p_error_on_4_transactions() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -660,6 +678,7 @@ async fn import__consensus_error_on_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -714,6 +733,7 @@ async fn import__consensus_error_on_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -774,6 +794,7 @@ async fn import__execution_error_on_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -834,6 +855,7 @@ async fn import__execution_error_on_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -862,6 +884,7 @@ async fn signature_always_fails() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -914,6 +937,7 @@ async fn import__can_work_in_two_loops() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
 
     // when
@@ -923,6 +947,106 @@ async fn import__can_work_in_two_loops() {
     assert_eq!((State::new(6, None), true), res);
 }
 
+#[tokio::test]
+async fn import__full_blocks_are_requested_with_a_single_request_per_batch() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks().times(1).returning(|range| {
+        let peer = random_peer();
+        let blocks = Some(range.map(empty_block).collect());
+        Ok(peer.bind(blocks))
+    });
+
+    let state = State::new(None, 5).into();
+    let mocks = Mocks {
+        p2p,
+        consensus_port: DefaultMocks::times([6, 1]),
+        executor: DefaultMocks::times([6]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        fetch_full_blocks: true,
+    };
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+#[tokio::test]
+async fn import__full_blocks_partial_response_imports_the_start_of_the_range() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks().times(1).returning(|range| {
+        let peer = random_peer();
+        let blocks = Some(range.take(2).map(empty_block).collect());
+        Ok(peer.bind(blocks))
+    });
+
+    let state = State::new(None, 5).into();
+    let mocks = Mocks {
+        p2p,
+        consensus_port: DefaultMocks::times([2, 1]),
+        executor: DefaultMocks::times([2]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        fetch_full_blocks: true,
+    };
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(1, None), false), res);
+}
+
+#[tokio::test]
+async fn import__full_blocks_with_invalid_transactions_are_not_imported() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks().times(1).returning(|range| {
+        let peer = random_peer();
+        let blocks = range
+            .map(empty_block)
+            .map(|mut block| {
+                block
+                    .entity
+                    .transactions_mut()
+                    .push(Transaction::default_test_tx());
+                block
+            })
+            .collect();
+        Ok(peer.bind(Some(blocks)))
+    });
+    p2p.expect_report_peer()
+        .times(1)
+        .withf(|_, reason| *reason == PeerReportReason::InvalidTransactions)
+        .returning(|_, _| Ok(()));
+
+    let state = State::new(3, 5).into();
+    let mocks = Mocks {
+        p2p,
+        consensus_port: DefaultMocks::times([1, 0]),
+        executor: DefaultMocks::times([0]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        fetch_full_blocks: true,
+    };
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(3, None), false), res);
+}
+
 async fn test_import_inner(
     state: SharedMutex<State>,
     mocks: Mocks,
@@ -1101,6 +1225,7 @@ impl PeerReportTestBuilder {
         let params = Config {
             block_stream_buffer_size: 10,
             header_batch_size: 10,
+            fetch_full_blocks: false,
         };
 
         let import = Import {
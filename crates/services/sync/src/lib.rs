@@ -7,8 +7,10 @@
 //! Responsible for syncing the blockchain from the network.
 
 pub mod import;
+pub mod light_sync;
 pub mod ports;
 pub mod service;
+pub mod snap_sync;
 pub mod state;
 pub mod sync;
 mod tracing_helpers;
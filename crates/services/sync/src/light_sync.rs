@@ -0,0 +1,246 @@
+//! # Light sync
+//! This module contains the sync of the light client that downloads only
+//! block headers instead of executing full blocks. Each header is verified
+//! by the consensus and should extend the previous header, so the light client
+//! follows the chain without the state.
+//!
+//! Transactions of the block are not stored. [`LightClient`] requests them
+//! from full peers on demand and verifies them against the transactions root
+//! of the stored header.
+
+use crate::{
+    ports::{
+        ConsensusPort,
+        HeaderImporterPort,
+        PeerReportReason,
+        PeerToPeerPort,
+    },
+    tracing_helpers::TraceErr,
+};
+use fuel_core_types::{
+    blockchain::header::BlockHeader,
+    fuel_tx::Transaction,
+    fuel_types::BlockHeight,
+    services::p2p::{
+        PeerId,
+        SourcePeer,
+    },
+};
+use std::{
+    ops::Range,
+    sync::Arc,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Copy, Debug)]
+/// Parameters for the light sync.
+pub struct Config {
+    /// The maximum number of headers to request in a single request.
+    pub header_batch_size: u32,
+    /// The number of peers to try for transactions of the block before giving up.
+    pub max_retries: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            header_batch_size: 100,
+            max_retries: 3,
+        }
+    }
+}
+
+/// Downloads and verifies block headers without executing blocks.
+pub struct LightSync<P, C> {
+    p2p: Arc<P>,
+    consensus: Arc<C>,
+    importer: Arc<dyn HeaderImporterPort + Send + Sync>,
+    config: Config,
+    /// The height of the latest stored header.
+    height: BlockHeight,
+}
+
+impl<P, C> LightSync<P, C>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    /// Creates a new light sync above the `current_height`.
+    pub fn new(
+        current_height: BlockHeight,
+        p2p: Arc<P>,
+        consensus: Arc<C>,
+        importer: Arc<dyn HeaderImporterPort + Send + Sync>,
+        config: Config,
+    ) -> Self {
+        Self {
+            p2p,
+            consensus,
+            importer,
+            config,
+            height: current_height,
+        }
+    }
+
+    /// Returns the height of the latest stored header.
+    pub fn height(&self) -> BlockHeight {
+        self.height
+    }
+
+    #[tracing::instrument(skip(self))]
+    /// Downloads headers up to the `target` height and returns the height
+    /// of the latest stored header. It stops at the first range of headers
+    /// that can't be downloaded or verified, and continues on the next call.
+    pub async fn sync(&mut self, target: BlockHeight) -> anyhow::Result<BlockHeight> {
+        let step = self.config.header_batch_size.max(1);
+        while self.height < target {
+            let Some(start) = self.height.succ().map(|height| *height) else {
+                break
+            };
+            let end = start.saturating_add(step).min((*target).saturating_add(1));
+            if !self.import_headers(start..end).await? {
+                break
+            }
+        }
+        Ok(self.height)
+    }
+
+    /// Requests the range of headers and stores them in order.
+    /// Returns `false` if the peer didn't send valid headers.
+    async fn import_headers(&mut self, range: Range<u32>) -> anyhow::Result<bool> {
+        let Ok(SourcePeer { peer_id, data }) = self
+            .p2p
+            .get_sealed_block_headers(range.clone())
+            .await
+            .trace_err("Failed to get headers")
+        else {
+            return Ok(false)
+        };
+        let Some(headers) = data.filter(|headers| !headers.is_empty()) else {
+            tracing::warn!("Peer {} didn't send headers {:?}", peer_id, range);
+            report_peer(
+                self.p2p.as_ref(),
+                &peer_id,
+                PeerReportReason::MissingBlockHeaders,
+            );
+            return Ok(false)
+        };
+
+        for header in headers {
+            let Some(height) = self.height.succ() else {
+                return Ok(false)
+            };
+            let result = if *header.entity.height() != height {
+                Err(anyhow::anyhow!(
+                    "The header doesn't follow the latest header"
+                ))
+            } else if !self.consensus.check_sealed_header(&header)? {
+                Err(anyhow::anyhow!(
+                    "The header doesn't pass the consensus check"
+                ))
+            } else {
+                self.importer.commit_header(header).await
+            };
+            if let Err(err) = result {
+                tracing::warn!(
+                    "Peer {} sent the invalid header at {}: {:?}",
+                    peer_id,
+                    height,
+                    err
+                );
+                report_peer(
+                    self.p2p.as_ref(),
+                    &peer_id,
+                    PeerReportReason::BadBlockHeader,
+                );
+                return Ok(false)
+            }
+            self.height = height;
+        }
+        Ok(true)
+    }
+}
+
+/// Fetches the data of blocks that the light client doesn't store from full peers.
+pub struct LightClient<P> {
+    p2p: Arc<P>,
+    config: Config,
+}
+
+impl<P> Clone for LightClient<P> {
+    fn clone(&self) -> Self {
+        Self {
+            p2p: self.p2p.clone(),
+            config: self.config,
+        }
+    }
+}
+
+impl<P> LightClient<P>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+{
+    /// Creates a new light client.
+    pub fn new(p2p: Arc<P>, config: Config) -> Self {
+        Self { p2p, config }
+    }
+
+    /// Requests transactions of the block from peers and verifies
+    /// them against the `header`.
+    pub async fn transactions(
+        &self,
+        header: &BlockHeader,
+    ) -> anyhow::Result<Vec<Transaction>> {
+        let height = **header.height();
+        let range = height..height.saturating_add(1);
+        let peers = self.p2p.get_peer_ids().await?;
+        for peer in peers.iter().take(self.config.max_retries.max(1)) {
+            match self.fetch(header, peer, range.clone()).await {
+                Ok(transactions) => return Ok(transactions),
+                Err(reason) => {
+                    tracing::warn!(
+                        "Failed to get transactions of the block at {} from {}: {:?}",
+                        height,
+                        peer,
+                        reason
+                    );
+                    report_peer(self.p2p.as_ref(), peer, reason);
+                }
+            }
+        }
+        Err(anyhow::anyhow!(
+            "Unable to get transactions of the block at {height} from peers"
+        ))
+    }
+
+    async fn fetch(
+        &self,
+        header: &BlockHeader,
+        peer: &PeerId,
+        range: Range<u32>,
+    ) -> Result<Vec<Transaction>, PeerReportReason> {
+        let transactions = self
+            .p2p
+            .get_transactions(peer.clone().bind(range))
+            .await
+            .trace_err("Failed to get transactions")
+            .ok()
+            .flatten()
+            .and_then(|transactions| transactions.into_iter().next())
+            .ok_or(PeerReportReason::MissingTransactions)?;
+
+        if header.validate_transactions(&transactions.0) {
+            Ok(transactions.0)
+        } else {
+            Err(PeerReportReason::InvalidTransactions)
+        }
+    }
+}
+
+fn report_peer<P: PeerToPeerPort>(p2p: &P, peer: &PeerId, reason: PeerReportReason) {
+    let _ = p2p
+        .report_peer(peer.clone(), reason)
+        .trace_err("Failed to report a peer");
+}
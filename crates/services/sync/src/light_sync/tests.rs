@@ -0,0 +1,232 @@
+#![allow(clippy::arithmetic_side_effects)]
+
+use super::*;
+use crate::{
+    import::test_helpers::empty_header,
+    ports::{
+        MockConsensusPort,
+        MockHeaderImporterPort,
+        MockPeerToPeerPort,
+    },
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        header::PartialBlockHeader,
+    },
+    services::p2p::Transactions,
+};
+use std::sync::Mutex;
+
+type Reports = Arc<Mutex<Vec<(PeerId, PeerReportReason)>>>;
+
+fn peer(i: u8) -> PeerId {
+    PeerId::from(vec![i])
+}
+
+fn config() -> Config {
+    Config {
+        header_batch_size: 2,
+        max_retries: 3,
+    }
+}
+
+fn p2p(reports: Reports) -> MockPeerToPeerPort {
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_report_peer().returning(move |peer, reason| {
+        reports.lock().unwrap().push((peer, reason));
+        Ok(())
+    });
+    p2p
+}
+
+fn consensus(invalid_height: Option<u32>) -> MockConsensusPort {
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(move |header| Ok(Some(**header.entity.height()) != invalid_height));
+    consensus
+}
+
+fn importer(committed: Arc<Mutex<Vec<u32>>>) -> MockHeaderImporterPort {
+    let mut importer = MockHeaderImporterPort::default();
+    importer.expect_commit_header().returning(move |header| {
+        committed.lock().unwrap().push(**header.entity.height());
+        Ok(())
+    });
+    importer
+}
+
+#[tokio::test]
+async fn headers_are_verified_and_committed_in_batches() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let mut p2p = p2p(reports.clone());
+    p2p.expect_get_sealed_block_headers()
+        .times(3)
+        .returning(|range| Ok(peer(1).bind(Some(range.map(empty_header).collect()))));
+    let committed = Arc::new(Mutex::new(vec![]));
+    let mut light_sync = LightSync::new(
+        0u32.into(),
+        Arc::new(p2p),
+        Arc::new(consensus(None)),
+        Arc::new(importer(committed.clone())),
+        config(),
+    );
+
+    // When
+    let height = light_sync.sync(5u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(height, 5u32.into());
+    assert_eq!(*committed.lock().unwrap(), vec![1, 2, 3, 4, 5]);
+    assert!(reports.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn header_failing_consensus_check_is_not_committed() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let mut p2p = p2p(reports.clone());
+    p2p.expect_get_sealed_block_headers()
+        .returning(|range| Ok(peer(1).bind(Some(range.map(empty_header).collect()))));
+    let committed = Arc::new(Mutex::new(vec![]));
+    let mut light_sync = LightSync::new(
+        0u32.into(),
+        Arc::new(p2p),
+        Arc::new(consensus(Some(3))),
+        Arc::new(importer(committed.clone())),
+        config(),
+    );
+
+    // When
+    let height = light_sync.sync(5u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(height, 2u32.into());
+    assert_eq!(*committed.lock().unwrap(), vec![1, 2]);
+    assert_eq!(
+        *reports.lock().unwrap(),
+        vec![(peer(1), PeerReportReason::BadBlockHeader)]
+    );
+}
+
+#[tokio::test]
+async fn header_that_does_not_extend_the_chain_is_not_committed() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let mut p2p = p2p(reports.clone());
+    p2p.expect_get_sealed_block_headers()
+        .returning(|range| Ok(peer(1).bind(Some(range.map(empty_header).collect()))));
+    let mut importer = MockHeaderImporterPort::default();
+    importer
+        .expect_commit_header()
+        .returning(|_| Err(anyhow::anyhow!("The previous root mismatch")));
+    let mut light_sync = LightSync::new(
+        0u32.into(),
+        Arc::new(p2p),
+        Arc::new(consensus(None)),
+        Arc::new(importer),
+        config(),
+    );
+
+    // When
+    let height = light_sync.sync(5u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(height, 0u32.into());
+    assert_eq!(
+        *reports.lock().unwrap(),
+        vec![(peer(1), PeerReportReason::BadBlockHeader)]
+    );
+}
+
+#[tokio::test]
+async fn missing_headers_are_reported() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let mut p2p = p2p(reports.clone());
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .returning(|_| Ok(peer(1).bind(None)));
+    let mut light_sync = LightSync::new(
+        0u32.into(),
+        Arc::new(p2p),
+        Arc::new(consensus(None)),
+        Arc::new(MockHeaderImporterPort::default()),
+        config(),
+    );
+
+    // When
+    let height = light_sync.sync(5u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(height, 0u32.into());
+    assert_eq!(
+        *reports.lock().unwrap(),
+        vec![(peer(1), PeerReportReason::MissingBlockHeaders)]
+    );
+}
+
+#[tokio::test]
+async fn transactions_are_verified_against_the_header() {
+    // Given
+    let transactions = vec![
+        Transaction::default_test_tx(),
+        Transaction::default_test_tx(),
+    ];
+    let block = Block::new(PartialBlockHeader::default(), transactions.clone(), &[]);
+    let reports = Arc::new(Mutex::new(vec![]));
+    let mut p2p = p2p(reports.clone());
+    p2p.expect_get_peer_ids()
+        .returning(|| Ok(vec![peer(1), peer(2), peer(3)]));
+    let valid_transactions = transactions.clone();
+    p2p.expect_get_transactions().returning(move |request| {
+        let transactions = match request.peer_id.as_ref() {
+            [1] => None,
+            [2] => Some(vec![Transactions(vec![])]),
+            _ => Some(vec![Transactions(valid_transactions.clone())]),
+        };
+        Ok(transactions)
+    });
+    let light_client = LightClient::new(Arc::new(p2p), config());
+
+    // When
+    let fetched = light_client.transactions(block.header()).await.unwrap();
+
+    // Then
+    assert_eq!(fetched, transactions);
+    assert_eq!(
+        *reports.lock().unwrap(),
+        vec![
+            (peer(1), PeerReportReason::MissingTransactions),
+            (peer(2), PeerReportReason::InvalidTransactions),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn fetching_transactions_fails_without_valid_peers() {
+    // Given
+    let block = Block::new(
+        PartialBlockHeader::default(),
+        vec![Transaction::default_test_tx()],
+        &[],
+    );
+    let reports = Arc::new(Mutex::new(vec![]));
+    let mut p2p = p2p(reports.clone());
+    p2p.expect_get_peer_ids().returning(|| Ok(vec![peer(1)]));
+    p2p.expect_get_transactions()
+        .returning(|_| Ok(Some(vec![Transactions(vec![])])));
+    let light_client = LightClient::new(Arc::new(p2p), config());
+
+    // When
+    let result = light_client.transactions(block.header()).await;
+
+    // Then
+    assert!(result.is_err());
+    assert_eq!(
+        *reports.lock().unwrap(),
+        vec![(peer(1), PeerReportReason::InvalidTransactions)]
+    );
+}
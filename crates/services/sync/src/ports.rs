@@ -9,6 +9,11 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
     services::p2p::{
+        snapshot::{
+            SnapshotChunk,
+            SnapshotChunksRequest,
+            SnapshotManifest,
+        },
         PeerId,
         SourcePeer,
         Transactions,
@@ -32,6 +37,10 @@ pub enum PeerReportReason {
     MissingTransactions,
     /// Received invalid transactions
     InvalidTransactions,
+    /// Did not receive requested snapshot chunks
+    MissingSnapshotChunks,
+    /// Received the snapshot manifest or chunk that doesn't pass verification
+    InvalidSnapshotData,
 }
 
 #[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
@@ -47,6 +56,14 @@ pub trait PeerToPeerPort {
         block_height_range: Range<u32>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>>;
 
+    /// Request a range of full sealed blocks from the network.
+    /// The peer may respond with fewer blocks from the start of the range
+    /// if all of them don't fit into a single response.
+    async fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlock>>>>;
+
     /// Request transactions from the network for the given block
     /// and source peer.
     async fn get_transactions(
@@ -54,6 +71,21 @@ pub trait PeerToPeerPort {
         block_ids: SourcePeer<Range<u32>>,
     ) -> anyhow::Result<Option<Vec<Transactions>>>;
 
+    /// Request the ids of all connected peers.
+    async fn get_peer_ids(&self) -> anyhow::Result<Vec<PeerId>>;
+
+    /// Request the manifest of the latest snapshot from the peer.
+    async fn get_snapshot_manifest(
+        &self,
+        peer: PeerId,
+    ) -> anyhow::Result<Option<SnapshotManifest>>;
+
+    /// Request a range of snapshot chunks from the source peer.
+    async fn get_snapshot_chunks(
+        &self,
+        request: SourcePeer<SnapshotChunksRequest>,
+    ) -> anyhow::Result<Option<Vec<SnapshotChunk>>>;
+
     /// Report a peer for some reason to modify their reputation.
     fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()>;
 }
@@ -79,3 +111,26 @@ pub trait BlockImporterPort {
     /// and commit it to the database.
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
 }
+
+#[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
+/// Port for importing the snapshot of the state downloaded by the snap-sync.
+pub trait SnapshotImporterPort {
+    /// Removes the local state that is replaced by the snapshot.
+    fn prepare(&self) -> anyhow::Result<()>;
+
+    /// Stores entries of the verified chunk.
+    fn import_chunk(&self, chunk: SnapshotChunk) -> anyhow::Result<()>;
+
+    /// Verifies that all chunks are imported and moves the local chain
+    /// to the height of the snapshot.
+    fn finalize(&self, manifest: &SnapshotManifest) -> anyhow::Result<()>;
+}
+
+#[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
+#[async_trait::async_trait]
+/// Port for importing block headers downloaded by the light client.
+pub trait HeaderImporterPort {
+    /// Verifies that the header extends the local chain and imports
+    /// the block without transactions.
+    async fn commit_header(&self, header: SealedBlockHeader) -> anyhow::Result<()>;
+}
@@ -6,11 +6,22 @@ use crate::{
         Config,
         Import,
     },
+    light_sync::{
+        self,
+        LightClient,
+        LightSync,
+    },
     ports::{
         self,
         BlockImporterPort,
         ConsensusPort,
+        HeaderImporterPort,
         PeerToPeerPort,
+        SnapshotImporterPort,
+    },
+    snap_sync::{
+        self,
+        SnapSync,
     },
     state::State,
     sync::SyncHeights,
@@ -59,9 +70,76 @@ where
         p2p,
         executor,
         consensus,
+        None,
     )?))
 }
 
+/// Creates an instance of runnable sync service that downloads the snapshot
+/// of the state above the `current_fuel_block_height` before the block import.
+pub fn new_service_with_snap_sync<P, E, C>(
+    current_fuel_block_height: BlockHeight,
+    p2p: P,
+    executor: E,
+    consensus: C,
+    snapshot_importer: Arc<dyn SnapshotImporterPort + Send + Sync>,
+    snap_sync_params: snap_sync::Config,
+    params: Config,
+) -> anyhow::Result<ServiceRunner<SyncTask<P, E, C>>>
+where
+    P: ports::PeerToPeerPort + Send + Sync + 'static,
+    E: ports::BlockImporterPort + Send + Sync + 'static,
+    C: ports::ConsensusPort + Send + Sync + 'static,
+{
+    let height_stream = p2p.height_stream();
+    let committed_height_stream = executor.committed_height_stream();
+    let state = State::new(Some(current_fuel_block_height.into()), None);
+    Ok(ServiceRunner::new(SyncTask::new(
+        height_stream,
+        committed_height_stream,
+        state,
+        params,
+        p2p,
+        executor,
+        consensus,
+        Some((
+            snapshot_importer,
+            snap_sync_params,
+            current_fuel_block_height,
+        )),
+    )?))
+}
+
+/// Creates an instance of runnable sync service of the light client
+/// that downloads only block headers above the `current_fuel_block_height`.
+/// The shared data of the service fetches transactions on demand.
+pub fn new_light_service<P, C>(
+    current_fuel_block_height: BlockHeight,
+    p2p: P,
+    consensus: C,
+    header_importer: Arc<dyn HeaderImporterPort + Send + Sync>,
+    params: light_sync::Config,
+) -> anyhow::Result<ServiceRunner<LightSyncTask<P, C>>>
+where
+    P: ports::PeerToPeerPort + Send + Sync + 'static,
+    C: ports::ConsensusPort + Send + Sync + 'static,
+{
+    let height_stream = p2p.height_stream();
+    let p2p = Arc::new(p2p);
+    let light_sync = LightSync::new(
+        current_fuel_block_height,
+        p2p.clone(),
+        Arc::new(consensus),
+        header_importer,
+        params,
+    );
+    let light_client = LightClient::new(p2p, params);
+    Ok(ServiceRunner::new(LightSyncTask {
+        height_stream,
+        light_sync,
+        light_client,
+    }))
+}
+
 /// Task for syncing heights.
 /// Contains import task as a child task.
 pub struct SyncTask<P, E, C>
@@ -72,6 +150,10 @@ where
 {
     sync_heights: SyncHeights,
     import_task_handle: ServiceRunner<ImportTask<P, E, C>>,
+    state: SharedMutex<State>,
+    /// The snap-sync performed once before the block import
+    /// and the height above which the snapshot is requested.
+    snap_sync: Option<(SnapSync<P, C>, BlockHeight)>,
 }
 
 struct ImportTask<P, E, C>(Import<P, E, C>);
@@ -82,6 +164,7 @@ where
     E: BlockImporterPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         height_stream: BoxStream<BlockHeight>,
         committed_height_stream: BoxStream<BlockHeight>,
@@ -90,6 +173,11 @@ where
         p2p: P,
         executor: E,
         consensus: C,
+        snap_sync: Option<(
+            Arc<dyn SnapshotImporterPort + Send + Sync>,
+            snap_sync::Config,
+            BlockHeight,
+        )>,
     ) -> anyhow::Result<Self> {
         let notify = Arc::new(Notify::new());
         let state = SharedMutex::new(state);
@@ -102,11 +190,18 @@ where
             state.clone(),
             notify.clone(),
         );
-        let import = Import::new(state, notify, params, p2p, executor, consensus);
+        let snap_sync = snap_sync.map(|(importer, config, current_height)| {
+            let snap_sync =
+                SnapSync::new(p2p.clone(), consensus.clone(), importer, config);
+            (snap_sync, current_height)
+        });
+        let import = Import::new(state.clone(), notify, params, p2p, executor, consensus);
         let import_task_handle = ServiceRunner::new(ImportTask(import));
         Ok(Self {
             sync_heights,
             import_task_handle,
+            state,
+            snap_sync,
         })
     }
 }
@@ -151,6 +246,11 @@ where
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         let mut sync_watcher = watcher.clone();
+        if let Some((snap_sync, current_height)) = self.snap_sync.take() {
+            if let Some(height) = snap_sync.run(current_height).await? {
+                self.state.apply(|state| state.commit(*height));
+            }
+        }
         self.import_task_handle.start_and_await().await?;
         let mut import_watcher = self.import_task_handle.state_watcher();
         self.sync_heights.map_stream(|height_stream| {
@@ -211,3 +311,64 @@ where
         Ok(self)
     }
 }
+
+/// Task for syncing block headers by the light client.
+pub struct LightSyncTask<P, C> {
+    height_stream: BoxStream<BlockHeight>,
+    light_sync: LightSync<P, C>,
+    light_client: LightClient<P>,
+}
+
+#[async_trait::async_trait]
+impl<P, C> RunnableTask for LightSyncTask<P, C>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    #[tracing::instrument(level = "debug", skip_all, err, ret)]
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            _ = watcher.while_started() => Ok(false),
+            height = self.height_stream.next() => {
+                let Some(height) = height else {
+                    return Ok(false)
+                };
+                if height > self.light_sync.height() {
+                    self.light_sync.sync(height).await?;
+                }
+                Ok(true)
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Headers are committed one by one, so there is nothing to dump.
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<P, C> RunnableService for LightSyncTask<P, C>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    const NAME: &'static str = "LightSyncTask";
+
+    type SharedData = LightClient<P>;
+    type TaskParams = ();
+
+    type Task = LightSyncTask<P, C>;
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.light_client.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
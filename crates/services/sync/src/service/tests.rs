@@ -66,6 +66,7 @@ async fn test_new_service() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        fetch_full_blocks: false,
     };
     let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
 
@@ -0,0 +1,326 @@
+//! # Snap-sync
+//! This module contains the snap-sync which downloads the state of the
+//! blockchain at a recent height from peers instead of executing all blocks
+//! since the genesis. After the snapshot is imported, the normal block import
+//! continues from the height of the snapshot.
+//!
+//! The block header doesn't commit to the state, so the operator of the node
+//! provides the [`TrustedSnapshot`] with the height and the root of the snapshot,
+//! and snapshots announced by peers with another root are ignored. The header
+//! of the snapshot should pass the consensus check. Each downloaded chunk is
+//! verified with the merkle proof against the trusted root, and peers that send
+//! invalid data are reported.
+//!
+//! The local state is cleared before the import of chunks, so if the snapshot
+//! can't be downloaded after that, the snap-sync fails with an error instead of
+//! falling back to the block import.
+
+use crate::{
+    ports::{
+        ConsensusPort,
+        PeerReportReason,
+        PeerToPeerPort,
+        SnapshotImporterPort,
+    },
+    tracing_helpers::TraceErr,
+};
+use fuel_core_types::{
+    blockchain::{
+        consensus::Consensus,
+        primitives::BlockId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
+    services::p2p::{
+        snapshot::{
+            SnapshotChunk,
+            SnapshotChunksRequest,
+            SnapshotManifest,
+        },
+        PeerId,
+    },
+};
+use futures::stream::StreamExt;
+use std::{
+    collections::HashMap,
+    ops::Range,
+    sync::Arc,
+    time::Duration,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The snapshot that the operator of the node trusts.
+pub struct TrustedSnapshot {
+    /// The height of the block at which the snapshot is taken.
+    pub height: BlockHeight,
+    /// The merkle root of chunks of the snapshot.
+    pub root: Bytes32,
+}
+
+#[derive(Clone, Copy, Debug)]
+/// Parameters for the snap-sync.
+pub struct Config {
+    /// The only snapshot that can be imported.
+    pub trusted_snapshot: TrustedSnapshot,
+    /// The maximum number of chunks to request in a single request.
+    pub chunks_per_request: u32,
+    /// The maximum number of requests in flight.
+    pub max_concurrent_requests: usize,
+    /// The number of peers to try for each range of chunks before giving up.
+    pub max_retries: usize,
+    /// The number of attempts to find peers with the trusted snapshot before
+    /// falling back to the block import from the current height.
+    pub max_attempts: usize,
+    /// The interval between attempts to find the snapshot.
+    pub retry_interval: Duration,
+}
+
+impl Config {
+    /// Creates the config for the `trusted_snapshot` with default parameters.
+    pub fn new(trusted_snapshot: TrustedSnapshot) -> Self {
+        Self {
+            trusted_snapshot,
+            chunks_per_request: 16,
+            max_concurrent_requests: 4,
+            max_retries: 3,
+            max_attempts: 10,
+            retry_interval: Duration::from_secs(3),
+        }
+    }
+}
+
+/// The trusted snapshot and peers that announced it.
+struct Candidate {
+    manifest: SnapshotManifest,
+    peers: Vec<PeerId>,
+}
+
+/// Downloads, verifies, and imports the snapshot of the state.
+pub struct SnapSync<P, C> {
+    p2p: Arc<P>,
+    consensus: Arc<C>,
+    importer: Arc<dyn SnapshotImporterPort + Send + Sync>,
+    config: Config,
+}
+
+impl<P, C> SnapSync<P, C>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    /// Creates a new snap-sync.
+    pub fn new(
+        p2p: Arc<P>,
+        consensus: Arc<C>,
+        importer: Arc<dyn SnapshotImporterPort + Send + Sync>,
+        config: Config,
+    ) -> Self {
+        Self {
+            p2p,
+            consensus,
+            importer,
+            config,
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    /// Imports the trusted snapshot above the `current_height` and returns its height.
+    /// Returns `None` if no peers announced the trusted snapshot after all attempts.
+    /// Returns an error if the download of the snapshot failed after
+    /// the local state was cleared.
+    pub async fn run(
+        &self,
+        current_height: BlockHeight,
+    ) -> anyhow::Result<Option<BlockHeight>> {
+        for attempt in 0..self.config.max_attempts {
+            if attempt > 0 {
+                tokio::time::sleep(self.config.retry_interval).await;
+            }
+
+            let Some(candidate) = self.find_snapshot(current_height).await? else {
+                tracing::info!("No peers have the trusted snapshot, attempt {}", attempt);
+                continue
+            };
+
+            let height = candidate.manifest.height();
+            tracing::info!(
+                "Downloading the snapshot at height {} with {} chunks",
+                height,
+                candidate.manifest.chunks_count
+            );
+            self.download(&candidate).await?;
+            tracing::info!("The snapshot at height {} is imported", height);
+            return Ok(Some(height))
+        }
+
+        tracing::warn!("Unable to snap-sync, falling back to the block import");
+        Ok(None)
+    }
+
+    /// Requests manifests from all connected peers and selects the trusted
+    /// snapshot announced by the most peers.
+    async fn find_snapshot(
+        &self,
+        current_height: BlockHeight,
+    ) -> anyhow::Result<Option<Candidate>> {
+        let peers = self.p2p.get_peer_ids().await?;
+        let manifests = futures::future::join_all(peers.into_iter().map(|peer| {
+            let p2p = self.p2p.clone();
+            async move {
+                let manifest = p2p
+                    .get_snapshot_manifest(peer.clone())
+                    .await
+                    .trace_err("Failed to get the snapshot manifest")
+                    .ok()
+                    .flatten();
+                (peer, manifest)
+            }
+        }))
+        .await;
+
+        let trusted = self.config.trusted_snapshot;
+        let mut candidates = HashMap::<(BlockId, u32), Candidate>::new();
+        for (peer, manifest) in manifests {
+            let Some(manifest) = manifest else { continue };
+
+            if manifest.height() <= current_height
+                || manifest.height() != trusted.height
+                || manifest.root != trusted.root
+            {
+                continue
+            }
+
+            if !self.check_manifest(&manifest)? {
+                self.report_peer(&peer, PeerReportReason::InvalidSnapshotData);
+                continue
+            }
+
+            let key = (manifest.header.entity.id(), manifest.chunks_count);
+            candidates
+                .entry(key)
+                .or_insert_with(|| Candidate {
+                    manifest,
+                    peers: vec![],
+                })
+                .peers
+                .push(peer);
+        }
+
+        Ok(candidates
+            .into_values()
+            .max_by_key(|candidate| candidate.peers.len()))
+    }
+
+    fn check_manifest(&self, manifest: &SnapshotManifest) -> anyhow::Result<bool> {
+        // The snapshot can't be taken at the genesis block,
+        // because the local node already has it.
+        if matches!(manifest.header.consensus, Consensus::Genesis(_)) {
+            return Ok(false)
+        }
+        self.consensus.check_sealed_header(&manifest.header)
+    }
+
+    /// Downloads all chunks of the snapshot and imports them in order.
+    /// Returns an error if some chunks can't be downloaded from any peer,
+    /// because the local state is already cleared at this point.
+    async fn download(&self, candidate: &Candidate) -> anyhow::Result<()> {
+        let manifest = &candidate.manifest;
+        let step = self.config.chunks_per_request.max(1);
+        let ranges = (0..manifest.chunks_count)
+            .step_by(step as usize)
+            .map(|start| start..start.saturating_add(step).min(manifest.chunks_count));
+
+        self.importer.prepare()?;
+
+        let mut chunks_stream = futures::stream::iter(ranges.enumerate())
+            .map(|(index, range)| self.fetch_with_retries(candidate, index, range))
+            .buffered(self.config.max_concurrent_requests.max(1));
+
+        while let Some(chunks) = chunks_stream.next().await {
+            let Some(chunks) = chunks else {
+                return Err(anyhow::anyhow!(
+                    "Unable to download the snapshot at {} after the local state \
+                    was cleared, restart the node to retry the snap-sync",
+                    manifest.height()
+                ))
+            };
+            for chunk in chunks {
+                self.importer.import_chunk(chunk)?;
+            }
+        }
+
+        self.importer.finalize(manifest)?;
+        Ok(())
+    }
+
+    /// Requests the range of chunks from peers that announced the snapshot.
+    /// Each range starts from a different peer to distribute the load.
+    async fn fetch_with_retries(
+        &self,
+        candidate: &Candidate,
+        index: usize,
+        range: Range<u32>,
+    ) -> Option<Vec<SnapshotChunk>> {
+        let peers = &candidate.peers;
+        for retry in 0..self.config.max_retries.max(1) {
+            let peer = peers.get(index.wrapping_add(retry).checked_rem(peers.len())?)?;
+            match self.fetch(&candidate.manifest, peer, range.clone()).await {
+                Ok(chunks) => return Some(chunks),
+                Err(reason) => {
+                    tracing::warn!(
+                        "Failed to get snapshot chunks {:?} from {}: {:?}",
+                        range,
+                        peer,
+                        reason
+                    );
+                    self.report_peer(peer, reason);
+                }
+            }
+        }
+        None
+    }
+
+    async fn fetch(
+        &self,
+        manifest: &SnapshotManifest,
+        peer: &PeerId,
+        range: Range<u32>,
+    ) -> Result<Vec<SnapshotChunk>, PeerReportReason> {
+        let request = SnapshotChunksRequest {
+            height: *manifest.height(),
+            range: range.clone(),
+        };
+        let chunks = self
+            .p2p
+            .get_snapshot_chunks(peer.clone().bind(request))
+            .await
+            .trace_err("Failed to get snapshot chunks")
+            .ok()
+            .flatten()
+            .ok_or(PeerReportReason::MissingSnapshotChunks)?;
+
+        let is_valid = chunks.len() == range.len()
+            && chunks
+                .iter()
+                .zip(range)
+                .all(|(chunk, index)| chunk.index == index && chunk.verify(manifest));
+
+        if is_valid {
+            Ok(chunks)
+        } else {
+            Err(PeerReportReason::InvalidSnapshotData)
+        }
+    }
+
+    fn report_peer(&self, peer: &PeerId, reason: PeerReportReason) {
+        let _ = self
+            .p2p
+            .report_peer(peer.clone(), reason)
+            .trace_err("Failed to report a peer");
+    }
+}
@@ -0,0 +1,326 @@
+#![allow(clippy::arithmetic_side_effects)]
+#![allow(clippy::cast_possible_truncation)]
+
+use super::*;
+use crate::{
+    import::test_helpers::empty_header,
+    ports::{
+        MockConsensusPort,
+        MockPeerToPeerPort,
+        MockSnapshotImporterPort,
+    },
+};
+use fuel_core_types::fuel_merkle::binary::in_memory::MerkleTree;
+use std::sync::Mutex;
+
+const HEIGHT: u32 = 10;
+const CHUNKS: u32 = 5;
+
+fn peer(i: u8) -> PeerId {
+    PeerId::from(vec![i])
+}
+
+/// Creates the manifest and all chunks with valid proofs.
+fn snapshot() -> (SnapshotManifest, Vec<SnapshotChunk>) {
+    let mut chunks: Vec<_> = (0..CHUNKS)
+        .map(|index| SnapshotChunk {
+            index,
+            column: 5,
+            entries: vec![(vec![index as u8; 34], vec![1, 2, 3])],
+            proof: vec![],
+        })
+        .collect();
+    let mut tree = MerkleTree::new();
+    for chunk in &chunks {
+        tree.push(chunk.hash().as_ref());
+    }
+    for chunk in &mut chunks {
+        let (_, proof) = tree.prove(chunk.index as u64).unwrap();
+        chunk.proof = proof;
+    }
+    let manifest = SnapshotManifest {
+        header: empty_header(HEIGHT),
+        root: tree.root().into(),
+        chunks_count: CHUNKS,
+    };
+    (manifest, chunks)
+}
+
+fn trusted_snapshot() -> TrustedSnapshot {
+    let (manifest, _) = snapshot();
+    TrustedSnapshot {
+        height: manifest.height(),
+        root: manifest.root,
+    }
+}
+
+fn config() -> Config {
+    Config {
+        trusted_snapshot: trusted_snapshot(),
+        chunks_per_request: 2,
+        max_concurrent_requests: 2,
+        max_retries: 3,
+        max_attempts: 1,
+        retry_interval: Duration::ZERO,
+    }
+}
+
+fn p2p(
+    peers: Vec<PeerId>,
+    corrupted_peer: Option<PeerId>,
+    reports: Arc<Mutex<Vec<(PeerId, PeerReportReason)>>>,
+) -> MockPeerToPeerPort {
+    let (manifest, chunks) = snapshot();
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_peer_ids()
+        .returning(move || Ok(peers.clone()));
+    p2p.expect_get_snapshot_manifest()
+        .returning(move |_| Ok(Some(manifest.clone())));
+    p2p.expect_get_snapshot_chunks().returning(move |request| {
+        let mut response: Vec<_> = chunks
+            [request.data.range.start as usize..request.data.range.end as usize]
+            .to_vec();
+        if Some(&request.peer_id) == corrupted_peer.as_ref() {
+            response[0].entries[0].1 = vec![4, 5, 6];
+        }
+        Ok(Some(response))
+    });
+    p2p.expect_report_peer().returning(move |peer, reason| {
+        reports.lock().unwrap().push((peer, reason));
+        Ok(())
+    });
+    p2p
+}
+
+fn consensus() -> MockConsensusPort {
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus
+}
+
+#[tokio::test]
+async fn snapshot_is_downloaded_verified_and_imported() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let p2p = p2p(vec![peer(1), peer(2), peer(3)], None, reports.clone());
+    let imported = Arc::new(Mutex::new(vec![]));
+    let mut importer = MockSnapshotImporterPort::default();
+    importer.expect_prepare().times(1).returning(|| Ok(()));
+    let imported_chunks = imported.clone();
+    importer.expect_import_chunk().returning(move |chunk| {
+        imported_chunks.lock().unwrap().push(chunk.index);
+        Ok(())
+    });
+    importer.expect_finalize().times(1).returning(|manifest| {
+        assert_eq!(*manifest.height(), HEIGHT);
+        Ok(())
+    });
+    let snap_sync = SnapSync::new(
+        Arc::new(p2p),
+        Arc::new(consensus()),
+        Arc::new(importer),
+        config(),
+    );
+
+    // When
+    let result = snap_sync.run(0u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(result, Some(HEIGHT.into()));
+    assert_eq!(*imported.lock().unwrap(), (0..CHUNKS).collect::<Vec<_>>());
+    assert!(reports.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn invalid_chunks_are_reported_and_requested_from_another_peer() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let p2p = p2p(vec![peer(1), peer(2)], Some(peer(1)), reports.clone());
+    let mut importer = MockSnapshotImporterPort::default();
+    importer.expect_prepare().returning(|| Ok(()));
+    importer
+        .expect_import_chunk()
+        .times(CHUNKS as usize)
+        .returning(|chunk| {
+            assert_eq!(chunk.entries[0].1, vec![1, 2, 3]);
+            Ok(())
+        });
+    importer.expect_finalize().times(1).returning(|_| Ok(()));
+    let snap_sync = SnapSync::new(
+        Arc::new(p2p),
+        Arc::new(consensus()),
+        Arc::new(importer),
+        config(),
+    );
+
+    // When
+    let result = snap_sync.run(0u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(result, Some(HEIGHT.into()));
+    let reports = reports.lock().unwrap();
+    assert!(!reports.is_empty());
+    assert!(reports
+        .iter()
+        .all(|report| *report == (peer(1), PeerReportReason::InvalidSnapshotData)));
+}
+
+#[tokio::test]
+async fn snapshot_from_single_peer_is_imported() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let p2p = p2p(vec![peer(1)], None, reports);
+    let mut importer = MockSnapshotImporterPort::default();
+    importer.expect_prepare().times(1).returning(|| Ok(()));
+    importer
+        .expect_import_chunk()
+        .times(CHUNKS as usize)
+        .returning(|_| Ok(()));
+    importer.expect_finalize().times(1).returning(|_| Ok(()));
+    let snap_sync = SnapSync::new(
+        Arc::new(p2p),
+        Arc::new(consensus()),
+        Arc::new(importer),
+        config(),
+    );
+
+    // When
+    let result = snap_sync.run(0u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(result, Some(HEIGHT.into()));
+}
+
+#[tokio::test]
+async fn snapshot_with_untrusted_root_is_not_imported() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let p2p = p2p(vec![peer(1), peer(2), peer(3)], None, reports.clone());
+    let mut importer = MockSnapshotImporterPort::default();
+    importer.expect_prepare().never();
+    let config = Config {
+        trusted_snapshot: TrustedSnapshot {
+            root: Bytes32::from([1; 32]),
+            ..trusted_snapshot()
+        },
+        ..config()
+    };
+    let snap_sync = SnapSync::new(
+        Arc::new(p2p),
+        Arc::new(consensus()),
+        Arc::new(importer),
+        config,
+    );
+
+    // When
+    let result = snap_sync.run(0u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(result, None);
+    assert!(reports.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn snapshot_at_untrusted_height_is_not_imported() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let p2p = p2p(vec![peer(1), peer(2)], None, reports);
+    let mut importer = MockSnapshotImporterPort::default();
+    importer.expect_prepare().never();
+    let config = Config {
+        trusted_snapshot: TrustedSnapshot {
+            height: (HEIGHT + 1).into(),
+            ..trusted_snapshot()
+        },
+        ..config()
+    };
+    let snap_sync = SnapSync::new(
+        Arc::new(p2p),
+        Arc::new(consensus()),
+        Arc::new(importer),
+        config,
+    );
+
+    // When
+    let result = snap_sync.run(0u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn snapshot_below_current_height_is_not_imported() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let p2p = p2p(vec![peer(1), peer(2)], None, reports);
+    let mut importer = MockSnapshotImporterPort::default();
+    importer.expect_prepare().never();
+    let snap_sync = SnapSync::new(
+        Arc::new(p2p),
+        Arc::new(consensus()),
+        Arc::new(importer),
+        config(),
+    );
+
+    // When
+    let result = snap_sync.run(HEIGHT.into()).await.unwrap();
+
+    // Then
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn manifest_with_invalid_header_is_reported() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let p2p = p2p(vec![peer(1), peer(2)], None, reports.clone());
+    let mut consensus = MockConsensusPort::default();
+    consensus
+        .expect_check_sealed_header()
+        .returning(|_| Ok(false));
+    let mut importer = MockSnapshotImporterPort::default();
+    importer.expect_prepare().never();
+    let snap_sync = SnapSync::new(
+        Arc::new(p2p),
+        Arc::new(consensus),
+        Arc::new(importer),
+        config(),
+    );
+
+    // When
+    let result = snap_sync.run(0u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(result, None);
+    assert_eq!(reports.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn failed_download_after_prepare_is_an_error() {
+    // Given
+    let reports = Arc::new(Mutex::new(vec![]));
+    let p2p = p2p(vec![peer(1)], Some(peer(1)), reports.clone());
+    let mut importer = MockSnapshotImporterPort::default();
+    importer.expect_prepare().times(1).returning(|| Ok(()));
+    importer.expect_import_chunk().returning(|_| Ok(()));
+    importer.expect_finalize().never();
+    let snap_sync = SnapSync::new(
+        Arc::new(p2p),
+        Arc::new(consensus()),
+        Arc::new(importer),
+        config(),
+    );
+
+    // When
+    let result = snap_sync.run(0u32.into()).await;
+
+    // Then
+    assert!(result.is_err());
+    assert!(reports
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|report| *report == (peer(1), PeerReportReason::InvalidSnapshotData)));
+}
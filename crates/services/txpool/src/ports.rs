@@ -7,6 +7,7 @@ use fuel_core_types::{
     },
     fuel_tx::{
         Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -19,11 +20,13 @@ use fuel_core_types::{
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
             NetworkData,
+            PeerId,
         },
     },
 };
 use std::sync::Arc;
 
+#[async_trait::async_trait]
 pub trait PeerToPeer: Send + Sync {
     type GossipedTransaction: NetworkData<Transaction>;
 
@@ -39,6 +42,20 @@ pub trait PeerToPeer: Send + Sync {
         message_info: GossipsubMessageInfo,
         validity: GossipsubMessageAcceptance,
     ) -> anyhow::Result<()>;
+
+    /// Creates a stream of peers with the newly established connections.
+    fn new_connected_peers(&self) -> BoxStream<PeerId>;
+
+    /// Requests the ids of the transactions from the pool of the peer.
+    async fn request_tx_ids(&self, peer_id: PeerId) -> anyhow::Result<Vec<TxId>>;
+
+    /// Requests transactions from the pool of the peer.
+    /// Returns only transactions known to the peer.
+    async fn request_transactions(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Transaction>>;
 }
 
 pub trait BlockImporter: Send + Sync {
@@ -38,6 +38,7 @@ use fuel_core_types::{
             GossipData,
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
+            PeerId,
             TransactionGossipData,
         },
         txpool::{
@@ -53,8 +54,13 @@ use fuel_core_types::{
 use anyhow::anyhow;
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::services::block_importer::SharedImportResult;
+use futures::future::{
+    BoxFuture,
+    OptionFuture,
+};
 use parking_lot::Mutex as ParkingMutex;
 use std::{
+    collections::VecDeque,
     sync::Arc,
     time::Duration,
 };
@@ -65,6 +71,13 @@ use tokio::{
 use tokio_stream::StreamExt;
 use update_sender::UpdateSender;
 
+/// The maximum number of connected peers waiting for the synchronization of the pools.
+/// New peers are skipped when the queue is full, the pool is filled by gossip anyway.
+const MAX_PEERS_TO_SYNC: usize = 32;
+/// The number of the recently synchronized peers that are not synchronized again
+/// when they reconnect.
+const MAX_SYNCED_PEERS: usize = 256;
+
 use self::update_sender::{
     MpscChannel,
     TxStatusStream,
@@ -145,15 +158,56 @@ impl<P2P, ViewProvider> Clone for SharedState<P2P, ViewProvider> {
 pub struct Task<P2P, ViewProvider> {
     gossiped_tx_stream: BoxStream<TransactionGossipData>,
     committed_block_stream: BoxStream<SharedImportResult>,
+    new_peers_stream: BoxStream<PeerId>,
+    /// The connected peers waiting for the synchronization of the pools.
+    peers_to_sync: VecDeque<PeerId>,
+    /// The recently synchronized peers, including the one in progress.
+    synced_peers: VecDeque<PeerId>,
+    /// The synchronization with the peer in progress. Peers are synchronized one by one,
+    /// so the same transactions are not requested from several peers at once.
+    pool_sync: Option<BoxFuture<'static, ()>>,
     shared: SharedState<P2P, ViewProvider>,
     ttl_timer: tokio::time::Interval,
 }
 
+impl<P2P, ViewProvider> Task<P2P, ViewProvider>
+where
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    ViewProvider: AtomicView + 'static,
+    ViewProvider::View: TxPoolDb,
+{
+    fn queue_sync_with_peer(&mut self, peer_id: PeerId) {
+        if self.peers_to_sync.len() >= MAX_PEERS_TO_SYNC
+            || self.peers_to_sync.contains(&peer_id)
+            || self.synced_peers.contains(&peer_id)
+        {
+            return
+        }
+
+        self.peers_to_sync.push_back(peer_id);
+    }
+
+    fn sync_with_next_peer(&mut self) {
+        if self.pool_sync.is_some() {
+            return
+        }
+
+        if let Some(peer_id) = self.peers_to_sync.pop_front() {
+            if self.synced_peers.len() >= MAX_SYNCED_PEERS {
+                self.synced_peers.pop_front();
+            }
+            self.synced_peers.push_back(peer_id.clone());
+            let shared = self.shared.clone();
+            self.pool_sync = Some(Box::pin(shared.sync_with_peer(peer_id)));
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl<P2P, ViewProvider, View> RunnableService for Task<P2P, ViewProvider>
 where
-    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
-    ViewProvider: AtomicView<View = View>,
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    ViewProvider: AtomicView<View = View> + 'static,
     View: TxPoolDb,
 {
     const NAME: &'static str = "TxPool";
@@ -179,8 +233,8 @@ where
 #[async_trait::async_trait]
 impl<P2P, ViewProvider, View> RunnableTask for Task<P2P, ViewProvider>
 where
-    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
-    ViewProvider: AtomicView<View = View>,
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    ViewProvider: AtomicView<View = View> + 'static,
     View: TxPoolDb,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
@@ -276,6 +330,22 @@ where
                     should_continue = false;
                 }
             }
+
+            peer_id = self.new_peers_stream.next() => {
+                if let Some(peer_id) = peer_id {
+                    self.queue_sync_with_peer(peer_id);
+                    self.sync_with_next_peer();
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+
+            _ = OptionFuture::from(self.pool_sync.as_mut()), if self.pool_sync.is_some() => {
+                self.pool_sync = None;
+                self.sync_with_next_peer();
+                should_continue = true;
+            }
         }
         Ok(should_continue)
     }
@@ -310,6 +380,17 @@ impl<P2P, ViewProvider> SharedState<P2P, ViewProvider> {
         self.txpool.lock().find(&ids)
     }
 
+    /// Returns the ids of at most `max_txs` transactions in the pool.
+    pub fn tx_ids(&self, max_txs: usize) -> Vec<TxId> {
+        self.txpool
+            .lock()
+            .txs()
+            .keys()
+            .take(max_txs)
+            .copied()
+            .collect()
+    }
+
     pub fn find_one(&self, id: TxId) -> Option<TxInfo> {
         self.txpool.lock().find_one(&id)
     }
@@ -355,6 +436,81 @@ where
     pub async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_and_maybe_broadcast(txs, true).await
+    }
+}
+
+impl<P2P, ViewProvider> SharedState<P2P, ViewProvider>
+where
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
+    ViewProvider: AtomicView,
+    ViewProvider::View: TxPoolDb,
+{
+    /// Fetches the transactions that are missing in the local pool from the pool of the peer.
+    /// The fetched transactions are validated as submitted ones, but they are not gossiped,
+    /// because the peer already has them.
+    async fn sync_with_peer(self, peer_id: PeerId) {
+        let tx_ids = match self.p2p.request_tx_ids(peer_id.clone()).await {
+            Ok(tx_ids) => tx_ids,
+            Err(e) => {
+                tracing::debug!(
+                    "Unable to get pooled transaction ids from the peer {:?}: {}",
+                    peer_id,
+                    e
+                );
+                return
+            }
+        };
+
+        let missing_tx_ids: Vec<_> = {
+            let pool = self.txpool.lock();
+            let free_slots = self.config.max_tx.saturating_sub(pool.pending_number());
+            tx_ids
+                .into_iter()
+                .filter(|tx_id| pool.find_one(tx_id).is_none())
+                .take(free_slots)
+                .collect()
+        };
+
+        if missing_tx_ids.is_empty() {
+            return
+        }
+
+        let txs = match self
+            .p2p
+            .request_transactions(peer_id.clone(), missing_tx_ids)
+            .await
+        {
+            Ok(txs) => txs,
+            Err(e) => {
+                tracing::debug!(
+                    "Unable to get pooled transactions from the peer {:?}: {}",
+                    peer_id,
+                    e
+                );
+                return
+            }
+        };
+
+        let txs = txs.into_iter().map(Arc::new).collect();
+        let inserted = self
+            .insert_and_maybe_broadcast(txs, false)
+            .await
+            .iter()
+            .filter(|result| result.is_ok())
+            .count();
+        tracing::info!(
+            "Synchronized {} transactions with the pool of the peer {:?}",
+            inserted,
+            peer_id
+        );
+    }
+
+    async fn insert_and_maybe_broadcast(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+        broadcast: bool,
     ) -> Vec<anyhow::Result<InsertionResult>> {
         // verify txs
         let current_height = *self.current_height.lock();
@@ -379,7 +535,7 @@ where
 
         for (ret, tx) in insertion.iter().zip(txs.into_iter()) {
             match ret {
-                Ok(_) => {
+                Ok(_) if broadcast => {
                     let result = self.p2p.broadcast_transaction(tx.clone());
                     if let Err(e) = result {
                         // It can be only in the case of p2p being down or requests overloading it.
@@ -389,7 +545,7 @@ where
                         );
                     }
                 }
-                Err(_) => {}
+                _ => {}
             }
         }
 
@@ -445,11 +601,12 @@ pub fn new_service<P2P, Importer, ViewProvider>(
 where
     Importer: BlockImporter,
     P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
-    ViewProvider: AtomicView,
+    ViewProvider: AtomicView + 'static,
     ViewProvider::View: TxPoolDb,
 {
     let p2p = Arc::new(p2p);
     let gossiped_tx_stream = p2p.gossiped_transaction_events();
+    let new_peers_stream = p2p.new_connected_peers();
     let committed_block_stream = importer.block_events();
     let mut ttl_timer = tokio::time::interval(config.transaction_ttl);
     ttl_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
@@ -459,6 +616,10 @@ where
     let task = Task {
         gossiped_tx_stream,
         committed_block_stream,
+        new_peers_stream,
+        peers_to_sync: VecDeque::new(),
+        synced_peers: VecDeque::new(),
+        pool_sync: None,
         shared: SharedState {
             tx_status_sender: TxStatusChange::new(
                 number_of_active_subscription,
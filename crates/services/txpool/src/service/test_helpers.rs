@@ -24,7 +24,10 @@ use fuel_core_types::{
     },
     services::{
         block_importer::ImportResult,
-        p2p::GossipsubMessageAcceptance,
+        p2p::{
+            GossipsubMessageAcceptance,
+            PeerId,
+        },
     },
 };
 use std::cell::RefCell;
@@ -67,6 +70,7 @@ impl TestContext {
 mockall::mock! {
     pub P2P {}
 
+    #[async_trait::async_trait]
     impl PeerToPeer for P2P {
         type GossipedTransaction = GossipedTransaction;
 
@@ -79,11 +83,25 @@ mockall::mock! {
             message_info: GossipsubMessageInfo,
             validity: GossipsubMessageAcceptance,
         ) -> anyhow::Result<()>;
+
+        fn new_connected_peers(&self) -> BoxStream<PeerId>;
+
+        async fn request_tx_ids(&self, peer_id: PeerId) -> anyhow::Result<Vec<TxId>>;
+
+        async fn request_transactions(
+            &self,
+            peer_id: PeerId,
+            tx_ids: Vec<TxId>,
+        ) -> anyhow::Result<Vec<Transaction>>;
     }
 }
 
 impl MockP2P {
     pub fn new_with_txs(txs: Vec<Transaction>) -> Self {
+        Self::new_with_txs_and_peers(txs, vec![])
+    }
+
+    pub fn new_with_txs_and_peers(txs: Vec<Transaction>, peers: Vec<PeerId>) -> Self {
         let mut p2p = MockP2P::default();
         p2p.expect_gossiped_transaction_events().returning(move || {
             let txs_clone = txs.clone();
@@ -97,6 +115,11 @@ impl MockP2P {
             });
             Box::pin(stream)
         });
+        p2p.expect_new_connected_peers().returning(move || {
+            let stream =
+                futures::stream::iter(peers.clone()).chain(futures::stream::pending());
+            Box::pin(stream)
+        });
         p2p.expect_broadcast_transaction()
             .returning(move |_| Ok(()));
         p2p
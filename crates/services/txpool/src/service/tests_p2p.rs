@@ -10,6 +10,10 @@ use fuel_core_types::fuel_tx::{
 };
 use std::{
     ops::Deref,
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
     time::Duration,
 };
 
@@ -133,3 +137,112 @@ async fn test_insert_from_p2p_does_not_broadcast_to_p2p() {
         "expected a timeout because no broadcast should have occurred"
     )
 }
+
+#[tokio::test]
+async fn missing_transactions_are_fetched_from_the_pool_of_new_peer() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let known_tx = ctx_builder.setup_script_tx(10);
+    let missing_tx = ctx_builder.setup_script_tx(20);
+    let known_tx_id = known_tx.id(&Default::default());
+    let missing_tx_id = missing_tx.id(&Default::default());
+    let peer_id = PeerId::from(vec![1]);
+
+    let mut p2p = MockP2P::new_with_txs_and_peers(vec![], vec![peer_id.clone()]);
+    p2p.expect_request_tx_ids()
+        .returning(move |_| Ok(vec![known_tx_id, missing_tx_id]));
+    let tx = missing_tx.clone();
+    p2p.expect_request_transactions()
+        .withf(move |_, tx_ids| tx_ids == &vec![missing_tx_id])
+        .times(1)
+        .returning(move |_, _| Ok(vec![tx.clone()]));
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    let result = service.shared.insert(vec![Arc::new(known_tx)]).await;
+    assert!(result[0].is_ok());
+    let mut receiver = service.shared.tx_update_subscribe(missing_tx_id).unwrap();
+
+    // when
+    service.start_and_await().await.unwrap();
+
+    // then
+    let res = receiver.next().await;
+    assert!(matches!(
+        res,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+    ));
+    let out = service.shared.find(vec![known_tx_id, missing_tx_id]);
+    assert!(out.iter().all(Option::is_some));
+}
+
+#[tokio::test]
+async fn failed_sync_with_peer_doesnt_block_sync_with_next_peer() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx1 = ctx_builder.setup_script_tx(10);
+    let tx1_id = tx1.id(&Default::default());
+    let failing_peer = PeerId::from(vec![1]);
+    let peer = PeerId::from(vec![2]);
+
+    let mut p2p =
+        MockP2P::new_with_txs_and_peers(vec![], vec![failing_peer.clone(), peer]);
+    p2p.expect_request_tx_ids().returning(move |peer_id| {
+        if peer_id == failing_peer {
+            Err(anyhow!("The peer doesn't support the request"))
+        } else {
+            Ok(vec![tx1_id])
+        }
+    });
+    p2p.expect_request_transactions()
+        .returning(move |_, _| Ok(vec![tx1.clone()]));
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    let mut receiver = service.shared.tx_update_subscribe(tx1_id).unwrap();
+
+    // when
+    service.start_and_await().await.unwrap();
+
+    // then
+    let res = receiver.next().await;
+    assert!(matches!(
+        res,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn reconnected_peer_is_synced_once() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx1 = ctx_builder.setup_script_tx(10);
+    let tx1_id = tx1.id(&Default::default());
+    let peer = PeerId::from(vec![1]);
+    let requests = Arc::new(AtomicUsize::new(0));
+
+    let mut p2p = MockP2P::new_with_txs_and_peers(vec![], vec![peer.clone(); 3]);
+    let requests_clone = requests.clone();
+    p2p.expect_request_tx_ids().returning(move |_| {
+        requests_clone.fetch_add(1, Ordering::SeqCst);
+        Ok(vec![tx1_id])
+    });
+    p2p.expect_request_transactions()
+        .returning(move |_, _| Ok(vec![tx1.clone()]));
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    let mut receiver = service.shared.tx_update_subscribe(tx1_id).unwrap();
+
+    // when
+    service.start_and_await().await.unwrap();
+
+    // then
+    let res = receiver.next().await;
+    assert!(matches!(
+        res,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+    ));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
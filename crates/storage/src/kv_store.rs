@@ -39,13 +39,15 @@ pub trait KeyValueStore {
     }
 
     /// Put the `Value` into the storage and return the old value.
+    ///
+    /// The default implementation is not atomic. The storage that can be used
+    /// concurrently should override it.
     fn replace(
         &self,
         key: &[u8],
         column: Self::Column,
         value: Value,
     ) -> StorageResult<Option<Value>> {
-        // FIXME: This is a race condition. We should use a transaction.
         let old_value = self.get(key, column)?;
         self.put(key, column, value)?;
         Ok(old_value)
@@ -56,13 +58,41 @@ pub trait KeyValueStore {
         -> StorageResult<usize>;
 
     /// Removes the value from the storage and returns it.
+    ///
+    /// The default implementation is not atomic. The storage that can be used
+    /// concurrently should override it.
     fn take(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
-        // FIXME: This is a race condition. We should use a transaction.
         let old_value = self.get(key, column)?;
         self.delete(key, column)?;
         Ok(old_value)
     }
 
+    /// Replaces the value under the `key` with the `new` value only if the current
+    /// value is equal to the `expected` one. `None` means the absence of the value,
+    /// so the `new` equal to `None` removes the value.
+    ///
+    /// Returns `Ok(())` if the value was swapped, otherwise returns the current value.
+    ///
+    /// The default implementation is not atomic. The storage that can be used
+    /// concurrently should override it.
+    fn compare_and_swap(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        expected: Option<&[u8]>,
+        new: Option<Value>,
+    ) -> StorageResult<Result<(), Option<Value>>> {
+        let current = self.get(key, column)?;
+        if current.as_deref().map(Vec::as_slice) != expected {
+            return Ok(Err(current))
+        }
+        match new {
+            Some(value) => self.put(key, column, value)?,
+            None => self.delete(key, column)?,
+        }
+        Ok(Ok(()))
+    }
+
     /// Removes the value from the storage.
     fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()>;
 
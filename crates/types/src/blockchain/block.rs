@@ -138,6 +138,21 @@ impl<T> Block<T> {
             }) => (header, transactions),
         }
     }
+
+    /// Creates the block without transactions. It is imported by
+    /// the light client that doesn't download transactions of the block.
+    pub fn header_only(header: BlockHeader) -> Self {
+        Block::V1(BlockV1 {
+            header,
+            transactions: vec![],
+        })
+    }
+
+    /// Returns `true` if the block contains only the header,
+    /// while the header commits to some transactions.
+    pub fn is_header_only(&self) -> bool {
+        self.transactions().is_empty() && self.header().transactions_count > 0
+    }
 }
 
 impl CompressedBlock {
@@ -1,6 +1,10 @@
 //! Contract entities
 
-use crate::fuel_tx::TxPointer;
+use crate::{
+    fuel_crypto::Hasher,
+    fuel_tx::TxPointer,
+    fuel_types::Bytes32,
+};
 use fuel_vm_private::fuel_tx::UtxoId;
 
 /// Contains information related to the latest contract utxo
@@ -12,3 +16,75 @@ pub struct ContractUtxoInfo {
     /// the tx pointer to the utxo
     pub tx_pointer: TxPointer,
 }
+
+/// The sibling of the node on the path from the leaf to the root of the sparse merkle tree.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SparseMerkleProofNode {
+    /// The height of the parent node that joins the path and the sibling.
+    pub height: u32,
+    /// The hash of the sibling node.
+    pub sibling: Bytes32,
+}
+
+/// The inclusion proof of the leaf in the sparse merkle tree,
+/// like the contract's state or balances tree.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SparseMerkleProof {
+    /// The root of the tree that the proof is generated against.
+    pub root: Bytes32,
+    /// The siblings on the path from the leaf to the root.
+    pub path: Vec<SparseMerkleProofNode>,
+}
+
+impl SparseMerkleProof {
+    /// Verifies that the `value` is stored under the `key` in the tree with the `root`.
+    /// The `key` and `value` are the encoded bytes used by the storage to build the tree.
+    pub fn verify(&self, key: &[u8], value: &[u8]) -> bool {
+        let leaf_key = Self::leaf_key(key);
+        let mut current = {
+            let mut hasher = Hasher::default();
+            hasher.input([LEAF_PREFIX]);
+            hasher.input(leaf_key);
+            hasher.input(Hasher::hash(value));
+            hasher.digest()
+        };
+
+        for node in &self.path {
+            let Some(is_right) = Self::is_right_child(&leaf_key, node.height) else {
+                return false
+            };
+
+            let mut hasher = Hasher::default();
+            hasher.input([NODE_PREFIX]);
+            if is_right {
+                hasher.input(node.sibling);
+                hasher.input(current);
+            } else {
+                hasher.input(current);
+                hasher.input(node.sibling);
+            }
+            current = hasher.digest();
+        }
+
+        current == self.root
+    }
+
+    /// Returns the leaf key used by the tree for the encoded storage `key`.
+    pub fn leaf_key(key: &[u8]) -> Bytes32 {
+        Hasher::hash(key)
+    }
+
+    /// Returns `true` if the path to the `leaf_key` goes through the right child of
+    /// the node at the `height`. Returns `None` if the `height` is out of the tree.
+    pub fn is_right_child(leaf_key: &Bytes32, height: u32) -> Option<bool> {
+        let depth = MAX_HEIGHT.checked_sub(height)?;
+        let byte = leaf_key.get((depth / 8) as usize)?;
+        Some(byte & (0b1000_0000 >> (depth % 8)) != 0)
+    }
+}
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+const MAX_HEIGHT: u32 = 256;
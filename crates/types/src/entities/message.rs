@@ -234,6 +234,26 @@ pub struct MerkleProof {
     pub proof_index: u64,
 }
 
+/// Proves that the transaction was included in the block
+/// against the `transactions_root` of the block header.
+pub struct TransactionProof {
+    /// Proof that the transaction is contained within the provided block header.
+    pub transaction_proof: MerkleProof,
+    /// The header of the block that contains the transaction.
+    pub block_header: BlockHeader,
+}
+
+/// Proves that the `MessageOut` receipt was produced in the block
+/// against the `message_receipt_root` of the block header.
+pub struct MessageReceiptProof {
+    /// The id of the message produced by the receipt.
+    pub message_id: MessageId,
+    /// Proof that the message id is contained within the provided block header.
+    pub message_proof: MerkleProof,
+    /// The header of the block that contains the receipt.
+    pub block_header: BlockHeader,
+}
+
 /// Proves to da layer that this message was included in a Fuel block.
 pub struct MessageProof {
     /// Proof that message is contained within the provided block header.
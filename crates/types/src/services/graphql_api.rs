@@ -3,6 +3,7 @@
 use crate::fuel_types::{
     Address,
     AssetId,
+    Bytes32,
     ContractId,
 };
 
@@ -21,3 +22,13 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The value of the storage slot of the contract.
+pub struct ContractStorageSlot {
+    /// The contract that owns the slot.
+    pub contract: ContractId,
+    /// The key of the slot.
+    pub key: Bytes32,
+    /// The value stored in the slot.
+    pub value: Bytes32,
+}
@@ -18,6 +18,9 @@ use std::{
 /// Contains types and logic for Peer Reputation
 pub mod peer_reputation;
 
+/// Contains types used by the snap-sync protocol
+pub mod snapshot;
+
 /// List of transactions
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -183,6 +186,28 @@ pub struct PeerInfo {
     pub app_score: f64,
 }
 
+/// The reachability of the node from the rest of the network
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkReachability {
+    /// The status detected by probing the node from other peers
+    pub status: ReachabilityStatus,
+    /// The confirmed external addresses of the node, including UPnP mappings
+    /// and circuits through relays
+    pub external_addresses: Vec<String>,
+}
+
+/// The status of the reachability of the node from the rest of the network
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReachabilityStatus {
+    /// The status is not detected yet
+    #[default]
+    Unknown,
+    /// Other peers can dial the node
+    Public,
+    /// The node is behind the NAT or the firewall and can only dial other peers
+    Private,
+}
+
 /// Contains information from the most recent heartbeat received by the peer
 pub struct HeartbeatData {
     /// The currently reported block height of the peer
@@ -1,3 +1,5 @@
+use tai64::Tai64;
+
 /// PeerScore type used for Peer Reputation
 pub type AppScore = f64;
 
@@ -21,3 +23,31 @@ impl PeerReport for AppScore {
         *self
     }
 }
+
+/// The ban of the peer that prevents connections with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeerBan {
+    /// The reason of the ban.
+    pub reason: String,
+    /// The time when the ban expires, `None` if the ban is permanent.
+    pub expires_at: Option<Tai64>,
+}
+
+impl PeerBan {
+    /// Returns `true` if the ban is expired at the `now` time.
+    pub fn is_expired(&self, now: Tai64) -> bool {
+        self.expires_at
+            .map_or(false, |expires_at| expires_at <= now)
+    }
+}
+
+/// The reputation of the peer that survives restarts of the node.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeerReputation {
+    /// The application score of the peer.
+    pub score: AppScore,
+    /// The active ban of the peer, if any.
+    pub ban: Option<PeerBan>,
+}
@@ -0,0 +1,109 @@
+//! Types used by the snap-sync protocol.
+//!
+//! The snapshot is the state of the node at some block height split into chunks.
+//! Each chunk contains a sorted range of entries of one column. The hashes of all
+//! chunks form a binary merkle tree, and its root is announced in the
+//! [`SnapshotManifest`] together with the header of the block. It allows verifying
+//! every chunk independently of others with the merkle proof.
+
+use crate::{
+    blockchain::SealedBlockHeader,
+    fuel_crypto::Hasher,
+    fuel_merkle::{
+        binary,
+        binary::root_calculator::MerkleRootCalculator,
+        common::ProofSet,
+    },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
+};
+use std::ops::Range;
+
+/// The description of the snapshot available at the peer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotManifest {
+    /// The header of the block at which the snapshot was taken.
+    pub header: SealedBlockHeader,
+    /// The merkle root of the hashes of all chunks.
+    pub root: Bytes32,
+    /// The number of chunks in the snapshot.
+    pub chunks_count: u32,
+}
+
+impl SnapshotManifest {
+    /// Returns the height of the snapshot.
+    pub fn height(&self) -> BlockHeight {
+        *self.header.entity.height()
+    }
+}
+
+/// The request of the range of chunks of the snapshot at the `height`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotChunksRequest {
+    /// The height of the snapshot.
+    pub height: u32,
+    /// The range of indexes of requested chunks.
+    pub range: Range<u32>,
+}
+
+/// The chunk of the snapshot with the proof of inclusion into the snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotChunk {
+    /// The index of the chunk in the snapshot.
+    pub index: u32,
+    /// The column of the database to which entries belong.
+    pub column: u32,
+    /// The sorted encoded key-value pairs.
+    pub entries: Vec<(Vec<u8>, Vec<u8>)>,
+    /// The merkle proof of the chunk hash against the [`SnapshotManifest::root`].
+    pub proof: ProofSet,
+}
+
+impl SnapshotChunk {
+    /// Returns the hash of the chunk content. The proof is not a part of the hash.
+    pub fn hash(&self) -> Bytes32 {
+        chunk_hash(self.index, self.column, &self.entries)
+    }
+
+    /// Verifies that the chunk belongs to the snapshot described by the `manifest`.
+    pub fn verify(&self, manifest: &SnapshotManifest) -> bool {
+        binary::verify(
+            &manifest.root.into(),
+            &self.hash(),
+            &self.proof,
+            u64::from(self.index),
+            u64::from(manifest.chunks_count),
+        )
+    }
+}
+
+/// Calculates the hash of the chunk content.
+pub fn chunk_hash(index: u32, column: u32, entries: &[(Vec<u8>, Vec<u8>)]) -> Bytes32 {
+    let mut hasher = Hasher::default();
+    hasher.input(index.to_be_bytes());
+    hasher.input(column.to_be_bytes());
+    for (key, value) in entries {
+        hasher.input((key.len() as u64).to_be_bytes());
+        hasher.input(key);
+        hasher.input((value.len() as u64).to_be_bytes());
+        hasher.input(value);
+    }
+    hasher.digest()
+}
+
+/// Calculates the root of the snapshot from the hashes of all chunks.
+pub fn snapshot_root<'a, I>(chunk_hashes: I) -> Bytes32
+where
+    I: IntoIterator<Item = &'a Bytes32>,
+{
+    let mut calculator = MerkleRootCalculator::new();
+    for hash in chunk_hashes {
+        calculator.push(hash.as_ref());
+    }
+    calculator.root().into()
+}
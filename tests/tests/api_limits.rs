@@ -0,0 +1,193 @@
+use fuel_core::service::{
+    config::{
+        AuthConfig,
+        QueryLimits,
+        RateLimit,
+        Role,
+    },
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    FuelClient,
+};
+use reqwest::StatusCode;
+
+async fn post_query(srv: &FuelService, api_key: &str, query: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .header("X-Api-Key", api_key)
+        .header("Content-Type", "application/json")
+        .body(serde_json::json!({ "query": query }).to_string())
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn page_size_above_limit_is_rejected() {
+    let mut config = Config::local_node();
+    config.query_limits = QueryLimits {
+        max_page_size: 5,
+        ..Default::default()
+    };
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let request = |results| PaginationRequest {
+        cursor: None,
+        results,
+        direction: PageDirection::Forward,
+    };
+
+    assert!(client.blocks(request(5)).await.is_ok());
+    let Err(err) = client.blocks(request(6)).await else {
+        panic!("The page above the limit should be rejected");
+    };
+    assert!(
+        err.to_string()
+            .contains("The page size `6` exceeds the limit `5`"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn query_deeper_than_limit_is_rejected() {
+    let mut config = Config::local_node();
+    config.query_limits = QueryLimits {
+        max_depth: 2,
+        ..Default::default()
+    };
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let shallow = post_query(&srv, "key", "{ chain { name } }").await;
+    let deep = post_query(&srv, "key", "{ chain { latestBlock { id } } }").await;
+
+    let shallow: serde_json::Value =
+        serde_json::from_str(&shallow.text().await.unwrap()).unwrap();
+    let deep: serde_json::Value =
+        serde_json::from_str(&deep.text().await.unwrap()).unwrap();
+    assert!(shallow.get("errors").is_none(), "{shallow}");
+    assert_eq!(
+        deep["errors"][0]["message"],
+        "The query depth 3 exceeds the limit 2"
+    );
+}
+
+#[tokio::test]
+async fn requests_above_rate_limit_are_rejected() {
+    let mut config = Config::local_node();
+    config.api_rate_limit = Some(RateLimit {
+        requests_per_sec: 1,
+        burst: 2,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let query = "{ chain { name } }";
+
+    assert_eq!(
+        post_query(&srv, "first", query).await.status(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        post_query(&srv, "first", query).await.status(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        post_query(&srv, "first", query).await.status(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+    // The API key can't be verified, so a new key doesn't bypass the limit.
+    assert_eq!(
+        post_query(&srv, "second", query).await.status(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+
+    // The health endpoints are not rate limited.
+    let health = reqwest::get(format!("http://{}/health/live", srv.bound_address))
+        .await
+        .unwrap();
+    assert_eq!(health.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn requests_above_rate_limit_are_rejected_per_api_key() {
+    let mut config = Config::local_node();
+    config.api_rate_limit = Some(RateLimit {
+        requests_per_sec: 1,
+        burst: 2,
+    });
+    config.api_auth = Some(AuthConfig {
+        api_keys: [
+            ("first".to_string(), Role::Public),
+            ("second".to_string(), Role::Public),
+        ]
+        .into_iter()
+        .collect(),
+        jwt_secret: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let query = "{ chain { name } }";
+
+    assert_eq!(
+        post_query(&srv, "first", query).await.status(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        post_query(&srv, "first", query).await.status(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        post_query(&srv, "first", query).await.status(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+    // The clients with the known API keys have their own limits.
+    assert_eq!(
+        post_query(&srv, "second", query).await.status(),
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn batch_requests_are_charged_per_request() {
+    let mut config = Config::local_node();
+    config.json_rpc = true;
+    config.api_rate_limit = Some(RateLimit {
+        requests_per_sec: 1,
+        burst: 4,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let post = |path: &str, body: serde_json::Value| {
+        reqwest::Client::new()
+            .post(format!("http://{}/{path}", srv.bound_address))
+            .header("Content-Type", "application/json")
+            .body(body.to_string())
+            .send()
+    };
+    let query = serde_json::json!({ "query": "{ chain { name } }" });
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "fuel_latestBlockHeight",
+        "id": 1
+    });
+
+    // Only the HTTP request itself is charged when the rest of the batch is rejected.
+    let batch = serde_json::json!([query, query, query, query, query]);
+    assert_eq!(
+        post("graphql", batch).await.unwrap().status(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+    let batch = serde_json::json!([request, request, request, request]);
+    let response: serde_json::Value =
+        post("jsonrpc", batch).await.unwrap().json().await.unwrap();
+    assert_eq!(response["error"]["code"], -32005, "{response}");
+
+    let batch = serde_json::json!([query, query]);
+    let response = post("graphql", batch).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(response.as_array().map(Vec::len), Some(2), "{response}");
+}
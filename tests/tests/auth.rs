@@ -0,0 +1,129 @@
+use fuel_core::service::{
+    config::{
+        AuthConfig,
+        Role,
+    },
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    UniqueIdentifier,
+};
+use reqwest::StatusCode;
+use std::net::{
+    Ipv4Addr,
+    SocketAddr,
+};
+
+async fn node_with_auth() -> FuelService {
+    let mut config = Config::local_node();
+    config.admin_addr = Some(SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0));
+    config.api_auth = Some(AuthConfig {
+        api_keys: [
+            ("submitter-key".to_string(), Role::Submitter),
+            ("admin-key".to_string(), Role::Admin),
+        ]
+        .into_iter()
+        .collect(),
+        jwt_secret: None,
+    });
+    FuelService::new_node(config).await.unwrap()
+}
+
+#[tokio::test]
+async fn produce_blocks_requires_admin_role() {
+    let srv = node_with_auth().await;
+    let public = FuelClient::from(srv.bound_address);
+    let submitter = public.clone().with_api_key("submitter-key");
+    let admin = public.clone().with_api_key("admin-key");
+    let admin_listener =
+        FuelClient::from(srv.shared.graph_ql.admin_bound_address.unwrap());
+
+    for client in [public, submitter] {
+        let err = client.produce_blocks(1, None).await.unwrap_err();
+        assert!(
+            err.to_string().contains("The `admin` role is required"),
+            "{err}"
+        );
+    }
+    assert_eq!(*admin.produce_blocks(1, None).await.unwrap(), 1);
+    assert_eq!(*admin_listener.produce_blocks(1, None).await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn submit_requires_submitter_role() {
+    let srv = node_with_auth().await;
+    let public = FuelClient::from(srv.bound_address);
+    let submitter = public.clone().with_api_key("submitter-key");
+    let tx = Transaction::default_test_tx();
+
+    let err = public.submit(&tx).await.unwrap_err();
+    assert!(
+        err.to_string().contains("The `submitter` role is required"),
+        "{err}"
+    );
+    submitter.submit_and_await_commit(&tx).await.unwrap();
+
+    // Queries are available to all clients.
+    assert!(public
+        .transaction(&tx.id(&Default::default()))
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn unknown_api_key_is_unauthorized() {
+    let srv = node_with_auth().await;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .header("X-Api-Key", "unknown-key")
+        .header("Content-Type", "application/json")
+        .body(r#"{ "query": "{ chain { name } }" }"#)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn all_clients_are_admins_without_auth() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    assert_eq!(*client.produce_blocks(1, None).await.unwrap(), 1);
+    assert!(srv.shared.graph_ql.admin_bound_address.is_none());
+}
+
+#[tokio::test]
+async fn only_public_listener_allows_configured_cors_origin() {
+    let mut config = Config::local_node();
+    config.admin_addr = Some(SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0));
+    config.api_cors_origin = "https://app.fuel.network".to_string();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let cors_origin = |address: SocketAddr| async move {
+        let response = reqwest::Client::new()
+            .post(format!("http://{address}/graphql"))
+            .header("Content-Type", "application/json")
+            .body(r#"{ "query": "{ chain { name } }" }"#)
+            .send()
+            .await
+            .unwrap();
+        response
+            .headers()
+            .get("access-control-allow-origin")
+            .map(|origin| origin.to_str().unwrap().to_string())
+    };
+
+    assert_eq!(
+        cors_origin(srv.bound_address).await.as_deref(),
+        Some("https://app.fuel.network")
+    );
+    assert_eq!(
+        cors_origin(srv.shared.graph_ql.admin_bound_address.unwrap()).await,
+        None
+    );
+}
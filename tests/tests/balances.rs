@@ -239,3 +239,89 @@ async fn first_5_balances() {
         assert_eq!(balances[i].amount, 300);
     }
 }
+
+#[tokio::test]
+async fn balances_are_paginated_in_both_directions() {
+    let owner = Address::from([10u8; 32]);
+    let asset_ids = (0..5u8)
+        .map(|i| AssetId::new([i; 32]))
+        .collect::<Vec<AssetId>>();
+
+    let coins = asset_ids
+        .iter()
+        .flat_map(|asset_id| [(50, *asset_id), (100, *asset_id)])
+        .map(|(amount, asset_id)| CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner,
+            amount,
+            asset_id,
+        })
+        .collect();
+    let messages = vec![MessageConfig {
+        sender: owner,
+        recipient: owner,
+        nonce: 0u64.into(),
+        amount: 60,
+        data: vec![],
+        da_height: DaBlockHeight::from(1usize),
+    }];
+
+    // setup config
+    let mut config = Config::local_node();
+    config.chain_conf.initial_state = Some(StateConfig {
+        height: None,
+        contracts: None,
+        coins: Some(coins),
+        messages: Some(messages),
+    });
+
+    // setup server & client
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    for direction in [PageDirection::Forward, PageDirection::Backward] {
+        let mut cursor = None;
+        let mut balances = vec![];
+        loop {
+            let page = client
+                .balances(
+                    &owner,
+                    PaginationRequest {
+                        cursor,
+                        results: 2,
+                        direction,
+                    },
+                )
+                .await
+                .unwrap();
+            balances.extend(page.results);
+            cursor = page.cursor;
+            if !page.has_next_page {
+                break
+            }
+        }
+
+        let mut expected = asset_ids.clone();
+        if direction == PageDirection::Backward {
+            expected.reverse();
+        }
+        let actual = balances
+            .iter()
+            .map(|balance| balance.asset_id)
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+
+        // Base asset includes the message = 50 + 100 + 60
+        for balance in balances {
+            if balance.asset_id == AssetId::BASE {
+                assert_eq!(balance.amount, 210);
+            } else {
+                assert_eq!(balance.amount, 150);
+            }
+        }
+    }
+}
@@ -101,6 +101,132 @@ async fn test_5_contract_balances(
     }
 }
 
+#[rstest]
+#[tokio::test]
+async fn contract_storage_slots_with_proofs(
+    #[values(PageDirection::Forward, PageDirection::Backward)] direction: PageDirection,
+) {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], None, None, None);
+    let slots = vec![
+        (Bytes32::new([0xaa; 32]), Bytes32::new([1; 32])),
+        (Bytes32::new([0xbb; 32]), Bytes32::new([2; 32])),
+        (
+            Bytes32::new([[0xaa; 16], [0xcc; 16]].concat().try_into().unwrap()),
+            Bytes32::new([3; 32]),
+        ),
+    ];
+    test_builder.contracts.get_mut(&contract_id).unwrap().state = Some(slots.clone());
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // every slot is proven against the same state root
+    let mut roots = vec![];
+    for (key, value) in &slots {
+        let slot = client
+            .contract_storage_slot(&contract_id, key)
+            .await
+            .unwrap()
+            .expect("The slot should exist");
+        assert_eq!(slot.slot.value, *value);
+        assert!(slot.verify());
+        roots.push(slot.proof.root);
+    }
+    roots.dedup();
+    assert_eq!(roots.len(), 1);
+
+    // the proof doesn't work for another value
+    let mut slot = client
+        .contract_storage_slot(&contract_id, &slots[0].0)
+        .await
+        .unwrap()
+        .unwrap();
+    slot.slot.value = Bytes32::new([9; 32]);
+    assert!(!slot.verify());
+
+    let missing = client
+        .contract_storage_slot(&contract_id, &Bytes32::new([0xdd; 32]))
+        .await
+        .unwrap();
+    assert!(missing.is_none());
+
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction,
+    };
+    let all_slots = client
+        .contract_storage_slots(&contract_id, None, request.clone())
+        .await
+        .unwrap();
+    assert_eq!(all_slots.results.len(), 3);
+
+    let prefixed_slots = client
+        .contract_storage_slots(&contract_id, Some(&[0xaa; 16]), request.clone())
+        .await
+        .unwrap();
+    let mut keys = prefixed_slots
+        .results
+        .iter()
+        .map(|slot| slot.key)
+        .collect::<Vec<_>>();
+    if direction == PageDirection::Backward {
+        keys.reverse();
+    }
+    assert_eq!(keys, vec![slots[0].0, slots[2].0]);
+
+    let too_long_prefix = client
+        .contract_storage_slots(&contract_id, Some(&[0xaa; 33]), request)
+        .await;
+    assert!(too_long_prefix.is_err());
+}
+
+#[tokio::test]
+async fn contract_balances_with_proofs() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let balances = vec![
+        (AssetId::new([1u8; 32]), 1000),
+        (AssetId::new([2u8; 32]), 400),
+        (AssetId::new([3u8; 32]), 700),
+    ];
+    let (_, contract_id) =
+        test_builder.setup_contract(vec![], Some(balances.clone()), None, None);
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let mut roots = vec![];
+    for (asset_id, amount) in &balances {
+        let mut balance = client
+            .contract_balance_with_proof(&contract_id, asset_id)
+            .await
+            .unwrap();
+        assert_eq!(balance.balance.amount, *amount);
+        assert!(balance.verify());
+        roots.push(balance.proof.as_ref().unwrap().root);
+
+        // the proof doesn't work for another amount
+        balance.balance.amount += 1;
+        assert!(!balance.verify());
+    }
+    roots.dedup();
+    assert_eq!(roots.len(), 1);
+
+    let missing = client
+        .contract_balance_with_proof(&contract_id, &AssetId::new([4u8; 32]))
+        .await
+        .unwrap();
+    assert_eq!(missing.balance.amount, 0);
+    assert!(missing.proof.is_none());
+}
+
 fn key(i: u8) -> Bytes32 {
     Bytes32::new(
         [0u8; 31]
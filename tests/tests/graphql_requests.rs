@@ -0,0 +1,145 @@
+use cynic::QueryBuilder;
+use fuel_core::service::{
+    config::QueryLimits,
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    schema::chain::ChainQuery,
+    FuelClient,
+};
+use fuel_core_types::fuel_crypto::Hasher;
+use serde_json::{
+    json,
+    Value,
+};
+
+const QUERY: &str = "{ chain { name } }";
+
+async fn post(srv: &FuelService, body: Value) -> Value {
+    reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .header("Content-Type", "application/json")
+        .body(body.to_string())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+fn persisted_query(hash: &str) -> Value {
+    json!({ "persistedQuery": { "version": 1, "sha256Hash": hash } })
+}
+
+#[tokio::test]
+async fn batch_request_returns_response_per_query() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let chain_name = client.chain_info().await.unwrap().name;
+
+    let results = client
+        .query_batch(vec![
+            ChainQuery::build(()),
+            ChainQuery::build(()),
+            ChainQuery::build(()),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 3);
+    for result in results {
+        assert_eq!(result.unwrap().chain.name, chain_name);
+    }
+}
+
+#[tokio::test]
+async fn batch_above_limit_is_rejected() {
+    let mut config = Config::local_node();
+    config.query_limits = QueryLimits {
+        max_batch_size: 2,
+        ..Default::default()
+    };
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    assert!(client
+        .query_batch(vec![ChainQuery::build(()), ChainQuery::build(())])
+        .await
+        .is_ok());
+    let err = client
+        .query_batch(vec![
+            ChainQuery::build(()),
+            ChainQuery::build(()),
+            ChainQuery::build(()),
+        ])
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("The batch size `3` exceeds the limit `2`"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn persisted_query_is_executed_by_hash_after_registration() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let hash = format!("{:x}", Hasher::hash(QUERY));
+
+    // The unknown hash is rejected, so the client sends the query along with the hash.
+    let not_found = post(&srv, json!({ "extensions": persisted_query(&hash) })).await;
+    assert_eq!(not_found["errors"][0]["message"], "PersistedQueryNotFound");
+    let registered = post(
+        &srv,
+        json!({ "query": QUERY, "extensions": persisted_query(&hash) }),
+    )
+    .await;
+    let persisted = post(&srv, json!({ "extensions": persisted_query(&hash) })).await;
+
+    assert!(registered.get("errors").is_none(), "{registered}");
+    assert_eq!(persisted, registered);
+
+    let metrics = reqwest::get(format!("http://{}/metrics", srv.bound_address))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(
+        metrics
+            .contains("graphql_query_parse_duration_seconds_count{persisted=\"true\"}"),
+        "{metrics}"
+    );
+}
+
+#[tokio::test]
+async fn persisted_query_with_wrong_hash_is_rejected() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let hash = format!("{:x}", Hasher::hash("{ chain { daHeight } }"));
+
+    let response = post(
+        &srv,
+        json!({ "query": QUERY, "extensions": persisted_query(&hash) }),
+    )
+    .await;
+
+    assert_eq!(
+        response["errors"][0]["message"],
+        "The `sha256Hash` doesn't match the query"
+    );
+}
+
+#[tokio::test]
+async fn persisted_queries_can_be_disabled() {
+    let mut config = Config::local_node();
+    config.persisted_queries_cache_size = 0;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let hash = format!("{:x}", Hasher::hash(QUERY));
+
+    let response = post(&srv, json!({ "extensions": persisted_query(&hash) })).await;
+
+    assert!(response["data"].is_null(), "{response}");
+    assert_ne!(response["errors"][0]["message"], "PersistedQueryNotFound");
+}
@@ -18,6 +18,32 @@ async fn health() {
     assert!(health);
 }
 
+#[tokio::test]
+async fn health_live_and_ready() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+
+    for endpoint in ["live", "ready"] {
+        let response =
+            reqwest::get(format!("http://{}/health/{endpoint}", srv.bound_address))
+                .await
+                .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+        let status: serde_json::Value =
+            serde_json::from_str(&response.text().await.unwrap()).unwrap();
+        assert_eq!(status["live"], true);
+        assert_eq!(status["ready"], true);
+        assert_eq!(status["database"]["reachable"], true);
+        assert_eq!(status["sync"]["local_height"], 0);
+        let services = status["services"].as_array().unwrap();
+        assert!(services
+            .iter()
+            .any(|service| service["name"] == "TxPool" && service["state"] == "started"));
+    }
+}
+
 #[cfg(feature = "default")]
 #[tokio::test]
 async fn can_restart_node() {
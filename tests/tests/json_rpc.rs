@@ -0,0 +1,326 @@
+use fuel_core::service::{
+    config::{
+        AuthConfig,
+        RateLimit,
+        Role,
+    },
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        UniqueIdentifier,
+    },
+    fuel_types::canonical::Serialize,
+};
+use futures::{
+    SinkExt,
+    StreamExt,
+};
+use reqwest::StatusCode;
+use serde_json::{
+    json,
+    Value,
+};
+use tokio_tungstenite::{
+    tungstenite::Message,
+    MaybeTlsStream,
+    WebSocketStream,
+};
+
+type Socket = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn node_with_json_rpc() -> FuelService {
+    let mut config = Config::local_node();
+    config.json_rpc = true;
+    FuelService::new_node(config).await.unwrap()
+}
+
+async fn post(srv: &FuelService, body: Value) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("http://{}/jsonrpc", srv.bound_address))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+}
+
+async fn call(srv: &FuelService, method: &str, params: Value) -> Value {
+    let response = post(
+        srv,
+        json!({ "jsonrpc": "2.0", "method": method, "params": params, "id": 1 }),
+    )
+    .await;
+    let response: Value = response.json().await.unwrap();
+    assert!(response.get("error").is_none(), "{response}");
+    response["result"].clone()
+}
+
+async fn connect(srv: &FuelService) -> Socket {
+    let (socket, _) =
+        tokio_tungstenite::connect_async(format!("ws://{}/jsonrpc", srv.bound_address))
+            .await
+            .unwrap();
+    socket
+}
+
+async fn request(socket: &mut Socket, method: &str, params: Value) -> Value {
+    let request =
+        json!({ "jsonrpc": "2.0", "method": method, "params": params, "id": 1 });
+    socket
+        .send(Message::Text(request.to_string()))
+        .await
+        .unwrap();
+    match socket.next().await.unwrap().unwrap() {
+        Message::Text(text) => serde_json::from_str(&text).unwrap(),
+        message => panic!("Unexpected message {message:?}"),
+    }
+}
+
+fn hex(bytes: Vec<u8>) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+#[tokio::test]
+async fn json_rpc_is_disabled_by_default() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+
+    let response = post(
+        &srv,
+        json!({ "jsonrpc": "2.0", "method": "fuel_latestBlockHeight", "id": 1 }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn submitted_transaction_is_queryable_over_http() {
+    let srv = node_with_json_rpc().await;
+    let client = FuelClient::from(srv.bound_address);
+    let tx = Transaction::default_test_tx();
+    let tx_id = tx.id(&Default::default());
+
+    let id = call(&srv, "fuel_submit", json!([hex(tx.to_bytes())])).await;
+    assert_eq!(id, format!("{tx_id:#x}"));
+    client.await_transaction_commit(&tx_id).await.unwrap();
+
+    let status = call(&srv, "fuel_transactionStatus", json!([id])).await;
+    assert_eq!(status["type"], "success");
+    let height = call(&srv, "fuel_latestBlockHeight", json!([])).await;
+    assert_eq!(height, 1);
+    let block = call(&srv, "fuel_blockByHeight", json!([1])).await;
+    assert_eq!(block["height"], 1);
+    assert!(block["transactionIds"].as_array().unwrap().contains(&id));
+    let block_by_id = call(&srv, "fuel_blockById", json!([block["id"]])).await;
+    assert_eq!(block_by_id, block);
+    let transaction = call(&srv, "fuel_transaction", json!([id])).await;
+    assert_eq!(transaction["id"], id);
+    assert_eq!(transaction["status"], status);
+    let receipts = call(&srv, "fuel_receipts", json!([id])).await;
+    assert!(!receipts.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn missing_entities_are_null() {
+    let srv = node_with_json_rpc().await;
+    let unknown = format!("0x{}", "00".repeat(32));
+
+    assert_eq!(
+        call(&srv, "fuel_blockByHeight", json!([100])).await,
+        Value::Null
+    );
+    assert_eq!(
+        call(&srv, "fuel_transaction", json!([unknown])).await,
+        Value::Null
+    );
+    assert_eq!(
+        call(&srv, "fuel_getBalance", json!([unknown, unknown])).await,
+        "0"
+    );
+}
+
+#[tokio::test]
+async fn batch_responds_to_each_request_except_notifications() {
+    let srv = node_with_json_rpc().await;
+
+    let response = post(
+        &srv,
+        json!([
+            { "jsonrpc": "2.0", "method": "fuel_latestBlockHeight", "id": 1 },
+            { "jsonrpc": "2.0", "method": "fuel_unknown", "id": 2 },
+            { "jsonrpc": "2.0", "method": "fuel_blockByHeight", "params": ["a"], "id": 3 },
+            { "jsonrpc": "2.0", "method": "fuel_latestBlockHeight" },
+        ]),
+    )
+    .await;
+    let response: Value = response.json().await.unwrap();
+
+    let response = response.as_array().unwrap();
+    assert_eq!(response.len(), 3);
+    assert_eq!(response[0]["result"], 0);
+    assert_eq!(response[1]["error"]["code"], -32601);
+    assert_eq!(response[2]["error"]["code"], -32602);
+
+    let notification = post(
+        &srv,
+        json!({ "jsonrpc": "2.0", "method": "fuel_latestBlockHeight" }),
+    )
+    .await;
+    assert_eq!(notification.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn batch_above_the_limit_is_rejected() {
+    let mut config = Config::local_node();
+    config.json_rpc = true;
+    config.query_limits.max_batch_size = 2;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let request =
+        json!({ "jsonrpc": "2.0", "method": "fuel_latestBlockHeight", "id": 1 });
+
+    let allowed: Value = post(&srv, json!([request, request]))
+        .await
+        .json()
+        .await
+        .unwrap();
+    let rejected: Value = post(&srv, json!([request, request, request]))
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(allowed.as_array().unwrap().len(), 2);
+    assert_eq!(rejected["error"]["code"], -32600);
+}
+
+#[tokio::test]
+async fn submit_requires_submitter_role() {
+    let mut config = Config::local_node();
+    config.json_rpc = true;
+    config.api_auth = Some(AuthConfig {
+        api_keys: [("submitter-key".to_string(), Role::Submitter)]
+            .into_iter()
+            .collect(),
+        jwt_secret: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let body = json!({
+        "jsonrpc": "2.0",
+        "method": "fuel_submit",
+        "params": [hex(Transaction::default_test_tx().to_bytes())],
+        "id": 1,
+    });
+
+    let public: Value = post(&srv, body.clone()).await.json().await.unwrap();
+    let submitter: Value = reqwest::Client::new()
+        .post(format!("http://{}/jsonrpc", srv.bound_address))
+        .header("X-Api-Key", "submitter-key")
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(public["error"]["code"], -32001);
+    assert!(submitter["result"].is_string(), "{submitter}");
+}
+
+#[tokio::test]
+async fn submit_and_await_notifies_subscriber_over_websocket() {
+    let srv = node_with_json_rpc().await;
+    let (mut socket, _) =
+        tokio_tungstenite::connect_async(format!("ws://{}/jsonrpc", srv.bound_address))
+            .await
+            .unwrap();
+    let tx = Transaction::default_test_tx();
+    let request = json!({
+        "jsonrpc": "2.0",
+        "method": "fuel_submitAndAwait",
+        "params": [hex(tx.to_bytes())],
+        "id": 1,
+    });
+    socket
+        .send(Message::Text(request.to_string()))
+        .await
+        .unwrap();
+
+    let mut messages = vec![];
+    while messages.len() < 2 {
+        match socket.next().await.unwrap().unwrap() {
+            Message::Text(text) => {
+                messages.push(serde_json::from_str::<Value>(&text).unwrap())
+            }
+            message => panic!("Unexpected message {message:?}"),
+        }
+    }
+
+    let subscription = &messages[0]["result"];
+    assert!(subscription.is_u64(), "{}", messages[0]);
+    assert_eq!(messages[1]["method"], "fuel_subscription");
+    assert_eq!(&messages[1]["params"]["subscription"], subscription);
+    assert_eq!(messages[1]["params"]["result"]["type"], "success");
+}
+
+#[tokio::test]
+async fn websocket_messages_are_rate_limited() {
+    let mut config = Config::local_node();
+    config.json_rpc = true;
+    config.api_rate_limit = Some(RateLimit {
+        requests_per_sec: 0,
+        burst: 3,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    // The upgrade request takes one token.
+    let mut socket = connect(&srv).await;
+
+    let first = request(&mut socket, "fuel_latestBlockHeight", json!([])).await;
+    let second = request(&mut socket, "fuel_latestBlockHeight", json!([])).await;
+    let third = request(&mut socket, "fuel_latestBlockHeight", json!([])).await;
+
+    assert_eq!(first["result"], 0);
+    assert_eq!(second["result"], 0);
+    assert_eq!(third["error"]["code"], -32005);
+}
+
+#[tokio::test]
+async fn subscriptions_per_connection_are_limited() {
+    let srv = node_with_json_rpc().await;
+    let mut socket = connect(&srv).await;
+    let mut subscriptions = vec![];
+    for i in 0..16u8 {
+        let tx_id = format!("0x{}", hex::encode([i; 32]));
+        let response = request(
+            &mut socket,
+            "fuel_subscribeTransactionStatus",
+            json!([tx_id]),
+        )
+        .await;
+        subscriptions.push(response["result"].clone());
+    }
+    let tx_id = format!("0x{}", "ff".repeat(32));
+
+    let rejected = request(
+        &mut socket,
+        "fuel_subscribeTransactionStatus",
+        json!([tx_id]),
+    )
+    .await;
+    let unsubscribed =
+        request(&mut socket, "fuel_unsubscribe", json!([subscriptions[0]])).await;
+    let accepted = request(
+        &mut socket,
+        "fuel_subscribeTransactionStatus",
+        json!([tx_id]),
+    )
+    .await;
+
+    assert!(subscriptions.iter().all(Value::is_u64), "{subscriptions:?}");
+    assert_eq!(rejected["error"]["code"], -32005);
+    assert_eq!(unsubscribed["result"], true);
+    assert!(accepted["result"].is_u64(), "{accepted}");
+}
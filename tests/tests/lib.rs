@@ -1,6 +1,8 @@
 #![deny(unused_must_use)]
 #![deny(warnings)]
 
+mod api_limits;
+mod auth;
 mod balances;
 mod blocks;
 mod chain;
@@ -13,8 +15,10 @@ mod deployment;
 mod fee_collection_contract;
 
 mod gas_price;
+mod graphql_requests;
 mod health;
 mod helpers;
+mod json_rpc;
 mod messages;
 mod metrics;
 mod node_info;
@@ -571,6 +571,106 @@ async fn can_get_message_proof() {
 }
 
 // TODO: Others test:  Data missing etc.
+#[tokio::test]
+async fn can_get_transaction_and_message_receipt_proofs() {
+    let recipient = Address::from([1; 32]);
+    let amount = 10;
+
+    // Send the message without data to the recipient from the script data.
+    let script: Vec<u8> = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::movi(0x11, amount),
+        op::smo(0x10, RegId::ZERO, RegId::ZERO, 0x11),
+        op::ret(RegId::ONE),
+    ]
+    .iter()
+    .flat_map(|op| u32::from(*op).to_be_bytes())
+    .collect();
+
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner = Input::predicate_owner(&predicate);
+    let coin_input = Input::coin_predicate(
+        Default::default(),
+        owner,
+        1000,
+        AssetId::BASE,
+        TxPointer::default(),
+        Default::default(),
+        Default::default(),
+        predicate,
+        vec![],
+    );
+    let script = Transaction::script(
+        1_000_000,
+        script,
+        recipient.to_vec(),
+        policies::Policies::new().with_gas_price(0),
+        vec![coin_input],
+        vec![],
+        vec![],
+    );
+    let transaction_id = script.id(&ChainId::default());
+
+    // setup server & client
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut script = script.into();
+    client
+        .estimate_predicates(&mut script)
+        .await
+        .expect("Should be able to estimate script tx");
+    let status = client.submit_and_await_commit(&script).await.unwrap();
+    assert!(
+        matches!(status, TransactionStatus::Success { .. }),
+        "{status:?}"
+    );
+
+    // Produce one more block, because we can't create proof for the last block.
+    let last_height = client.produce_blocks(1, None).await.unwrap();
+
+    let receipts = client.receipts(&transaction_id).await.unwrap().unwrap();
+    let message_id = receipts
+        .iter()
+        .find_map(|r| r.message_id())
+        .expect("The script sends the message");
+    let nonce = receipts.iter().find_map(|r| r.nonce()).unwrap();
+    let message_proof = client
+        .message_proof(&transaction_id, nonce, None, Some(last_height))
+        .await
+        .unwrap()
+        .unwrap();
+
+    // The receipt proof is verifiable against the header of the message block.
+    let receipt_proof = client
+        .message_receipt_proof(&transaction_id, nonce)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(receipt_proof.message_id, message_id);
+    assert_eq!(
+        receipt_proof.block_header.id,
+        message_proof.message_block_header.id
+    );
+    assert!(receipt_proof.verify());
+
+    // The executed transaction is provable against the `transactions_root`
+    // of its block, while the submitted one is not.
+    let transaction_proof = client
+        .transaction_proof(&transaction_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let executed_transaction = client
+        .transaction(&transaction_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .transaction;
+    assert!(transaction_proof.verify(&executed_transaction));
+    assert!(!transaction_proof.verify(&script));
+}
+
 fn verify_merkle<D: AsRef<[u8]>>(
     root: Bytes32,
     data: &D,
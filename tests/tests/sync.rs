@@ -1,7 +1,11 @@
 use fuel_core::p2p_test_helpers::*;
+use fuel_core_client::client::FuelClient;
 use fuel_core_types::{
     fuel_crypto::SecretKey,
-    fuel_tx::Input,
+    fuel_tx::{
+        AssetId,
+        Input,
+    },
 };
 use itertools::Itertools;
 use rand::{
@@ -325,3 +329,113 @@ async fn test_multiple_producers_same_key() {
         v.consistency_10s(&expected).await;
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn light_client_syncs_headers_and_fetches_transactions_from_peers() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+
+    // Create a producer and a light client that share the same key pair.
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+    let Nodes {
+        mut producers,
+        mut validators,
+        bootstrap_nodes: _dont_drop,
+    } = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(
+            ProducerSetup::new(secret).with_txs(3).with_name("Alice"),
+        )],
+        [Some(
+            ValidatorSetup::new(pub_key)
+                .with_name("Bob")
+                .light_client(true),
+        )],
+        None,
+    )
+    .await;
+
+    let mut producer = producers.pop().unwrap();
+    let light_client = validators.pop().unwrap();
+
+    let expected = producer.insert_txs().await;
+    producer.consistency_10s(&expected).await;
+    let producer_height = producer.db.latest_height().unwrap();
+
+    // Wait up to 10 seconds for the light client to sync headers.
+    tokio::time::timeout(std::time::Duration::from_secs(10), async {
+        while light_client.db.latest_height().unwrap() < producer_height {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("The light client should sync headers");
+
+    // The light client doesn't store transactions.
+    assert_eq!(light_client.db.all_transactions(None, None).count(), 0);
+
+    // But the headers go through the importer, so the off-chain worker sees them.
+    tokio::time::timeout(std::time::Duration::from_secs(10), async {
+        while light_client
+            .node
+            .shared
+            .database
+            .off_chain()
+            .latest_height()
+            .unwrap_or_default()
+            < producer_height
+        {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("The off-chain worker should process headers");
+
+    // The light client doesn't advertise heights that it can't serve.
+    let producer_client = FuelClient::from(producer.node.bound_address);
+    let peers = producer_client.connected_peers_info().await.unwrap();
+    assert!(peers.iter().all(|info| {
+        info.heartbeat_data.block_height.unwrap_or_default() < producer_height
+    }));
+
+    // The JSON-RPC API isn't served, because its methods need the state too.
+    let response = reqwest::Client::new()
+        .post(format!(
+            "http://{}/jsonrpc",
+            light_client.node.bound_address
+        ))
+        .header("Content-Type", "application/json")
+        .body(
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "fuel_getBalance",
+                "params": [format!("{pub_key:#x}"), format!("{:#x}", AssetId::BASE)],
+                "id": 1
+            })
+            .to_string(),
+        )
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+
+    // The state queries are rejected instead of returning empty results.
+    let light_client = FuelClient::from(light_client.node.bound_address);
+    let error = light_client
+        .balance(&pub_key, None)
+        .await
+        .expect_err("The balance should not be available");
+    assert!(error.to_string().contains("light client mode"));
+
+    // But it fetches transactions of blocks from the producer on demand.
+    for height in 1..=*producer_height {
+        let expected = producer_client
+            .block_by_height(height)
+            .await
+            .unwrap()
+            .unwrap();
+        let block = light_client.block_by_height(height).await.unwrap().unwrap();
+        assert_eq!(block.id, expected.id);
+        assert_eq!(block.transactions, expected.transactions);
+    }
+}
@@ -11,7 +11,18 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    schema::{
+        tx::{
+            TransactionDirection,
+            TransactionOutcome,
+            TransactionsByOwnerFilter,
+        },
+        Tai64Timestamp,
+    },
+    types::{
+        BalanceChange,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_poa::service::Mode;
@@ -20,6 +31,7 @@ use fuel_core_types::{
     fuel_crypto::SecretKey,
     fuel_tx::*,
     fuel_types::ChainId,
+    tai64::Tai64,
 };
 use itertools::Itertools;
 use rand::{
@@ -650,6 +662,93 @@ async fn get_owned_transactions() {
     assert_eq!(&charlie_txs, &[tx1, tx2, tx3]);
 }
 
+#[tokio::test]
+async fn get_owned_transactions_filtered_with_balance_changes() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    let tx1 = context.transfer(alice, charlie, 1).await.unwrap();
+    let tx2 = context.transfer(charlie, bob, 2).await.unwrap();
+    let tx3 = context.transfer(bob, charlie, 3).await.unwrap();
+
+    let client = context.client;
+    let charlie_txs = |filter: TransactionsByOwnerFilter| {
+        let client = &client;
+        async move {
+            client
+                .transactions_by_owner_filtered(
+                    &charlie,
+                    filter,
+                    PaginationRequest {
+                        cursor: None,
+                        results: 5,
+                        direction: PageDirection::Forward,
+                    },
+                )
+                .await
+                .unwrap()
+                .results
+                .into_iter()
+                .map(|tx| {
+                    (
+                        tx.transaction.transaction.id(&ChainId::default()),
+                        tx.balance_changes,
+                    )
+                })
+                .collect_vec()
+        }
+    };
+    let change = |amount| {
+        vec![BalanceChange {
+            asset_id: AssetId::default(),
+            amount,
+        }]
+    };
+
+    let sent = charlie_txs(TransactionsByOwnerFilter {
+        direction: Some(TransactionDirection::Sent),
+        ..Default::default()
+    })
+    .await;
+    let received = charlie_txs(TransactionsByOwnerFilter {
+        direction: Some(TransactionDirection::Received),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(sent, vec![(tx2, change(-2))]);
+    assert_eq!(received, vec![(tx1, change(1)), (tx3, change(3))]);
+
+    let succeeded = charlie_txs(TransactionsByOwnerFilter {
+        asset_id: Some(AssetId::default().into()),
+        to_time: Some(Tai64Timestamp(Tai64::now() + 3600)),
+        status: Some(TransactionOutcome::Success),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(succeeded.len(), 3);
+
+    let other_asset = charlie_txs(TransactionsByOwnerFilter {
+        asset_id: Some(AssetId::from([1; 32]).into()),
+        ..Default::default()
+    })
+    .await;
+    let failed = charlie_txs(TransactionsByOwnerFilter {
+        status: Some(TransactionOutcome::Failure),
+        ..Default::default()
+    })
+    .await;
+    let future = charlie_txs(TransactionsByOwnerFilter {
+        from_time: Some(Tai64Timestamp(Tai64::now() + 3600)),
+        ..Default::default()
+    })
+    .await;
+    assert!(other_asset.is_empty());
+    assert!(failed.is_empty());
+    assert!(future.is_empty());
+}
+
 // add random val for unique tx
 fn create_mock_tx(val: u64) -> Transaction {
     let mut rng = StdRng::seed_from_u64(val);